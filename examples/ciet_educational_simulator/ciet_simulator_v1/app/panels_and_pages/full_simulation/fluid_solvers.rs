@@ -1,11 +1,6 @@
-use std::ops::Deref;
-use std::ops::DerefMut;
-use std::sync::Arc;
-use std::sync::Mutex;
 use std::thread;
 
-use tuas_boussinesq_solver::pre_built_components::ciet_three_branch_plus_dracs::solver_functions::get_mass_flowrate_two_branches;
-use tuas_boussinesq_solver::pre_built_components::ciet_three_branch_plus_dracs::solver_functions::get_mass_flowrate_vector_for_dhx_heater_and_ctah_branches;
+use tuas_boussinesq_solver::pre_built_components::ciet_three_branch_plus_dracs::solver_functions::try_get_branch_mass_flowrates_with_previous_solution;
 use tuas_boussinesq_solver::prelude::beta_testing::InsulatedPorousMediaFluidComponent;
 // first, copy and paste the dracs loop functions over 
 //
@@ -24,6 +19,11 @@ use tuas_boussinesq_solver::
 array_control_vol_and_fluid_component_collections::
 fluid_component_collection::
 fluid_component_super_collection::FluidComponentSuperCollection;
+use tuas_boussinesq_solver::
+array_control_vol_and_fluid_component_collections::
+fluid_component_collection::
+fluid_component_super_collection::ParallelBranchSolution;
+use tuas_boussinesq_solver::tuas_lib_error::TuasLibError;
 
 use tuas_boussinesq_solver::pre_built_components::
 insulated_pipes_and_fluid_components::InsulatedFluidComponent;
@@ -31,6 +31,18 @@ use tuas_boussinesq_solver::pre_built_components::
 non_insulated_fluid_components::NonInsulatedFluidComponent;
 
 
+/// parallel branch solutions from the previous timestep, used to 
+/// warm start the primary loop flow solver
+///
+/// each combination of open branches is kept separately, as the 
+/// branches are rebuilt every timestep
+#[derive(Debug,Clone,Default)]
+pub struct PriLoopParallelSolutions {
+    pub dhx_and_heater_branches: Option<ParallelBranchSolution>,
+    pub heater_and_ctah_branches: Option<ParallelBranchSolution>,
+    pub all_three_branches: Option<ParallelBranchSolution>,
+}
+
 pub fn three_branch_pri_loop_flowrates_parallel_ver_4(
     pump_pressure: Pressure,
     ctah_branch_blocked: bool,
@@ -75,8 +87,9 @@ pub fn three_branch_pri_loop_flowrates_parallel_ver_4(
     pipe_15 :&InsulatedFluidComponent,
     pipe_16 :&InsulatedFluidComponent,
     pipe_17a :&InsulatedFluidComponent,
+    previous_solutions: &mut PriLoopParallelSolutions,
     ) ->
-(MassRate, MassRate, MassRate) {
+Result<(MassRate, MassRate, MassRate), TuasLibError> {
 
 
     let mut heater_branch = 
@@ -143,26 +156,35 @@ pub fn three_branch_pri_loop_flowrates_parallel_ver_4(
         pri_loop_branches.fluid_component_super_vector.push(dhx_branch);
         pri_loop_branches.fluid_component_super_vector.push(heater_branch);
 
-        let (dhx_flow, heater_flow) = 
-            get_mass_flowrate_two_branches(
-                &pri_loop_branches);
+        let dhx_and_heater_solution = 
+            try_get_branch_mass_flowrates_with_previous_solution(
+                &pri_loop_branches,
+                &mut previous_solutions.dhx_and_heater_branches)?;
+
+        let dhx_flow = dhx_and_heater_solution.branch_mass_flowrates[0];
+        let heater_flow = dhx_and_heater_solution.branch_mass_flowrates[1];
 
-        return (dhx_flow, heater_flow, MassRate::ZERO);
+        return Ok((dhx_flow, heater_flow, MassRate::ZERO));
 
     } else if dhx_branch_blocked && !ctah_branch_blocked {
 
         pri_loop_branches.fluid_component_super_vector.push(heater_branch);
         pri_loop_branches.fluid_component_super_vector.push(ctah_branch);
 
-        let (heater_flow, ctah_flow) = 
-            get_mass_flowrate_two_branches(
-                &pri_loop_branches);
-        return (MassRate::ZERO, heater_flow, ctah_flow);
+        let heater_and_ctah_solution = 
+            try_get_branch_mass_flowrates_with_previous_solution(
+                &pri_loop_branches,
+                &mut previous_solutions.heater_and_ctah_branches)?;
+
+        let heater_flow = heater_and_ctah_solution.branch_mass_flowrates[0];
+        let ctah_flow = heater_and_ctah_solution.branch_mass_flowrates[1];
+
+        return Ok((MassRate::ZERO, heater_flow, ctah_flow));
 
     } else if ctah_branch_blocked && dhx_branch_blocked {
         // all flows blocked, no nothing to see here
 
-        return (MassRate::ZERO, MassRate::ZERO, MassRate::ZERO);
+        return Ok((MassRate::ZERO, MassRate::ZERO, MassRate::ZERO));
     } else {
         
         // two loops open scenario 
@@ -173,30 +195,19 @@ pub fn three_branch_pri_loop_flowrates_parallel_ver_4(
         pri_loop_ctah_and_heater_br.fluid_component_super_vector.push(heater_branch.clone());
         pri_loop_ctah_and_heater_br.fluid_component_super_vector.push(ctah_branch.clone());
 
-        // create pointers for two branch flow 
-
-        let heater_br_flow_two_br_scenario = Arc::new(Mutex::new(
-                MassRate::ZERO));
-        let ctah_br_flow_two_br_scenario = Arc::new(Mutex::new(
-                MassRate::ZERO));
+        let mut previous_heater_and_ctah_solution = 
+            previous_solutions.heater_and_ctah_branches.take();
 
-        let heater_br_flow_two_br_scenario_clone = heater_br_flow_two_br_scenario.clone();
-        let ctah_br_flow_two_br_scenario_clone = ctah_br_flow_two_br_scenario.clone();
-
-        // spawn a thread 
+        // spawn a thread, which returns the two branch solution, 
+        // and hands back the previous solution for the next timestep
 
         let two_branch_flow_scenario_join_handle = 
             thread::spawn(move ||{
-                let (heater_flow_two_br, ctah_flow_two_br) = 
-                    get_mass_flowrate_two_branches(
-                        &pri_loop_ctah_and_heater_br);
-
-                // load this into the arc mutex ptr 
-
-                *heater_br_flow_two_br_scenario_clone.lock().unwrap().deref_mut()
-                    = heater_flow_two_br;
-                *ctah_br_flow_two_br_scenario_clone.lock().unwrap().deref_mut()
-                    = ctah_flow_two_br;
+                let heater_and_ctah_solution = 
+                    try_get_branch_mass_flowrates_with_previous_solution(
+                        &pri_loop_ctah_and_heater_br,
+                        &mut previous_heater_and_ctah_solution);
+                (heater_and_ctah_solution, previous_heater_and_ctah_solution)
             });
 
         // all loops opened scenario
@@ -204,31 +215,41 @@ pub fn three_branch_pri_loop_flowrates_parallel_ver_4(
         pri_loop_branches.fluid_component_super_vector.push(heater_branch);
         pri_loop_branches.fluid_component_super_vector.push(ctah_branch);
 
-        let (dhx_flow, heater_flow, ctah_flow) = 
-            get_mass_flowrate_vector_for_dhx_heater_and_ctah_branches(
-                &pri_loop_branches);
+        let three_branch_solution = 
+            try_get_branch_mass_flowrates_with_previous_solution(
+                &pri_loop_branches,
+                &mut previous_solutions.all_three_branches);
+
+        // load the two branch solution before returning any errors, 
+        // so that its previous solution is kept
+        let (heater_and_ctah_solution, previous_heater_and_ctah_solution) = 
+            two_branch_flow_scenario_join_handle.join().unwrap();
+        previous_solutions.heater_and_ctah_branches = 
+            previous_heater_and_ctah_solution;
 
+        let three_branch_solution = three_branch_solution?;
+        let heater_and_ctah_solution = heater_and_ctah_solution?;
 
+        let dhx_flow = three_branch_solution.branch_mass_flowrates[0];
+        let heater_flow = three_branch_solution.branch_mass_flowrates[1];
+        let ctah_flow = three_branch_solution.branch_mass_flowrates[2];
 
         // if dhx flow is downwards, (positive flow, is ok)
         // if negative flow, then block it 
 
         let flow_diode_block_flow: bool = dhx_flow < MassRate::ZERO;
 
-        // load the two branch solution
-        two_branch_flow_scenario_join_handle.join().unwrap();
+        let heater_flow_two_br = heater_and_ctah_solution.branch_mass_flowrates[0];
+        let ctah_flow_two_br = heater_and_ctah_solution.branch_mass_flowrates[1];
 
         if flow_diode_block_flow {
 
-
             let dhx_flow = MassRate::ZERO;
-            let heater_flow: MassRate = *heater_br_flow_two_br_scenario.lock().unwrap().deref();
-            let ctah_flow: MassRate = *ctah_br_flow_two_br_scenario.lock().unwrap().deref();
-            return (dhx_flow, heater_flow, ctah_flow);
+            return Ok((dhx_flow, heater_flow_two_br, ctah_flow_two_br));
 
         } else {
 
-            return (dhx_flow, heater_flow, ctah_flow);
+            return Ok((dhx_flow, heater_flow, ctah_flow));
         }
 
 
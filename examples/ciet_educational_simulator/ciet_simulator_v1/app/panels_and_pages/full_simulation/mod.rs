@@ -1,9 +1,9 @@
 use std::{ops::{Deref, DerefMut}, sync::{Arc, Mutex}, thread, time::{Duration, SystemTime}};
 
 use diagnostics::pri_loop_heater_temperature_diagnostics_ver_4;
-use fluid_solvers::three_branch_pri_loop_flowrates_parallel_ver_4;
+use fluid_solvers::{three_branch_pri_loop_flowrates_parallel_ver_4, PriLoopParallelSolutions};
 use heat_transfer_solvers::{ciet_pri_loop_three_branch_link_up_components_ver_4, pri_loop_three_branch_advance_timestep_except_dhx_ver_4};
-use tuas_boussinesq_solver::{boussinesq_thermophysical_properties::LiquidMaterial, pre_built_components::ciet_three_branch_plus_dracs::components::{new_active_ctah_horizontal, new_active_ctah_vertical}, prelude::beta_testing::{HeatTransferEntity, InsulatedPorousMediaFluidComponent}, single_control_vol::SingleCVNode};
+use tuas_boussinesq_solver::{boussinesq_thermophysical_properties::LiquidMaterial, pre_built_components::ciet_three_branch_plus_dracs::components::{new_active_ctah_horizontal, new_active_ctah_vertical}, prelude::beta_testing::{HeatTransferEntity, InsulatedPorousMediaFluidComponent}, single_control_vol::SingleCVNode, tuas_lib_error::TuasLibError};
 use uom::si::{length::{inch, meter}, mass_rate::kilogram_per_second, power::kilowatt, pressure::{atmosphere, pascal}};
 
 use crate::ciet_simulator_v1::app::panels_and_pages::online_calibration::HeaterType;
@@ -481,6 +481,10 @@ pub fn educational_ciet_loop_version_4(
         = MassRate::ZERO;
     let mut mass_flowrate_heater_br: MassRate 
         = MassRate::ZERO;
+    // previous parallel branch solutions, to warm start the 
+    // primary loop flow solver
+    let mut pri_loop_parallel_solutions = 
+        PriLoopParallelSolutions::default();
 
     let ambient_htc = HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0);
 
@@ -644,6 +648,8 @@ pub fn educational_ciet_loop_version_4(
         let mass_flow_dracs_loop_ptr = Arc::new(Mutex::new(mass_flowrate_dracs_loop_abs.clone()));
         let mass_flow_ctah_br_ptr = Arc::new(Mutex::new(mass_flowrate_ctah_br.clone()));
         let mass_flow_heater_br_ptr = Arc::new(Mutex::new(mass_flowrate_heater_br.clone()));
+        let pri_loop_parallel_solutions_ptr = Arc::new(Mutex::new(
+                pri_loop_parallel_solutions.clone()));
 
         // clone the pointers to move into the mass flowrate calc
 
@@ -651,6 +657,8 @@ pub fn educational_ciet_loop_version_4(
         let mass_flow_dracs_loop_ptr_clone = mass_flow_dracs_loop_ptr.clone();
         let mass_flow_ctah_br_ptr_clone = mass_flow_ctah_br_ptr.clone();
         let mass_flow_heater_br_ptr_clone = mass_flow_heater_br_ptr.clone();
+        let pri_loop_parallel_solutions_ptr_clone = 
+            pri_loop_parallel_solutions_ptr.clone();
 
         let reference_tchx_and_ctah_htc = 
             HeatTransfer::new::<watt_per_square_meter_kelvin>(40.0);
@@ -952,7 +960,9 @@ pub fn educational_ciet_loop_version_4(
         let dhx_branch_blocked = local_ciet_state.is_dhx_branch_blocked;
 
 
-        let pri_flowrate_join_handle = thread::spawn(move || {
+        // solver errors are returned from the thread, and unwrapped 
+        // when the thread is joined
+        let pri_flowrate_join_handle = thread::spawn(move || -> Result<(), TuasLibError> {
             //
             // first, absolute mass flowrate across two branches
             let cloned_dhx_shell_side_pipe_24 = 
@@ -962,7 +972,7 @@ pub fn educational_ciet_loop_version_4(
 
             match local_ciet_state.current_heater_type {
                 HeaterType::InsulatedHeaterV1Fine15Mesh => {
-                    let (dhx_flow, heater_flow, ctah_flow) = 
+                    let pri_loop_flowrates = 
                         three_branch_pri_loop_flowrates_parallel_ver_4(
                             pump_pressure, 
                             ctah_branch_blocked, 
@@ -1005,7 +1015,10 @@ pub fn educational_ciet_loop_version_4(
                             &cloned_flowmeter_40_14a, 
                             &cloned_pipe_15, 
                             &cloned_pipe_16, 
-                            &cloned_pipe_17a);
+                            &cloned_pipe_17a,
+                            &mut pri_loop_parallel_solutions_ptr_clone.lock().unwrap());
+
+                    let (dhx_flow, heater_flow, ctah_flow) = pri_loop_flowrates?;
                     *mass_flow_dhx_br_ptr_clone.lock().unwrap().deref_mut() 
                         = dhx_flow;
                     *mass_flow_heater_br_ptr_clone.lock().unwrap().deref_mut() 
@@ -1015,7 +1028,7 @@ pub fn educational_ciet_loop_version_4(
                 },
                 HeaterType::InsulatedHeaterV1Coarse8Mesh => {
 
-                    let (dhx_flow, heater_flow, ctah_flow) = 
+                    let pri_loop_flowrates = 
                         three_branch_pri_loop_flowrates_parallel_ver_4(
                             pump_pressure, 
                             ctah_branch_blocked, 
@@ -1058,7 +1071,10 @@ pub fn educational_ciet_loop_version_4(
                             &cloned_flowmeter_40_14a, 
                             &cloned_pipe_15, 
                             &cloned_pipe_16, 
-                            &cloned_pipe_17a);
+                            &cloned_pipe_17a,
+                            &mut pri_loop_parallel_solutions_ptr_clone.lock().unwrap());
+
+                    let (dhx_flow, heater_flow, ctah_flow) = pri_loop_flowrates?;
 
                     *mass_flow_dhx_br_ptr_clone.lock().unwrap().deref_mut() 
                         = dhx_flow;
@@ -1069,7 +1085,7 @@ pub fn educational_ciet_loop_version_4(
                 },
             }
 
-
+            Ok(())
 
         }
         );
@@ -1316,6 +1332,14 @@ pub fn educational_ciet_loop_version_4(
         let tube_side_total_mass_flowrate = -counter_clockwise_dracs_flowrate;
         let shell_side_total_mass_flowrate = dhx_flow;
 
+        // the dhx is counter current, unless flow through one 
+        // side reverses
+        dhx_sthe.try_set_flow_arrangement(
+            tuas_boussinesq_solver::pre_built_components::shell_and_tube_heat_exchanger::
+            flow_arrangement::ShellAndTubeFlowArrangement::
+            single_pass_from_mass_flowrate_directions(
+                tube_side_total_mass_flowrate, 
+                shell_side_total_mass_flowrate)).unwrap();
         dhx_sthe.lateral_and_miscellaneous_connections(
             prandtl_wall_correction_setting, 
             tube_side_total_mass_flowrate, 
@@ -1326,7 +1350,7 @@ pub fn educational_ciet_loop_version_4(
         // join the mass flow calculation handles 
 
         dracs_flowrate_join_handle.join().unwrap();
-        pri_flowrate_join_handle.join().unwrap();
+        pri_flowrate_join_handle.join().unwrap().unwrap();
 
 
         // record and mutate global flowrates
@@ -1334,6 +1358,8 @@ pub fn educational_ciet_loop_version_4(
         mass_flowrate_dhx_br = *mass_flow_dhx_br_ptr.lock().unwrap().deref();
         mass_flowrate_ctah_br = *mass_flow_ctah_br_ptr.lock().unwrap().deref();
         mass_flowrate_heater_br = *mass_flow_heater_br_ptr.lock().unwrap().deref();
+        pri_loop_parallel_solutions = 
+            pri_loop_parallel_solutions_ptr.lock().unwrap().clone();
 
 
         // display temperatures and stuff
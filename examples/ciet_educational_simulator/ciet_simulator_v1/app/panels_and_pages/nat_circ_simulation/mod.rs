@@ -592,6 +592,15 @@ pub fn _coupled_dracs_loop_version_7(
         let tube_side_total_mass_flowrate = -counter_clockwise_dracs_flowrate;
         let shell_side_total_mass_flowrate = counter_clockwise_pri_loop_flowrate;
 
+        // the dhx is counter current, unless flow through one 
+        // side reverses
+        dhx_sthe.try_set_flow_arrangement(
+            tuas_boussinesq_solver::pre_built_components::shell_and_tube_heat_exchanger::
+            flow_arrangement::ShellAndTubeFlowArrangement::
+            single_pass_from_mass_flowrate_directions(
+                tube_side_total_mass_flowrate, 
+                shell_side_total_mass_flowrate)).unwrap();
+
         dhx_sthe.heat_transfer_to_ambient = ambient_htc;
         dhx_sthe.lateral_and_miscellaneous_connections(
             prandtl_wall_correction_setting, 
@@ -345,7 +345,9 @@ pub (crate) fn advance_timestep_for_specified_conductance_array_cv(
             let dummy_conductance_to_coolant_fraction: ThermalConductance = 
             ThermalConductance::new::<watt_per_kelvin>(0.0);
             let dummy_coolant_temperature_at_boundary = ThermodynamicTemperature::new::<kelvin>(293.0);
-            conductance_array_ref[i] = ThermalConductance::new::<watt_per_kelvin>(0.0);
+            // Hs[n] is the one ignored, Hs[n-1] still connects
+            // the last node to the node before it
+            conductance_array_ref[i+1] = ThermalConductance::new::<watt_per_kelvin>(0.0);
 
             conductance_matrix[[i,i-1]] = -conductance_array_ref[i];
             conductance_matrix[[i,i]] = vol_fraction[i] * total_volume * rho_cp[i] / dt 
@@ -380,3 +382,47 @@ pub (crate) fn advance_timestep_for_specified_conductance_array_cv(
     return Ok(temperature_array);
     
 }
+
+/// sets the inner and outer single cvs of an array cv to the 
+/// end temperatures of the new temperature array
+///
+/// the enthalpy, temperature and liquid mass of both cvs are updated,
+/// the cv temperature is what the links to other heat transfer 
+/// entities use, so it must be kept in sync with the array
+///
+/// the rate_enthalpy_change_vector and max_timestep_vector 
+/// of both cvs are then cleared for the next timestep
+pub (crate) fn set_end_single_cvs_to_array_temperatures(
+    inner_single_cv: &mut SingleCVNode,
+    outer_single_cv: &mut SingleCVNode,
+    new_temperature_array: &Array1<ThermodynamicTemperature>,
+) -> Result<(), TuasLibError> {
+
+    let inner_node_temperature: ThermodynamicTemperature = 
+    *new_temperature_array.first().ok_or(
+        TuasLibError::GenericStringError(
+            "temperature array is empty".to_string()))?;
+
+    let outer_node_temperature: ThermodynamicTemperature = 
+    *new_temperature_array.last().ok_or(
+        TuasLibError::GenericStringError(
+            "temperature array is empty".to_string()))?;
+
+    for (single_cv, node_temperature) in [
+        (inner_single_cv, inner_node_temperature),
+        (outer_single_cv, outer_node_temperature)] {
+
+        single_cv.current_timestep_control_volume_specific_enthalpy = 
+        try_get_h(
+            single_cv.material_control_volume,
+            node_temperature,
+            single_cv.pressure_control_volume)?;
+
+        single_cv.temperature = node_temperature;
+
+        single_cv.set_liquid_cv_mass_from_temperature()?;
+        single_cv.clear_vectors()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::pressure::pascal;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::fluid_component::FluidComponent;
+use super::fluid_component_collection::FluidComponentCollection;
+use super::fluid_component_collection::FluidComponentCollectionOreintation;
+use super::fluid_component_collection::FluidComponentCollectionMethods;
+use super::fluid_component_super_collection::FluidComponentSuperCollection;
+use super::root_finding::RootFindingSettings;
+use super::fluid_component_traits::FluidComponentTrait;
+
+/// gravitational acceleration used to convert pressure to head, 
+/// and for buoyancy
+pub const GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED: f64 = 9.81;
+
+impl FluidComponent {
+
+    /// obtains the absolute pressure at each node of the
+    /// fluid component given the absolute pressure at its inlet
+    /// (back) and the mass flowrate through it
+    ///
+    /// for flow diodes and control valves, the nodes are those of 
+    /// the fluid array within. The loss of the diode or valve on top 
+    /// of the fluid array's own loss (eg. reverse flow loss or 
+    /// the valve loss at partial opening) is spread uniformly along 
+    /// the nodes, just like the fluid array's own pressure change
+    pub fn get_absolute_pressure_vector(&self,
+        inlet_pressure: Pressure,
+        mass_flowrate: MassRate) -> Result<Vec<Pressure>, TuasLibError> {
+
+        match self {
+            FluidComponent::FluidArray(fluid_array) => {
+                fluid_array.get_absolute_pressure_vector(
+                    inlet_pressure, mass_flowrate)
+            },
+            // all tubes are assumed to have the same pressure profile,
+            // so we only need the flow through one tube
+            FluidComponent::ParallelUniformFluidArray(
+                fluid_array, number_of_tubes) => {
+                let flow_through_one_tube = mass_flowrate
+                    / (*number_of_tubes as f64);
+
+                fluid_array.get_absolute_pressure_vector(
+                    inlet_pressure, flow_through_one_tube)
+            },
+            FluidComponent::FlowDiode(flow_diode) => {
+                let node_pressure_vec = flow_diode.fluid_array
+                    .get_absolute_pressure_vector(
+                        inlet_pressure, mass_flowrate)?;
+
+                let additional_pressure_loss = 
+                    flow_diode.get_pressure_loss_immutable(mass_flowrate)
+                    - flow_diode.fluid_array
+                    .get_pressure_loss_immutable(mass_flowrate);
+
+                Ok(subtract_uniformly_distributed_pressure_loss(
+                        node_pressure_vec, additional_pressure_loss))
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                let node_pressure_vec = control_valve.fluid_array
+                    .get_absolute_pressure_vector(
+                        inlet_pressure, mass_flowrate)?;
+
+                let additional_pressure_loss = 
+                    control_valve.get_pressure_loss_immutable(mass_flowrate)
+                    - control_valve.fluid_array
+                    .get_pressure_loss_immutable(mass_flowrate);
+
+                Ok(subtract_uniformly_distributed_pressure_loss(
+                        node_pressure_vec, additional_pressure_loss))
+            },
+        }
+    }
+}
+
+/// spreads a pressure loss uniformly along the nodes, 
+/// with the node pressures taken at the node centres
+fn subtract_uniformly_distributed_pressure_loss(
+    node_pressure_vec: Vec<Pressure>,
+    pressure_loss: Pressure) -> Vec<Pressure> {
+
+    let number_of_nodes = node_pressure_vec.len();
+
+    node_pressure_vec.into_iter().enumerate().map(
+        |(node_index, node_pressure)| {
+            let fraction_of_length = (node_index as f64 + 0.5)
+                / number_of_nodes as f64;
+            node_pressure - pressure_loss * fraction_of_length
+        }).collect()
+}
+
+impl FluidComponentCollection {
+
+    /// obtains the absolute pressure at the inlet of every component
+    /// in a series collection (eg. a solved loop)
+    ///
+    /// the absolute pressure is only known at one reference location,
+    /// (eg. the cover gas pressure of the expansion tank), which is
+    /// taken to be the inlet of the component at
+    /// reference_component_index. Pressure changes across components
+    /// are then added going downstream (in the direction of the
+    /// component vector) and subtracted going upstream
+    ///
+    /// the mass flowrate should be the solved loop mass flowrate
+    pub fn try_get_inlet_absolute_pressures(&self,
+        mass_flowrate: MassRate,
+        reference_pressure: Pressure,
+        reference_component_index: usize) -> Result<Vec<Pressure>, TuasLibError> {
+
+        if self.orientation != FluidComponentCollectionOreintation::Series {
+            return Err(TuasLibError::GenericStringError(
+                "absolute pressures can only be found for components \
+                in series".to_string()));
+        }
+
+        let number_of_components = self.components.len();
+
+        if reference_component_index >= number_of_components {
+            return Err(TuasLibError::GenericStringError(
+                "reference component index is out of range".to_string()));
+        }
+
+        let pressure_change_vec: Vec<Pressure> = self.components.iter()
+            .map(|component| component.get_pressure_change_immutable(
+                    mass_flowrate))
+            .collect();
+
+        let mut inlet_pressure_vec: Vec<Pressure> =
+            vec![reference_pressure; number_of_components];
+
+        // going downstream of the reference component
+        for index in (reference_component_index+1)..number_of_components {
+            inlet_pressure_vec[index] = inlet_pressure_vec[index-1]
+                + pressure_change_vec[index-1];
+        }
+
+        // going upstream of the reference component
+        for index in (0..reference_component_index).rev() {
+            inlet_pressure_vec[index] = inlet_pressure_vec[index+1]
+                - pressure_change_vec[index];
+        }
+
+        Ok(inlet_pressure_vec)
+    }
+
+    /// obtains the absolute pressure at every node of every component
+    /// in a series collection (eg. a solved loop), given a reference
+    /// absolute pressure at the inlet of the component at
+    /// reference_component_index
+    ///
+    /// one vector of node pressures is returned per component
+    pub fn try_get_absolute_pressure_profile(&self,
+        mass_flowrate: MassRate,
+        reference_pressure: Pressure,
+        reference_component_index: usize) -> Result<Vec<Vec<Pressure>>, TuasLibError> {
+
+        let inlet_pressure_vec = self.try_get_inlet_absolute_pressures(
+            mass_flowrate,
+            reference_pressure,
+            reference_component_index)?;
+
+        self.components.iter().zip(inlet_pressure_vec.iter())
+            .map(|(component, inlet_pressure)| {
+                component.get_absolute_pressure_vector(
+                    *inlet_pressure, mass_flowrate)
+            })
+            .collect()
+    }
+}
+
+impl FluidComponentSuperCollection {
+
+    /// obtains the absolute pressure at every node of every component
+    /// in a super collection (eg. a full CIET loop), given a reference 
+    /// absolute pressure at the inlet of one component 
+    /// (eg. the expansion tank)
+    ///
+    /// the reference component is given by the index of its 
+    /// collection (branch) within the super collection, and its index 
+    /// within that collection. Every collection must be in series.
+    ///
+    /// for a series super collection, mass_flowrate flows through 
+    /// every collection, and the pressures are found by walking 
+    /// through the collections from the reference component
+    ///
+    /// for a parallel super collection, mass_flowrate is the total 
+    /// flow through all the branches. The pressure change across the 
+    /// branches and the mass flowrate through each branch are solved 
+    /// for, and all branches share the same inlet pressure, which 
+    /// is found by walking back from the reference component
+    ///
+    /// one vector per collection is returned, each containing 
+    /// one vector of node pressures per component
+    pub fn try_get_absolute_pressure_profile(&self,
+        mass_flowrate: MassRate,
+        reference_pressure: Pressure,
+        reference_collection_index: usize,
+        reference_component_index: usize) -> Result<Vec<Vec<Vec<Pressure>>>, TuasLibError> {
+
+        let collection_vector = &self.fluid_component_super_vector;
+        let number_of_collections = collection_vector.len();
+
+        if reference_collection_index >= number_of_collections {
+            return Err(TuasLibError::GenericStringError(
+                "reference collection index is out of range".to_string()));
+        }
+
+        let mass_flowrate_through_each_collection: Vec<MassRate> = 
+            match self.orientation {
+                FluidComponentCollectionOreintation::Series => {
+                    vec![mass_flowrate; number_of_collections]
+                },
+                FluidComponentCollectionOreintation::Parallel => {
+                    let pressure_change = self.try_get_pressure_change(
+                        mass_flowrate, &RootFindingSettings::default())?;
+
+                    self.get_mass_flowrate_across_each_parallel_branch(
+                        pressure_change)
+                },
+            };
+
+        // inlet pressure of the collection containing the reference
+        let reference_collection_inlet_pressure: Pressure = 
+            collection_vector[reference_collection_index]
+            .try_get_inlet_absolute_pressures(
+                mass_flowrate_through_each_collection[reference_collection_index],
+                reference_pressure,
+                reference_component_index)?[0];
+
+        let mut collection_inlet_pressure_vec: Vec<Pressure> = 
+            vec![reference_collection_inlet_pressure; number_of_collections];
+
+        // for parallel branches, all branches share the same inlet 
+        // pressure. For collections in series, walk downstream and 
+        // upstream of the reference collection
+        if self.orientation == FluidComponentCollectionOreintation::Series {
+
+            let pressure_change_vec: Vec<Pressure> = collection_vector.iter()
+                .map(|collection| collection.get_pressure_change(mass_flowrate))
+                .collect();
+
+            for index in (reference_collection_index+1)..number_of_collections {
+                collection_inlet_pressure_vec[index] = 
+                    collection_inlet_pressure_vec[index-1]
+                    + pressure_change_vec[index-1];
+            }
+
+            for index in (0..reference_collection_index).rev() {
+                collection_inlet_pressure_vec[index] = 
+                    collection_inlet_pressure_vec[index+1]
+                    - pressure_change_vec[index];
+            }
+        }
+
+        collection_vector.iter()
+            .zip(collection_inlet_pressure_vec.iter())
+            .zip(mass_flowrate_through_each_collection.iter())
+            .map(|((collection, inlet_pressure), collection_mass_flowrate)| {
+                collection.try_get_absolute_pressure_profile(
+                    *collection_mass_flowrate, *inlet_pressure, 0)
+            })
+            .collect()
+    }
+}
+
+/// net positive suction head available (NPSHa) at a pump inlet
+///
+/// NPSHa = (P_suction - P_vapour) / (rho g)
+///
+/// where P_suction is the absolute pressure at the pump inlet,
+/// which can be found using try_get_inlet_absolute_pressures.
+/// The velocity head is neglected, which is conservative.
+///
+/// If NPSHa is less than the NPSH required by the pump,
+/// the pump will cavitate
+pub fn get_net_positive_suction_head_available(
+    suction_absolute_pressure: Pressure,
+    vapour_pressure: Pressure,
+    fluid_density: MassDensity) -> Length {
+
+    Length::new::<meter>(
+        (suction_absolute_pressure.get::<pascal>()
+         - vapour_pressure.get::<pascal>())
+        / fluid_density.get::<kilogram_per_cubic_meter>()
+        / GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED)
+}
+
+/// unit tests for absolute pressure and NPSH
+pub mod unit_test_absolute_pressure;
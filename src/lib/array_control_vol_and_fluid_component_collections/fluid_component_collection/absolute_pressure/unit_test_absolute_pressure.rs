@@ -0,0 +1,440 @@
+/// a vertical pipe with no flow should have a hydrostatic
+/// pressure profile along its nodes
+#[test]
+pub fn hydrostatic_node_pressures_in_vertical_pipe() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+
+    // 3 inner nodes, 5 nodes in total
+    let vertical_pipe = FluidComponent::FluidArray(FluidArray::new_cylinder(
+        Length::new::<meter>(2.0),
+        Length::new::<inch>(2.0),
+        ThermodynamicTemperature::new::<kelvin>(298.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(0.0),
+        3,
+        Angle::new::<degree>(90.0)));
+
+    let fluid_density = vertical_pipe
+        .get_fluid_density_immutable_at_ref_temperature()
+        .get::<kilogram_per_cubic_meter>();
+
+    let inlet_pressure = Pressure::new::<atmosphere>(2.0);
+
+    let pressure_vec = vertical_pipe.get_absolute_pressure_vector(
+        inlet_pressure, MassRate::ZERO)?;
+
+    assert_eq!(pressure_vec.len(), 5);
+
+    for (node_index, node_pressure) in pressure_vec.iter().enumerate() {
+        let node_height = 2.0 * (node_index as f64 + 0.5) / 5.0;
+        let expected_pressure = inlet_pressure.get::<pascal>()
+            - fluid_density * 9.81 * node_height;
+
+        approx::assert_relative_eq!(
+            node_pressure.get::<pascal>(),
+            expected_pressure,
+            max_relative = 1e-12);
+    }
+
+    Ok(())
+}
+
+/// walking around a solved closed loop from the reference
+/// pressure should return to the reference pressure, and the
+/// result should not depend on where the reference is placed
+#[test]
+pub fn absolute_pressures_around_closed_loop() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+
+    let new_pipe = |length: f64, incline_angle: f64| -> FluidComponent {
+        FluidComponent::FluidArray(FluidArray::new_cylinder(
+            Length::new::<meter>(length),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::SteelSS304L,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(5.0),
+            2,
+            Angle::new::<degree>(incline_angle)))
+    };
+
+    let mut pump_body = new_pipe(0.5, 0.0);
+    pump_body.set_internal_pressure_source(Pressure::new::<pascal>(5000.0));
+
+    let mut closed_loop = 
+        FluidComponentCollection::new_series_component_collection();
+    closed_loop.set_fluid_component_vector(vec![
+        pump_body,
+        new_pipe(2.0, 90.0),
+        new_pipe(1.0, 0.0),
+        new_pipe(2.0, -90.0)]);
+
+    let mass_flowrate = closed_loop
+        .get_mass_flowrate_from_pressure_change(Pressure::ZERO);
+    assert!(mass_flowrate.get::<kilogram_per_second>() > 0.0);
+
+    let reference_pressure = Pressure::new::<atmosphere>(1.0);
+
+    let inlet_pressures = closed_loop.try_get_inlet_absolute_pressures(
+        mass_flowrate, reference_pressure, 0)?;
+
+    // back to the pump inlet after one loop
+    let loop_outlet_pressure = inlet_pressures[3]
+        + closed_loop.components[3]
+        .get_pressure_change_immutable(mass_flowrate);
+
+    approx::assert_relative_eq!(
+        loop_outlet_pressure.get::<pascal>(),
+        reference_pressure.get::<pascal>(),
+        max_relative = 1e-6);
+
+    // pump outlet pressure is higher than the top of the loop
+    assert!(inlet_pressures[1] > inlet_pressures[2]);
+
+    // placing the reference at the top of the loop gives the
+    // same pressures
+    let inlet_pressures_from_top = closed_loop
+        .try_get_inlet_absolute_pressures(
+            mass_flowrate, inlet_pressures[2], 2)?;
+
+    for (pressure, pressure_from_top) in inlet_pressures.iter()
+        .zip(inlet_pressures_from_top.iter()) {
+        approx::assert_relative_eq!(
+            pressure.get::<pascal>(),
+            pressure_from_top.get::<pascal>(),
+            max_relative = 1e-12);
+    }
+
+    // node profile, 4 nodes per pipe and the first node of each
+    // pipe is downstream of its inlet
+    let pressure_profile = closed_loop.try_get_absolute_pressure_profile(
+        mass_flowrate, reference_pressure, 0)?;
+
+    assert_eq!(pressure_profile.len(), 4);
+    assert_eq!(pressure_profile[1].len(), 4);
+    assert!(pressure_profile[1][0] < inlet_pressures[1]);
+    assert!(pressure_profile[3][0] > inlet_pressures[3]);
+
+    // reference out of range and parallel collections are errors
+    assert!(closed_loop.try_get_inlet_absolute_pressures(
+        mass_flowrate, reference_pressure, 4).is_err());
+
+    let mut parallel_collection = 
+        FluidComponentCollection::new_parallel_component_collection();
+    parallel_collection.set_fluid_component_vector(closed_loop.components.clone());
+    assert!(parallel_collection.try_get_inlet_absolute_pressures(
+        mass_flowrate, reference_pressure, 0).is_err());
+
+    Ok(())
+}
+
+/// NPSH available from the suction pressure and vapour pressure,
+/// and the NPSH margin of a pump
+#[test]
+pub fn npsh_available_and_pump_margin() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::pressure::{atmosphere, kilopascal, pascal};
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::angular_velocity::revolution_per_minute;
+    use uom::si::volume_rate::cubic_meter_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::moment_of_inertia::kilogram_square_meter;
+    use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+    use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::centrifugal_pump::CentrifugalPump;
+    use super::get_net_positive_suction_head_available;
+
+    // hand calc, 100 kPa suction, 2 kPa vapour pressure, water
+    let npsh_available = get_net_positive_suction_head_available(
+        Pressure::new::<kilopascal>(100.0),
+        Pressure::new::<kilopascal>(2.0),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0));
+
+    approx::assert_relative_eq!(
+        npsh_available.get::<meter>(),
+        98000.0/1000.0/9.81,
+        max_relative = 1e-12);
+
+    let rated_speed = AngularVelocity::new::<revolution_per_minute>(1450.0);
+
+    let mut pump = CentrifugalPump::new(
+        rated_speed,
+        VolumeRate::new::<cubic_meter_per_second>(0.003),
+        Length::new::<meter>(2.0),
+        Length::new::<meter>(3.0),
+        Ratio::new::<ratio>(0.6),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        MomentOfInertia::new::<kilogram_square_meter>(0.01))?;
+    pump.rated_npsh_required = Length::new::<meter>(3.0);
+
+    let fluid_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let suction_pressure = Pressure::new::<atmosphere>(1.0);
+
+    let vapour_pressure = LiquidMaterial::DowthermA
+        .try_get_vapour_pressure(fluid_temperature)?;
+    let fluid_density = try_get_rho(LiquidMaterial::DowthermA.into(),
+        fluid_temperature, suction_pressure)?;
+
+    let expected_margin = (suction_pressure.get::<pascal>()
+        - vapour_pressure.get::<pascal>())
+        / fluid_density.get::<kilogram_per_cubic_meter>() / 9.81 - 3.0;
+
+    let margin = pump.try_get_npsh_margin(suction_pressure,
+        LiquidMaterial::DowthermA, fluid_temperature)?;
+
+    approx::assert_relative_eq!(
+        margin.get::<meter>(),
+        expected_margin,
+        max_relative = 1e-12);
+    assert!(margin.get::<meter>() > 0.0);
+
+    // low suction pressure, the pump cavitates
+    let low_suction_margin = pump.try_get_npsh_margin(
+        Pressure::new::<kilopascal>(20.0),
+        LiquidMaterial::DowthermA, fluid_temperature)?;
+    assert!(low_suction_margin.get::<meter>() < 0.0);
+
+    // at half speed, NPSH required is a quarter
+    pump.set_shaft_speed(rated_speed * 0.5);
+    approx::assert_relative_eq!(
+        pump.get_npsh_required().get::<meter>(),
+        0.75,
+        max_relative = 1e-12);
+
+    Ok(())
+}
+
+/// a flow diode in a series loop should have node pressures 
+/// like any other pipe, and its node pressures should 
+/// include the reverse flow loss of the diode
+#[test]
+pub fn absolute_pressures_with_flow_diode_in_series_loop() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::flow_diode::FlowDiode;
+
+    let new_fluid_array = |length: f64, incline_angle: f64| -> FluidArray {
+        FluidArray::new_cylinder(
+            Length::new::<meter>(length),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::SteelSS304L,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(5.0),
+            2,
+            Angle::new::<degree>(incline_angle))
+    };
+
+    let mut pump_body = FluidComponent::FluidArray(new_fluid_array(0.5, 0.0));
+    pump_body.set_internal_pressure_source(Pressure::new::<pascal>(5000.0));
+
+    let check_valve = FluidComponent::FlowDiode(
+        FlowDiode::new_ideal_check_valve(new_fluid_array(1.0, 0.0)));
+
+    let mut closed_loop = 
+        FluidComponentCollection::new_series_component_collection();
+    closed_loop.set_fluid_component_vector(vec![
+        pump_body,
+        FluidComponent::FluidArray(new_fluid_array(2.0, 90.0)),
+        check_valve.clone(),
+        FluidComponent::FluidArray(new_fluid_array(2.0, -90.0))]);
+
+    let reference_pressure = Pressure::new::<atmosphere>(1.0);
+
+    // in both flow directions, the last node of the diode sits half
+    // a node short of the diode outlet
+    for mass_flowrate in [
+        closed_loop.get_mass_flowrate_from_pressure_change(Pressure::ZERO),
+        MassRate::new::<kilogram_per_second>(-1.0e-5)] {
+
+        let inlet_pressures = closed_loop.try_get_inlet_absolute_pressures(
+            mass_flowrate, reference_pressure, 0)?;
+        let pressure_profile = closed_loop.try_get_absolute_pressure_profile(
+            mass_flowrate, reference_pressure, 0)?;
+
+        // 4 nodes in the check valve, no gaps
+        assert_eq!(pressure_profile[2].len(), 4);
+
+        let expected_last_node_pressure = inlet_pressures[2]
+            + check_valve.get_pressure_change_immutable(mass_flowrate)
+            * 3.5/4.0;
+
+        approx::assert_relative_eq!(
+            pressure_profile[2][3].get::<pascal>(),
+            expected_last_node_pressure.get::<pascal>(),
+            max_relative = 1e-9);
+    }
+
+    // in reverse flow, nearly all of the pressure drop is across
+    // the check valve
+    let reverse_profile = closed_loop.try_get_absolute_pressure_profile(
+        MassRate::new::<kilogram_per_second>(-1.0e-5), reference_pressure, 0)?;
+    assert!(reverse_profile[2][3] > reverse_profile[2][0]);
+
+    Ok(())
+}
+
+/// a super collection in series should give the same pressures 
+/// as one series collection with all the components, and parallel 
+/// branches should share the same inlet pressure
+#[test]
+pub fn absolute_pressures_in_super_collections() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_super_collection::FluidComponentSuperCollection;
+
+    let new_pipe = |length: f64, incline_angle: f64| -> FluidComponent {
+        FluidComponent::FluidArray(FluidArray::new_cylinder(
+            Length::new::<meter>(length),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::SteelSS304L,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(5.0),
+            2,
+            Angle::new::<degree>(incline_angle)))
+    };
+
+    let mut pump_body = new_pipe(0.5, 0.0);
+    pump_body.set_internal_pressure_source(Pressure::new::<pascal>(5000.0));
+
+    let series_collection = |components: Vec<FluidComponent>| {
+        let mut collection = 
+            FluidComponentCollection::new_series_component_collection();
+        collection.set_fluid_component_vector(components);
+        collection
+    };
+
+    let whole_loop = series_collection(vec![
+        pump_body.clone(), new_pipe(2.0, 90.0),
+        new_pipe(1.0, 0.0), new_pipe(2.0, -90.0)]);
+
+    let mut split_loop = FluidComponentSuperCollection::default();
+    split_loop.set_orientation_to_series();
+    split_loop.fluid_component_super_vector = vec![
+        series_collection(vec![pump_body, new_pipe(2.0, 90.0)]),
+        series_collection(vec![new_pipe(1.0, 0.0), new_pipe(2.0, -90.0)])];
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.1);
+    let reference_pressure = Pressure::new::<atmosphere>(1.0);
+
+    // reference at the top of the loop, like an expansion tank
+    let whole_loop_profile = whole_loop.try_get_absolute_pressure_profile(
+        mass_flowrate, reference_pressure, 2)?;
+    let split_loop_profile = split_loop.try_get_absolute_pressure_profile(
+        mass_flowrate, reference_pressure, 1, 0)?;
+
+    let flattened_split_loop_profile: Vec<Vec<Pressure>> = 
+        split_loop_profile.into_iter().flatten().collect();
+
+    assert_eq!(flattened_split_loop_profile, whole_loop_profile);
+
+    // two parallel branches with a closed loop flow of zero
+    let mut parallel_branches = FluidComponentSuperCollection::default();
+    parallel_branches.fluid_component_super_vector = vec![
+        series_collection(vec![new_pipe(1.0, 90.0), new_pipe(1.0, 0.0)]),
+        series_collection(vec![new_pipe(3.0, 0.0), new_pipe(1.0, 90.0)])];
+
+    let branch_profile = parallel_branches.try_get_absolute_pressure_profile(
+        MassRate::ZERO, reference_pressure, 1, 0)?;
+
+    let first_branch_inlet_pressures = parallel_branches
+        .fluid_component_super_vector[0]
+        .try_get_inlet_absolute_pressures(MassRate::ZERO, reference_pressure, 0)?;
+    let pressure_change_across_branches = parallel_branches
+        .try_get_pressure_change(MassRate::ZERO, &Default::default())?;
+    let branch_mass_flowrates = parallel_branches
+        .get_mass_flowrate_across_each_parallel_branch(
+            pressure_change_across_branches);
+
+    assert_eq!(branch_profile[0], parallel_branches
+        .fluid_component_super_vector[0]
+        .try_get_absolute_pressure_profile(
+            branch_mass_flowrates[0], first_branch_inlet_pressures[0], 0)?);
+
+    // the collections must be in range
+    assert!(parallel_branches.try_get_absolute_pressure_profile(
+        MassRate::ZERO, reference_pressure, 2, 0).is_err());
+
+    Ok(())
+}
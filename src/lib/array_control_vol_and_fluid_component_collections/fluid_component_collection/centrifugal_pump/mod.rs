@@ -0,0 +1,366 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::length::meter;
+use uom::si::time::second;
+use uom::si::angular_velocity::radian_per_second;
+use uom::si::moment_of_inertia::kilogram_square_meter;
+use uom::si::torque::newton_meter;
+use uom::si::volume_rate::cubic_meter_per_second;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::pressure::pascal;
+use uom::ConstZero;
+
+use crate::tuas_lib_error::TuasLibError;
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+
+use super::fluid_component_traits::FluidComponentTrait;
+use super::absolute_pressure::get_net_positive_suction_head_available;
+use super::absolute_pressure::GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED;
+
+/// a centrifugal pump model with a head-flow curve and a shaft
+/// speed state
+///
+/// The pump itself is not a fluid component. Instead, it computes
+/// the internal pressure source of the pump body (eg. the CTAH pump
+/// from new_ctah_pump) from the branch flow every timestep. This
+/// way, the existing collection solvers need not change. For CIET, 
+/// see new_ctah_centrifugal_pump and 
+/// ctah_branch_builder_with_centrifugal_pump.
+///
+/// The head curve at rated speed is a parabola through the shutoff
+/// head H_0 and the rated point (Q_r, H_r). Using the affinity laws
+/// (H ~ omega^2, Q ~ omega), the head at speed ratio
+/// alpha = omega/omega_r is
+///
+/// H = H_0 alpha |alpha| + (H_r - H_0)/Q_r^2 Q |Q|
+///
+/// This is a simplified four quadrant (homologous) curve:
+///
+/// 1. at zero speed (locked rotor), the pump acts as a flow
+///    resistance in both directions
+/// 2. in reverse flow with the pump running, the head increases
+///    with reverse flow as energy is dissipated in the impeller
+///
+/// The shaft speed follows
+///
+/// I d omega/dt = tau_motor - tau_hydraulic
+///
+/// where the hydraulic torque comes from a homologous torque curve
+/// in alpha and the flow ratio nu = Q/Q_r:
+///
+/// tau_hydraulic = tau_r (beta_0 alpha |alpha| + (1 - beta_0) alpha |nu|)
+///
+/// beta_0 is the shutoff torque as a fraction of the rated torque,
+/// and the rated torque is tau_r = rho g Q_r H_r / (eta_r omega_r).
+/// Along a homologous line (nu/alpha constant), the torque goes
+/// with alpha^2 as in the affinity laws. Like the head curve, this
+/// does not cover turbine operation, so the flow does not drive
+/// the rotor.
+///
+/// When the motor trips in a loop where the flow follows the speed
+/// (nu = alpha), the pump coasts down with
+/// omega(t) = omega_0 / (1 + t/t_c), t_c = I omega_r^2 / (tau_r omega_0)
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct CentrifugalPump {
+    /// rated shaft speed
+    pub rated_speed: AngularVelocity,
+    /// rated volumetric flowrate
+    pub rated_volumetric_flowrate: VolumeRate,
+    /// pump head at the rated flowrate and speed
+    pub rated_head: Length,
+    /// pump head at zero flow and rated speed
+    pub shutoff_head: Length,
+    /// hydraulic torque at the rated point
+    pub rated_hydraulic_torque: Torque,
+    /// moment of inertia of the rotor, motor and coupling
+    pub moment_of_inertia: MomentOfInertia,
+    /// torque supplied by the motor when it is powered
+    pub motor_torque: Torque,
+    /// whether the motor is powered, false after a trip
+    pub motor_on: bool,
+    /// whether the rotor is locked (eg. seized shaft or
+    /// anti reverse rotation device)
+    pub rotor_locked: bool,
+    /// net positive suction head required at rated speed, 
+    /// zero by default (no cavitation check)
+    pub rated_npsh_required: Length,
+    /// hydraulic torque at zero flow and rated speed as a fraction 
+    /// of the rated hydraulic torque, 0.5 by default which is 
+    /// typical of radial impellers
+    pub shutoff_torque_ratio: Ratio,
+    /// current shaft speed
+    shaft_speed: AngularVelocity,
+}
+
+impl CentrifugalPump {
+
+    /// creates a new pump running at rated speed with the motor on
+    ///
+    /// the motor torque is set to the rated hydraulic torque,
+    /// so that the pump stays at rated speed until it trips
+    pub fn new(rated_speed: AngularVelocity,
+        rated_volumetric_flowrate: VolumeRate,
+        rated_head: Length,
+        shutoff_head: Length,
+        rated_efficiency: Ratio,
+        rated_density: MassDensity,
+        moment_of_inertia: MomentOfInertia) -> Result<Self, TuasLibError> {
+
+        if rated_speed.get::<radian_per_second>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "rated pump speed must be more than 0".to_string()));
+        }
+
+        if rated_volumetric_flowrate.get::<cubic_meter_per_second>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "rated pump flowrate must be more than 0".to_string()));
+        }
+
+        if rated_head.get::<meter>() <= 0.0 || shutoff_head.get::<meter>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "rated and shutoff pump head must be more than 0".to_string()));
+        }
+
+        // otherwise the locked rotor would not resist flow
+        if shutoff_head <= rated_head {
+            return Err(TuasLibError::GenericStringError(
+                "pump shutoff head must be more than rated head".to_string()));
+        }
+
+        let efficiency = rated_efficiency.get::<ratio>();
+        if efficiency <= 0.0 || efficiency > 1.0 {
+            return Err(TuasLibError::GenericStringError(
+                "rated pump efficiency must be between 0 and 1".to_string()));
+        }
+
+        if moment_of_inertia.get::<kilogram_square_meter>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "pump moment of inertia must be more than 0".to_string()));
+        }
+
+        // tau_r = rho g Q_r H_r / (eta_r omega_r)
+        let rated_hydraulic_torque = Torque::new::<newton_meter>(
+            rated_density.get::<kilogram_per_cubic_meter>()
+            * GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED
+            * rated_volumetric_flowrate.get::<cubic_meter_per_second>()
+            * rated_head.get::<meter>()
+            / efficiency
+            / rated_speed.get::<radian_per_second>());
+
+        Ok(Self {
+            rated_speed,
+            rated_volumetric_flowrate,
+            rated_head,
+            shutoff_head,
+            rated_hydraulic_torque,
+            moment_of_inertia,
+            motor_torque: rated_hydraulic_torque,
+            motor_on: true,
+            rotor_locked: false,
+            rated_npsh_required: Length::ZERO,
+            shutoff_torque_ratio: Ratio::new::<ratio>(0.5),
+            shaft_speed: rated_speed,
+        })
+    }
+
+    /// gets the current shaft speed
+    pub fn get_shaft_speed(&self) -> AngularVelocity {
+        if self.rotor_locked {
+            return AngularVelocity::ZERO;
+        }
+        self.shaft_speed
+    }
+
+    /// sets the shaft speed, eg. for a pump starting from rest
+    pub fn set_shaft_speed(&mut self, shaft_speed: AngularVelocity) {
+        self.shaft_speed = shaft_speed;
+    }
+
+    /// shaft speed as a fraction of rated speed (alpha)
+    pub fn get_speed_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            self.get_shaft_speed().get::<radian_per_second>()
+            / self.rated_speed.get::<radian_per_second>())
+    }
+
+    /// trips the motor, the pump will coast down
+    pub fn trip(&mut self) {
+        self.motor_on = false;
+    }
+
+    /// powers the motor
+    pub fn start(&mut self) {
+        self.motor_on = true;
+    }
+
+    /// pump head at the current shaft speed for a given
+    /// volumetric flowrate, negative flowrates are reverse flow
+    pub fn get_pump_head(&self,
+        volumetric_flowrate: VolumeRate) -> Length {
+
+        let alpha = self.get_speed_ratio().get::<ratio>();
+        let flowrate = volumetric_flowrate.get::<cubic_meter_per_second>();
+        let rated_flowrate = self.rated_volumetric_flowrate
+            .get::<cubic_meter_per_second>();
+
+        let shutoff_head = self.shutoff_head.get::<meter>();
+        let quadratic_coefficient = (self.rated_head.get::<meter>()
+            - shutoff_head) / rated_flowrate.powi(2);
+
+        Length::new::<meter>(
+            shutoff_head * alpha * alpha.abs()
+            + quadratic_coefficient * flowrate * flowrate.abs())
+    }
+
+    /// pressure rise across the pump, rho g H, for a given
+    /// mass flowrate and fluid density
+    pub fn get_pump_pressure_rise(&self,
+        mass_flowrate: MassRate,
+        fluid_density: MassDensity) -> Pressure {
+
+        let pump_head = self.get_pump_head(mass_flowrate/fluid_density);
+
+        Pressure::new::<pascal>(
+            fluid_density.get::<kilogram_per_cubic_meter>()
+            * GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED
+            * pump_head.get::<meter>())
+    }
+
+    /// sets the internal pressure source of the pump body using the
+    /// branch mass flowrate and the fluid density of the pump body
+    ///
+    /// this should be called every timestep before the branch
+    /// flowrates are calculated
+    pub fn set_internal_pressure_source<T: FluidComponentTrait>(
+        &self,
+        pump_body: &mut T,
+        mass_flowrate: MassRate) -> Pressure {
+
+        let fluid_density = pump_body
+            .get_fluid_density_immutable_at_ref_temperature();
+        let pump_pressure_rise = self.get_pump_pressure_rise(
+            mass_flowrate, fluid_density);
+
+        pump_body.set_internal_pressure_source(pump_pressure_rise);
+
+        pump_pressure_rise
+    }
+
+    /// net positive suction head required at the current shaft 
+    /// speed, this scales with speed squared (affinity laws)
+    pub fn get_npsh_required(&self) -> Length {
+        let alpha = self.get_speed_ratio().get::<ratio>();
+
+        self.rated_npsh_required * alpha * alpha
+    }
+
+    /// NPSH margin (NPSHa - NPSHr) at the pump inlet given the 
+    /// absolute suction pressure, fluid material and temperature
+    ///
+    /// a negative margin means the pump will cavitate
+    pub fn try_get_npsh_margin(&self,
+        suction_absolute_pressure: Pressure,
+        fluid_material: LiquidMaterial,
+        fluid_temperature: ThermodynamicTemperature) -> Result<Length, TuasLibError> {
+
+        let vapour_pressure = fluid_material
+            .try_get_vapour_pressure(fluid_temperature)?;
+        let fluid_density = try_get_rho(fluid_material.into(), 
+            fluid_temperature, suction_absolute_pressure)?;
+
+        let npsh_available = get_net_positive_suction_head_available(
+            suction_absolute_pressure, vapour_pressure, fluid_density);
+
+        Ok(npsh_available - self.get_npsh_required())
+    }
+
+    /// flowrate as a fraction of rated flowrate (nu)
+    pub fn get_flow_ratio(&self, volumetric_flowrate: VolumeRate) -> Ratio {
+        volumetric_flowrate / self.rated_volumetric_flowrate
+    }
+
+    /// hydraulic torque on the impeller at the current shaft speed
+    /// and a given volumetric flowrate through the pump
+    ///
+    /// tau = tau_r (beta_0 alpha |alpha| + (1 - beta_0) alpha |nu|)
+    pub fn get_hydraulic_torque(&self,
+        volumetric_flowrate: VolumeRate) -> Torque {
+        let alpha = self.get_speed_ratio().get::<ratio>();
+        let nu = self.get_flow_ratio(volumetric_flowrate).get::<ratio>();
+        let beta_0 = self.shutoff_torque_ratio.get::<ratio>();
+
+        self.rated_hydraulic_torque
+            * (beta_0 * alpha * alpha.abs() + (1.0 - beta_0) * alpha * nu.abs())
+    }
+
+    /// advances the shaft speed over one timestep given the
+    /// volumetric flowrate through the pump, which is held
+    /// constant over the timestep
+    ///
+    /// this uses an implicit Euler step, which is unconditionally
+    /// stable for the hydraulic torque:
+    ///
+    /// p omega_new + a omega_new |omega_new| = c
+    ///
+    /// p = 1 + dt tau_r (1 - beta_0) |nu| / (I omega_r)
+    /// a = dt tau_r beta_0 / (I omega_r^2)
+    /// c = omega + dt tau_motor / I
+    pub fn advance_timestep(&mut self,
+        timestep: Time,
+        volumetric_flowrate: VolumeRate) -> Result<(), TuasLibError> {
+
+        let dt = timestep.get::<second>();
+
+        if dt < 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "timestep must not be negative".to_string()));
+        }
+
+        let beta_0 = self.shutoff_torque_ratio.get::<ratio>();
+
+        if !(0.0..=1.0).contains(&beta_0) {
+            return Err(TuasLibError::GenericStringError(
+                "pump shutoff torque ratio must be between 0 and 1"
+                .to_string()));
+        }
+
+        if self.rotor_locked {
+            self.shaft_speed = AngularVelocity::ZERO;
+            return Ok(());
+        }
+
+        let moment_of_inertia = self.moment_of_inertia
+            .get::<kilogram_square_meter>();
+        let rated_speed = self.rated_speed.get::<radian_per_second>();
+
+        let motor_torque = if self.motor_on {
+            self.motor_torque.get::<newton_meter>()
+        } else {
+            0.0
+        };
+
+        let rated_torque = self.rated_hydraulic_torque.get::<newton_meter>();
+        let nu = self.get_flow_ratio(volumetric_flowrate).get::<ratio>();
+
+        let p = 1.0 + dt * rated_torque * (1.0 - beta_0) * nu.abs()
+            / (moment_of_inertia * rated_speed);
+        let a = dt * rated_torque * beta_0
+            / (moment_of_inertia * rated_speed * rated_speed);
+        let c = self.shaft_speed.get::<radian_per_second>()
+            + dt * motor_torque / moment_of_inertia;
+
+        // root of the quadratic written to avoid cancellation,
+        // omega_new has the same sign as c
+        let new_shaft_speed = c.signum() * 2.0 * c.abs()
+            / (p + (p * p + 4.0 * a * c.abs()).sqrt());
+
+        self.shaft_speed = AngularVelocity::new::<radian_per_second>(
+            new_shaft_speed);
+
+        Ok(())
+    }
+}
+
+/// unit tests for the centrifugal pump
+pub mod unit_test_centrifugal_pump;
@@ -0,0 +1,265 @@
+/// the head curve should pass through the shutoff and rated points
+/// and scale with the affinity laws
+#[test]
+pub fn pump_head_curve_and_affinity_laws() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::angular_velocity::revolution_per_minute;
+    use uom::si::volume_rate::cubic_meter_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::moment_of_inertia::kilogram_square_meter;
+    use super::CentrifugalPump;
+
+    let rated_speed = AngularVelocity::new::<revolution_per_minute>(1450.0);
+    let rated_flowrate = VolumeRate::new::<cubic_meter_per_second>(0.01);
+
+    let mut pump = CentrifugalPump::new(
+        rated_speed,
+        rated_flowrate,
+        Length::new::<meter>(20.0),
+        Length::new::<meter>(26.0),
+        Ratio::new::<ratio>(0.7),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        MomentOfInertia::new::<kilogram_square_meter>(0.05))?;
+
+    approx::assert_relative_eq!(
+        pump.get_pump_head(VolumeRate::ZERO).get::<meter>(),
+        26.0);
+    approx::assert_relative_eq!(
+        pump.get_pump_head(rated_flowrate).get::<meter>(),
+        20.0,
+        max_relative = 1e-12);
+
+    // half speed, the homologous point at half flow has a
+    // quarter of the head
+    pump.set_shaft_speed(rated_speed * 0.5);
+
+    approx::assert_relative_eq!(
+        pump.get_pump_head(rated_flowrate * 0.5).get::<meter>(),
+        5.0,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        pump.get_hydraulic_torque(rated_flowrate * 0.5)
+            .get::<uom::si::torque::newton_meter>(),
+        0.25 * pump.rated_hydraulic_torque
+            .get::<uom::si::torque::newton_meter>(),
+        max_relative = 1e-12);
+
+    // at rated speed, the torque depends on flow as well,
+    // from the shutoff torque at zero flow to the rated torque
+    // at rated flow, and reverse flow takes more torque
+    pump.set_shaft_speed(rated_speed);
+
+    approx::assert_relative_eq!(
+        pump.get_hydraulic_torque(VolumeRate::ZERO)
+            .get::<uom::si::torque::newton_meter>(),
+        pump.shutoff_torque_ratio.get::<ratio>() * pump.rated_hydraulic_torque
+            .get::<uom::si::torque::newton_meter>(),
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        pump.get_hydraulic_torque(rated_flowrate)
+            .get::<uom::si::torque::newton_meter>(),
+        pump.rated_hydraulic_torque
+            .get::<uom::si::torque::newton_meter>(),
+        max_relative = 1e-12);
+    assert!(pump.get_hydraulic_torque(-rated_flowrate * 0.5) 
+        > pump.get_hydraulic_torque(VolumeRate::ZERO));
+
+    // locked rotor, the pump is a flow resistance which opposes
+    // flow in both directions
+    pump.rotor_locked = true;
+
+    assert_eq!(pump.get_shaft_speed(), AngularVelocity::ZERO);
+    assert!(pump.get_pump_head(rated_flowrate).get::<meter>() < 0.0);
+    assert!(pump.get_pump_head(-rated_flowrate).get::<meter>() > 0.0);
+    approx::assert_relative_eq!(
+        pump.get_pump_head(rated_flowrate).get::<meter>(),
+        -pump.get_pump_head(-rated_flowrate).get::<meter>());
+
+    // reverse flow with the pump running gives more than shutoff head
+    pump.rotor_locked = false;
+    pump.set_shaft_speed(rated_speed);
+    assert!(pump.get_pump_head(-rated_flowrate).get::<meter>() > 26.0);
+
+    Ok(())
+}
+
+/// after a trip, if the flow follows the shaft speed, the shaft 
+/// speed should follow the analytical coastdown omega_0/(1 + t/t_c), 
+/// and with the motor on and rated flow, the pump should stay at 
+/// rated speed
+///
+/// without flow, the hydraulic torque is lower, so the pump 
+/// should coast down more slowly
+#[test]
+pub fn pump_coastdown_after_trip() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::time::second;
+    use uom::si::angular_velocity::{radian_per_second, revolution_per_minute};
+    use uom::si::volume_rate::cubic_meter_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::moment_of_inertia::kilogram_square_meter;
+    use uom::si::torque::newton_meter;
+    use super::CentrifugalPump;
+
+    let rated_speed = AngularVelocity::new::<revolution_per_minute>(1450.0);
+    let rated_flowrate = VolumeRate::new::<cubic_meter_per_second>(0.01);
+
+    let mut pump = CentrifugalPump::new(
+        rated_speed,
+        rated_flowrate,
+        Length::new::<meter>(20.0),
+        Length::new::<meter>(26.0),
+        Ratio::new::<ratio>(0.7),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        MomentOfInertia::new::<kilogram_square_meter>(0.05))?;
+
+    let timestep = Time::new::<second>(0.001);
+
+    // motor on, speed stays at rated speed
+    for _ in 0..100 {
+        pump.advance_timestep(timestep, rated_flowrate)?;
+    }
+    approx::assert_relative_eq!(
+        pump.get_shaft_speed().get::<radian_per_second>(),
+        rated_speed.get::<radian_per_second>(),
+        max_relative = 1e-9);
+
+    // trip and coast down for 5 s, the flow follows the speed
+    // (a loop dominated by quadratic losses)
+    let mut pump_without_flow = pump;
+    pump.trip();
+    pump_without_flow.trip();
+    for _ in 0..5000 {
+        let homologous_flowrate = rated_flowrate * pump.get_speed_ratio();
+        pump.advance_timestep(timestep, homologous_flowrate)?;
+        pump_without_flow.advance_timestep(timestep, VolumeRate::ZERO)?;
+    }
+
+    let omega_rated = rated_speed.get::<radian_per_second>();
+    let coastdown_time_constant = 0.05 * omega_rated
+        / pump.rated_hydraulic_torque.get::<newton_meter>();
+    let expected_speed = omega_rated / (1.0 + 5.0/coastdown_time_constant);
+
+    approx::assert_relative_eq!(
+        pump.get_shaft_speed().get::<radian_per_second>(),
+        expected_speed,
+        max_relative = 1e-3);
+
+    assert!(pump_without_flow.get_shaft_speed() > pump.get_shaft_speed());
+
+    // restarting the motor brings the pump back to rated speed
+    pump.start();
+    for _ in 0..20000 {
+        let homologous_flowrate = rated_flowrate * pump.get_speed_ratio();
+        pump.advance_timestep(timestep, homologous_flowrate)?;
+    }
+    approx::assert_relative_eq!(
+        pump.get_shaft_speed().get::<radian_per_second>(),
+        omega_rated,
+        max_relative = 1e-3);
+
+    assert!(pump.advance_timestep(-timestep, rated_flowrate).is_err());
+
+    pump.shutoff_torque_ratio = Ratio::new::<ratio>(1.5);
+    assert!(pump.advance_timestep(timestep, rated_flowrate).is_err());
+
+    Ok(())
+}
+
+/// a pump in a closed loop with a pipe, the internal pressure
+/// source is updated from the loop flowrate until the pump
+/// pressure rise balances the pipe pressure loss
+#[test]
+pub fn pump_operating_point_in_closed_loop() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::angular_velocity::revolution_per_minute;
+    use uom::si::volume_rate::cubic_meter_per_second;
+    use uom::si::moment_of_inertia::kilogram_square_meter;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use super::CentrifugalPump;
+
+    let new_pipe = |length: f64, form_loss: f64| -> FluidComponent {
+        FluidComponent::FluidArray(FluidArray::new_cylinder(
+            Length::new::<meter>(length),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::SteelSS304L,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(form_loss),
+            0,
+            Angle::new::<degree>(0.0)))
+    };
+
+    let mut pump_body = new_pipe(0.3, 0.0);
+    let loop_pipe = new_pipe(5.0, 10.0);
+
+    let fluid_density = pump_body
+        .get_fluid_density_immutable_at_ref_temperature();
+
+    let pump = CentrifugalPump::new(
+        AngularVelocity::new::<revolution_per_minute>(1450.0),
+        VolumeRate::new::<cubic_meter_per_second>(0.003),
+        Length::new::<meter>(2.0),
+        Length::new::<meter>(3.0),
+        Ratio::new::<ratio>(0.6),
+        fluid_density,
+        MomentOfInertia::new::<kilogram_square_meter>(0.01))?;
+
+    let mut mass_flowrate = MassRate::ZERO;
+
+    for _ in 0..100 {
+        pump.set_internal_pressure_source(&mut pump_body, mass_flowrate);
+
+        let mut closed_loop = 
+            FluidComponentCollection::new_series_component_collection();
+        closed_loop.set_fluid_component_vector(vec![pump_body.clone(), loop_pipe.clone()]);
+
+        let new_mass_flowrate = closed_loop
+            .get_mass_flowrate_from_pressure_change(Pressure::ZERO);
+
+        // under relaxation
+        mass_flowrate = mass_flowrate * 0.5 + new_mass_flowrate * 0.5;
+    }
+
+    let pump_pressure_rise = pump.get_pump_pressure_rise(
+        mass_flowrate, fluid_density);
+    let loop_pressure_loss = pump_body.get_pressure_loss_immutable(
+        mass_flowrate)
+        + loop_pipe.get_pressure_loss_immutable(mass_flowrate);
+
+    assert!(mass_flowrate.get::<kilogram_per_second>() > 0.0);
+    approx::assert_relative_eq!(
+        pump_pressure_rise.get::<pascal>(),
+        loop_pressure_loss.get::<pascal>(),
+        max_relative = 1e-4);
+
+    Ok(())
+}
@@ -49,6 +49,7 @@ use uom::num_traits::ToPrimitive;
 use uom::si::f64::{Pressure, MassRate};
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::pressure::pascal;
+use uom::ConstZero;
 
 
 // the peroxide crate for root finders
@@ -61,6 +62,9 @@ use roots::SimpleConvergency;
 
 use super::fluid_component::FluidComponent;
 use super::fluid_component_traits::FluidComponentTrait;
+use super::root_finding::RootFindingSettings;
+use super::fluid_component_super_collection::ParallelBranchSolution;
+use crate::tuas_lib_error::TuasLibError;
 
 /// contains associated functions which take a fluid component
 /// vector and calculate mass flowrates and pressure changes
@@ -526,10 +530,47 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
     /// calculates pressure change given a mass
     /// flowrate through a parallel collection of
     /// fluid pipes or components
+    ///
+    /// this uses RootFindingSettings::component_collection_default() and panics if 
+    /// the root finder does not converge, 
+    /// see try_calculate_pressure_change_from_mass_flowrate
+    #[deprecated(note = "panics if the root finder does not converge, use try_calculate_pressure_change_from_mass_flowrate instead")]
     fn calculate_pressure_change_from_mass_flowrate(
         mass_flowrate: MassRate,
         fluid_component_vector: &Vec<FluidComponent>) -> Pressure {
 
+        <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_from_mass_flowrate(
+                mass_flowrate, 
+                fluid_component_vector, 
+                &RootFindingSettings::component_collection_default())
+                .expect("parallel collection pressure change did not converge, \
+                    use try_calculate_pressure_change_from_mass_flowrate to handle the error")
+    }
+
+    /// calculates pressure change given a mass
+    /// flowrate through a parallel collection of
+    /// fluid pipes or components using user specified root 
+    /// finding settings
+    ///
+    /// returns an error with the last bracket if the root 
+    /// finder does not converge
+    fn try_calculate_pressure_change_from_mass_flowrate(
+        mass_flowrate: MassRate,
+        fluid_component_vector: &Vec<FluidComponent>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(unblocked_component_vector) = 
+            <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            obtain_unblocked_component_vector(fluid_component_vector)? {
+
+            return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_from_mass_flowrate(
+                    mass_flowrate, 
+                    &unblocked_component_vector, 
+                    root_finding_settings);
+        }
+
         // for calculating pressure change in a parallel collection from
         // mass flowrate, 
         // i will need to iteratively guess the pressure change
@@ -617,10 +658,11 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
                 zero_mass_flowrate;
 
             return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     guess_average_mass_flowrate, 
                     user_requested_mass_flowrate, 
-                    fluid_component_vector);
+                    fluid_component_vector,
+                    root_finding_settings);
         }
 
         // if flow is non zero, then we will have to deal with 3 bounding cases
@@ -721,10 +763,11 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
                 zero_mass_flowrate;
 
             return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     guess_average_mass_flowrate, 
                     user_requested_mass_flowrate, 
-                    fluid_component_vector);
+                    fluid_component_vector,
+                    root_finding_settings);
         }
 
         // next we can go to the other extreme, where external flowrate is 
@@ -755,10 +798,11 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
 
 
             return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     guess_average_mass_flowrate, 
                     user_requested_mass_flowrate, 
-                    fluid_component_vector);
+                    fluid_component_vector,
+                    root_finding_settings);
 
         }
 
@@ -803,10 +847,11 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
                 zero_mass_flowrate;
 
             return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     guess_average_mass_flowrate, 
                     user_requested_mass_flowrate, 
-                    fluid_component_vector);
+                    fluid_component_vector,
+                    root_finding_settings);
 
 
         }
@@ -826,10 +871,11 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
             /number_of_branches;
 
         return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
-            calculate_pressure_change_using_guessed_branch_mass_flowrate(
+            try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                 guess_average_mass_flowrate, 
                 user_requested_mass_flowrate, 
-                fluid_component_vector);
+                fluid_component_vector,
+                root_finding_settings);
 
     }
 
@@ -838,12 +884,48 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
     /// given a guessed flowrate through each branch
     /// and user specified flowrate
     ///
+    /// this uses RootFindingSettings::component_collection_default() and panics if 
+    /// the root finder does not converge
+    #[deprecated(note = "panics if the root finder does not converge, use try_calculate_pressure_change_using_guessed_branch_mass_flowrate instead")]
     #[inline]
     fn calculate_pressure_change_using_guessed_branch_mass_flowrate(
         guess_average_mass_flowrate: MassRate,
         user_specified_mass_flowrate: MassRate,
         fluid_component_vector: &Vec<FluidComponent>) -> Pressure {
 
+        <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                guess_average_mass_flowrate, 
+                user_specified_mass_flowrate, 
+                fluid_component_vector, 
+                &RootFindingSettings::component_collection_default())
+                .expect("parallel collection pressure change did not converge, \
+                    use try_calculate_pressure_change_using_guessed_branch_mass_flowrate to handle the error")
+    }
+
+    /// calculates pressure change at user specified mass flowrate
+    /// given a guessed flowrate through each branch
+    /// and user specified flowrate, using user specified root
+    /// finding settings
+    ///
+    #[inline]
+    fn try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+        guess_average_mass_flowrate: MassRate,
+        user_specified_mass_flowrate: MassRate,
+        fluid_component_vector: &Vec<FluidComponent>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(unblocked_component_vector) = 
+            <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            obtain_unblocked_component_vector(fluid_component_vector)? {
+
+            return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                    guess_average_mass_flowrate, 
+                    user_specified_mass_flowrate, 
+                    &unblocked_component_vector, 
+                    root_finding_settings);
+        }
 
         // first i am applying the average gussed flowrate through all branches
         // this is the trivial solution
@@ -945,19 +1027,154 @@ pub trait FluidComponentCollectionParallelAssociatedFunctions {
 
         // i can't use a convergency value too strict, perhaps 1e-9 will do!
         //
-        let mut convergency = SimpleConvergency { eps:1e-9_f64, max_iter:30 };
-
-        let pressure_change_pascals_result_user_specified_flow
-            = find_root_brent(
+        let pressure_change_pascals_user_specified_flow: f64 = 
+            root_finding_settings.find_root(
+                pressure_change_from_mass_flowrate_root,
                 user_specified_pressure_upper_bound.value,
-                user_specified_pressure_lower_bound.value,
-                &pressure_change_from_mass_flowrate_root,
-                &mut convergency);
+                user_specified_pressure_lower_bound.value)?;
 
-        let pressure_change_pascals_user_specified_flow: f64 = 
-            pressure_change_pascals_result_user_specified_flow.unwrap();
+        return Ok(Pressure::new::<pascal>(pressure_change_pascals_user_specified_flow));
+    }
+
+    /// calculates pressure change given a mass flowrate through a 
+    /// parallel collection, starting from an initial guess of the 
+    /// pressure change (usually the solution from the previous 
+    /// timestep)
+    ///
+    /// this skips the bracketing heuristics used in 
+    /// try_calculate_pressure_change_from_mass_flowrate. When branch 
+    /// flows change little between timesteps, only a few evaluations 
+    /// of the branches are needed
+    fn try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+        mass_flowrate: MassRate,
+        fluid_component_vector: &Vec<FluidComponent>,
+        pressure_change_initial_guess: Pressure,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(unblocked_component_vector) = 
+            <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            obtain_unblocked_component_vector(fluid_component_vector)? {
+
+            return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+                    mass_flowrate, 
+                    &unblocked_component_vector, 
+                    pressure_change_initial_guess, 
+                    root_finding_settings);
+        }
+
+        let pressure_change_from_mass_flowrate_root = 
+            |branch_pressure_change_pascals: f64| -> f64 {
+
+                let iterated_pressure = 
+                    Pressure::new::<pascal>(branch_pressure_change_pascals);
+
+                let iterated_mass_flowrate =
+                    <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                    calculate_mass_flowrate_from_pressure_change(
+                        iterated_pressure, 
+                        fluid_component_vector);
+
+                (iterated_mass_flowrate - mass_flowrate)
+                    .get::<kilogram_per_second>()
+        };
+
+        let pressure_change_pascals: f64 = 
+            root_finding_settings.find_root_from_initial_guess(
+                pressure_change_from_mass_flowrate_root,
+                pressure_change_initial_guess.get::<pascal>())?;
+
+        Ok(Pressure::new::<pascal>(pressure_change_pascals))
+    }
+
+    /// calculates pressure change given a mass flowrate through a 
+    /// parallel collection, warm started from the previous solution if 
+    /// there is one
+    ///
+    /// the previous pressure change is tried as an initial guess 
+    /// first. If that fails, the largest of the previous component 
+    /// mass flowrates is used as the guessed upper bound of the 
+    /// component mass flowrates. Otherwise, (or without a previous 
+    /// solution) the usual bracketing heuristics are used
+    fn try_calculate_pressure_change_from_mass_flowrate_with_previous_solution(
+        mass_flowrate: MassRate,
+        fluid_component_vector: &Vec<FluidComponent>,
+        previous_parallel_solution: Option<&ParallelBranchSolution>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(previous_parallel_solution) = previous_parallel_solution {
+
+            let warm_started_pressure_change = 
+                <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+                    mass_flowrate, 
+                    fluid_component_vector, 
+                    previous_parallel_solution.pressure_change, 
+                    root_finding_settings);
+
+            if warm_started_pressure_change.is_ok() {
+                return warm_started_pressure_change;
+            }
+
+            let largest_previous_branch_mass_flowrate: MassRate = 
+                previous_parallel_solution.branch_mass_flowrates.iter()
+                .map(|branch_mass_flowrate| branch_mass_flowrate.abs())
+                .fold(mass_flowrate.abs(), |largest_mass_flowrate, branch_mass_flowrate| {
+                    largest_mass_flowrate.max(branch_mass_flowrate)
+                });
+
+            if largest_previous_branch_mass_flowrate > MassRate::ZERO {
+
+                let guessed_pressure_change = 
+                    <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                    try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                        largest_previous_branch_mass_flowrate, 
+                        mass_flowrate, 
+                        fluid_component_vector, 
+                        root_finding_settings);
+
+                if guessed_pressure_change.is_ok() {
+                    return guessed_pressure_change;
+                }
+            }
+        }
+
+        <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_from_mass_flowrate(
+                mass_flowrate, 
+                fluid_component_vector, 
+                root_finding_settings)
+    }
+
+    /// blocked components (eg. with a closed valve) carry no flow, 
+    /// and their pressure change at any nonzero flow is infinite, 
+    /// so they are left out of the root finding
+    ///
+    /// returns None if no component is blocked, and an error if 
+    /// all the components are blocked
+    fn obtain_unblocked_component_vector(
+        fluid_component_vector: &[FluidComponent]) 
+        -> Result<Option<Vec<FluidComponent>>, TuasLibError> {
+
+        if !fluid_component_vector.iter().any(
+            |component| component.is_flow_blocked()) {
+            return Ok(None);
+        }
+
+        let unblocked_component_vector: Vec<FluidComponent> = 
+            fluid_component_vector.iter()
+            .filter(|component| !component.is_flow_blocked())
+            .cloned()
+            .collect();
+
+        if unblocked_component_vector.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "all components in parallel are blocked, \
+                pressure change cannot be found from mass flowrate"
+                .to_string()));
+        }
 
-        return Pressure::new::<pascal>(pressure_change_pascals_user_specified_flow);
+        Ok(Some(unblocked_component_vector))
     }
 
     /// This function takes a mass flowrate and applies it to each
@@ -0,0 +1,513 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::fluid_component::FluidComponent;
+use super::fluid_component_traits::FluidComponentTrait;
+use super::root_finding::RootFindingSettings;
+
+/// converts a flow coefficient Cv (US gpm at 1 psi pressure drop
+/// with water) to Kv (m3/h at 1 bar pressure drop with water)
+pub const CV_TO_KV_CONVERSION_FACTOR: f64 = 0.865;
+
+/// inherent valve characteristic, this is the fraction of the
+/// fully open flow coefficient as a function of valve position
+///
+/// Kv(x) = Kv_open f(x)
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum ValveCharacteristic {
+    /// f(x) = x
+    Linear,
+    /// f(x) = R^(x-1) for x > 0, where R is the rangeability,
+    /// (typically 20-50)
+    ///
+    /// the valve is assumed to have tight shutoff at x = 0
+    EqualPercentage {
+        /// rangeability, ratio of maximum to minimum
+        /// controllable flow coefficient
+        rangeability: Ratio
+    },
+    /// f(x) = sqrt(x)
+    QuickOpening,
+}
+
+impl ValveCharacteristic {
+
+    /// returns the fraction of the fully open flow coefficient
+    /// at a valve position between 0 (closed) and 1 (fully open)
+    pub fn get_relative_flow_coefficient(&self,
+        valve_position: Ratio) -> Ratio {
+
+        let x = valve_position.get::<ratio>().clamp(0.0, 1.0);
+
+        if x == 0.0 {
+            return Ratio::ZERO;
+        }
+
+        let relative_flow_coefficient = match self {
+            ValveCharacteristic::Linear => x,
+            ValveCharacteristic::EqualPercentage { rangeability } => {
+                rangeability.get::<ratio>().powf(x - 1.0)
+            },
+            ValveCharacteristic::QuickOpening => x.sqrt(),
+        };
+
+        Ratio::new::<ratio>(relative_flow_coefficient)
+    }
+}
+
+/// a control valve, this is a fluid component whose pressure loss
+/// depends on valve position
+///
+/// The FluidArray carries the geometry, fluid properties and
+/// temperatures, as well as the loss correlation of the valve body
+/// and connecting pipework. The valve itself adds a form loss
+///
+/// K(x) = K_open / f(x)^2
+///
+/// where K_open is the fully open form loss (based on the
+/// cross sectional area of the fluid array) and f(x) is the
+/// valve characteristic.
+///
+/// When the valve is fully closed, no flow passes through it. The
+/// collection solvers skip closed valves in parallel branches, and
+/// series collections with a closed valve have no flow
+///
+/// The valve position can follow a setpoint with a finite
+/// actuator stroke time, this is done using advance_timestep
+#[derive(Debug,Clone,PartialEq)]
+pub struct ControlValve {
+    /// the fluid array, which carries the loss correlation for the
+    /// valve body and connecting pipework
+    pub fluid_array: FluidArray,
+    /// inherent valve characteristic
+    pub valve_characteristic: ValveCharacteristic,
+    /// form loss of the valve when fully open, based on the cross
+    /// sectional area of the fluid array
+    pub fully_open_form_loss: Ratio,
+    /// time taken for the actuator to move the valve from
+    /// fully closed to fully open, None means the valve
+    /// moves instantly
+    pub actuator_stroke_time: Option<Time>,
+    /// valve position, 0 is closed and 1 is fully open
+    valve_position: Ratio,
+    /// valve position setpoint
+    valve_position_setpoint: Ratio,
+    /// mass flowrate through the valve
+    mass_flowrate: MassRate,
+    /// pressure loss through the valve
+    pressure_loss: Pressure,
+}
+
+impl ControlValve {
+
+    /// creates a new fully open control valve using a fully open
+    /// form loss (based on the cross sectional area of the fluid array)
+    pub fn new(fluid_array: FluidArray,
+        fully_open_form_loss: Ratio,
+        valve_characteristic: ValveCharacteristic) -> Result<Self, TuasLibError> {
+
+        if fully_open_form_loss.get::<ratio>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "fully open valve form loss must be more than 0".to_string()));
+        }
+
+        let mut fluid_array = fluid_array;
+        let mass_flowrate = fluid_array.get_mass_flowrate();
+
+        let mut control_valve = Self {
+            fluid_array,
+            valve_characteristic,
+            fully_open_form_loss,
+            actuator_stroke_time: None,
+            valve_position: Ratio::new::<ratio>(1.0),
+            valve_position_setpoint: Ratio::new::<ratio>(1.0),
+            mass_flowrate,
+            pressure_loss: Pressure::ZERO,
+        };
+
+        control_valve.pressure_loss =
+            control_valve.get_pressure_loss_immutable(mass_flowrate);
+
+        Ok(control_valve)
+    }
+
+    /// creates a new fully open control valve using the fully open
+    /// flow coefficient Kv (m3/h of water at 1 bar pressure drop)
+    ///
+    /// Delta P [bar] = SG (Q [m3/h] / Kv)^2
+    ///
+    /// is converted into a form loss based on the cross sectional
+    /// area of the fluid array
+    pub fn new_from_kv(fluid_array: FluidArray,
+        fully_open_kv: f64,
+        valve_characteristic: ValveCharacteristic) -> Result<Self, TuasLibError> {
+
+        if fully_open_kv <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "fully open valve Kv must be more than 0".to_string()));
+        }
+
+        let xs_area_square_meters: f64 =
+            fluid_array.get_cross_sectional_area_immutable().value;
+
+        // Delta P = 1e5 (rho/1000) (3600 Q/Kv)^2 = K rho Q^2/(2A^2)
+        let fully_open_form_loss = Ratio::new::<ratio>(
+            2.0 * xs_area_square_meters.powi(2) * 1.0e5 * 3600.0_f64.powi(2)
+            / 1000.0 / fully_open_kv.powi(2));
+
+        Self::new(fluid_array, fully_open_form_loss, valve_characteristic)
+    }
+
+    /// creates a new fully open control valve using the fully open
+    /// flow coefficient Cv (US gpm of water at 1 psi pressure drop)
+    pub fn new_from_cv(fluid_array: FluidArray,
+        fully_open_cv: f64,
+        valve_characteristic: ValveCharacteristic) -> Result<Self, TuasLibError> {
+
+        Self::new_from_kv(fluid_array,
+            fully_open_cv * CV_TO_KV_CONVERSION_FACTOR,
+            valve_characteristic)
+    }
+
+    /// gets the current valve position
+    pub fn get_valve_position(&self) -> Ratio {
+        self.valve_position
+    }
+
+    /// sets the valve position immediately (and the setpoint
+    /// along with it), ignoring actuator dynamics
+    pub fn set_valve_position(&mut self,
+        valve_position: Ratio) -> Result<(), TuasLibError> {
+
+        check_valve_position(valve_position)?;
+
+        self.valve_position = valve_position;
+        self.valve_position_setpoint = valve_position;
+        self.update_pressure_loss_from_mass_flowrate();
+
+        Ok(())
+    }
+
+    /// gets the valve position setpoint
+    pub fn get_valve_position_setpoint(&self) -> Ratio {
+        self.valve_position_setpoint
+    }
+
+    /// sets the valve position setpoint, the valve moves towards
+    /// it when advance_timestep is called
+    pub fn set_valve_position_setpoint(&mut self,
+        valve_position_setpoint: Ratio) -> Result<(), TuasLibError> {
+
+        check_valve_position(valve_position_setpoint)?;
+        self.valve_position_setpoint = valve_position_setpoint;
+
+        Ok(())
+    }
+
+    /// moves the valve towards its setpoint over one timestep
+    ///
+    /// the actuator moves at a constant rate of one full stroke
+    /// per stroke time. If no stroke time is given, the valve
+    /// moves to its setpoint immediately
+    pub fn advance_timestep(&mut self,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        if timestep.get::<second>() < 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "timestep must not be negative".to_string()));
+        }
+
+        let position_error = self.valve_position_setpoint
+            - self.valve_position;
+
+        let new_valve_position = match self.actuator_stroke_time {
+            Some(stroke_time) if stroke_time.get::<second>() > 0.0 => {
+                let maximum_position_change: Ratio = timestep/stroke_time;
+
+                if position_error.abs() <= maximum_position_change {
+                    self.valve_position_setpoint
+                } else {
+                    self.valve_position
+                        + maximum_position_change * position_error.signum()
+                }
+            },
+            _ => self.valve_position_setpoint,
+        };
+
+        self.valve_position = new_valve_position;
+        self.update_pressure_loss_from_mass_flowrate();
+
+        Ok(())
+    }
+
+    /// returns true if the valve is fully closed
+    pub fn is_fully_closed(&self) -> bool {
+        self.valve_characteristic.get_relative_flow_coefficient(
+            self.valve_position).get::<ratio>() == 0.0
+    }
+
+    /// form loss K of the valve at its current position, based on
+    /// the cross sectional area of the fluid array
+    ///
+    /// this is infinite when the valve is closed
+    pub fn get_valve_form_loss(&self) -> Ratio {
+
+        let relative_flow_coefficient = self.valve_characteristic
+            .get_relative_flow_coefficient(self.valve_position);
+
+        if relative_flow_coefficient.get::<ratio>() == 0.0 {
+            return Ratio::new::<ratio>(f64::INFINITY);
+        }
+
+        self.fully_open_form_loss
+            / relative_flow_coefficient
+            / relative_flow_coefficient
+    }
+
+    /// pressure loss across the valve alone (excluding the valve
+    /// body loss correlation of the fluid array) at a given
+    /// mass flowrate
+    ///
+    /// Delta P = K(x) m |m| / (2 rho A^2)
+    pub fn get_valve_pressure_loss_immutable(&self,
+        mass_flowrate: MassRate) -> Pressure {
+
+        if mass_flowrate == MassRate::ZERO {
+            return Pressure::ZERO;
+        }
+
+        let fluid_density = self.fluid_array.get_fluid_density_immutable();
+        let xs_area = self.fluid_array.get_cross_sectional_area_immutable();
+
+        self.get_valve_form_loss() * mass_flowrate * mass_flowrate.abs()
+            / (2.0 * fluid_density * xs_area * xs_area)
+    }
+
+    /// keeps the stored pressure loss consistent with the
+    /// stored mass flowrate after the valve position changes
+    fn update_pressure_loss_from_mass_flowrate(&mut self) {
+        if self.is_fully_closed() {
+            self.mass_flowrate = MassRate::ZERO;
+            self.fluid_array.set_mass_flowrate(MassRate::ZERO);
+        }
+        self.pressure_loss = self.get_pressure_loss_immutable(
+            self.mass_flowrate);
+    }
+}
+
+impl ControlValve {
+
+    /// the valve and valve body losses are in series, so the
+    /// mass flowrate is found iteratively
+    ///
+    /// the mass flowrate is bracketed between zero and the smaller
+    /// of the flowrates through the valve body alone and the
+    /// valve alone at the same pressure loss
+    ///
+    /// returns an error if the root finder fails rather than 
+    /// panicking
+    pub fn try_get_mass_flowrate_from_pressure_loss(
+        &self, pressure_loss: Pressure) -> Result<MassRate, TuasLibError> {
+
+        if self.is_fully_closed() || pressure_loss == Pressure::ZERO {
+            return Ok(MassRate::ZERO);
+        }
+
+        let mass_flowrate_bound = self.get_mass_flowrate_bound(pressure_loss);
+
+        let pressure_loss_root = |mass_flowrate_kg_per_s: f64| -> f64 {
+            let mass_flowrate = MassRate::new::<kilogram_per_second>(
+                mass_flowrate_kg_per_s);
+
+            (self.get_pressure_loss_immutable(mass_flowrate)
+             - pressure_loss.abs()).value
+        };
+
+        // due to roundoff, the pressure loss at the bound may be 
+        // just under the required pressure loss when one of the 
+        // losses dominates, the bound is then the root
+        if pressure_loss_root(
+            mass_flowrate_bound.get::<kilogram_per_second>()) <= 0.0 {
+            return Ok(mass_flowrate_bound * pressure_loss.value.signum());
+        }
+
+        let root_finding_settings = RootFindingSettings {
+            absolute_tolerance: 1e-12,
+            ..Default::default()
+        };
+
+        let mass_flowrate_magnitude = root_finding_settings.find_root(
+            pressure_loss_root,
+            0.0,
+            mass_flowrate_bound.get::<kilogram_per_second>())?;
+
+        Ok(MassRate::new::<kilogram_per_second>(
+            mass_flowrate_magnitude * pressure_loss.value.signum()))
+    }
+
+    /// smaller of the flowrates through the valve body alone and 
+    /// the valve alone at the given pressure loss, the flowrate 
+    /// through the valve and valve body together cannot exceed this
+    fn get_mass_flowrate_bound(&self, pressure_loss: Pressure) -> MassRate {
+
+        let fluid_density = self.fluid_array.get_fluid_density_immutable();
+        let xs_area = self.fluid_array.get_cross_sectional_area_immutable();
+
+        let valve_only_mass_flowrate: MassRate =
+            (2.0 * fluid_density * xs_area * xs_area * pressure_loss.abs()
+             / self.get_valve_form_loss()).sqrt();
+
+        let valve_body_only_mass_flowrate: MassRate = self.fluid_array
+            .get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss.abs());
+
+        valve_only_mass_flowrate.min(valve_body_only_mass_flowrate)
+    }
+}
+
+fn check_valve_position(valve_position: Ratio) -> Result<(), TuasLibError> {
+    let x = valve_position.get::<ratio>();
+
+    if !(0.0..=1.0).contains(&x) {
+        return Err(TuasLibError::GenericStringError(
+            "valve position must be between 0 (closed) and 1 (open)"
+            .to_string()));
+    }
+
+    Ok(())
+}
+
+impl FluidComponentTrait for ControlValve {
+    fn get_mass_flowrate(&mut self) -> MassRate  {
+        self.mass_flowrate
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+        self.pressure_loss = self.get_pressure_loss_immutable(mass_flowrate);
+
+        // the fluid array needs the mass flowrate for advection
+        self.fluid_array.set_mass_flowrate(mass_flowrate);
+    }
+
+    /// the valve and valve body losses are in series, so the
+    /// mass flowrate is found iteratively
+    ///
+    /// panics if the root finder fails, the same as for the 
+    /// FlowDiode, see try_get_mass_flowrate_from_pressure_loss
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        self.try_get_mass_flowrate_from_pressure_loss(pressure_loss)
+            .expect("control valve mass flowrate did not converge, \
+                use try_get_mass_flowrate_from_pressure_loss to handle the error")
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        self.pressure_loss
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.pressure_loss = pressure_loss;
+        self.mass_flowrate =
+            self.get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+
+        self.fluid_array.set_mass_flowrate(self.mass_flowrate);
+    }
+
+    /// pressure loss is the sum of the valve body loss
+    /// (from the fluid array) and the valve form loss
+    ///
+    /// for a closed valve, this is infinite unless the mass
+    /// flowrate is zero
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        if mass_flowrate == MassRate::ZERO {
+            return Pressure::ZERO;
+        }
+
+        self.fluid_array.get_pressure_loss_immutable(mass_flowrate)
+            + self.get_valve_pressure_loss_immutable(mass_flowrate)
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        self.fluid_array.get_cross_sectional_area()
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        self.fluid_array.get_cross_sectional_area_immutable()
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        self.fluid_array.get_hydraulic_diameter()
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        self.fluid_array.get_hydraulic_diameter_immutable()
+    }
+
+    fn get_fluid_viscosity_at_ref_temperature(&mut self) -> DynamicViscosity {
+        self.fluid_array.get_fluid_viscosity()
+    }
+
+    fn get_fluid_viscosity_immutable_at_ref_temperature(&self) -> DynamicViscosity {
+        self.fluid_array.get_fluid_viscosity_immutable()
+    }
+
+    fn get_fluid_density_at_ref_temperature(&mut self) -> MassDensity {
+        self.fluid_array.get_fluid_density()
+    }
+
+    fn get_fluid_density_immutable_at_ref_temperature(&self) -> MassDensity {
+        self.fluid_array.get_fluid_density_immutable()
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        self.fluid_array.get_component_length()
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        self.fluid_array.get_component_length_immutable()
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        self.fluid_array.get_incline_angle()
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        self.fluid_array.get_incline_angle_immutable()
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        self.fluid_array.get_internal_pressure_source()
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        self.fluid_array.get_internal_pressure_source_immutable()
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.fluid_array.set_internal_pressure_source(internal_pressure)
+    }
+
+    fn is_flow_blocked(&self) -> bool {
+        self.is_fully_closed()
+    }
+}
+
+impl From<ControlValve> for FluidComponent {
+    fn from(control_valve: ControlValve) -> Self {
+        FluidComponent::ControlValve(control_valve)
+    }
+}
+
+/// unit tests for control valves
+pub mod unit_test_control_valve;
@@ -0,0 +1,317 @@
+/// checks the inherent valve characteristics at a few positions
+#[test]
+pub fn valve_characteristic_values() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::ValveCharacteristic;
+
+    let half_open = Ratio::new::<ratio>(0.5);
+    let fully_open = Ratio::new::<ratio>(1.0);
+    let closed = Ratio::new::<ratio>(0.0);
+
+    let equal_percentage = ValveCharacteristic::EqualPercentage {
+        rangeability: Ratio::new::<ratio>(50.0) };
+
+    approx::assert_relative_eq!(
+        ValveCharacteristic::Linear
+        .get_relative_flow_coefficient(half_open).get::<ratio>(),
+        0.5);
+    approx::assert_relative_eq!(
+        ValveCharacteristic::QuickOpening
+        .get_relative_flow_coefficient(half_open).get::<ratio>(),
+        0.5_f64.sqrt());
+    // 50^(-0.5)
+    approx::assert_relative_eq!(
+        equal_percentage
+        .get_relative_flow_coefficient(half_open).get::<ratio>(),
+        0.141421356,
+        max_relative = 1e-8);
+
+    for characteristic in [ValveCharacteristic::Linear,
+        ValveCharacteristic::QuickOpening, equal_percentage] {
+
+        approx::assert_relative_eq!(
+            characteristic.get_relative_flow_coefficient(fully_open)
+            .get::<ratio>(),
+            1.0);
+        approx::assert_relative_eq!(
+            characteristic.get_relative_flow_coefficient(closed)
+            .get::<ratio>(),
+            0.0);
+    }
+
+    Ok(())
+}
+
+/// the valve loss from a Kv value should match
+/// Q [m3/h] = Kv sqrt(Delta P [bar]/SG) at full opening,
+/// and mass flowrate should decrease as the valve closes
+#[test]
+pub fn control_valve_kv_and_throttling() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, bar, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use super::{ControlValve, ValveCharacteristic};
+
+    let valve_body = FluidArray::new_cylinder(
+        Length::new::<meter>(0.2),
+        Length::new::<inch>(2.0),
+        ThermodynamicTemperature::new::<kelvin>(298.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(0.0),
+        0,
+        Angle::new::<degree>(0.0));
+
+    let fully_open_kv: f64 = 40.0;
+
+    let mut control_valve = ControlValve::new_from_kv(
+        valve_body,
+        fully_open_kv,
+        ValveCharacteristic::Linear)?;
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(2.0);
+    let density = control_valve.get_fluid_density_immutable_at_ref_temperature();
+
+    // Kv definition
+    let volumetric_flowrate_cubic_meter_per_hour =
+        (mass_flowrate/density).value * 3600.0;
+    let specific_gravity = density.get::<kilogram_per_cubic_meter>()/1000.0;
+    let kv_pressure_loss_bar = specific_gravity
+        * (volumetric_flowrate_cubic_meter_per_hour/fully_open_kv).powi(2);
+
+    approx::assert_relative_eq!(
+        control_valve.get_valve_pressure_loss_immutable(mass_flowrate)
+        .get::<bar>(),
+        kv_pressure_loss_bar,
+        max_relative = 1e-9);
+
+    // round trip and throttling
+    let pressure_loss = Pressure::new::<pascal>(5000.0);
+    let mut previous_mass_flowrate =
+        MassRate::new::<kilogram_per_second>(f64::INFINITY);
+
+    for valve_position in [1.0, 0.75, 0.5, 0.25, 0.05] {
+        control_valve.set_valve_position(
+            Ratio::new::<ratio>(valve_position))?;
+
+        let mass_flowrate = control_valve
+            .get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+
+        assert!(mass_flowrate < previous_mass_flowrate);
+        previous_mass_flowrate = mass_flowrate;
+
+        // the try_ variant gives the same flowrate without panicking
+        assert_eq!(
+            control_valve.try_get_mass_flowrate_from_pressure_loss(
+                pressure_loss)?,
+            mass_flowrate);
+
+        // and so does the fluid component, which returns the 
+        // error rather than panicking
+        let pressure_change = 
+            control_valve.get_hydrostatic_pressure_change_immutable_at_ref_temperature()
+            - pressure_loss;
+        let control_valve_component: FluidComponent = 
+            control_valve.clone().into();
+        approx::assert_relative_eq!(
+            control_valve_component
+            .try_get_mass_flowrate_from_pressure_change_immutable(
+                pressure_change)?.get::<kilogram_per_second>(),
+            mass_flowrate.get::<kilogram_per_second>(),
+            max_relative = 1e-9);
+
+        approx::assert_relative_eq!(
+            control_valve.get_pressure_loss_immutable(mass_flowrate)
+            .get::<pascal>(),
+            5000.0,
+            max_relative = 1e-6);
+
+        // reverse flow
+        approx::assert_relative_eq!(
+            control_valve.get_mass_flowrate_from_pressure_loss_immutable(
+                -pressure_loss).get::<kilogram_per_second>(),
+            -mass_flowrate.get::<kilogram_per_second>(),
+            max_relative = 1e-9);
+    }
+
+    // closed valve
+    control_valve.set_valve_position(Ratio::new::<ratio>(0.0))?;
+    assert!(control_valve.is_flow_blocked());
+    assert_eq!(
+        control_valve.get_mass_flowrate_from_pressure_loss_immutable(
+            pressure_loss),
+        MassRate::new::<kilogram_per_second>(0.0));
+
+    // out of range positions are rejected
+    assert!(control_valve.set_valve_position(
+            Ratio::new::<ratio>(1.5)).is_err());
+
+    Ok(())
+}
+
+/// the valve should move towards its setpoint at one full
+/// stroke per stroke time
+#[test]
+pub fn control_valve_actuator_stroke_time() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::time::second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use super::{ControlValve, ValveCharacteristic};
+
+    let valve_body = FluidArray::new_cylinder(
+        Length::new::<meter>(0.2),
+        Length::new::<inch>(2.0),
+        ThermodynamicTemperature::new::<kelvin>(298.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(0.0),
+        0,
+        Angle::new::<degree>(0.0));
+
+    let mut control_valve = ControlValve::new(
+        valve_body,
+        Ratio::new::<ratio>(2.0),
+        ValveCharacteristic::EqualPercentage {
+            rangeability: Ratio::new::<ratio>(30.0) })?;
+
+    control_valve.actuator_stroke_time = Some(Time::new::<second>(10.0));
+    control_valve.set_valve_position_setpoint(Ratio::new::<ratio>(0.0))?;
+
+    control_valve.advance_timestep(Time::new::<second>(2.5))?;
+
+    approx::assert_relative_eq!(
+        control_valve.get_valve_position().get::<ratio>(),
+        0.75,
+        max_relative = 1e-12);
+    assert!(!control_valve.is_flow_blocked());
+
+    // the valve should not overshoot its setpoint
+    control_valve.advance_timestep(Time::new::<second>(10.0))?;
+
+    approx::assert_relative_eq!(
+        control_valve.get_valve_position().get::<ratio>(),
+        0.0);
+    assert!(control_valve.is_flow_blocked());
+
+    // without a stroke time, the valve moves instantly
+    control_valve.actuator_stroke_time = None;
+    control_valve.set_valve_position_setpoint(Ratio::new::<ratio>(0.4))?;
+    control_valve.advance_timestep(Time::new::<second>(0.1))?;
+
+    approx::assert_relative_eq!(
+        control_valve.get_valve_position().get::<ratio>(),
+        0.4);
+
+    Ok(())
+}
+
+/// a closed valve in a parallel branch should make the
+/// collection behave as if the branch was removed, and a closed
+/// valve in series should stop all flow
+#[test]
+pub fn closed_control_valve_in_collections() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use super::{ControlValve, ValveCharacteristic};
+
+    let therminol_pipe = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<inch>(2.0),
+        ThermodynamicTemperature::new::<kelvin>(298.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::Copper,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(5.0),
+        0,
+        Angle::new::<degree>(0.0));
+
+    let mut control_valve = ControlValve::new(
+        therminol_pipe.clone(),
+        Ratio::new::<ratio>(1.0),
+        ValveCharacteristic::Linear)?;
+    control_valve.set_valve_position(Ratio::new::<ratio>(0.0))?;
+
+    let closed_valve: FluidComponent = control_valve.into();
+    let pipe = FluidComponent::FluidArray(therminol_pipe);
+
+    let mut parallel_collection = 
+        FluidComponentCollection::new_parallel_component_collection();
+    parallel_collection.set_fluid_component_vector(vec![pipe.clone(), closed_valve.clone()]);
+
+    let mut single_pipe = 
+        FluidComponentCollection::new_parallel_component_collection();
+    single_pipe.set_fluid_component_vector(vec![pipe.clone()]);
+
+    // one pipe alone gives about -1.238 kg/s at +1000 Pa
+    // (see collection_fluid_components_in_parallel)
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(-1.238);
+
+    approx::assert_relative_eq!(
+        parallel_collection.get_pressure_change(mass_flowrate)
+        .get::<pascal>(),
+        single_pipe.get_pressure_change(mass_flowrate).get::<pascal>(),
+        max_relative = 1e-6);
+
+    approx::assert_relative_eq!(
+        parallel_collection.get_mass_flowrate_from_pressure_change(
+            Pressure::new::<pascal>(1000.0)).get::<kilogram_per_second>(),
+        -1.238,
+        max_relative = 1e-3);
+
+    // closed valve in series
+    let mut series_collection = 
+        FluidComponentCollection::new_series_component_collection();
+    series_collection.set_fluid_component_vector(vec![pipe, closed_valve]);
+
+    assert!(series_collection.is_flow_blocked());
+    assert_eq!(
+        series_collection.get_mass_flowrate_from_pressure_change(
+            Pressure::new::<pascal>(1000.0)),
+        MassRate::new::<kilogram_per_second>(0.0));
+
+    Ok(())
+}
@@ -0,0 +1,356 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::ConstZero;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::fluid_component::FluidComponent;
+use super::fluid_component_traits::FluidComponentTrait;
+
+/// for an ideal check valve, reverse flow is not exactly zero.
+///
+/// Instead, the reverse mass flowrate at a given pressure loss is this
+/// fraction of the forward mass flowrate at the same pressure loss.
+/// This keeps mass flowrate a strictly increasing function of
+/// pressure loss so that the root finders used for parallel branches
+/// always have a unique root
+///
+/// for turbulent flow, this means the reverse pressure loss
+/// is about 1e8 times the forward pressure loss at the same flowrate
+pub const IDEAL_CHECK_VALVE_LEAKAGE_FRACTION: f64 = 1.0e-4;
+
+/// how the flow diode behaves in reverse flow
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum FlowDiodeReverseLoss {
+    /// reverse flow uses a separate loss correlation
+    Correlation(DimensionlessDarcyLossCorrelations),
+    /// ideal check valve, reverse flow is (almost) fully blocked
+    ///
+    /// see IDEAL_CHECK_VALVE_LEAKAGE_FRACTION
+    IdealCheckValve,
+}
+
+/// a flow diode or check valve, this is a fluid component
+/// whose pressure loss depends on flow direction
+///
+/// The FluidArray carries the geometry, fluid properties and
+/// temperatures. Its fluid_component_loss_properties are used
+/// for forward flow (positive mass flowrate), and
+/// reverse_loss is used for negative mass flowrate
+///
+/// Both loss correlations give zero pressure loss at zero flow
+/// and are increasing functions of Reynolds number. Stitching them
+/// together at zero flow therefore gives a pressure loss which is
+/// continuous and increasing in mass flowrate, so the
+/// parallel and series root finders still work
+///
+/// For CIET, flow diodes are used to stop reverse flow in the
+/// DRACS loop and heater branches. Previously, this was done by
+/// blocking entire branches (eg. ctah_branch_blocked) by hand
+#[derive(Debug,Clone,PartialEq)]
+pub struct FlowDiode {
+    /// the fluid array, which carries the forward loss correlation
+    pub fluid_array: FluidArray,
+    /// loss behaviour in reverse flow
+    pub reverse_loss: FlowDiodeReverseLoss,
+    /// mass flowrate through the flow diode
+    mass_flowrate: MassRate,
+    /// pressure loss through the flow diode
+    pressure_loss: Pressure,
+}
+
+impl FlowDiode {
+
+    /// creates a new flow diode, forward flow uses the loss
+    /// correlation of the fluid array while reverse flow uses the
+    /// supplied loss correlation
+    pub fn new(fluid_array: FluidArray,
+        reverse_loss_correlation: DimensionlessDarcyLossCorrelations) -> Self {
+
+        Self::new_with_reverse_loss(fluid_array,
+            FlowDiodeReverseLoss::Correlation(reverse_loss_correlation))
+    }
+
+    /// creates a new ideal check valve, forward flow uses the loss
+    /// correlation of the fluid array while reverse flow is
+    /// blocked
+    pub fn new_ideal_check_valve(fluid_array: FluidArray) -> Self {
+
+        Self::new_with_reverse_loss(fluid_array,
+            FlowDiodeReverseLoss::IdealCheckValve)
+    }
+
+    /// creates a new flow diode with a given reverse flow behaviour,
+    /// the mass flowrate is taken from the fluid array
+    pub fn new_with_reverse_loss(mut fluid_array: FluidArray,
+        reverse_loss: FlowDiodeReverseLoss) -> Self {
+
+        let mass_flowrate = fluid_array.get_mass_flowrate();
+
+        let mut flow_diode = Self {
+            fluid_array,
+            reverse_loss,
+            mass_flowrate,
+            pressure_loss: Pressure::ZERO,
+        };
+
+        flow_diode.pressure_loss = flow_diode.get_pressure_loss_immutable(
+            mass_flowrate);
+
+        flow_diode
+    }
+
+    /// gets the forward loss correlation
+    pub fn get_forward_loss_correlation(&self) ->
+    DimensionlessDarcyLossCorrelations {
+        self.fluid_array.fluid_component_loss_properties
+    }
+
+    /// diodicity is the ratio of reverse to forward pressure loss
+    /// at the same magnitude of mass flowrate
+    ///
+    /// a diodicity of 1 means the component does not
+    /// distinguish between flow directions
+    ///
+    /// both pressure losses are zero at zero flow, so the
+    /// diodicity is undefined there and an error is returned
+    pub fn get_diodicity(&self, mass_flowrate: MassRate) 
+    -> Result<Ratio, TuasLibError> {
+
+        if mass_flowrate == MassRate::ZERO || !mass_flowrate.value.is_finite() {
+            return Err(TuasLibError::GenericStringError(
+                "diodicity needs a nonzero and finite mass flowrate"
+                .to_string()));
+        }
+
+        let forward_pressure_loss =
+            self.try_get_pressure_loss(mass_flowrate.abs())?;
+        let reverse_pressure_loss =
+            self.try_get_pressure_loss(-mass_flowrate.abs())?;
+
+        Ok(Ratio::new::<ratio>(
+            (reverse_pressure_loss/forward_pressure_loss).get::<ratio>().abs()))
+    }
+
+    /// returns the mass flowrate through the flow diode for a given
+    /// pressure loss, or an error if the loss correlation cannot 
+    /// be inverted
+    pub fn try_get_mass_flowrate_from_pressure_loss(
+        &self, pressure_loss: Pressure) -> Result<MassRate, TuasLibError> {
+
+        let forward_loss_correlation = self.get_forward_loss_correlation();
+
+        // forward flow
+        if pressure_loss.value >= 0.0 {
+            return self.get_mass_flowrate_from_pressure_loss_using_correlation(
+                forward_loss_correlation, pressure_loss);
+        }
+
+        // reverse flow
+        match self.reverse_loss {
+            FlowDiodeReverseLoss::Correlation(reverse_loss_correlation) => {
+                self.get_mass_flowrate_from_pressure_loss_using_correlation(
+                    reverse_loss_correlation, pressure_loss)
+            },
+            FlowDiodeReverseLoss::IdealCheckValve => {
+                Ok(self.get_mass_flowrate_from_pressure_loss_using_correlation(
+                    forward_loss_correlation, pressure_loss)?
+                    * IDEAL_CHECK_VALVE_LEAKAGE_FRACTION)
+            },
+        }
+    }
+
+    /// returns the pressure loss through the flow diode for a given
+    /// mass flowrate, or an error if the loss correlation fails
+    pub fn try_get_pressure_loss(
+        &self, mass_flowrate: MassRate) -> Result<Pressure, TuasLibError> {
+
+        let forward_loss_correlation = self.get_forward_loss_correlation();
+
+        // forward flow
+        if mass_flowrate.value >= 0.0 {
+            return self.get_pressure_loss_from_mass_flowrate_using_correlation(
+                forward_loss_correlation, mass_flowrate);
+        }
+
+        // reverse flow
+        match self.reverse_loss {
+            FlowDiodeReverseLoss::Correlation(reverse_loss_correlation) => {
+                self.get_pressure_loss_from_mass_flowrate_using_correlation(
+                    reverse_loss_correlation, mass_flowrate)
+            },
+            // the inverse of scaling the mass flowrate by the
+            // leakage fraction
+            FlowDiodeReverseLoss::IdealCheckValve => {
+                self.get_pressure_loss_from_mass_flowrate_using_correlation(
+                    forward_loss_correlation,
+                    mass_flowrate / IDEAL_CHECK_VALVE_LEAKAGE_FRACTION)
+            },
+        }
+    }
+
+    /// returns the mass flowrate given a pressure loss and a
+    /// loss correlation, using the geometry and fluid properties
+    /// of the fluid array
+    fn get_mass_flowrate_from_pressure_loss_using_correlation(
+        &self,
+        loss_correlation: DimensionlessDarcyLossCorrelations,
+        pressure_loss: Pressure) -> Result<MassRate, TuasLibError> {
+
+        let hydraulic_diameter = self.fluid_array.get_hydraulic_diameter_immutable();
+        let fluid_viscosity = self.fluid_array.get_fluid_viscosity_immutable();
+        let fluid_density = self.fluid_array.get_fluid_density_immutable();
+        let xs_area = self.fluid_array.get_cross_sectional_area_immutable();
+
+        let reynolds_number: Ratio = loss_correlation.
+            get_reynolds_from_pressure_loss(
+                pressure_loss,
+                hydraulic_diameter,
+                fluid_density,
+                fluid_viscosity
+            )?;
+
+        Ok(xs_area * fluid_viscosity * reynolds_number / hydraulic_diameter)
+    }
+
+    /// returns the pressure loss given a mass flowrate and a
+    /// loss correlation, using the geometry and fluid properties
+    /// of the fluid array
+    fn get_pressure_loss_from_mass_flowrate_using_correlation(
+        &self,
+        loss_correlation: DimensionlessDarcyLossCorrelations,
+        mass_flowrate: MassRate) -> Result<Pressure, TuasLibError> {
+
+        let hydraulic_diameter = self.fluid_array.get_hydraulic_diameter_immutable();
+        let fluid_viscosity = self.fluid_array.get_fluid_viscosity_immutable();
+        let fluid_density = self.fluid_array.get_fluid_density_immutable();
+        let xs_area = self.fluid_array.get_cross_sectional_area_immutable();
+
+        let reynolds_number: Ratio = mass_flowrate
+            / xs_area
+            * hydraulic_diameter
+            / fluid_viscosity;
+
+        loss_correlation.get_pressure_loss_from_reynolds(
+            reynolds_number,
+            hydraulic_diameter,
+            fluid_density,
+            fluid_viscosity
+        )
+    }
+}
+
+impl FluidComponentTrait for FlowDiode {
+    fn get_mass_flowrate(&mut self) -> MassRate  {
+        self.mass_flowrate
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+        self.pressure_loss = self.get_pressure_loss_immutable(mass_flowrate);
+
+        // the fluid array needs the mass flowrate for advection
+        self.fluid_array.set_mass_flowrate(mass_flowrate);
+    }
+
+    /// panics if the loss correlation cannot be inverted, 
+    /// the same as for the FluidArray, 
+    /// see try_get_mass_flowrate_from_pressure_loss
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        self.try_get_mass_flowrate_from_pressure_loss(pressure_loss).unwrap()
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        self.pressure_loss
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.pressure_loss = pressure_loss;
+        self.mass_flowrate =
+            self.get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+
+        self.fluid_array.set_mass_flowrate(self.mass_flowrate);
+    }
+
+    /// panics if the loss correlation fails, the same as 
+    /// for the FluidArray, see try_get_pressure_loss
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        self.try_get_pressure_loss(mass_flowrate).unwrap()
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        self.fluid_array.get_cross_sectional_area()
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        self.fluid_array.get_cross_sectional_area_immutable()
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        self.fluid_array.get_hydraulic_diameter()
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        self.fluid_array.get_hydraulic_diameter_immutable()
+    }
+
+    fn get_fluid_viscosity_at_ref_temperature(&mut self) -> DynamicViscosity {
+        self.fluid_array.get_fluid_viscosity()
+    }
+
+    fn get_fluid_viscosity_immutable_at_ref_temperature(&self) -> DynamicViscosity {
+        self.fluid_array.get_fluid_viscosity_immutable()
+    }
+
+    fn get_fluid_density_at_ref_temperature(&mut self) -> MassDensity {
+        self.fluid_array.get_fluid_density()
+    }
+
+    fn get_fluid_density_immutable_at_ref_temperature(&self) -> MassDensity {
+        self.fluid_array.get_fluid_density_immutable()
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        self.fluid_array.get_component_length()
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        self.fluid_array.get_component_length_immutable()
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        self.fluid_array.get_incline_angle()
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        self.fluid_array.get_incline_angle_immutable()
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        self.fluid_array.get_internal_pressure_source()
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        self.fluid_array.get_internal_pressure_source_immutable()
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.fluid_array.set_internal_pressure_source(internal_pressure)
+    }
+}
+
+impl From<FlowDiode> for FluidComponent {
+    fn from(flow_diode: FlowDiode) -> Self {
+        FluidComponent::FlowDiode(flow_diode)
+    }
+}
+
+/// unit tests for flow diodes and check valves
+pub mod unit_test_flow_diode;
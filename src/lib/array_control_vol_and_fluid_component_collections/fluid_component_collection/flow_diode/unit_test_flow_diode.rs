@@ -0,0 +1,235 @@
+/// a flow diode should use the forward loss correlation of its
+/// fluid array in forward flow and the reverse loss correlation
+/// in reverse flow
+#[test]
+pub fn flow_diode_forward_and_reverse_pressure_loss() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use super::FlowDiode;
+
+    let new_pipe = |form_loss: f64| -> FluidArray {
+        FluidArray::new_cylinder(
+            Length::new::<meter>(1.0),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::Copper,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(form_loss),
+            0,
+            Angle::new::<degree>(0.0))
+    };
+
+    let forward_pipe = new_pipe(5.0);
+    let reverse_pipe = new_pipe(50.0);
+
+    let flow_diode = FlowDiode::new(
+        forward_pipe.clone(),
+        reverse_pipe.fluid_component_loss_properties);
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(1.0);
+
+    // forward flow
+    approx::assert_relative_eq!(
+        flow_diode.get_pressure_loss_immutable(mass_flowrate)
+        .get::<pascal>(),
+        forward_pipe.get_pressure_loss_immutable(mass_flowrate)
+        .get::<pascal>(),
+        max_relative = 1e-12);
+
+    // reverse flow
+    approx::assert_relative_eq!(
+        flow_diode.get_pressure_loss_immutable(-mass_flowrate)
+        .get::<pascal>(),
+        reverse_pipe.get_pressure_loss_immutable(-mass_flowrate)
+        .get::<pascal>(),
+        max_relative = 1e-12);
+
+    // the reverse direction should be more resistive
+    assert!(flow_diode.get_diodicity(mass_flowrate)?.get::<ratio>() > 1.0);
+
+    // diodicity is undefined at zero flow
+    assert!(flow_diode.get_diodicity(
+            MassRate::new::<kilogram_per_second>(0.0)).is_err());
+
+    // round trip in both directions
+    for mass_flowrate_value in [-1.0, -0.01, 0.01, 1.0] {
+        let mass_flowrate =
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+        let pressure_loss =
+            flow_diode.get_pressure_loss_immutable(mass_flowrate);
+
+        approx::assert_relative_eq!(
+            flow_diode.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss).get::<kilogram_per_second>(),
+            mass_flowrate_value,
+            max_relative = 1e-6);
+    }
+
+    Ok(())
+}
+
+/// an ideal check valve should let almost no flow through in
+/// reverse, but mass flowrate must still increase strictly
+/// with pressure loss so that root finding works
+#[test]
+pub fn ideal_check_valve_is_monotonic() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use super::{FlowDiode, IDEAL_CHECK_VALVE_LEAKAGE_FRACTION};
+
+    let check_valve = FlowDiode::new_ideal_check_valve(
+        FluidArray::new_cylinder(
+            Length::new::<meter>(1.0),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::Copper,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(5.0),
+            0,
+            Angle::new::<degree>(0.0)));
+
+    let mut previous_mass_flowrate =
+        MassRate::new::<kilogram_per_second>(f64::NEG_INFINITY);
+
+    for pressure_loss_value in [-1e4, -1e2, -1.0, 0.0, 1.0, 1e2, 1e4] {
+
+        let mass_flowrate = check_valve.
+            get_mass_flowrate_from_pressure_loss_immutable(
+                Pressure::new::<pascal>(pressure_loss_value));
+
+        assert!(mass_flowrate > previous_mass_flowrate);
+        previous_mass_flowrate = mass_flowrate;
+    }
+
+    // reverse flow is a small fraction of forward flow
+    let pressure_loss = Pressure::new::<pascal>(1000.0);
+    let forward_mass_flowrate = check_valve.
+        get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+    let reverse_mass_flowrate = check_valve.
+        get_mass_flowrate_from_pressure_loss_immutable(-pressure_loss);
+
+    approx::assert_relative_eq!(
+        (reverse_mass_flowrate/forward_mass_flowrate).get::<ratio>(),
+        -IDEAL_CHECK_VALVE_LEAKAGE_FRACTION,
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// a pipe and an ideal check valve in parallel, in forward flow
+/// both branches carry flow, in reverse flow only the pipe does
+#[test]
+pub fn ideal_check_valve_in_parallel_collection() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::root_finding::RootFindingSettings;
+    use super::FlowDiode;
+
+    let therminol_pipe = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<inch>(2.0),
+        ThermodynamicTemperature::new::<kelvin>(298.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::Copper,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(5.0),
+        0,
+        Angle::new::<degree>(0.0));
+
+    let check_valve: FluidComponent =
+        FlowDiode::new_ideal_check_valve(therminol_pipe.clone()).into();
+
+    let mut parallel_collection = 
+        FluidComponentCollection::new_parallel_component_collection();
+    parallel_collection.set_fluid_component_vector(vec![
+        FluidComponent::FluidArray(therminol_pipe),
+        check_valve]);
+
+    // one pipe alone gives about -1.238 kg/s at +1000 Pa
+    // of pressure change (see collection_fluid_components_in_parallel)
+    let pressure_change = Pressure::new::<pascal>(1000.0);
+
+    // reverse flow, the check valve blocks flow
+    let reverse_mass_flowrate = parallel_collection.
+        get_mass_flowrate_from_pressure_change(pressure_change);
+
+    approx::assert_relative_eq!(
+        reverse_mass_flowrate.get::<kilogram_per_second>(),
+        -1.238,
+        max_relative = 1e-3);
+
+    // forward flow, both branches carry flow
+    let forward_mass_flowrate = parallel_collection.
+        get_mass_flowrate_from_pressure_change(-pressure_change);
+
+    approx::assert_relative_eq!(
+        forward_mass_flowrate.get::<kilogram_per_second>(),
+        2.0 * 1.238,
+        max_relative = 1e-3);
+
+    // the parallel root finder should recover the pressure change
+    // in both directions, the check valve gives very wide pressure 
+    // bounds in reverse flow, so i allow more iterations than 
+    // the collection default
+    let root_finding_settings = RootFindingSettings {
+        max_iterations: 70,
+        ..RootFindingSettings::component_collection_default()
+    };
+
+    approx::assert_relative_eq!(
+        parallel_collection.try_get_pressure_change(
+            reverse_mass_flowrate, &root_finding_settings)?
+        .get::<pascal>(),
+        1000.0,
+        max_relative = 1e-3);
+
+    approx::assert_relative_eq!(
+        parallel_collection.try_get_pressure_change(
+            forward_mass_flowrate, &root_finding_settings)?
+        .get::<pascal>(),
+        -1000.0,
+        max_relative = 1e-3);
+
+    Ok(())
+}
@@ -2,6 +2,9 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_
 use uom::si::f64::*;
 
 use super::fluid_component_traits::FluidComponentTrait;
+use super::flow_diode::FlowDiode;
+use super::control_valve::ControlValve;
+use crate::tuas_lib_error::TuasLibError;
 
 
 #[derive(Debug,Clone,PartialEq)]
@@ -20,6 +23,41 @@ pub enum FluidComponent {
     /// the mass flowrate through one tube, then divide by number 
     /// of tubes (the u32 value)
     ParallelUniformFluidArray(FluidArray,u32),
+    /// flow diodes or check valves, these have a pressure loss 
+    /// which depends on flow direction
+    FlowDiode(FlowDiode),
+    /// control valves, these have a pressure loss which depends 
+    /// on valve position, and block flow when fully closed
+    ControlValve(ControlValve),
+}
+
+impl FluidComponent {
+
+    /// gets the mass flowrate of the component given a fixed 
+    /// pressure change
+    ///
+    /// flow diodes and control valves return an error if their 
+    /// mass flowrate cannot be found rather than panicking
+    pub fn try_get_mass_flowrate_from_pressure_change_immutable(
+        &self, pressure_change: Pressure) -> Result<MassRate, TuasLibError> {
+
+        let pressure_loss = -pressure_change +
+            self.get_hydrostatic_pressure_change_immutable_at_ref_temperature()+
+            self.get_internal_pressure_source_immutable();
+
+        match self {
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.try_get_mass_flowrate_from_pressure_loss(
+                    pressure_loss)
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.try_get_mass_flowrate_from_pressure_loss(
+                    pressure_loss)
+            },
+            _ => Ok(self.get_mass_flowrate_from_pressure_loss_immutable(
+                    pressure_loss)),
+        }
+    }
 }
 
 impl FluidComponentTrait for FluidComponent {
@@ -59,6 +97,12 @@ impl FluidComponentTrait for FluidComponent {
                 return flow_through_one_tube * (*number_of_tubes as f64);
 
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_mass_flowrate()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_mass_flowrate()
+            },
         }
     }
 
@@ -98,6 +142,12 @@ impl FluidComponentTrait for FluidComponent {
                 fluid_array.set_mass_flowrate(flow_through_one_tube);
 
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.set_mass_flowrate(mass_flowrate)
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.set_mass_flowrate(mass_flowrate)
+            },
         }
     }
 
@@ -143,6 +193,12 @@ impl FluidComponentTrait for FluidComponent {
 
 
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_mass_flowrate_from_pressure_loss_immutable(pressure_loss)
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_mass_flowrate_from_pressure_loss_immutable(pressure_loss)
+            },
         }
     }
 
@@ -158,6 +214,12 @@ impl FluidComponentTrait for FluidComponent {
                 // same for each tube
                 fluid_array.get_pressure_loss()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_pressure_loss()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_pressure_loss()
+            },
         }
     }
 
@@ -171,6 +233,12 @@ impl FluidComponentTrait for FluidComponent {
                 // same for each tube
                 fluid_array.set_pressure_loss(pressure_loss)
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.set_pressure_loss(pressure_loss)
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.set_pressure_loss(pressure_loss)
+            },
         }
     }
 
@@ -188,6 +256,12 @@ impl FluidComponentTrait for FluidComponent {
 
                 fluid_array.get_pressure_loss_immutable(flow_through_one_tube)
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_pressure_loss_immutable(mass_flowrate)
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_pressure_loss_immutable(mass_flowrate)
+            },
         }
     }
 
@@ -222,6 +296,12 @@ impl FluidComponentTrait for FluidComponent {
 
                 xs_area_one_tube * (*number_of_tubes as f64)
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_cross_sectional_area()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_cross_sectional_area()
+            },
         }
     }
 
@@ -238,6 +318,12 @@ impl FluidComponentTrait for FluidComponent {
 
                 xs_area_one_tube * (*number_of_tubes as f64)
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_cross_sectional_area_immutable()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_cross_sectional_area_immutable()
+            },
         }
     }
 
@@ -292,6 +378,12 @@ impl FluidComponentTrait for FluidComponent {
                 hydraulic_diameter_overall
 
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_hydraulic_diameter()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_hydraulic_diameter()
+            },
         }
     }
 
@@ -346,6 +438,12 @@ impl FluidComponentTrait for FluidComponent {
                 hydraulic_diameter_overall
 
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_hydraulic_diameter_immutable()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_hydraulic_diameter_immutable()
+            },
         }
     }
 
@@ -357,6 +455,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_fluid_viscosity()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_fluid_viscosity_at_ref_temperature()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_fluid_viscosity_at_ref_temperature()
+            },
         }
     }
 
@@ -368,6 +472,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_fluid_viscosity_immutable()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_fluid_viscosity_immutable_at_ref_temperature()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_fluid_viscosity_immutable_at_ref_temperature()
+            },
         }
     }
 
@@ -379,6 +489,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_fluid_density()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_fluid_density_at_ref_temperature()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_fluid_density_at_ref_temperature()
+            },
         }
     }
 
@@ -391,6 +507,12 @@ impl FluidComponentTrait for FluidComponent {
                 fluid_array.get_fluid_density_immutable()
             },
             
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_fluid_density_immutable_at_ref_temperature()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_fluid_density_immutable_at_ref_temperature()
+            },
         }
     }
 
@@ -403,6 +525,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_component_length()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_component_length()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_component_length()
+            },
         }
     }
 
@@ -415,6 +543,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_component_length_immutable()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_component_length_immutable()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_component_length_immutable()
+            },
         }
     }
 
@@ -426,6 +560,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_incline_angle()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_incline_angle()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_incline_angle()
+            },
         }
     }
 
@@ -437,6 +577,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_incline_angle_immutable()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_incline_angle_immutable()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_incline_angle_immutable()
+            },
         }
     }
 
@@ -450,6 +596,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_internal_pressure_source()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_internal_pressure_source()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_internal_pressure_source()
+            },
         }
     }
 
@@ -463,6 +615,12 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.get_internal_pressure_source_immutable()
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.get_internal_pressure_source_immutable()
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.get_internal_pressure_source_immutable()
+            },
         }
     }
 
@@ -478,6 +636,21 @@ impl FluidComponentTrait for FluidComponent {
             FluidComponent::ParallelUniformFluidArray(fluid_array,_) => {
                 fluid_array.set_internal_pressure_source(internal_pressure)
             },
+            FluidComponent::FlowDiode(flow_diode) => {
+                flow_diode.set_internal_pressure_source(internal_pressure)
+            },
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.set_internal_pressure_source(internal_pressure)
+            },
+        }
+    }
+
+    fn is_flow_blocked(&self) -> bool {
+        match self {
+            FluidComponent::ControlValve(control_valve) => {
+                control_valve.is_flow_blocked()
+            },
+            _ => false,
         }
     }
 }
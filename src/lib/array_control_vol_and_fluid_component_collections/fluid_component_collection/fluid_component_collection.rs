@@ -53,6 +53,9 @@ use uom::si::mass_rate::kilogram_per_second;
 use super::collection_series_and_parallel_functions::FluidComponentCollectionSeriesAssociatedFunctions;
 use super::collection_series_and_parallel_functions::FluidComponentCollectionParallelAssociatedFunctions;
 use super::fluid_component::FluidComponent;
+use super::fluid_component_traits::FluidComponentTrait;
+use super::root_finding::RootFindingSettings;
+use super::fluid_component_super_collection::ParallelBranchSolution;
 use crate::tuas_lib_error::TuasLibError;
 
 
@@ -230,6 +233,144 @@ impl FluidComponentCollection{
             Ok(())
     }
 
+    /// returns true if no flow can pass through the collection
+    ///
+    /// components in series are blocked if any one component 
+    /// is blocked (eg. a closed valve), components in parallel 
+    /// are blocked only if all of them are blocked
+    pub fn is_flow_blocked(&self) -> bool {
+        match self.orientation {
+            FluidComponentCollectionOreintation::Series => {
+                self.components.iter().any(
+                    |component| component.is_flow_blocked())
+            },
+            FluidComponentCollectionOreintation::Parallel => {
+                !self.components.is_empty() &&
+                    self.components.iter().all(
+                        |component| component.is_flow_blocked())
+            },
+        }
+    }
+
+
+
+    /// solves for the pressure change across the parallel components 
+    /// and the mass flowrate through each component, given the total 
+    /// mass flowrate
+    ///
+    /// the previous solution (if any) is used to warm start the 
+    /// solve, and is replaced by the new solution for the next call. 
+    /// If the warm started solve fails, the usual bracketing 
+    /// heuristics are used
+    ///
+    /// the previous solution is kept by the caller rather than the 
+    /// collection, so collections which are rebuilt every timestep 
+    /// can still be warm started, and setting it to None forces a 
+    /// cold start
+    pub fn solve_parallel_component_flows(&self,
+        mass_flowrate: MassRate,
+        previous_parallel_solution: &mut Option<ParallelBranchSolution>,
+        root_finding_settings: &RootFindingSettings) 
+        -> Result<ParallelBranchSolution, TuasLibError> {
+
+        if self.orientation != FluidComponentCollectionOreintation::Parallel {
+            return Err(TuasLibError::GenericStringError(
+                "component flows can only be solved for parallel \
+                collections".to_string()));
+        }
+
+        let pressure_change = 
+            <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_from_mass_flowrate_with_previous_solution(
+                mass_flowrate, 
+                &self.components, 
+                previous_parallel_solution.as_ref(), 
+                root_finding_settings)?;
+
+        let branch_mass_flowrates: Vec<MassRate> = self.components.iter()
+            .map(|component| {
+                component.try_get_mass_flowrate_from_pressure_change_immutable(
+                    pressure_change)
+            })
+            .collect::<Result<Vec<MassRate>, TuasLibError>>()?;
+
+        let parallel_solution = ParallelBranchSolution {
+            mass_flowrate,
+            pressure_change,
+            branch_mass_flowrates,
+        };
+
+        *previous_parallel_solution = Some(parallel_solution.clone());
+
+        Ok(parallel_solution)
+    }
+
+    /// gets the pressure change across the collection for a given 
+    /// mass flowrate using user specified root finding settings
+    ///
+    /// for components in parallel, this returns an error with the last 
+    /// bracket if the root finder does not converge rather than 
+    /// panicking like get_pressure_change does
+    pub fn try_get_pressure_change(&self,
+        fluid_mass_flowrate: MassRate,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        self.try_get_pressure_change_with_optional_initial_guess(
+            fluid_mass_flowrate, None, root_finding_settings)
+    }
+
+    /// gets the pressure change across the collection for a given 
+    /// mass flowrate, starting from an initial guess of the pressure 
+    /// change
+    ///
+    /// for components in parallel, the root finder brackets the root 
+    /// around the initial guess. Use the pressure change from the 
+    /// previous timestep as the initial guess to warm start the solver
+    pub fn try_get_pressure_change_with_initial_guess(&self,
+        fluid_mass_flowrate: MassRate,
+        pressure_change_initial_guess: Pressure,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        self.try_get_pressure_change_with_optional_initial_guess(
+            fluid_mass_flowrate, 
+            Some(pressure_change_initial_guess), 
+            root_finding_settings)
+    }
+
+    /// gets the pressure change across the collection, 
+    /// warm started if an initial guess is given
+    fn try_get_pressure_change_with_optional_initial_guess(&self,
+        fluid_mass_flowrate: MassRate,
+        pressure_change_initial_guess: Option<Pressure>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        let parallel_components: &Vec<FluidComponent> = match self.orientation {
+            FluidComponentCollectionOreintation::Parallel => &self.components,
+            FluidComponentCollectionOreintation::Series => {
+                return Ok(self.get_pressure_change(fluid_mass_flowrate));
+            },
+        };
+
+        // blocked components (eg. closed valves) are left out of 
+        // the root finding by the associated functions
+        match pressure_change_initial_guess {
+            Some(pressure_change_initial_guess) => {
+                <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                    try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+                        fluid_mass_flowrate, 
+                        parallel_components,
+                        pressure_change_initial_guess,
+                        root_finding_settings)
+            },
+            None => {
+                <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+                    try_calculate_pressure_change_from_mass_flowrate(
+                        fluid_mass_flowrate, 
+                        parallel_components,
+                        root_finding_settings)
+            },
+        }
+    }
 
 }
 
@@ -242,10 +383,11 @@ impl FluidComponentCollectionMethods for FluidComponentCollection {
 
         match orientation {
             FluidComponentCollectionOreintation::Parallel => {
-                let fluid_component_vector = &self.components;
-                <Self as FluidComponentCollectionParallelAssociatedFunctions>::
-                    calculate_pressure_change_from_mass_flowrate(
-                        fluid_mass_flowrate, fluid_component_vector)
+                self.try_get_pressure_change(
+                    fluid_mass_flowrate, 
+                    &RootFindingSettings::component_collection_default())
+                    .expect("parallel collection pressure change did not converge, \
+                        use try_get_pressure_change to handle the error")
             },
             FluidComponentCollectionOreintation::Series => {
                 let fluid_component_vector = &self.components;
@@ -271,6 +413,12 @@ impl FluidComponentCollectionMethods for FluidComponentCollection {
                         pressure_change, fluid_component_vector)
             },
             FluidComponentCollectionOreintation::Series => {
+                // a blocked component (eg. a closed valve) stops 
+                // flow in the whole series
+                if self.is_flow_blocked() {
+                    return MassRate::new::<kilogram_per_second>(0.0);
+                }
+
                 let fluid_component_vector = &self.components;
                 <Self as FluidComponentCollectionSeriesAssociatedFunctions>::
                     calculate_mass_flowrate_from_pressure_change(
@@ -50,6 +50,9 @@ use super::fluid_component_collection::*;
 use super::super_collection_series_and_parallel_functions::FluidComponentSuperCollectionSeriesAssociatedFunctions;
 use super::super_collection_series_and_parallel_functions::FluidComponentSuperCollectionParallelAssociatedFunctions;
 use uom::si::f64::*;
+use uom::ConstZero;
+use super::root_finding::RootFindingSettings;
+use crate::tuas_lib_error::TuasLibError;
 
 /// A struct containing a vector of fluid component collections
 #[derive(Debug,Clone,PartialEq)]
@@ -62,6 +65,19 @@ pub struct FluidComponentSuperCollection {
     pub orientation: FluidComponentCollectionOreintation,
 }
 
+/// solution of a parallel super collection (or parallel collection),
+/// which the caller keeps to warm start the next solve
+#[derive(Debug,Clone,PartialEq)]
+pub struct ParallelBranchSolution {
+    /// total mass flowrate through all branches
+    pub mass_flowrate: MassRate,
+    /// pressure change across each branch
+    pub pressure_change: Pressure,
+    /// mass flowrate through each branch (or component), in the 
+    /// order the branches were added
+    pub branch_mass_flowrates: Vec<MassRate>,
+}
+
 
 impl FluidComponentSuperCollection {
 
@@ -223,6 +239,122 @@ impl FluidComponentSuperCollection {
             mass_rate_vector
     }
 
+
+    /// solves for the pressure change across the parallel branches 
+    /// and the mass flowrate through each branch, given the total 
+    /// mass flowrate
+    ///
+    /// the previous solution (if any) is used to warm start the 
+    /// solve, and is replaced by the new solution for the next call. 
+    /// If the warm started solve fails, the usual bracketing 
+    /// heuristics are used
+    ///
+    /// the previous solution is kept by the caller rather than the 
+    /// super collection, so super collections which are rebuilt 
+    /// every timestep can still be warm started, and setting it to 
+    /// None forces a cold start
+    pub fn solve_parallel_branch_flows(&self,
+        mass_flowrate: MassRate,
+        previous_parallel_solution: &mut Option<ParallelBranchSolution>,
+        root_finding_settings: &RootFindingSettings) 
+        -> Result<ParallelBranchSolution, TuasLibError> {
+
+        if self.orientation != FluidComponentCollectionOreintation::Parallel {
+            return Err(TuasLibError::GenericStringError(
+                "branch flows can only be solved for parallel \
+                super collections".to_string()));
+        }
+
+        let pressure_change = 
+            <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_from_mass_flowrate_with_previous_solution(
+                mass_flowrate, 
+                &self.fluid_component_super_vector, 
+                previous_parallel_solution.as_ref(), 
+                root_finding_settings)?;
+
+        let branch_mass_flowrates = 
+            self.get_mass_flowrate_across_each_parallel_branch(
+                pressure_change);
+
+        let parallel_solution = ParallelBranchSolution {
+            mass_flowrate,
+            pressure_change,
+            branch_mass_flowrates,
+        };
+
+        *previous_parallel_solution = Some(parallel_solution.clone());
+
+        Ok(parallel_solution)
+    }
+
+    /// gets the pressure change across the collection for a given 
+    /// mass flowrate using user specified root finding settings
+    ///
+    /// for branches in parallel, this returns an error with the last 
+    /// bracket if the root finder does not converge rather than 
+    /// panicking like get_pressure_change does
+    pub fn try_get_pressure_change(&self,
+        fluid_mass_flowrate: MassRate,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        self.try_get_pressure_change_with_optional_initial_guess(
+            fluid_mass_flowrate, None, root_finding_settings)
+    }
+
+    /// gets the pressure change across the collection for a given 
+    /// mass flowrate, starting from an initial guess of the pressure 
+    /// change
+    ///
+    /// for branches in parallel, the root finder brackets the root 
+    /// around the initial guess. Use the pressure change from the 
+    /// previous timestep as the initial guess to warm start the solver
+    pub fn try_get_pressure_change_with_initial_guess(&self,
+        fluid_mass_flowrate: MassRate,
+        pressure_change_initial_guess: Pressure,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        self.try_get_pressure_change_with_optional_initial_guess(
+            fluid_mass_flowrate, 
+            Some(pressure_change_initial_guess), 
+            root_finding_settings)
+    }
+
+    /// gets the pressure change across the collection, 
+    /// warm started if an initial guess is given
+    fn try_get_pressure_change_with_optional_initial_guess(&self,
+        fluid_mass_flowrate: MassRate,
+        pressure_change_initial_guess: Option<Pressure>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        let parallel_branches: &Vec<FluidComponentCollection> = match self.orientation {
+            FluidComponentCollectionOreintation::Parallel => &self.fluid_component_super_vector,
+            FluidComponentCollectionOreintation::Series => {
+                return Ok(self.get_pressure_change(fluid_mass_flowrate));
+            },
+        };
+
+        // blocked branches (eg. with a closed valve) are left out of 
+        // the root finding by the associated functions
+        match pressure_change_initial_guess {
+            Some(pressure_change_initial_guess) => {
+                <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                    try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+                        fluid_mass_flowrate, 
+                        parallel_branches,
+                        pressure_change_initial_guess,
+                        root_finding_settings)
+            },
+            None => {
+                <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                    try_calculate_pressure_change_from_mass_flowrate(
+                        fluid_mass_flowrate, 
+                        parallel_branches,
+                        root_finding_settings)
+            },
+        }
+    }
+
 }
 
 /// the default is to provide an empty vector
@@ -261,10 +393,11 @@ impl FluidComponentCollectionMethods for FluidComponentSuperCollection {
 
         match orientation {
             FluidComponentCollectionOreintation::Parallel => {
-                let fluid_component_vector = &self.fluid_component_super_vector;
-                <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
-                    calculate_pressure_change_from_mass_flowrate(
-                        fluid_mass_flowrate, fluid_component_vector)
+                self.try_get_pressure_change(
+                    fluid_mass_flowrate, 
+                    &RootFindingSettings::default())
+                    .expect("parallel super collection pressure change did not converge, \
+                        use try_get_pressure_change to handle the error")
             },
             FluidComponentCollectionOreintation::Series => {
                 let fluid_component_vector = &self.fluid_component_super_vector;
@@ -290,6 +423,12 @@ impl FluidComponentCollectionMethods for FluidComponentSuperCollection {
                         pressure_change, fluid_component_vector)
             },
             FluidComponentCollectionOreintation::Series => {
+                // a blocked branch stops flow in the whole series
+                if self.fluid_component_super_vector.iter().any(
+                    |branch| branch.is_flow_blocked()) {
+                    return MassRate::ZERO;
+                }
+
                 let fluid_component_vector = &self.fluid_component_super_vector;
                 <Self as FluidComponentSuperCollectionSeriesAssociatedFunctions>::
                     calculate_mass_flowrate_from_pressure_change(
@@ -277,6 +277,15 @@ pub trait FluidComponentTrait {
         &mut self,
         internal_pressure: Pressure);
 
+    /// returns true if the component blocks flow completely,
+    /// eg. a fully closed valve
+    ///
+    /// blocked components carry no flow, so they are skipped when 
+    /// solving for pressure change across parallel collections
+    fn is_flow_blocked(&self) -> bool {
+        false
+    }
+
 }
 
 /// contains methods to get pressure loss 
@@ -81,6 +81,26 @@ pub mod fluid_component;
 /// calculated from collections of fluid components
 pub mod fluid_component_traits;
 
+/// flow diodes and check valves, fluid components with 
+/// a pressure loss that depends on flow direction
+pub mod flow_diode;
+
+/// control valves, fluid components with a pressure loss 
+/// that depends on valve position
+pub mod control_valve;
+
+/// centrifugal pumps with a head curve and shaft speed, which 
+/// set the internal pressure source of a pump body
+pub mod centrifugal_pump;
+
+/// absolute pressure along a solved series loop from a reference 
+/// pressure, and net positive suction head for pump inlets
+pub mod absolute_pressure;
+
+/// root finders and their settings (method, tolerances and 
+/// iterations) for the collection solvers
+pub mod root_finding;
+
 /// contains functions which calculate mass flowrate and pressure drop 
 /// for components connected in series or parallel 
 pub mod collection_series_and_parallel_functions;
@@ -0,0 +1,460 @@
+use crate::tuas_lib_error::TuasLibError;
+
+/// bracketing root finding methods available to the
+/// collection solvers
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum RootFindingMethod {
+    /// Brent-Dekker, inverse quadratic interpolation and secant
+    /// steps safeguarded by bisection
+    Brent,
+    /// regula falsi with the Illinois modification, the function
+    /// value at a retained end of the bracket is halved so that
+    /// both ends of the bracket move
+    IllinoisRegulaFalsi,
+    /// Newton-Raphson with a finite difference derivative,
+    /// falling back to bisection whenever the Newton step leaves
+    /// the bracket or does not reduce it fast enough
+    BracketedNewton,
+}
+
+/// settings for the root finders used in the collection solvers
+///
+/// the root is considered found when either the bracket is
+/// narrower than absolute_tolerance + relative_tolerance |x|, or
+/// the magnitude of the residual is below absolute_tolerance.
+/// This is the same as the eps in the SimpleConvergency
+/// struct of the roots crate which was used previously
+///
+/// If the method does not converge, the fallback method (if any)
+/// is tried on the original bracket
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct RootFindingSettings {
+    /// main root finding method
+    pub method: RootFindingMethod,
+    /// method used if the main method does not converge
+    pub fallback_method: Option<RootFindingMethod>,
+    /// absolute tolerance on the root and the residual
+    pub absolute_tolerance: f64,
+    /// tolerance on the root relative to its magnitude
+    pub relative_tolerance: f64,
+    /// maximum number of iterations for each method
+    pub max_iterations: usize,
+    /// maximum number of times the bracket around an initial guess
+    /// is widened (by a factor of 4 each time) before giving up
+    pub max_bracket_expansions: usize,
+}
+
+impl Default for RootFindingSettings {
+    /// Brent-Dekker with regula falsi as a fallback, this is what
+    /// the super collection solver used before the settings were
+    /// made configurable
+    fn default() -> Self {
+        Self {
+            method: RootFindingMethod::Brent,
+            fallback_method: Some(RootFindingMethod::IllinoisRegulaFalsi),
+            absolute_tolerance: 1e-9,
+            relative_tolerance: 0.0,
+            max_iterations: 70,
+            max_bracket_expansions: 20,
+        }
+    }
+}
+
+impl RootFindingSettings {
+
+    /// returns settings using a single method with the default
+    /// tolerances and no fallback method
+    pub fn new(method: RootFindingMethod) -> Self {
+        Self {
+            method,
+            fallback_method: None,
+            ..Default::default()
+        }
+    }
+
+    /// default settings for the parallel solver of the 
+    /// FluidComponentCollection, this is the same as the default
+    /// settings but with 30 iterations, which is what the 
+    /// FluidComponentCollection used before the settings were 
+    /// made configurable
+    ///
+    /// branches with check valves give very wide pressure bounds 
+    /// in reverse flow and may need more iterations, in that case 
+    /// use the try_ functions with your own settings
+    pub fn component_collection_default() -> Self {
+        Self {
+            max_iterations: 30,
+            ..Default::default()
+        }
+    }
+
+    /// tolerance on the root at a given estimate of the root
+    fn get_tolerance(&self, root_estimate: f64) -> f64 {
+        self.absolute_tolerance + self.relative_tolerance * root_estimate.abs()
+    }
+
+    /// finds the root of a function between two bounds, the bounds
+    /// may be given in any order but the function must change sign
+    /// between them
+    ///
+    /// returns TuasLibError::RootNotBracketed if the function does
+    /// not change sign between the bounds, and
+    /// TuasLibError::RootFindingNonConvergence with the last bracket
+    /// if neither the method nor the fallback method converges
+    pub fn find_root<F: Fn(f64) -> f64>(&self,
+        function: F,
+        first_bound: f64,
+        second_bound: f64) -> Result<f64, TuasLibError> {
+
+        let root_result = self.find_root_using_method(
+            self.method, &function, first_bound, second_bound);
+
+        match (root_result, self.fallback_method) {
+            (Err(TuasLibError::RootFindingNonConvergence { .. }),
+             Some(fallback_method)) => {
+                self.find_root_using_method(
+                    fallback_method, &function, first_bound, second_bound)
+            },
+            (root_result, _) => root_result,
+        }
+    }
+
+    /// finds the root of a function starting from an initial guess,
+    /// usually the solution from the previous timestep
+    ///
+    /// a local slope is estimated with a forward difference, and the
+    /// bracket is placed around the Newton estimate of the root.
+    /// If the bracket does not contain a sign change, it is widened
+    /// by a factor of 4 on each side, up to max_bracket_expansions
+    /// times. When the guess is close to the root, the bracket is
+    /// tight and the root is found in a few iterations
+    ///
+    /// returns TuasLibError::RootFindingNonConvergence with the
+    /// widest bracket tried if no sign change is found within
+    /// max_bracket_expansions, or if the bracket stops being finite
+    pub fn find_root_from_initial_guess<F: Fn(f64) -> f64>(&self,
+        function: F,
+        initial_guess: f64) -> Result<f64, TuasLibError> {
+
+        let guess_residual = function(initial_guess);
+
+        if guess_residual.abs() <= self.absolute_tolerance {
+            return Ok(initial_guess);
+        }
+
+        let difference_step = f64::EPSILON.sqrt() * initial_guess.abs().max(1.0);
+        let slope = (function(initial_guess + difference_step)
+            - guess_residual)/difference_step;
+
+        // twice the distance to the Newton estimate, so that the
+        // root is usually inside the first bracket
+        let newton_step = guess_residual/slope;
+        let mut bracket_step = if newton_step.is_finite() && newton_step != 0.0 {
+            2.0 * newton_step.abs() + difference_step
+        } else {
+            0.01 * initial_guess.abs().max(1.0)
+        };
+
+        // check the side the Newton step points to first
+        let newton_direction = if newton_step.is_finite() {
+            -newton_step.signum()
+        } else {
+            1.0
+        };
+
+        // widest bracket which was tried
+        let mut widest_bracket_step = 0.0;
+        let mut bracket_expansions: usize = 0;
+
+        loop {
+
+            if !(initial_guess + bracket_step).is_finite()
+                || !(initial_guess - bracket_step).is_finite() {
+                break;
+            }
+
+            for direction in [newton_direction, -newton_direction] {
+                let bound = initial_guess + direction * bracket_step;
+                let bound_residual = function(bound);
+
+                if bound_residual.signum() != guess_residual.signum()
+                    || bound_residual.abs() <= self.absolute_tolerance {
+                    return self.find_root(&function, initial_guess, bound);
+                }
+            }
+
+            widest_bracket_step = bracket_step;
+
+            if bracket_expansions == self.max_bracket_expansions {
+                break;
+            }
+
+            bracket_step *= 4.0;
+            bracket_expansions += 1;
+        }
+
+        Err(TuasLibError::RootFindingNonConvergence {
+            lower_bound: initial_guess - widest_bracket_step,
+            upper_bound: initial_guess + widest_bracket_step,
+            iterations: bracket_expansions,
+        })
+    }
+
+    /// finds the root using one particular method
+    fn find_root_using_method<F: Fn(f64) -> f64>(&self,
+        method: RootFindingMethod,
+        function: &F,
+        first_bound: f64,
+        second_bound: f64) -> Result<f64, TuasLibError> {
+
+        let first_residual = function(first_bound);
+        let second_residual = function(second_bound);
+
+        // either bound is already a root
+        if first_residual.abs() <= self.absolute_tolerance {
+            return Ok(first_bound);
+        }
+        if second_residual.abs() <= self.absolute_tolerance {
+            return Ok(second_bound);
+        }
+
+        if first_residual.signum() == second_residual.signum()
+            || first_residual.is_nan() || second_residual.is_nan() {
+            return Err(TuasLibError::RootNotBracketed {
+                lower_bound: first_bound.min(second_bound),
+                upper_bound: first_bound.max(second_bound),
+            });
+        }
+
+        match method {
+            RootFindingMethod::Brent => self.brent(function,
+                first_bound, first_residual,
+                second_bound, second_residual),
+            RootFindingMethod::IllinoisRegulaFalsi => self.illinois(function,
+                first_bound, first_residual,
+                second_bound, second_residual),
+            RootFindingMethod::BracketedNewton => self.bracketed_newton(function,
+                first_bound, first_residual,
+                second_bound, second_residual),
+        }
+    }
+
+    /// Brent-Dekker method, adapted from zbrent in
+    /// Numerical Recipes (Press et al.)
+    ///
+    /// b is the current best estimate and the root always lies
+    /// between b and c
+    fn brent<F: Fn(f64) -> f64>(&self,
+        function: &F,
+        mut a: f64, mut fa: f64,
+        mut b: f64, mut fb: f64) -> Result<f64, TuasLibError> {
+
+        let mut c = b;
+        let mut fc = fb;
+        let mut d = 0.0_f64;
+        let mut e = 0.0_f64;
+
+        for _ in 0..self.max_iterations {
+
+            if fb.signum() == fc.signum() {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+
+            let tol = 2.0 * f64::EPSILON * b.abs()
+                + 0.5 * self.get_tolerance(b);
+            let xm = 0.5 * (c - b);
+
+            if xm.abs() <= tol || fb.abs() <= self.absolute_tolerance {
+                return Ok(b);
+            }
+
+            if e.abs() >= tol && fa.abs() > fb.abs() {
+                // attempt inverse quadratic interpolation
+                // (or secant if only two points are available)
+                let s = fb/fa;
+                let (mut p, mut q);
+
+                if a == c {
+                    p = 2.0 * xm * s;
+                    q = 1.0 - s;
+                } else {
+                    let q_ac = fa/fc;
+                    let r = fb/fc;
+                    p = s * (2.0 * xm * q_ac * (q_ac - r) - (b - a) * (r - 1.0));
+                    q = (q_ac - 1.0) * (r - 1.0) * (s - 1.0);
+                }
+
+                if p > 0.0 {
+                    q = -q;
+                }
+                p = p.abs();
+
+                let min1 = 3.0 * xm * q - (tol * q).abs();
+                let min2 = (e * q).abs();
+
+                if 2.0 * p < min1.min(min2) {
+                    // interpolation accepted
+                    e = d;
+                    d = p/q;
+                } else {
+                    // bisection
+                    d = xm;
+                    e = d;
+                }
+            } else {
+                // bounds decreasing too slowly, use bisection
+                d = xm;
+                e = d;
+            }
+
+            a = b;
+            fa = fb;
+
+            if d.abs() > tol {
+                b += d;
+            } else {
+                b += tol * xm.signum();
+            }
+
+            fb = function(b);
+        }
+
+        // after the last step, the root lies between b and c, or
+        // between b and the previous estimate (now in a)
+        let other_end = if fb.signum() == fc.signum() { a } else { c };
+
+        Err(TuasLibError::RootFindingNonConvergence {
+            lower_bound: b.min(other_end),
+            upper_bound: b.max(other_end),
+            iterations: self.max_iterations,
+        })
+    }
+
+    /// regula falsi with the Illinois modification
+    fn illinois<F: Fn(f64) -> f64>(&self,
+        function: &F,
+        mut a: f64, mut fa: f64,
+        mut b: f64, mut fb: f64) -> Result<f64, TuasLibError> {
+
+        // which end of the bracket was retained last,
+        // -1 for a, +1 for b
+        let mut side: i8 = 0;
+
+        for _ in 0..self.max_iterations {
+
+            let c = (a * fb - b * fa)/(fb - fa);
+            let fc = function(c);
+
+            if fc.abs() <= self.absolute_tolerance
+                || (b - a).abs() <= self.get_tolerance(c) {
+                return Ok(c);
+            }
+
+            if fc.signum() == fb.signum() {
+                b = c;
+                fb = fc;
+                if side == -1 {
+                    fa *= 0.5;
+                }
+                side = -1;
+            } else if fc.signum() == fa.signum() {
+                a = c;
+                fa = fc;
+                if side == 1 {
+                    fb *= 0.5;
+                }
+                side = 1;
+            } else {
+                // fc is exactly zero
+                return Ok(c);
+            }
+        }
+
+        Err(TuasLibError::RootFindingNonConvergence {
+            lower_bound: a.min(b),
+            upper_bound: a.max(b),
+            iterations: self.max_iterations,
+        })
+    }
+
+    /// Newton-Raphson safeguarded by bisection, adapted from
+    /// rtsafe in Numerical Recipes (Press et al.)
+    ///
+    /// the derivative is estimated with a forward difference
+    fn bracketed_newton<F: Fn(f64) -> f64>(&self,
+        function: &F,
+        a: f64, fa: f64,
+        b: f64, _fb: f64) -> Result<f64, TuasLibError> {
+
+        // orient the bracket so that f(x_low) < 0
+        let (mut x_low, mut x_high) = if fa < 0.0 { (a, b) } else { (b, a) };
+
+        let derivative = |x: f64, fx: f64| -> f64 {
+            let step = f64::EPSILON.sqrt() * x.abs().max(1.0);
+            (function(x + step) - fx)/step
+        };
+
+        let mut x = 0.5 * (a + b);
+        let mut dx_old = (b - a).abs();
+        let mut dx = dx_old;
+        let mut fx = function(x);
+        let mut dfx = derivative(x, fx);
+
+        for _ in 0..self.max_iterations {
+
+            if fx.abs() <= self.absolute_tolerance {
+                return Ok(x);
+            }
+
+            let newton_step_out_of_range =
+                ((x - x_high) * dfx - fx) * ((x - x_low) * dfx - fx) > 0.0;
+            let newton_step_too_slow =
+                (2.0 * fx).abs() > (dx_old * dfx).abs();
+
+            if newton_step_out_of_range || newton_step_too_slow
+                || !dfx.is_finite() || dfx == 0.0 {
+                dx_old = dx;
+                dx = 0.5 * (x_high - x_low);
+                x = x_low + dx;
+            } else {
+                dx_old = dx;
+                dx = fx/dfx;
+                x -= dx;
+            }
+
+            if dx.abs() <= self.get_tolerance(x) {
+                return Ok(x);
+            }
+
+            fx = function(x);
+            dfx = derivative(x, fx);
+
+            if fx < 0.0 {
+                x_low = x;
+            } else {
+                x_high = x;
+            }
+        }
+
+        Err(TuasLibError::RootFindingNonConvergence {
+            lower_bound: x_low.min(x_high),
+            upper_bound: x_low.max(x_high),
+            iterations: self.max_iterations,
+        })
+    }
+}
+
+/// unit tests for the root finders
+pub mod unit_test_root_finding;
@@ -0,0 +1,455 @@
+/// all methods should find the roots of smooth and badly scaled
+/// functions, with bounds given in either order
+#[test]
+pub fn root_finding_methods_converge() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use super::{RootFindingMethod, RootFindingSettings};
+
+    let methods = [RootFindingMethod::Brent,
+        RootFindingMethod::IllinoisRegulaFalsi,
+        RootFindingMethod::BracketedNewton];
+
+    for method in methods {
+        let settings = RootFindingSettings {
+            absolute_tolerance: 1e-12,
+            max_iterations: 100,
+            ..RootFindingSettings::new(method)
+        };
+
+        let root = settings.find_root(|x: f64| x * x - 2.0, 0.0, 2.0)?;
+        approx::assert_relative_eq!(root, 2.0_f64.sqrt(), max_relative = 1e-10);
+
+        let root = settings.find_root(|x: f64| x * x - 2.0, 2.0, 0.0)?;
+        approx::assert_relative_eq!(root, 2.0_f64.sqrt(), max_relative = 1e-10);
+
+        // pressure loss like function, flat near zero and steep
+        // far away, over a wide bracket
+        let root = settings.find_root(
+            |x: f64| x * x.abs() - 1.0e4, -1.0e6, 1.0e6)?;
+        approx::assert_relative_eq!(root, 100.0, max_relative = 1e-10);
+    }
+
+    Ok(())
+}
+
+/// a bracket without a sign change and a root finder which runs
+/// out of iterations should both return errors instead of panicking
+#[test]
+pub fn root_finding_errors() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use crate::tuas_lib_error::TuasLibError;
+    use super::{RootFindingMethod, RootFindingSettings};
+
+    let settings = RootFindingSettings::default();
+
+    let not_bracketed = settings.find_root(|x: f64| x * x + 1.0, -1.0, 2.0);
+    assert!(matches!(not_bracketed,
+        Err(TuasLibError::RootNotBracketed {
+            lower_bound: -1.0, upper_bound: 2.0 })));
+
+    for method in [RootFindingMethod::Brent,
+        RootFindingMethod::IllinoisRegulaFalsi,
+        RootFindingMethod::BracketedNewton] {
+
+        let settings = RootFindingSettings {
+            absolute_tolerance: 1e-15,
+            max_iterations: 3,
+            ..RootFindingSettings::new(method)
+        };
+
+        let non_convergence = settings.find_root(
+            |x: f64| x.powi(3) - 2.0, -10.0, 10.0);
+
+        match non_convergence {
+            Err(TuasLibError::RootFindingNonConvergence {
+                lower_bound, upper_bound, iterations }) => {
+                // the last bracket still contains the root and is
+                // narrower than the initial one
+                let root = 2.0_f64.cbrt();
+                assert!(lower_bound <= root && root <= upper_bound);
+                assert!(upper_bound - lower_bound < 20.0);
+                assert_eq!(iterations, 3);
+            },
+            _ => panic!("expected non convergence for {:?}", method),
+        }
+    }
+
+    Ok(())
+}
+
+/// the pressure change across pipes in parallel should not depend
+/// on the root finding method, and failed root finding should be
+/// returned as an error
+#[test]
+pub fn parallel_collection_with_root_finding_settings() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::tuas_lib_error::TuasLibError;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_super_collection::
+        FluidComponentSuperCollection;
+    use super::{RootFindingMethod, RootFindingSettings};
+
+    let new_pipe = |length: f64, form_loss: f64| -> FluidComponent {
+        FluidComponent::FluidArray(FluidArray::new_cylinder(
+            Length::new::<meter>(length),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::Copper,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(form_loss),
+            0,
+            Angle::new::<degree>(0.0)))
+    };
+
+    let mut parallel_collection = 
+        FluidComponentCollection::new_parallel_component_collection();
+    parallel_collection.set_fluid_component_vector(vec![new_pipe(1.0, 5.0), new_pipe(2.0, 20.0)]);
+
+    let mut super_collection = FluidComponentSuperCollection::default();
+    let mut first_branch = 
+        FluidComponentCollection::new_series_component_collection();
+    first_branch.clone_and_add_component(&new_pipe(1.0, 5.0));
+    let mut second_branch = 
+        FluidComponentCollection::new_series_component_collection();
+    second_branch.clone_and_add_component(&new_pipe(2.0, 20.0));
+
+    super_collection.fluid_component_super_vector = vec![
+        first_branch, second_branch];
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(1.5);
+
+    let reference_pressure_change = parallel_collection
+        .get_pressure_change(mass_flowrate);
+
+    for method in [RootFindingMethod::Brent,
+        RootFindingMethod::IllinoisRegulaFalsi,
+        RootFindingMethod::BracketedNewton] {
+
+        let settings = RootFindingSettings {
+            max_iterations: 200,
+            ..RootFindingSettings::new(method)
+        };
+
+        approx::assert_relative_eq!(
+            parallel_collection.try_get_pressure_change(
+                mass_flowrate, &settings)?.get::<pascal>(),
+            reference_pressure_change.get::<pascal>(),
+            max_relative = 1e-6);
+
+        approx::assert_relative_eq!(
+            super_collection.try_get_pressure_change(
+                mass_flowrate, &settings)?.get::<pascal>(),
+            reference_pressure_change.get::<pascal>(),
+            max_relative = 1e-6);
+    }
+
+    // one iteration is not enough
+    let settings = RootFindingSettings {
+        max_iterations: 1,
+        ..RootFindingSettings::new(RootFindingMethod::Brent)
+    };
+
+    assert!(matches!(
+        parallel_collection.try_get_pressure_change(mass_flowrate, &settings),
+        Err(TuasLibError::RootFindingNonConvergence { .. })));
+    assert!(matches!(
+        super_collection.try_get_pressure_change(mass_flowrate, &settings),
+        Err(TuasLibError::RootFindingNonConvergence { .. })));
+
+    Ok(())
+}
+
+/// starting from a guess close to the root should need fewer
+/// function evaluations than bracketing from wide bounds,
+/// and a poor guess should still converge
+#[test]
+pub fn root_finding_from_initial_guess() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use std::cell::Cell;
+    use crate::tuas_lib_error::TuasLibError;
+    use super::RootFindingSettings;
+
+    let settings = RootFindingSettings::default();
+
+    let function_evaluations = Cell::new(0);
+    let pressure_loss_like_function = |x: f64| -> f64 {
+        function_evaluations.set(function_evaluations.get() + 1);
+        x * x.abs() - 1.0e4
+    };
+
+    let root = settings.find_root(
+        pressure_loss_like_function, -1.0e6, 1.0e6)?;
+    let cold_start_evaluations = function_evaluations.replace(0);
+    approx::assert_relative_eq!(root, 100.0, max_relative = 1e-9);
+
+    let root = settings.find_root_from_initial_guess(
+        pressure_loss_like_function, 101.0)?;
+    let warm_start_evaluations = function_evaluations.replace(0);
+    approx::assert_relative_eq!(root, 100.0, max_relative = 1e-9);
+
+    assert!(warm_start_evaluations < cold_start_evaluations);
+
+    // poor guesses on either side of the root
+    for initial_guess in [-5.0e3, 0.0, 1.0e5] {
+        let root = settings.find_root_from_initial_guess(
+            pressure_loss_like_function, initial_guess)?;
+        approx::assert_relative_eq!(root, 100.0, max_relative = 1e-9);
+    }
+
+    // without a root, the bracket stops growing after 
+    // max_bracket_expansions and stays finite
+    let no_root = settings.find_root_from_initial_guess(
+        |x: f64| x * x + 1.0, 1.0);
+    match no_root {
+        Err(TuasLibError::RootFindingNonConvergence {
+            lower_bound, upper_bound, iterations }) => {
+            assert!(lower_bound.is_finite() && upper_bound.is_finite());
+            assert_eq!(iterations, settings.max_bracket_expansions);
+        },
+        _ => panic!("expected a non convergence error, got {:?}", no_root),
+    }
+
+    Ok(())
+}
+
+/// a parallel super collection should give the same branch flows
+/// whether or not it is warm started with the previous solution
+#[test]
+pub fn parallel_super_collection_warm_start() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_super_collection::
+        {FluidComponentSuperCollection, ParallelBranchSolution};
+    use super::RootFindingSettings;
+
+    // the first branch has a pump, so that there is circulation
+    // between branches at zero net flow
+    let new_branch = |form_loss: f64, pump_pressure: f64| 
+        -> FluidComponentCollection {
+        let mut pipe = FluidComponent::FluidArray(FluidArray::new_cylinder(
+            Length::new::<meter>(1.0),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::Copper,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(form_loss),
+            0,
+            Angle::new::<degree>(0.0)));
+        pipe.set_internal_pressure_source(
+            Pressure::new::<pascal>(pump_pressure));
+
+        let mut branch = 
+            FluidComponentCollection::new_series_component_collection();
+        branch.clone_and_add_component(&pipe);
+        branch
+    };
+
+    let settings = RootFindingSettings::default();
+
+    // the previous solution is kept outside of the super collection
+    let mut previous_parallel_solution: Option<ParallelBranchSolution> 
+        = None;
+
+    // pump pressure ramps up over a few timesteps, the super 
+    // collection is rebuilt every timestep as in the CIET simulator
+    for pump_pressure in [1000.0, 1050.0, 1100.0, 1150.0] {
+
+        let mut super_collection = FluidComponentSuperCollection::default();
+        super_collection.fluid_component_super_vector = vec![
+            new_branch(5.0, pump_pressure),
+            new_branch(10.0, 0.0),
+            new_branch(20.0, 0.0)];
+
+        let cold_start_solution = super_collection
+            .solve_parallel_branch_flows(MassRate::ZERO, &mut None, &settings)?;
+
+        let warm_start_solution = super_collection
+            .solve_parallel_branch_flows(MassRate::ZERO, 
+                &mut previous_parallel_solution, &settings)?;
+
+        assert_eq!(previous_parallel_solution.as_ref(), 
+            Some(&warm_start_solution));
+
+        approx::assert_relative_eq!(
+            warm_start_solution.pressure_change.get::<pascal>(),
+            cold_start_solution.pressure_change.get::<pascal>(),
+            max_relative = 1e-6);
+
+        for (warm, cold) in warm_start_solution.branch_mass_flowrates.iter()
+            .zip(cold_start_solution.branch_mass_flowrates.iter()) {
+            approx::assert_relative_eq!(
+                warm.get::<kilogram_per_second>(),
+                cold.get::<kilogram_per_second>(),
+                max_relative = 1e-5);
+        }
+
+        // net flow is zero, with the pumped branch in forward flow
+        let net_mass_flowrate: MassRate = warm_start_solution
+            .branch_mass_flowrates.iter().copied().sum();
+        approx::assert_abs_diff_eq!(
+            net_mass_flowrate.get::<kilogram_per_second>(),
+            0.0,
+            epsilon = 1e-8);
+        assert!(warm_start_solution.branch_mass_flowrates[0] > MassRate::ZERO);
+    }
+
+    Ok(())
+}
+
+/// the solution of a parallel collection is kept so that the next 
+/// solve is warm started, the previous component flows alone should 
+/// also be enough to bracket the root
+///
+/// solving should not change the collection itself, so two 
+/// identical collections still compare equal after one is solved
+#[test]
+pub fn parallel_collection_warm_start() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{inch, meter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component::FluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_collection::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::collection_series_and_parallel_functions::
+        FluidComponentCollectionParallelAssociatedFunctions;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_super_collection::
+        ParallelBranchSolution;
+    use super::RootFindingSettings;
+
+    let new_pipe = |length: f64, form_loss: f64| -> FluidComponent {
+        FluidComponent::FluidArray(FluidArray::new_cylinder(
+            Length::new::<meter>(length),
+            Length::new::<inch>(2.0),
+            ThermodynamicTemperature::new::<kelvin>(298.0),
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::Copper,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(form_loss),
+            0,
+            Angle::new::<degree>(0.0)))
+    };
+
+    let mut parallel_collection = 
+        FluidComponentCollection::new_parallel_component_collection();
+    parallel_collection.set_fluid_component_vector(
+        vec![new_pipe(1.0, 5.0), new_pipe(2.0, 20.0)]);
+
+    let settings = RootFindingSettings::default();
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(1.5);
+    let unsolved_collection = parallel_collection.clone();
+
+    let mut previous_parallel_solution: Option<ParallelBranchSolution> = None;
+
+    let cold_start_solution = parallel_collection
+        .solve_parallel_component_flows(mass_flowrate, 
+            &mut previous_parallel_solution, &settings)?;
+
+    assert_eq!(previous_parallel_solution.as_ref(), 
+        Some(&cold_start_solution));
+    assert_eq!(parallel_collection, unsolved_collection);
+
+    // the component flows add up to the total flow
+    let total_mass_flowrate: MassRate = cold_start_solution
+        .branch_mass_flowrates.iter().copied().sum();
+    approx::assert_relative_eq!(
+        total_mass_flowrate.get::<kilogram_per_second>(),
+        1.5,
+        max_relative = 1e-6);
+
+    // warm started from the previous solution, at a slightly 
+    // higher flow
+    let higher_mass_flowrate = MassRate::new::<kilogram_per_second>(1.6);
+    let warm_start_solution = parallel_collection
+        .solve_parallel_component_flows(higher_mass_flowrate, 
+            &mut previous_parallel_solution, &settings)?;
+
+    approx::assert_relative_eq!(
+        warm_start_solution.pressure_change.get::<pascal>(),
+        parallel_collection.try_get_pressure_change(
+            higher_mass_flowrate, &settings)?.get::<pascal>(),
+        max_relative = 1e-6);
+
+    // the previous component flows alone are enough to bracket 
+    // the root when the previous pressure change is not used
+    let pressure_change_from_previous_branch_flows = 
+        <FluidComponentCollection as FluidComponentCollectionParallelAssociatedFunctions>::
+        try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+            cold_start_solution.branch_mass_flowrates[0].abs()
+            .max(cold_start_solution.branch_mass_flowrates[1].abs()),
+            mass_flowrate,
+            &parallel_collection.components,
+            &settings)?;
+
+    approx::assert_relative_eq!(
+        pressure_change_from_previous_branch_flows.get::<pascal>(),
+        cold_start_solution.pressure_change.get::<pascal>(),
+        max_relative = 1e-6);
+
+    // no previous solution forces a cold start
+    let fallback_solution = parallel_collection
+        .solve_parallel_component_flows(mass_flowrate, &mut None, &settings)?;
+
+    approx::assert_relative_eq!(
+        fallback_solution.pressure_change.get::<pascal>(),
+        cold_start_solution.pressure_change.get::<pascal>(),
+        max_relative = 1e-6);
+
+    // series collections have no branch flows to solve for
+    let mut series_collection = 
+        FluidComponentCollection::new_series_component_collection();
+    series_collection.set_fluid_component_vector(vec![new_pipe(1.0, 5.0)]);
+    assert!(series_collection
+        .solve_parallel_component_flows(mass_flowrate, &mut None, &settings).is_err());
+
+    Ok(())
+}
@@ -49,15 +49,18 @@
 extern crate roots;
 use peroxide::fuga::Algorithm;
 use roots::find_root_brent;
-use roots::find_root_regula_falsi;
 use roots::SimpleConvergency;
 use uom::num_traits::ToPrimitive;
 use uom::si::f64::{Pressure, MassRate};
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::pressure::pascal;
+use uom::ConstZero;
 
 use super::fluid_component_collection::FluidComponentCollection;
 use super::fluid_component_collection::FluidComponentCollectionMethods;
+use super::root_finding::RootFindingSettings;
+use super::fluid_component_super_collection::ParallelBranchSolution;
+use crate::tuas_lib_error::TuasLibError;
 /// contains associated functions which take a fluid component collection
 /// vector and calculate mass flowrates and pressure changes
 /// and losses from it
@@ -745,11 +748,49 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
     ///
     /// v0.0.9: added regula falsi algorithm to ensure 
     /// solver is more robust
+    ///
+    /// this uses the default root finding settings and panics if 
+    /// the root finder does not converge, 
+    /// see try_calculate_pressure_change_from_mass_flowrate
+    #[deprecated(note = "panics if the root finder does not converge, use try_calculate_pressure_change_from_mass_flowrate instead")]
     fn calculate_pressure_change_from_mass_flowrate(
         mass_flowrate: MassRate,
         fluid_component_collection_vector: 
         &Vec<FluidComponentCollection>) -> Pressure {
 
+        <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_from_mass_flowrate(
+                mass_flowrate, 
+                fluid_component_collection_vector, 
+                &RootFindingSettings::default())
+                .expect("parallel super collection pressure change did not converge, \
+                    use try_calculate_pressure_change_from_mass_flowrate to handle the error")
+    }
+
+    /// calculates pressure change given a mass
+    /// flowrate through a parallel collection of
+    /// fluid pipes or components using user specified root 
+    /// finding settings
+    ///
+    /// returns an error with the last bracket if the root 
+    /// finder does not converge
+    fn try_calculate_pressure_change_from_mass_flowrate(
+        mass_flowrate: MassRate,
+        fluid_component_collection_vector: 
+        &Vec<FluidComponentCollection>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(unblocked_branch_vector) = 
+            <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            obtain_unblocked_branch_vector(fluid_component_collection_vector)? {
+
+            return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_from_mass_flowrate(
+                    mass_flowrate, 
+                    &unblocked_branch_vector, 
+                    root_finding_settings);
+        }
+
         // for calculating pressure change in a parallel super
         // collection from
         // mass flowrate, 
@@ -867,10 +908,11 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
 
 
             return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     max_mass_flowrate_across_each_branch, 
                     user_requested_mass_flowrate, 
-                    fluid_component_collection_vector);
+                    fluid_component_collection_vector,
+                    root_finding_settings);
         }
 
 
@@ -990,10 +1032,11 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
             // pressure drop bounds for the branches
 
             return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     max_mass_flowrate_across_each_branch, 
                     user_requested_mass_flowrate, 
-                    fluid_component_collection_vector);
+                    fluid_component_collection_vector,
+                    root_finding_settings);
         }
 
         // next we can go to the other extreme, where external flowrate is 
@@ -1024,10 +1067,11 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
 
 
             return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     guess_average_mass_flowrate, 
                     user_requested_mass_flowrate, 
-                    fluid_component_collection_vector);
+                    fluid_component_collection_vector,
+                    root_finding_settings);
 
         }
 
@@ -1072,10 +1116,11 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
                 zero_mass_flowrate;
 
             return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
-                calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                     guess_average_mass_flowrate, 
                     user_requested_mass_flowrate, 
-                    fluid_component_collection_vector);
+                    fluid_component_collection_vector,
+                    root_finding_settings);
 
 
         }
@@ -1095,10 +1140,11 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
             /number_of_branches;
 
         return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
-            calculate_pressure_change_using_guessed_branch_mass_flowrate(
+            try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
                 guess_average_mass_flowrate, 
                 user_requested_mass_flowrate, 
-                fluid_component_collection_vector);
+                fluid_component_collection_vector,
+                root_finding_settings);
 
     }
 
@@ -1180,12 +1226,49 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
     /// the guessed flowrate should provide an upper bound for the given 
     /// flowrate
     ///
+    /// this uses the default root finding settings and panics if 
+    /// the root finder does not converge
+    #[deprecated(note = "panics if the root finder does not converge, use try_calculate_pressure_change_using_guessed_branch_mass_flowrate instead")]
     #[inline]
     fn calculate_pressure_change_using_guessed_branch_mass_flowrate(
         individual_branch_guess_upper_bound_mass_flowrate: MassRate,
         user_specified_mass_flowrate: MassRate,
         fluid_component_collection_vector: &Vec<FluidComponentCollection>) -> Pressure {
 
+        <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                individual_branch_guess_upper_bound_mass_flowrate, 
+                user_specified_mass_flowrate, 
+                fluid_component_collection_vector, 
+                &RootFindingSettings::default())
+                .expect("parallel super collection pressure change did not converge, \
+                    use try_calculate_pressure_change_using_guessed_branch_mass_flowrate to handle the error")
+    }
+
+    /// calculates pressure change at user specified mass flowrate
+    /// given a guessed upper bound flowrate through each branch, 
+    /// using user specified root finding settings
+    ///
+    /// returns an error with the last bracket if the root 
+    /// finder does not converge
+    #[inline]
+    fn try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+        individual_branch_guess_upper_bound_mass_flowrate: MassRate,
+        user_specified_mass_flowrate: MassRate,
+        fluid_component_collection_vector: &Vec<FluidComponentCollection>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(unblocked_branch_vector) = 
+            <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            obtain_unblocked_branch_vector(fluid_component_collection_vector)? {
+
+            return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                    individual_branch_guess_upper_bound_mass_flowrate, 
+                    user_specified_mass_flowrate, 
+                    &unblocked_branch_vector, 
+                    root_finding_settings);
+        }
 
         // first i am applying the guessed maximum 
         // flowrate through all branches
@@ -1303,48 +1386,156 @@ pub trait FluidComponentSuperCollectionParallelAssociatedFunctions {
         // may wanna delete later
         //panic!("{:?}", user_specified_pressure_upper_bound);
 
-        // i can't use a convergency value too strict, perhaps 1e-9 will do!
-        //
-        let mut convergency = SimpleConvergency { 
-            eps:1e-9_f64, 
-            max_iter: 70
+        // by default, Brent-Dekker is tried first, and regula 
+        // falsi is used if it does not converge
+        let pressure_change_pascals_user_specified_flow: f64 = 
+            root_finding_settings.find_root(
+                pressure_change_from_mass_flowrate_root,
+                user_specified_pressure_upper_bound.value,
+                user_specified_pressure_lower_bound.value)?;
+
+        Ok(Pressure::new::<pascal>(pressure_change_pascals_user_specified_flow))
+    }
+
+    /// calculates pressure change given a mass flowrate through a 
+    /// parallel collection, starting from an initial guess of the 
+    /// pressure change (usually the solution from the previous 
+    /// timestep)
+    ///
+    /// this skips the bracketing heuristics used in 
+    /// try_calculate_pressure_change_from_mass_flowrate. When branch 
+    /// flows change little between timesteps, only a few evaluations 
+    /// of the branches are needed
+    fn try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+        mass_flowrate: MassRate,
+        fluid_component_collection_vector: &Vec<FluidComponentCollection>,
+        pressure_change_initial_guess: Pressure,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(unblocked_branch_vector) = 
+            <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            obtain_unblocked_branch_vector(fluid_component_collection_vector)? {
+
+            return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+                    mass_flowrate, 
+                    &unblocked_branch_vector, 
+                    pressure_change_initial_guess, 
+                    root_finding_settings);
+        }
+
+        let pressure_change_from_mass_flowrate_root = 
+            |branch_pressure_change_pascals: f64| -> f64 {
+
+                let iterated_pressure = 
+                    Pressure::new::<pascal>(branch_pressure_change_pascals);
+
+                let iterated_mass_flowrate =
+                    <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                    calculate_mass_flowrate_from_pressure_change(
+                        iterated_pressure, 
+                        fluid_component_collection_vector);
+
+                (iterated_mass_flowrate - mass_flowrate)
+                    .get::<kilogram_per_second>()
         };
 
-        let mut pressure_change_pascals_result_user_specified_flow
-            = find_root_brent(
-                user_specified_pressure_upper_bound.value,
-                user_specified_pressure_lower_bound.value,
-                &pressure_change_from_mass_flowrate_root,
-                &mut convergency);
-
-        match pressure_change_pascals_result_user_specified_flow {
-            Ok(pressure_change_pascals_user_specified_flow) => {
-                return Pressure::new::<pascal>(pressure_change_pascals_user_specified_flow);
-            },
-            Err(_error_msg) => {
-                dbg!("Brent Dekker algo failed, trying regula falsi");
-            },
+        let pressure_change_pascals: f64 = 
+            root_finding_settings.find_root_from_initial_guess(
+                pressure_change_from_mass_flowrate_root,
+                pressure_change_initial_guess.get::<pascal>())?;
+
+        Ok(Pressure::new::<pascal>(pressure_change_pascals))
+    }
+
+    /// calculates pressure change given a mass flowrate through a 
+    /// parallel super collection, warm started from the previous solution if 
+    /// there is one
+    ///
+    /// the previous pressure change is tried as an initial guess 
+    /// first. If that fails, the largest of the previous branch 
+    /// mass flowrates is used as the guessed upper bound of the 
+    /// branch mass flowrates. Otherwise, (or without a previous 
+    /// solution) the usual bracketing heuristics are used
+    fn try_calculate_pressure_change_from_mass_flowrate_with_previous_solution(
+        mass_flowrate: MassRate,
+        fluid_component_collection_vector: &Vec<FluidComponentCollection>,
+        previous_parallel_solution: Option<&ParallelBranchSolution>,
+        root_finding_settings: &RootFindingSettings) -> Result<Pressure, TuasLibError> {
+
+        if let Some(previous_parallel_solution) = previous_parallel_solution {
+
+            let warm_started_pressure_change = 
+                <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                try_calculate_pressure_change_from_mass_flowrate_with_initial_guess(
+                    mass_flowrate, 
+                    fluid_component_collection_vector, 
+                    previous_parallel_solution.pressure_change, 
+                    root_finding_settings);
+
+            if warm_started_pressure_change.is_ok() {
+                return warm_started_pressure_change;
+            }
+
+            let largest_previous_branch_mass_flowrate: MassRate = 
+                previous_parallel_solution.branch_mass_flowrates.iter()
+                .map(|branch_mass_flowrate| branch_mass_flowrate.abs())
+                .fold(mass_flowrate.abs(), |largest_mass_flowrate, branch_mass_flowrate| {
+                    largest_mass_flowrate.max(branch_mass_flowrate)
+                });
+
+            if largest_previous_branch_mass_flowrate > MassRate::ZERO {
+
+                let guessed_pressure_change = 
+                    <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+                    try_calculate_pressure_change_using_guessed_branch_mass_flowrate(
+                        largest_previous_branch_mass_flowrate, 
+                        mass_flowrate, 
+                        fluid_component_collection_vector, 
+                        root_finding_settings);
+
+                if guessed_pressure_change.is_ok() {
+                    return guessed_pressure_change;
+                }
+            }
         }
 
-        // try the regula falsi
+        <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            try_calculate_pressure_change_from_mass_flowrate(
+                mass_flowrate, 
+                fluid_component_collection_vector, 
+                root_finding_settings)
+    }
 
-        pressure_change_pascals_result_user_specified_flow = 
-            find_root_regula_falsi(
-                user_specified_pressure_upper_bound.value,
-                user_specified_pressure_lower_bound.value,
-                &pressure_change_from_mass_flowrate_root,
-                &mut convergency);
-
-        match pressure_change_pascals_result_user_specified_flow {
-            Ok(pressure_change_pascals_user_specified_flow) => {
-                return Pressure::new::<pascal>(pressure_change_pascals_user_specified_flow);
-            },
-            Err(_error_msg) => {
-                dbg!("Regula falsi algo failed");
-            },
+    /// blocked branches (eg. with a closed valve) carry no flow, 
+    /// and their pressure change at any nonzero flow is infinite, 
+    /// so they are left out of the root finding
+    ///
+    /// returns None if no branch is blocked, and an error if 
+    /// all the branches are blocked
+    fn obtain_unblocked_branch_vector(
+        fluid_component_collection_vector: &[FluidComponentCollection]) 
+        -> Result<Option<Vec<FluidComponentCollection>>, TuasLibError> {
+
+        if !fluid_component_collection_vector.iter().any(
+            |branch| branch.is_flow_blocked()) {
+            return Ok(None);
+        }
+
+        let unblocked_branch_vector: Vec<FluidComponentCollection> = 
+            fluid_component_collection_vector.iter()
+            .filter(|branch| !branch.is_flow_blocked())
+            .cloned()
+            .collect();
+
+        if unblocked_branch_vector.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "all branches in parallel are blocked, \
+                pressure change cannot be found from mass flowrate"
+                .to_string()));
         }
 
-        todo!("debugging: all root finding methods used not successful");
+        Ok(Some(unblocked_branch_vector))
     }
 
     /// This function takes a mass flowrate and applies it to each
@@ -75,7 +75,6 @@ pub fn simple_fluid_collection_example_6 () {
     use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component::FluidComponent;
     use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
-    use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollectionOreintation;
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollection;
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollectionMethods;
 
@@ -154,20 +153,18 @@ pub fn simple_fluid_collection_example_6 () {
 
     // let's now put all of them in series.
     
-    let parallel_collection_of_therminol_pipes = 
-        FluidComponentCollection{
-            components: vec![therminol_pipe_1.clone(),
-            therminol_pipe_2.clone(),
-            therminol_pipe_3.clone(),
-            therminol_pipe_4.clone(),
-            therminol_pipe_5.clone(),
-            therminol_pipe_6.clone(),
-            therminol_pipe_7.clone(),
-            therminol_pipe_8.clone(),
-            therminol_pipe_9.clone(),
-            therminol_pipe_10.clone(),],
-            orientation: FluidComponentCollectionOreintation::Parallel,
-        };
+    let mut parallel_collection_of_therminol_pipes = 
+        FluidComponentCollection::new_parallel_component_collection();
+    parallel_collection_of_therminol_pipes.set_fluid_component_vector(vec![therminol_pipe_1.clone(),
+        therminol_pipe_2.clone(),
+        therminol_pipe_3.clone(),
+        therminol_pipe_4.clone(),
+        therminol_pipe_5.clone(),
+        therminol_pipe_6.clone(),
+        therminol_pipe_7.clone(),
+        therminol_pipe_8.clone(),
+        therminol_pipe_9.clone(),
+        therminol_pipe_10.clone(),]);
 
 
     // now let's have a pressure change of 1000 Pa across the parallel 
@@ -77,7 +77,6 @@ pub fn super_fluid_collection_example_7 () {
     use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component::FluidComponent;
     use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
-    use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollectionOreintation;
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollection;
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollectionMethods;
     use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_super_collection::FluidComponentSuperCollection;
@@ -157,20 +156,18 @@ pub fn super_fluid_collection_example_7 () {
 
     // let's now put all of them in series.
     
-    let series_collection_of_therminol_pipes_1 = 
-        FluidComponentCollection{
-            components: vec![therminol_pipe_1.clone(),
-            therminol_pipe_2.clone(),
-            therminol_pipe_3.clone(),
-            therminol_pipe_4.clone(),
-            therminol_pipe_5.clone(),
-            therminol_pipe_6.clone(),
-            therminol_pipe_7.clone(),
-            therminol_pipe_8.clone(),
-            therminol_pipe_9.clone(),
-            therminol_pipe_10.clone(),],
-            orientation: FluidComponentCollectionOreintation::Series,
-        };
+    let mut series_collection_of_therminol_pipes_1 = 
+        FluidComponentCollection::new_series_component_collection();
+    series_collection_of_therminol_pipes_1.set_fluid_component_vector(vec![therminol_pipe_1.clone(),
+        therminol_pipe_2.clone(),
+        therminol_pipe_3.clone(),
+        therminol_pipe_4.clone(),
+        therminol_pipe_5.clone(),
+        therminol_pipe_6.clone(),
+        therminol_pipe_7.clone(),
+        therminol_pipe_8.clone(),
+        therminol_pipe_9.clone(),
+        therminol_pipe_10.clone(),]);
     // we'll make three of these pipes in series 
 
     let collection_2 = series_collection_of_therminol_pipes_1.clone();
@@ -25,6 +25,24 @@ pub mod conductance_array_functions;
 /// you can't really couple these arrays laterally though
 pub mod one_dimension_cartesian_conducting_medium;
 
+/// contains a generic struct which abstracts away calculation details 
+///
+/// this is shared by the spherical and cylindrical (r only) arrays,
+/// which only differ in their shell volumes, areas and resistances
+pub mod one_dimension_radial_conducting_medium;
+/// contains a full struct which abstracts away calculation details 
+///
+/// this is relevant for one dimension spherical (r only) coordinates,
+/// eg. fuel pebbles, with volumes and conduction areas going with r^2
+pub mod one_dimension_spherical_conducting_medium;
+
+/// contains a full struct which abstracts away calculation details 
+///
+/// this is relevant for one dimension cylindrical (r only) coordinates,
+/// eg. thick pipe walls or heater rods, with volumes and conduction 
+/// areas going with r
+pub mod one_dimension_cylindrical_conducting_medium;
+
 
 /// contains a full struct which abstracts away calculation details 
 /// 1 dimensional solid arrays
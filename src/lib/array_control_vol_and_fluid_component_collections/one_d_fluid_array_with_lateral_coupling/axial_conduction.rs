@@ -0,0 +1,114 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::fluid_mechanics_correlations::churchill_friction_factor::darcy;
+use crate::tuas_lib_error::TuasLibError;
+
+/// axial conduction models for fluid arrays
+///
+/// the fluid array uses upwind advection, which is fine for high
+/// peclet number flows. For low peclet number flows, such as near
+/// stagnant natural circulation startup or liquid metals, heat
+/// conducted axially through the fluid is no longer negligible
+///
+/// the axial conductance between adjacent nodes is:
+///
+/// H_axial = k_eff A_xs / dx
+///
+/// where k_eff is the effective axial thermal conductivity based on
+/// the bulk fluid temperature. This only applies within the fluid
+/// array, conduction to adjacent components is not included
+#[derive(Debug,Clone,Copy,Default,PartialEq)]
+pub enum FluidAxialConductionModel {
+    /// molecular conduction (k_eff = k) when the peclet
+    /// number Pe = Re Pr is below 100, otherwise axial conduction
+    /// is neglected
+    ///
+    /// this is how fluid arrays have always behaved
+    #[default]
+    LowPecletNumberMolecular,
+
+    /// axial conduction is neglected at all peclet numbers,
+    /// so heat only moves axially by advection
+    Neglected,
+
+    /// molecular conduction (k_eff = k) at all peclet numbers
+    Molecular,
+
+    /// molecular conduction plus axial turbulent diffusion at all
+    /// peclet numbers
+    ///
+    /// for turbulent pipe flow, Taylor's axial dispersion
+    /// coefficient is:
+    ///
+    /// D_ax = 10.1 R u_tau
+    ///
+    /// with R the pipe radius and u_tau = u sqrt(f_darcy/8)
+    /// the friction velocity. Adding rho cp D_ax to the molecular
+    /// conductivity gives:
+    ///
+    /// k_eff / k = 1 + 5.05 Pe sqrt(f_darcy/8)
+    ///
+    /// where the smooth pipe churchill friction factor is used.
+    /// The turbulent term is linearly interpolated from zero at
+    /// Re = 2300 to its full value at Re = 4000, as with the
+    /// transition region of the Gnielinski correlation
+    ///
+    /// Taylor, G. I. (1954). The dispersion of matter in turbulent
+    /// flow through a pipe. Proceedings of the Royal Society of
+    /// London. Series A, 223(1155), 446-468.
+    MolecularAndTurbulentDiffusion,
+}
+
+impl FluidAxialConductionModel {
+
+    /// gets the effective axial thermal conductivity divided by
+    /// the molecular thermal conductivity of the fluid (k_eff/k)
+    ///
+    /// this is zero whenever axial conduction is neglected
+    ///
+    /// reynolds and prandtl numbers are those of the bulk fluid,
+    /// only the magnitude of the reynolds number is used
+    pub fn get_effective_conductivity_ratio(&self,
+        reynolds: Ratio,
+        prandtl: Ratio) -> Result<Ratio, TuasLibError> {
+
+        let reynolds_abs = reynolds.abs();
+        let peclet_number: Ratio = reynolds_abs * prandtl;
+
+        let conductivity_ratio: f64 = match self {
+            Self::LowPecletNumberMolecular => {
+                if peclet_number.get::<ratio>() < 100.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            },
+            Self::Neglected => 0.0,
+            Self::Molecular => 1.0,
+            Self::MolecularAndTurbulentDiffusion => {
+
+                let reynolds_value = reynolds_abs.get::<ratio>();
+
+                // the interpolation factor is zero for laminar flow
+                // and one for turbulent flow
+                let gamma: f64 = ((reynolds_value - 2300.0)/(4000.0 - 2300.0))
+                    .clamp(0.0, 1.0);
+
+                if gamma == 0.0 {
+                    1.0
+                } else {
+                    let smooth_pipe_roughness_ratio = 0.0;
+                    let darcy_friction_factor = darcy(
+                        reynolds_value,
+                        smooth_pipe_roughness_ratio)?;
+
+                    1.0 + gamma * 5.05 * peclet_number.get::<ratio>()
+                        * (darcy_friction_factor/8.0).sqrt()
+                }
+            },
+        };
+
+        Ok(Ratio::new::<ratio>(conductivity_ratio))
+    }
+}
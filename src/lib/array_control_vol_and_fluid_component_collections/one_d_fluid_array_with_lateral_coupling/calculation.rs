@@ -633,7 +633,6 @@ impl FluidArray{
             pressure
         )?;
 
-        let peclet_number = reynolds * prandtl_number;
         let average_axial_conductance: ThermalConductance;
 
         // note: this part is quite buggy as in the peclet number correction 
@@ -644,11 +643,21 @@ impl FluidArray{
         // I ascertained manually setting peclet number to zero does not 
         // visibly change the results, hence, 
         // it seems okay for now
+        //
+        // the axial conduction model decides whether axial conduction 
+        // is considered, by default, only low peclet number flows 
+        // (Pe < 100) have axial conduction
+        //
+        // the model also gives k_eff/k, which includes 
+        // axial turbulent diffusion if the user wants it
+
+        let axial_conductivity_ratio: Ratio = self.axial_conduction_model
+            .get_effective_conductivity_ratio(reynolds, prandtl_number)?;
 
-        let low_peclet_number_flow = peclet_number.value < 100.0;
+        let axial_conduction_considered = axial_conductivity_ratio.value > 0.0;
         
-        if low_peclet_number_flow {
-            // for low peclet number flows, consider conduction
+        if axial_conduction_considered {
+            // if axial conduction is considered,
             // which means we need to get axial conductance 
             // between nodes 
 
@@ -662,6 +671,7 @@ impl FluidArray{
             // per timestep to expedite the speed of calculation
 
             average_axial_conductance = 
+                axial_conductivity_ratio *
                 average_fluid_conductivity * 
                 self.xs_area / node_length;
 
@@ -760,7 +770,7 @@ impl FluidArray{
             }
             // done for loop
         }
-        // done peclet number check (fluid array)
+        // done axial conduction check (fluid array)
 
         
         //dbg!(&power_source_vector);
@@ -8,6 +8,8 @@ use crate::single_control_vol::SingleCVNode;
 
 use super::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
 use super::FluidArray;
+use super::axial_conduction::FluidAxialConductionModel;
+use crate::fluid_mechanics_correlations::non_circular_ducts::DuctShape;
 use uom::si::f64::*;
 use uom::si::ratio::ratio;
 use uom::si::mass_rate::kilogram_per_second;
@@ -142,6 +144,9 @@ impl FluidArray {
             internal_pressure_source: Pressure::new::<atmosphere>(0.0),
             fluid_component_loss_properties: pipe_losses,
             nusselt_correlation: pipe_nusselt,
+            duct_shape: DuctShape::default(),
+            axial_conduction_model: FluidAxialConductionModel::default(),
+            mixed_convection_correction: false,
             lateral_adjacent_array_temperature_vector: vec![],
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
@@ -288,6 +293,9 @@ impl FluidArray {
             internal_pressure_source: Pressure::new::<atmosphere>(0.0),
             fluid_component_loss_properties: pipe_losses,
             nusselt_correlation: pipe_nusselt,
+            duct_shape: DuctShape::default(),
+            axial_conduction_model: FluidAxialConductionModel::default(),
+            mixed_convection_correction: false,
             lateral_adjacent_array_temperature_vector: vec![],
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
@@ -545,6 +553,9 @@ impl FluidArray {
             internal_pressure_source: Pressure::new::<atmosphere>(0.0),
             fluid_component_loss_properties: pipe_losses,
             nusselt_correlation: pipe_nusselt,
+            duct_shape: DuctShape::default(),
+            axial_conduction_model: FluidAxialConductionModel::default(),
+            mixed_convection_correction: false,
             lateral_adjacent_array_temperature_vector: vec![],
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
@@ -661,6 +672,9 @@ impl FluidArray {
             internal_pressure_source: Pressure::new::<atmosphere>(0.0),
             fluid_component_loss_properties: pipe_losses,
             nusselt_correlation: pipe_nusselt,
+            duct_shape: DuctShape::default(),
+            axial_conduction_model: FluidAxialConductionModel::default(),
+            mixed_convection_correction: false,
             lateral_adjacent_array_temperature_vector: vec![],
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
@@ -9,6 +9,8 @@ use crate::single_control_vol::SingleCVNode;
 
 use super::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
 use super::FluidArray;
+use super::axial_conduction::FluidAxialConductionModel;
+use crate::fluid_mechanics_correlations::non_circular_ducts::DuctShape;
 use uom::si::f64::*;
 use uom::si::area::square_meter;
 use uom::si::length::meter;
@@ -113,6 +115,9 @@ impl Default for FluidArray {
             internal_pressure_source: Pressure::new::<atmosphere>(0.0),
             fluid_component_loss_properties: pipe_losses,
             nusselt_correlation: pipe_nusselt,
+            duct_shape: DuctShape::default(),
+            axial_conduction_model: FluidAxialConductionModel::default(),
+            mixed_convection_correction: false,
             lateral_adjacent_array_temperature_vector: vec![],
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
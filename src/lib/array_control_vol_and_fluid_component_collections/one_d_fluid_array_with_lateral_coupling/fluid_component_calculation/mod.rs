@@ -2,6 +2,13 @@ use crate::boussinesq_thermophysical_properties::density::try_get_rho;
 use crate::boussinesq_thermophysical_properties::dynamic_viscosity::try_get_mu_viscosity;
 use crate::tuas_lib_error::TuasLibError;
 use crate::fluid_mechanics_correlations::churchill_friction_factor;
+use crate::fluid_mechanics_correlations::packed_bed_friction_factor;
+use crate::fluid_mechanics_correlations::form_loss_fittings::{FittingsFormLoss, PipeFitting};
+use crate::fluid_mechanics_correlations::non_circular_ducts::DuctShape;
+use crate::heat_transfer_correlations::baffled_shell_side::BaffledShellSide;
+use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityEnvelope;
+use crate::heat_transfer_correlations::mixed_convection::MixedConvectionDarcyLoss;
+use crate::boussinesq_thermophysical_properties::prandtl::try_get_prandtl;
 use roots::*;
 use uom::typenum::P2;
 use uom::num_traits::Zero;
@@ -21,6 +28,21 @@ pub enum DimensionlessDarcyLossCorrelations {
     ///
     /// and also a K ratio for generic form losses
     Pipe(Ratio, Ratio, Ratio),
+    /// pipe loss with a list of fittings whose form losses 
+    /// depend on Reynolds number (2-K and 3-K methods), must input 
+    /// roughness ratio, length to diameter ratio and the 
+    /// summed fittings
+    ///
+    /// (f L/D + K) = f L/D + K_fittings(Re, f)
+    PipeWithFittings(Ratio, Ratio, FittingsFormLoss),
+    /// pipe loss for non circular ducts, must input 
+    /// roughness ratio, length to diameter ratio, a K ratio 
+    /// for generic form losses and the duct shape
+    ///
+    /// the laminar term of the churchill correlation is replaced 
+    /// by the fRe of the duct shape, while the turbulent terms 
+    /// use the hydraulic diameter as usual
+    NonCircularPipe(Ratio, Ratio, Ratio, DuctShape),
     /// Reynold's power correlation in the form 
     /// f_darcy = A + B Re^(C)
     ///
@@ -33,8 +55,46 @@ pub enum DimensionlessDarcyLossCorrelations {
     /// randomly packed columns and fluidized beds. Industrial 
     /// & Engineering Chemistry, 41(6), 1179-1184.
     ///
-    /// not done yet
-    Ergun
+    /// The first in the tuple is the bed porosity,
+    /// the second is the bed length to hydraulic diameter ratio (L/D_h),
+    /// the third is the hydraulic diameter to particle diameter 
+    /// ratio (D_h/d_p)
+    ///
+    /// the flow area of the component should be the empty bed 
+    /// cross sectional area so that the velocity is the superficial 
+    /// velocity
+    Ergun(Ratio, Ratio, Ratio),
+
+    /// KTA 3102.3 pebble bed pressure drop correlation
+    ///
+    /// The first in the tuple is the bed porosity,
+    /// the second is the bed length to hydraulic diameter ratio (L/D_h),
+    /// the third is the hydraulic diameter to pebble diameter 
+    /// ratio (D_h/d_p)
+    KTAPebbleBed(Ratio, Ratio, Ratio),
+
+    /// Carman-Kozeny equation for creeping flow through packed beds
+    ///
+    /// The first in the tuple is the bed porosity,
+    /// the second is the bed length to hydraulic diameter ratio (L/D_h),
+    /// the third is the hydraulic diameter to particle diameter 
+    /// ratio (D_h/d_p)
+    CarmanKozeny(Ratio, Ratio, Ratio),
+
+    /// Bell-Delaware shell side pressure drop for segmentally 
+    /// baffled heat exchangers, including crossflow, window 
+    /// and end zone pressure drops (nozzles excluded)
+    ///
+    /// (f L/D + K) is based on the reference flow area and 
+    /// hydraulic diameter of the shell side
+    BellDelawareShellSide(BaffledShellSide),
+
+    /// Kern shell side pressure drop for segmentally 
+    /// baffled heat exchangers
+    ///
+    /// (f L/D + K) is based on the reference flow area and 
+    /// hydraulic diameter of the shell side
+    KernShellSide(BaffledShellSide),
 }
 
 impl Default for DimensionlessDarcyLossCorrelations {
@@ -69,6 +129,64 @@ impl DimensionlessDarcyLossCorrelations {
             form_loss);
     }
 
+    /// creates a new pipe object with a list of fittings,
+    /// the fittings K values are based on the velocity within 
+    /// the pipe
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::length::meter;
+    /// use uom::si::ratio::ratio;
+    /// use uom::si::angle::degree;
+    /// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+    ///     form_loss_fittings::PipeFitting;
+    /// use tuas_boussinesq_solver::array_control_vol_and_fluid_component_collections::
+    ///     one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::
+    ///     DimensionlessDarcyLossCorrelations;
+    ///
+    /// let fittings = vec![
+    ///     PipeFitting::SharpEntrance,
+    ///     PipeFitting::Elbow { 
+    ///         bend_radius_to_diameter: Ratio::new::<ratio>(1.5),
+    ///         angle: Angle::new::<degree>(90.0) },
+    ///     PipeFitting::TeeThroughRun,
+    ///     PipeFitting::Exit,
+    /// ];
+    ///
+    /// let pipe_with_fittings = DimensionlessDarcyLossCorrelations::
+    ///     new_pipe_with_fittings(
+    ///         Length::new::<meter>(2.0),
+    ///         Length::new::<meter>(1.5e-5),
+    ///         Length::new::<meter>(2.79e-2),
+    ///         &fittings).unwrap();
+    ///
+    /// let fldk = pipe_with_fittings.fldk_based_on_darcy_friction_factor(
+    ///     Ratio::new::<ratio>(10000.0)).unwrap();
+    ///
+    /// // the entrance and exit alone give K = 1.5 in turbulent flow
+    /// assert!(fldk.get::<ratio>() > 1.5);
+    /// ```
+    pub fn new_pipe_with_fittings(
+        pipe_length: Length,
+        surface_roughness: Length,
+        hydraulic_diameter: Length,
+        fittings: &[PipeFitting]
+    ) -> Result<Self, TuasLibError> {
+        let length_to_diameter_ratio: Ratio = 
+        pipe_length/hydraulic_diameter;
+
+        let roughness_ratio: Ratio = 
+        surface_roughness/hydraulic_diameter;
+
+        let fittings_form_loss = FittingsFormLoss::new(
+            fittings, hydraulic_diameter)?;
+
+        Ok(Self::PipeWithFittings(
+            roughness_ratio,
+            length_to_diameter_ratio,
+            fittings_form_loss))
+    }
+
     /// creates a new simple reynolds power correlation object 
     /// in the form
     /// Reynold's power correlation in the form 
@@ -82,6 +200,90 @@ impl DimensionlessDarcyLossCorrelations {
             a, b, c);
     }
 
+    /// creates a new packed bed object using the Ergun equation
+    ///
+    /// the hydraulic diameter should be the same one used by 
+    /// the fluid component to calculate Reynolds number
+    pub fn new_ergun_packed_bed(
+        bed_length: Length,
+        particle_diameter: Length,
+        hydraulic_diameter: Length,
+        porosity: Ratio) -> Self {
+
+        let length_to_diameter_ratio: Ratio = 
+        bed_length/hydraulic_diameter;
+
+        let diameter_to_particle_diameter_ratio: Ratio = 
+        hydraulic_diameter/particle_diameter;
+
+        Self::Ergun(
+            porosity,
+            length_to_diameter_ratio,
+            diameter_to_particle_diameter_ratio)
+    }
+
+    /// creates a new pebble bed object using the KTA 3102.3 
+    /// correlation
+    ///
+    /// the hydraulic diameter should be the same one used by 
+    /// the fluid component to calculate Reynolds number
+    pub fn new_kta_pebble_bed(
+        bed_length: Length,
+        pebble_diameter: Length,
+        hydraulic_diameter: Length,
+        porosity: Ratio) -> Self {
+
+        let length_to_diameter_ratio: Ratio = 
+        bed_length/hydraulic_diameter;
+
+        let diameter_to_particle_diameter_ratio: Ratio = 
+        hydraulic_diameter/pebble_diameter;
+
+        Self::KTAPebbleBed(
+            porosity,
+            length_to_diameter_ratio,
+            diameter_to_particle_diameter_ratio)
+    }
+
+    /// creates a new packed bed object using the Carman-Kozeny 
+    /// equation
+    ///
+    /// the hydraulic diameter should be the same one used by 
+    /// the fluid component to calculate Reynolds number
+    pub fn new_carman_kozeny_packed_bed(
+        bed_length: Length,
+        particle_diameter: Length,
+        hydraulic_diameter: Length,
+        porosity: Ratio) -> Self {
+
+        let length_to_diameter_ratio: Ratio = 
+        bed_length/hydraulic_diameter;
+
+        let diameter_to_particle_diameter_ratio: Ratio = 
+        hydraulic_diameter/particle_diameter;
+
+        Self::CarmanKozeny(
+            porosity,
+            length_to_diameter_ratio,
+            diameter_to_particle_diameter_ratio)
+    }
+
+    /// gets the validity envelope of the friction factor correlation 
+    /// used, if one is declared
+    ///
+    /// pipes use the churchill correlation, packed bed, shell side 
+    /// and user specified correlations have no declared envelope
+    pub fn get_validity_envelope(&self) -> Option<CorrelationValidityEnvelope> {
+        match self {
+            Self::Pipe(..) |
+            Self::PipeWithFittings(..) |
+            Self::NonCircularPipe(..) => {
+                Some(churchill_friction_factor::CHURCHILL_VALIDITY_ENVELOPE)
+            },
+            _ => None,
+        }
+    }
+
     /// gets the (f L/D + K) based on reynolds number and 
     /// other fluid component properties
     ///
@@ -133,6 +335,39 @@ impl DimensionlessDarcyLossCorrelations {
 
                     total_friction_factor
             },
+            DimensionlessDarcyLossCorrelations::NonCircularPipe(roughness_ratio,
+                length_to_diameter,
+                form_loss,
+                duct_shape) => {
+
+                    let laminar_friction_reynolds_product = 
+                        duct_shape.laminar_darcy_friction_reynolds_product()?;
+
+                    churchill_friction_factor::
+                        darcy_with_laminar_friction_reynolds_product(
+                            reynolds.get::<ratio>(),
+                            roughness_ratio.get::<ratio>(),
+                            laminar_friction_reynolds_product.get::<ratio>())?
+                        * length_to_diameter.get::<ratio>()
+                    + form_loss.get::<ratio>()
+            },
+            // f L/D + K_fittings(Re, f)
+            DimensionlessDarcyLossCorrelations::PipeWithFittings(roughness_ratio,
+                length_to_diameter,
+                fittings_form_loss) => {
+
+                    let darcy_friction_factor = 
+                    churchill_friction_factor::darcy(
+                        reynolds.get::<ratio>(),
+                        roughness_ratio.get::<ratio>())?;
+
+                    let fittings_k = fittings_form_loss.get_form_loss_k(
+                        reynolds, 
+                        Ratio::new::<ratio>(darcy_friction_factor));
+
+                    darcy_friction_factor * length_to_diameter.get::<ratio>()
+                    + fittings_k.get::<ratio>()
+            },
             // f L/D + K = A + B Re^(C)
             DimensionlessDarcyLossCorrelations::SimpleReynoldsPower(a, b, c) => {
                 let friction_factor  = a.get::<ratio>() + b.get::<ratio>()
@@ -141,8 +376,42 @@ impl DimensionlessDarcyLossCorrelations {
                 friction_factor
 
             },
-            DimensionlessDarcyLossCorrelations::Ergun => {
-                todo!()
+            DimensionlessDarcyLossCorrelations::Ergun(porosity,
+                length_to_diameter,
+                diameter_to_particle_diameter) => {
+                    packed_bed_friction_factor::ergun_fldk(
+                        reynolds.get::<ratio>(),
+                        porosity.get::<ratio>(),
+                        length_to_diameter.get::<ratio>(),
+                        diameter_to_particle_diameter.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::KTAPebbleBed(porosity,
+                length_to_diameter,
+                diameter_to_particle_diameter) => {
+                    packed_bed_friction_factor::kta_pebble_bed_fldk(
+                        reynolds.get::<ratio>(),
+                        porosity.get::<ratio>(),
+                        length_to_diameter.get::<ratio>(),
+                        diameter_to_particle_diameter.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::CarmanKozeny(porosity,
+                length_to_diameter,
+                diameter_to_particle_diameter) => {
+                    packed_bed_friction_factor::carman_kozeny_fldk(
+                        reynolds.get::<ratio>(),
+                        porosity.get::<ratio>(),
+                        length_to_diameter.get::<ratio>(),
+                        diameter_to_particle_diameter.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::BellDelawareShellSide(
+                shell_side) => {
+                    shell_side.try_get_bell_delaware_fldk(reynolds)?
+                        .get::<ratio>()
+            },
+            DimensionlessDarcyLossCorrelations::KernShellSide(
+                shell_side) => {
+                    shell_side.try_get_kern_fldk(reynolds)?
+                        .get::<ratio>()
             },
         };
 
@@ -198,6 +467,27 @@ impl DimensionlessDarcyLossCorrelations {
 
                     darcy_friction_factor
             },
+            DimensionlessDarcyLossCorrelations::PipeWithFittings(roughness_ratio,
+                _length_to_diameter,
+                _fittings_form_loss) => {
+                    churchill_friction_factor::darcy(
+                        reynolds.get::<ratio>(),
+                        roughness_ratio.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::NonCircularPipe(roughness_ratio,
+                _length_to_diameter,
+                _form_loss,
+                duct_shape) => {
+
+                    let laminar_friction_reynolds_product = 
+                        duct_shape.laminar_darcy_friction_reynolds_product()?;
+
+                    churchill_friction_factor::
+                        darcy_with_laminar_friction_reynolds_product(
+                            reynolds.get::<ratio>(),
+                            roughness_ratio.get::<ratio>(),
+                            laminar_friction_reynolds_product.get::<ratio>())?
+            },
             // this is not implemented for other correlations
             _ => todo!()
         };
@@ -254,6 +544,46 @@ impl DimensionlessDarcyLossCorrelations {
     pub fn get_reynolds_number_from_bejan(&self,
         bejan_input: Ratio) -> Result<Ratio,TuasLibError>{
 
+        // packed beds have their own inversions, Ergun and 
+        // Carman-Kozeny are quadratic and linear in Re respectively 
+        // so they can be done analytically
+        match self {
+            DimensionlessDarcyLossCorrelations::Ergun(porosity,
+                length_to_diameter,
+                diameter_to_particle_diameter) => {
+                    let reynolds_number = packed_bed_friction_factor::
+                        ergun_reynolds_from_bejan(
+                            bejan_input.get::<ratio>(),
+                            porosity.get::<ratio>(),
+                            length_to_diameter.get::<ratio>(),
+                            diameter_to_particle_diameter.get::<ratio>())?;
+                    return Ok(Ratio::new::<ratio>(reynolds_number));
+            },
+            DimensionlessDarcyLossCorrelations::KTAPebbleBed(porosity,
+                length_to_diameter,
+                diameter_to_particle_diameter) => {
+                    let reynolds_number = packed_bed_friction_factor::
+                        kta_pebble_bed_reynolds_from_bejan(
+                            bejan_input.get::<ratio>(),
+                            porosity.get::<ratio>(),
+                            length_to_diameter.get::<ratio>(),
+                            diameter_to_particle_diameter.get::<ratio>())?;
+                    return Ok(Ratio::new::<ratio>(reynolds_number));
+            },
+            DimensionlessDarcyLossCorrelations::CarmanKozeny(porosity,
+                length_to_diameter,
+                diameter_to_particle_diameter) => {
+                    let reynolds_number = packed_bed_friction_factor::
+                        carman_kozeny_reynolds_from_bejan(
+                            bejan_input.get::<ratio>(),
+                            porosity.get::<ratio>(),
+                            length_to_diameter.get::<ratio>(),
+                            diameter_to_particle_diameter.get::<ratio>())?;
+                    return Ok(Ratio::new::<ratio>(reynolds_number));
+            },
+            _ => (),
+        }
+
         // we have to make a pressure drop root 
 
         // first we need limits for maximum and minimum reynolds 
@@ -460,6 +790,104 @@ impl FluidArray {
         pressure_loss
     }
 
+    /// gets the darcy loss correlation corrected for mixed 
+    /// convection, using the current bulk temperature, incline angle 
+    /// and the given wall temperature
+    ///
+    /// the wall temperature is kept within the temperature 
+    /// bounds of the fluid properties
+    pub fn get_mixed_convection_darcy_loss(&self,
+        wall_temperature: ThermodynamicTemperature) 
+        -> Result<MixedConvectionDarcyLoss, TuasLibError> {
+
+        let mut fluid_array = self.clone();
+        let material = fluid_array.material_control_volume;
+
+        let wall_temperature = wall_temperature
+            .min(material.max_temperature())
+            .max(material.min_temperature());
+
+        let prandtl_bulk = try_get_prandtl(material,
+            fluid_array.try_get_bulk_temperature()?,
+            fluid_array.pressure_control_volume)?;
+
+        // the reynolds number is supplied when the loss is calculated
+        let mixed_convection_data = fluid_array.get_mixed_convection_data(
+            Ratio::ZERO,
+            prandtl_bulk,
+            wall_temperature)?;
+
+        Ok(MixedConvectionDarcyLoss {
+            forced_convection_loss: self.fluid_component_loss_properties,
+            mixed_convection_data,
+        })
+    }
+
+    /// gets the pressure loss for prebuilt components, which know 
+    /// the wall temperature 
+    ///
+    /// if mixed_convection_correction is set, the (f L/D + K) term 
+    /// is corrected for mixed convection, otherwise this is 
+    /// get_pressure_loss_immutable and the wall temperature 
+    /// is not used
+    pub fn get_pressure_loss_with_wall_temperature_immutable(
+        &self, mass_flowrate: MassRate,
+        wall_temperature: ThermodynamicTemperature) -> Pressure {
+
+        if !self.mixed_convection_correction {
+            return self.get_pressure_loss_immutable(mass_flowrate);
+        }
+
+        let hydraulic_diameter = self.get_hydraulic_diameter_immutable();
+        let fluid_viscosity = self.get_fluid_viscosity_immutable();
+        let fluid_density = self.get_fluid_density_immutable();
+
+        let reynolds_number: Ratio = mass_flowrate 
+        / self.get_cross_sectional_area_immutable()
+        * hydraulic_diameter
+        / fluid_viscosity;
+
+        self.get_mixed_convection_darcy_loss(wall_temperature).unwrap()
+            .get_pressure_loss_from_reynolds(
+                reynolds_number,
+                hydraulic_diameter,
+                fluid_density,
+                fluid_viscosity
+            ).unwrap()
+    }
+
+    /// gets the mass flowrate for prebuilt components, which know 
+    /// the wall temperature 
+    ///
+    /// if mixed_convection_correction is set, the (f L/D + K) term 
+    /// is corrected for mixed convection, otherwise this is 
+    /// get_mass_flowrate_from_pressure_loss_immutable and the 
+    /// wall temperature is not used
+    pub fn get_mass_flowrate_from_pressure_loss_with_wall_temperature_immutable(
+        &self, pressure_loss: Pressure,
+        wall_temperature: ThermodynamicTemperature) -> MassRate {
+
+        if !self.mixed_convection_correction {
+            return self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+        }
+
+        let hydraulic_diameter = self.get_hydraulic_diameter_immutable();
+        let fluid_viscosity = self.get_fluid_viscosity_immutable();
+        let fluid_density = self.get_fluid_density_immutable();
+
+        let reynolds_number: Ratio = 
+            self.get_mixed_convection_darcy_loss(wall_temperature).unwrap()
+            .get_reynolds_from_pressure_loss(
+                pressure_loss,
+                hydraulic_diameter,
+                fluid_density,
+                fluid_viscosity
+            ).unwrap();
+
+        self.xs_area * fluid_viscosity * reynolds_number / hydraulic_diameter
+    }
+
     /// gets cross sectional area using a mutable borrow
     pub fn get_cross_sectional_area(&mut self) -> Area {
         self.xs_area
@@ -576,6 +1004,50 @@ impl FluidArray {
         self.internal_pressure_source
     }
 
+    /// sets the duct shape of the fluid array, 
+    /// this corrects the laminar Nusselt number and, if the 
+    /// loss correlation is a pipe, the laminar friction factor 
+    /// for annuli, rectangular ducts and parallel plates
+    ///
+    /// other loss correlations (eg. Reynolds power fits) are 
+    /// left alone as they are assumed to be fitted to data already
+    pub fn set_duct_shape(&mut self, duct_shape: DuctShape) {
+        self.duct_shape = duct_shape;
+
+        let pipe_parameters = match self.fluid_component_loss_properties {
+            DimensionlessDarcyLossCorrelations::Pipe(
+                roughness_ratio, length_to_diameter, form_loss) => {
+                Some((roughness_ratio, length_to_diameter, form_loss))
+            },
+            DimensionlessDarcyLossCorrelations::NonCircularPipe(
+                roughness_ratio, length_to_diameter, form_loss, _) => {
+                Some((roughness_ratio, length_to_diameter, form_loss))
+            },
+            _ => None,
+        };
+
+        if let Some((roughness_ratio, length_to_diameter, form_loss)) 
+            = pipe_parameters {
+
+            self.fluid_component_loss_properties = match duct_shape {
+                DuctShape::CircularPipe => {
+                    DimensionlessDarcyLossCorrelations::Pipe(
+                        roughness_ratio, length_to_diameter, form_loss)
+                },
+                _ => {
+                    DimensionlessDarcyLossCorrelations::NonCircularPipe(
+                        roughness_ratio, length_to_diameter, form_loss, 
+                        duct_shape)
+                },
+            };
+        }
+    }
+
+    /// gets the duct shape of the fluid array
+    pub fn get_duct_shape(&self) -> DuctShape {
+        self.duct_shape
+    }
+
     /// sets the internal pressure source
     /// this is meant to simulate if the fluid array happens to have 
     /// a simulated pump or pressure source 
@@ -592,3 +1064,15 @@ pub mod unit_test_dimensionless_darcy_loss_correlations;
 /// unit tests for DimensionlessDarcyLossCorrelations get and set 
 /// mass flowrate and pressure change
 pub mod unit_test_mass_flowrate_and_pressure_change_dimensionless_darcy_loss;
+
+/// unit tests for packed bed (Ergun, KTA, Carman-Kozeny) 
+/// DimensionlessDarcyLossCorrelations
+pub mod unit_test_packed_bed_darcy_loss_correlations;
+
+/// unit tests for pipes with fittings (2-K and 3-K methods) in
+/// DimensionlessDarcyLossCorrelations
+pub mod unit_test_pipe_with_fittings_darcy_loss_correlations;
+
+/// unit tests for non circular duct friction and Nusselt number 
+/// corrections
+pub mod unit_test_non_circular_duct_corrections;
@@ -0,0 +1,322 @@
+/// checks laminar fRe and Nusselt numbers for non circular ducts
+/// against Shah and London values
+#[test]
+pub fn non_circular_duct_laminar_values() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use crate::fluid_mechanics_correlations::non_circular_ducts::*;
+
+    // annulus with r* = 0.5, fanning fRe = 23.81
+    let annulus = DuctShape::Annulus {
+        radius_ratio: Ratio::new::<ratio>(0.5),
+        heated_wall: AnnulusHeatedWall::InnerWall
+    };
+    approx::assert_relative_eq!(
+        annulus.laminar_darcy_friction_reynolds_product()?.get::<ratio>(),
+        4.0 * 23.81,
+        max_relative = 1e-3);
+
+    // square duct, fanning fRe = 14.227, Nu_H1 = 3.608
+    let square = DuctShape::Rectangular {
+        aspect_ratio: Ratio::new::<ratio>(1.0) };
+    approx::assert_relative_eq!(
+        square.laminar_darcy_friction_reynolds_product()?.get::<ratio>(),
+        4.0 * 14.227,
+        max_relative = 2e-3);
+    approx::assert_relative_eq!(
+        square.laminar_nusselt_uniform_heat_flux()?.get::<ratio>(),
+        3.608,
+        max_relative = 2e-3);
+
+    // parallel plates, darcy fRe = 96, Nu = 8.235
+    approx::assert_relative_eq!(
+        DuctShape::ParallelPlates
+        .laminar_darcy_friction_reynolds_product()?.get::<ratio>(),
+        96.0);
+    approx::assert_relative_eq!(
+        DuctShape::ParallelPlates
+        .laminar_nusselt_uniform_heat_flux()?.get::<ratio>(),
+        8.235);
+
+    // annulus inner wall heated, r* = 0.5 is halfway between
+    // 6.583 (r* = 0.4) and 5.912 (r* = 0.6)
+    approx::assert_relative_eq!(
+        annulus.laminar_nusselt_uniform_heat_flux()?.get::<ratio>(),
+        0.5 * (6.583 + 5.912),
+        max_relative = 1e-9);
+
+    // circular pipe gives no correction at any Re
+    for reynolds in [100.0, 3000.0, 1e5] {
+        approx::assert_relative_eq!(
+            DuctShape::CircularPipe.nusselt_shape_factor(
+                Ratio::new::<ratio>(reynolds))?.get::<ratio>(),
+            1.0);
+    }
+
+    // non circular ducts get no correction in turbulent flow
+    approx::assert_relative_eq!(
+        square.nusselt_shape_factor(
+            Ratio::new::<ratio>(1e5))?.get::<ratio>(),
+        1.0);
+
+    Ok(())
+}
+
+/// the modified churchill correlation should reduce to the
+/// original one for circular pipes, give fRe/Re in laminar flow
+/// and match the circular pipe in fully turbulent flow
+#[test]
+pub fn churchill_with_laminar_shape_correction() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use crate::fluid_mechanics_correlations::churchill_friction_factor::*;
+
+    for reynolds in [100.0, 2000.0, 5000.0, 1e5] {
+        approx::assert_relative_eq!(
+            darcy_with_laminar_friction_reynolds_product(
+                reynolds, 1e-4, 64.0)?,
+            darcy(reynolds, 1e-4)?,
+            max_relative = 1e-12);
+    }
+
+    // laminar, parallel plates
+    approx::assert_relative_eq!(
+        darcy_with_laminar_friction_reynolds_product(100.0, 1e-4, 96.0)?,
+        96.0/100.0,
+        max_relative = 1e-3);
+
+    // turbulent, parallel plates
+    approx::assert_relative_eq!(
+        darcy_with_laminar_friction_reynolds_product(1e5, 1e-4, 96.0)?,
+        darcy(1e5, 1e-4)?,
+        max_relative = 1e-6);
+
+    Ok(())
+}
+
+/// setting the duct shape on an annular fluid array should change
+/// the laminar pressure loss and Nusselt number but leave the
+/// turbulent Nusselt number alone
+#[test]
+pub fn annular_fluid_array_duct_shape() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::fluid_mechanics_correlations::non_circular_ducts::*;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+
+    let inner_diameter = Length::new::<meter>(0.02);
+    let outer_diameter = Length::new::<meter>(0.04);
+    let length = Length::new::<meter>(1.0);
+
+    let mut annular_array = FluidArray::new_annular_cylinder(
+        length,
+        inner_diameter,
+        outer_diameter,
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(0.0),
+        2,
+        Angle::new::<degree>(0.0));
+
+    let mut circular_reference = annular_array.clone();
+
+    let annulus = DuctShape::Annulus {
+        radius_ratio: Ratio::new::<ratio>(0.5),
+        heated_wall: AnnulusHeatedWall::InnerWall
+    };
+    annular_array.set_duct_shape(annulus);
+
+    assert_eq!(annular_array.get_duct_shape(), annulus);
+
+    let laminar_reynolds = Ratio::new::<ratio>(100.0);
+
+    // laminar friction, fLDK = fRe/Re * L/D
+    let fldk = annular_array.fluid_component_loss_properties
+        .fldk_based_on_darcy_friction_factor(laminar_reynolds)?;
+    let length_to_diameter: Ratio =
+        length/annular_array.get_hydraulic_diameter();
+
+    assert!(matches!(annular_array.fluid_component_loss_properties,
+        DimensionlessDarcyLossCorrelations::NonCircularPipe(..)));
+
+    approx::assert_relative_eq!(
+        fldk.get::<ratio>(),
+        (annulus.laminar_darcy_friction_reynolds_product()?
+         / laminar_reynolds * length_to_diameter).get::<ratio>(),
+        max_relative = 1e-3);
+
+    // laminar nusselt is scaled by Nu_annulus/Nu_pipe
+    let prandtl = Ratio::new::<ratio>(10.0);
+    let nusselt_annulus = annular_array.get_nusselt(
+        laminar_reynolds, prandtl, prandtl)?;
+    let nusselt_pipe = circular_reference.get_nusselt(
+        laminar_reynolds, prandtl, prandtl)?;
+
+    approx::assert_relative_eq!(
+        (nusselt_annulus/nusselt_pipe).get::<ratio>(),
+        annulus.laminar_nusselt_uniform_heat_flux()?.get::<ratio>()/4.354,
+        max_relative = 1e-3);
+
+    // in turbulent flow, the nusselt number is unchanged
+    let turbulent_reynolds = Ratio::new::<ratio>(2e4);
+    let nusselt_annulus = annular_array.get_nusselt(
+        turbulent_reynolds, prandtl, prandtl)?;
+    let nusselt_pipe = circular_reference.get_nusselt(
+        turbulent_reynolds, prandtl, prandtl)?;
+
+    approx::assert_relative_eq!(
+        nusselt_annulus.get::<ratio>(),
+        nusselt_pipe.get::<ratio>(),
+        max_relative = 1e-6);
+
+    // setting it back to a circular pipe restores the pipe correlation
+    annular_array.set_duct_shape(DuctShape::CircularPipe);
+    assert_eq!(annular_array.fluid_component_loss_properties,
+        circular_reference.fluid_component_loss_properties);
+
+    Ok(())
+}
+
+/// annulus nusselt correlations already include the duct 
+/// shape, so the fluid array should not correct them again
+#[test]
+pub fn annular_fluid_array_with_annulus_nusselt_correlation() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::fluid_mechanics_correlations::non_circular_ducts::*;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+
+    let mut annular_array = FluidArray::new_annular_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        Length::new::<meter>(0.04),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(0.0),
+        2,
+        Angle::new::<degree>(0.0));
+
+    annular_array.set_duct_shape(DuctShape::Annulus {
+        radius_ratio: Ratio::new::<ratio>(0.5),
+        heated_wall: AnnulusHeatedWall::OuterWall
+    });
+
+    annular_array.nusselt_correlation = NusseltCorrelation::
+        AnnulusLaminarUniformHeatFlux(
+            Ratio::new::<ratio>(0.5), AnnulusHeatedWall::OuterWall);
+
+    let prandtl = Ratio::new::<ratio>(20.0);
+    let laminar_nusselt = annular_array.get_nusselt(
+        Ratio::new::<ratio>(100.0), prandtl, prandtl)?;
+
+    approx::assert_relative_eq!(
+        laminar_nusselt.get::<ratio>(),
+        annulus_laminar_nusselt_uniform_heat_flux(
+            0.5, AnnulusHeatedWall::OuterWall)?,
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// only the generic circular pipe correlations are corrected for 
+/// duct shape, a ReynoldsPrandtl correlation is fitted to its own 
+/// geometry and should be unchanged by the duct shape
+#[test]
+pub fn annular_fluid_array_with_reynolds_prandtl_correlation() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::fluid_mechanics_correlations::non_circular_ducts::*;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::NusseltPrandtlReynoldsData;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::FluidArray;
+
+    let mut annular_array = FluidArray::new_annular_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        Length::new::<meter>(0.04),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        Ratio::new::<ratio>(0.0),
+        2,
+        Angle::new::<degree>(0.0));
+
+    // Nu = 4.0 + 0.02 Re^0.8 Pr^0.4
+    let reynolds_prandtl_correlation = NusseltCorrelation::ReynoldsPrandtl(
+        NusseltPrandtlReynoldsData {
+            reynolds: Ratio::new::<ratio>(1.0),
+            prandtl_bulk: Ratio::new::<ratio>(1.0),
+            prandtl_wall: Ratio::new::<ratio>(1.0),
+            constant: Ratio::new::<ratio>(4.0),
+            reynolds_prandtl_coefficient: Ratio::new::<ratio>(0.02),
+            reynolds_power: 0.8,
+            prandtl_power: 0.4,
+            prandtl_correction_factor_power: 0.0,
+        });
+
+    annular_array.nusselt_correlation = reynolds_prandtl_correlation;
+    assert!(!reynolds_prandtl_correlation.is_circular_pipe_generic());
+
+    let mut circular_reference = annular_array.clone();
+
+    annular_array.set_duct_shape(DuctShape::Annulus {
+        radius_ratio: Ratio::new::<ratio>(0.5),
+        heated_wall: AnnulusHeatedWall::InnerWall
+    });
+
+    // in laminar flow, a pipe correlation would be scaled by 
+    // Nu_annulus/Nu_pipe, but this one is left alone
+    let laminar_reynolds = Ratio::new::<ratio>(100.0);
+    let prandtl = Ratio::new::<ratio>(10.0);
+
+    let nusselt_annulus = annular_array.get_nusselt(
+        laminar_reynolds, prandtl, prandtl)?;
+    let nusselt_reference = circular_reference.get_nusselt(
+        laminar_reynolds, prandtl, prandtl)?;
+
+    approx::assert_relative_eq!(
+        nusselt_annulus.get::<ratio>(),
+        nusselt_reference.get::<ratio>(),
+        max_relative = 1e-12);
+
+    approx::assert_relative_eq!(
+        nusselt_annulus.get::<ratio>(),
+        4.0 + 0.02 * 100.0_f64.powf(0.8) * 10.0_f64.powf(0.4),
+        max_relative = 1e-9);
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+/// checks the 3-K method for a 90 degree elbow in a 1 inch pipe 
+/// against a hand calculation 
+///
+/// K = 800/Re + 0.091 (1 + 4.0/1^0.3)
+#[test]
+pub fn ninety_degree_elbow_three_k_hand_calc() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::angle::degree;
+    use uom::si::length::inch;
+    use crate::fluid_mechanics_correlations::form_loss_fittings::PipeFitting;
+
+    let elbow = PipeFitting::Elbow { 
+        bend_radius_to_diameter: Ratio::new::<ratio>(1.0), 
+        angle: Angle::new::<degree>(90.0) 
+    };
+
+    // turbulent 
+    let k_turbulent = elbow.get_form_loss_k(
+        Ratio::new::<ratio>(1.0e4), 
+        Ratio::new::<ratio>(0.03), 
+        Length::new::<inch>(1.0))?;
+
+    approx::assert_relative_eq!(
+        k_turbulent.get::<ratio>(),
+        800.0/1.0e4 + 0.091 * 5.0,
+        max_relative = 1e-9);
+
+    // laminar, the K_1/Re term dominates
+    let k_laminar = elbow.get_form_loss_k(
+        Ratio::new::<ratio>(100.0), 
+        Ratio::new::<ratio>(0.64), 
+        Length::new::<inch>(1.0))?;
+
+    approx::assert_relative_eq!(
+        k_laminar.get::<ratio>(),
+        800.0/100.0 + 0.091 * 5.0,
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// checks the Hooper contraction and expansion correlations 
+/// in laminar and turbulent flow
+#[test]
+pub fn sudden_contraction_and_expansion_hand_calc() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use crate::fluid_mechanics_correlations::form_loss_fittings::PipeFitting;
+
+    let diameter = Length::new::<meter>(0.05);
+    let beta: f64 = 0.5;
+    let friction_factor: f64 = 0.02;
+
+    let contraction = PipeFitting::SuddenContraction { 
+        diameter_ratio: Ratio::new::<ratio>(beta) };
+    let expansion = PipeFitting::SuddenExpansion { 
+        diameter_ratio: Ratio::new::<ratio>(beta) };
+
+    // laminar contraction, K = (1.2 + 160/Re)(1/beta^4 - 1)
+    let k = contraction.get_form_loss_k(
+        Ratio::new::<ratio>(1000.0), 
+        Ratio::new::<ratio>(friction_factor), 
+        diameter)?;
+    approx::assert_relative_eq!(
+        k.get::<ratio>(),
+        (1.2 + 0.16) * 15.0,
+        max_relative = 1e-9);
+
+    // turbulent contraction, K = (0.6 + 0.48 f)(1/beta^2)(1/beta^2 - 1)
+    let k = contraction.get_form_loss_k(
+        Ratio::new::<ratio>(1.0e5), 
+        Ratio::new::<ratio>(friction_factor), 
+        diameter)?;
+    approx::assert_relative_eq!(
+        k.get::<ratio>(),
+        (0.6 + 0.48 * friction_factor) * 4.0 * 3.0,
+        max_relative = 1e-9);
+
+    // laminar expansion, K = 2 (1 - beta^4)
+    let k = expansion.get_form_loss_k(
+        Ratio::new::<ratio>(1000.0), 
+        Ratio::new::<ratio>(friction_factor), 
+        diameter)?;
+    approx::assert_relative_eq!(
+        k.get::<ratio>(),
+        2.0 * (1.0 - 0.0625),
+        max_relative = 1e-9);
+
+    // turbulent expansion, K = (1 + 0.8 f)(1 - beta^2)^2
+    let k = expansion.get_form_loss_k(
+        Ratio::new::<ratio>(1.0e5), 
+        Ratio::new::<ratio>(friction_factor), 
+        diameter)?;
+    approx::assert_relative_eq!(
+        k.get::<ratio>(),
+        (1.0 + 0.8 * friction_factor) * 0.75 * 0.75,
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// a pipe with fittings should have the pipe friction plus the 
+/// sum of the fitting K values, and the Re from Be inversion 
+/// should recover the original Re in both directions
+#[test]
+pub fn pipe_with_fittings_get_re_from_be_round_trip() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::angle::degree;
+    use uom::si::length::meter;
+    use crate::fluid_mechanics_correlations::churchill_friction_factor;
+    use crate::fluid_mechanics_correlations::form_loss_fittings::PipeFitting;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+
+    let pipe_length = Length::new::<meter>(2.0);
+    let roughness = Length::new::<meter>(1.5e-5);
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+
+    let fittings = vec![
+        PipeFitting::SharpEntrance,
+        PipeFitting::Elbow { 
+            bend_radius_to_diameter: Ratio::new::<ratio>(2.0), 
+            angle: Angle::new::<degree>(90.0) },
+        PipeFitting::Elbow { 
+            bend_radius_to_diameter: Ratio::new::<ratio>(2.0), 
+            angle: Angle::new::<degree>(45.0) },
+        PipeFitting::TeeThroughBranch,
+        PipeFitting::Exit,
+    ];
+
+    let pipe_with_fittings = DimensionlessDarcyLossCorrelations::
+        new_pipe_with_fittings(
+            pipe_length, 
+            roughness, 
+            hydraulic_diameter, 
+            &fittings)?;
+
+    let reynolds_number = Ratio::new::<ratio>(8000.0);
+    let roughness_ratio: f64 = (roughness/hydraulic_diameter).get::<ratio>();
+    let length_to_diameter: f64 = 
+        (pipe_length/hydraulic_diameter).get::<ratio>();
+
+    let darcy = churchill_friction_factor::darcy(8000.0, roughness_ratio)?;
+
+    let mut fittings_k: f64 = 0.0;
+    for fitting in fittings.iter() {
+        fittings_k += fitting.get_form_loss_k(
+            reynolds_number, 
+            Ratio::new::<ratio>(darcy), 
+            hydraulic_diameter)?.get::<ratio>();
+    }
+
+    let fldk = pipe_with_fittings.fldk_based_on_darcy_friction_factor(
+        reynolds_number)?;
+
+    approx::assert_relative_eq!(
+        fldk.get::<ratio>(),
+        darcy * length_to_diameter + fittings_k,
+        max_relative = 1e-9);
+
+    // round trip
+    let bejan_number = pipe_with_fittings.get_bejan_number_from_reynolds(
+        reynolds_number)?;
+
+    let reynolds_test = pipe_with_fittings.get_reynolds_number_from_bejan(
+        bejan_number)?;
+    approx::assert_relative_eq!(
+        reynolds_test.get::<ratio>(),
+        8000.0,
+        max_relative = 1e-6);
+
+    let reynolds_test_reverse = pipe_with_fittings.
+        get_reynolds_number_from_bejan(-bejan_number)?;
+    approx::assert_relative_eq!(
+        reynolds_test_reverse.get::<ratio>(),
+        -8000.0,
+        max_relative = 1e-6);
+
+    Ok(())
+}
+
+/// the Hooper contraction switches regime at Re = 2500 and the 
+/// expansion at Re = 4000, K should be continuous at both points 
+/// and Be should keep increasing with Re across the transition, 
+/// otherwise the Re from Be inversion can fail
+///
+/// a 45 degree elbow has about 0.78 of the 90 degree K_i (Darby, 2001)
+#[test]
+pub fn contraction_and_expansion_continuous_across_regime_switch() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::angle::degree;
+    use uom::si::length::{inch, meter};
+    use crate::fluid_mechanics_correlations::form_loss_fittings::PipeFitting;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+
+    let diameter = Length::new::<meter>(0.05);
+    let friction_factor = Ratio::new::<ratio>(0.04);
+
+    let contraction = PipeFitting::SuddenContraction { 
+        diameter_ratio: Ratio::new::<ratio>(0.5) };
+    let expansion = PipeFitting::SuddenExpansion { 
+        diameter_ratio: Ratio::new::<ratio>(0.5) };
+
+    for (fitting, switch_reynolds) in [(contraction, 2500.0), (expansion, 4000.0)] {
+        let k_below = fitting.get_form_loss_k(
+            Ratio::new::<ratio>(switch_reynolds * (1.0 - 1e-9)), 
+            friction_factor, 
+            diameter)?;
+        let k_above = fitting.get_form_loss_k(
+            Ratio::new::<ratio>(switch_reynolds * (1.0 + 1e-9)), 
+            friction_factor, 
+            diameter)?;
+
+        approx::assert_relative_eq!(
+            k_below.get::<ratio>(),
+            k_above.get::<ratio>(),
+            max_relative = 1e-6);
+    }
+
+    // a short pipe, so the fittings dominate the loss
+    let pipe_with_fittings = DimensionlessDarcyLossCorrelations::
+        new_pipe_with_fittings(
+            Length::new::<meter>(0.5), 
+            Length::new::<meter>(1.5e-5), 
+            diameter, 
+            &[contraction, expansion])?;
+
+    let mut previous_bejan_number = pipe_with_fittings
+        .get_bejan_number_from_reynolds(Ratio::new::<ratio>(1000.0))?;
+
+    for step in 1..=180 {
+        let reynolds_number = Ratio::new::<ratio>(1000.0 + 50.0 * step as f64);
+        let bejan_number = pipe_with_fittings
+            .get_bejan_number_from_reynolds(reynolds_number)?;
+
+        assert!(bejan_number > previous_bejan_number);
+        previous_bejan_number = bejan_number;
+    }
+
+    let bejan_number = pipe_with_fittings.get_bejan_number_from_reynolds(
+        Ratio::new::<ratio>(3000.0))?;
+    approx::assert_relative_eq!(
+        pipe_with_fittings.get_reynolds_number_from_bejan(bejan_number)?
+            .get::<ratio>(),
+        3000.0,
+        max_relative = 1e-6);
+
+    // 45 degree elbow, r/D = 1 in a 1 inch pipe at high Re
+    let elbow = PipeFitting::Elbow { 
+        bend_radius_to_diameter: Ratio::new::<ratio>(1.0), 
+        angle: Angle::new::<degree>(45.0) 
+    };
+    let k_turbulent = elbow.get_form_loss_k(
+        Ratio::new::<ratio>(1.0e4), 
+        Ratio::new::<ratio>(0.03), 
+        Length::new::<inch>(1.0))?;
+
+    approx::assert_relative_eq!(
+        k_turbulent.get::<ratio>(),
+        500.0/1.0e4 + 0.78 * 0.091 * 5.0,
+        max_relative = 1e-9);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::fluid_mechanics_correlations::non_circular_ducts::DuctShape;
 use crate::single_control_vol::SingleCVNode;
 use crate::boussinesq_thermophysical_properties::Material;
 use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
@@ -9,6 +10,7 @@ use crate::tuas_lib_error::TuasLibError;
 use ndarray_linalg::error::LinalgError;
 
 use self::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+use self::axial_conduction::FluidAxialConductionModel;
 
 
 /// this is essentially a 1D pipe array containing two CVs 
@@ -99,6 +101,29 @@ pub struct FluidArray {
     /// nusselt correlation 
     pub nusselt_correlation: NusseltCorrelation,
 
+    /// duct shape, used to correct laminar Nusselt numbers 
+    /// and friction factors for non circular ducts
+    ///
+    /// use set_duct_shape so that the loss correlation is 
+    /// updated as well
+    pub(crate) duct_shape: DuctShape,
+
+    /// axial conduction model for heat conducted (and turbulently 
+    /// diffused) along the fluid array between adjacent nodes
+    ///
+    /// by default, molecular conduction is only included 
+    /// for low peclet number flows (Pe < 100)
+    pub axial_conduction_model: FluidAxialConductionModel,
+
+    /// if true, prebuilt components correct the nusselt number 
+    /// and the (f L/D + K) term for mixed convection (buoyancy) 
+    /// using the wall temperature, 
+    /// see get_nusselt_with_wall_temperature and 
+    /// get_pressure_loss_with_wall_temperature_immutable
+    ///
+    /// this is off by default
+    pub mixed_convection_correction: bool,
+
     /// now fluid arrays can be connected to solid arrays 
     /// or other fluid arrays adjacent to it radially
     ///
@@ -293,6 +318,10 @@ pub mod fluid_component_calculation;
 /// type conversion 
 pub mod type_conversion;
 
+/// axial conduction and turbulent diffusion models 
+/// for heat transfer between adjacent fluid nodes
+pub mod axial_conduction;
+
 
 /// unit tests, especially for connection with single control volumes 
 /// among other verification tests
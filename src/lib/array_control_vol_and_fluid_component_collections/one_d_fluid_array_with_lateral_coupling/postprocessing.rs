@@ -3,6 +3,7 @@ use crate::tuas_lib_error::TuasLibError;
 
 use super::FluidArray;
 use uom::si::f64::*;
+use uom::si::acceleration::meter_per_second_squared;
 
 impl FluidArray {
 
@@ -30,4 +31,44 @@ impl FluidArray {
         Ok(reversed_vec)
     }
 
+
+    /// obtains the absolute pressure at each node of the fluid array 
+    /// given the absolute pressure at the inlet (back) of the array 
+    /// and the mass flowrate through the array
+    ///
+    /// the pressure change across the array (pressure loss, 
+    /// hydrostatic pressure and internal pressure source) is 
+    /// distributed uniformly along the array, and the pressure 
+    /// of each node is taken at the node centre
+    ///
+    /// nodes go from back to front, as with the temperature vector
+    pub fn get_absolute_pressure_vector(&self, 
+        inlet_pressure: Pressure,
+        mass_flowrate: MassRate) -> Result<Vec<Pressure>,TuasLibError>{
+
+        let g: Acceleration = 
+            Acceleration::new::<meter_per_second_squared>(-9.81);
+
+        let hydrostatic_pressure_increase: Pressure = 
+            self.get_fluid_density_immutable() * g 
+            * self.get_component_length_immutable() 
+            * self.get_incline_angle_immutable().sin();
+
+        let pressure_change: Pressure = 
+            -self.get_pressure_loss_immutable(mass_flowrate)
+            + hydrostatic_pressure_increase 
+            + self.get_internal_pressure_source_immutable();
+
+        let number_of_nodes = self.len();
+
+        let pressure_vec: Vec<Pressure> = (0..number_of_nodes).map(
+            |node_index| {
+                let fraction_of_length = (node_index as f64 + 0.5)
+                    / number_of_nodes as f64;
+                inlet_pressure + pressure_change * fraction_of_length
+            }).collect();
+
+        Ok(pressure_vec)
+    }
+
 }
@@ -3,7 +3,13 @@ use uom::si::f64::*;
 use uom::si::thermodynamic_temperature::kelvin;
 
 
+use uom::si::acceleration::meter_per_second_squared;
 use crate::boussinesq_thermophysical_properties::prandtl::try_get_prandtl;
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+use crate::boussinesq_thermophysical_properties::dynamic_viscosity::try_get_mu_viscosity;
+use crate::heat_transfer_correlations::mixed_convection::MixedConvectionData;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::absolute_pressure::GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED;
+use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityMonitor;
 use crate::tuas_lib_error::TuasLibError;
 use crate::boussinesq_thermophysical_properties::Material;
 use crate::boussinesq_thermophysical_properties::thermal_diffusivity::try_get_alpha_thermal_diffusivity;
@@ -301,11 +307,198 @@ impl FluidArray {
         // for reverse flow, if Re < 0, take absolute 
         let reynolds_abs = reynolds.abs();
 
-        self.nusselt_correlation.
+        let nusselt_hydraulic_diameter = self.nusselt_correlation.
             estimate_based_on_prandtl_darcy_and_reynolds_wall_correction(
                 prandtl_bulk,
                 prandtl_wall,
                 darcy_plus_normalised_form_loss,
-                reynolds_abs)
+                reynolds_abs)?;
+
+        // only the generic circular pipe correlations are corrected 
+        // for duct shape, annulus correlations already account 
+        // for it and other correlations are fitted to their own 
+        // geometry
+        if !self.nusselt_correlation.is_circular_pipe_generic() {
+            return Ok(nusselt_hydraulic_diameter);
+        }
+
+        // for non circular ducts, the laminar nusselt number 
+        // is corrected for duct shape
+        let duct_shape_factor = self.duct_shape
+            .nusselt_shape_factor(reynolds_abs)?;
+
+        Ok(nusselt_hydraulic_diameter * duct_shape_factor)
+    }
+
+    /// gets the local nusselt number of each node divided by the
+    /// nusselt number of the whole array (from get_nusselt)
+    ///
+    /// the local length to diameter ratio is measured from the
+    /// inlet, which is the back for positive flow and the front
+    /// for reverse flow. So entrance effects are concentrated
+    /// at whichever end the fluid enters
+    ///
+    /// the ratios average to one, and are all one for correlations
+    /// without developing flow effects
+    #[inline]
+    pub fn get_local_nusselt_ratio_vector(&mut self,
+        reynolds: Ratio,
+        prandtl_bulk: Ratio,
+        prandtl_wall: Ratio) -> Result<Vec<Ratio>, TuasLibError>{
+
+        // same darcy friction factor (with normalised form losses)
+        // as get_nusselt
+        let fldk: Ratio = self
+            .fluid_component_loss_properties
+            .fldk_based_on_darcy_friction_factor(reynolds)?
+            ;
+
+        let length_to_diameter: Ratio =
+            self.get_component_length()/
+            self.get_hydraulic_diameter();
+
+        let darcy_plus_normalised_form_loss: Ratio =
+            fldk/length_to_diameter;
+
+        // the duct shape factor cancels out in the ratio,
+        // so it is not applied here
+        let mut local_nusselt_ratio_vector = self.nusselt_correlation
+            .try_get_local_nusselt_ratio_vector(
+                prandtl_bulk,
+                prandtl_wall,
+                darcy_plus_normalised_form_loss,
+                reynolds.abs(),
+                length_to_diameter,
+                self.len())?;
+
+        // for reverse flow, the inlet is at the front
+        if self.get_mass_flowrate().is_sign_negative() {
+            local_nusselt_ratio_vector.reverse();
+        }
+
+        Ok(local_nusselt_ratio_vector)
+    }
+
+    /// checks the nusselt and friction factor correlations of this
+    /// array against their validity envelopes, using the 
+    /// same reynolds number and bulk prandtl number as get_nusselt
+    ///
+    /// the results are recorded in the monitor, which usually 
+    /// belongs to the component as fluid arrays are cloned 
+    /// during preprocessing
+    #[inline]
+    pub fn try_check_correlation_validity(&self,
+        correlation_validity_monitor: &mut CorrelationValidityMonitor,
+        reynolds: Ratio,
+        prandtl_bulk: Ratio) -> Result<(), TuasLibError>{
+
+        let reynolds_abs = reynolds.abs();
+        let length_to_diameter: Ratio = 
+            self.get_component_length_immutable()/
+            self.get_hydraulic_diameter_immutable();
+
+        correlation_validity_monitor.try_check_nusselt_correlation(
+            &self.nusselt_correlation,
+            reynolds_abs,
+            prandtl_bulk,
+            length_to_diameter)?;
+
+        correlation_validity_monitor.try_check_friction_correlation(
+            &self.fluid_component_loss_properties,
+            reynolds_abs,
+            length_to_diameter)
+    }
+
+    /// gets the grashof number based on hydraulic diameter and the 
+    /// density difference between the bulk fluid and fluid at the 
+    /// wall temperature
+    ///
+    /// Gr = g (rho_bulk - rho_wall)/rho_bulk D^3 / nu^2
+    ///
+    /// this is positive when the wall is hotter than the fluid
+    #[inline]
+    pub fn get_grashof(&mut self,
+        wall_temperature: ThermodynamicTemperature) -> Result<Ratio, TuasLibError>{
+
+        let bulk_temperature = self.try_get_bulk_temperature()?;
+        let material = self.material_control_volume;
+        let pressure = self.pressure_control_volume;
+
+        let bulk_density = try_get_rho(material, bulk_temperature, pressure)?;
+        let wall_density = try_get_rho(material, wall_temperature, pressure)?;
+        let viscosity = try_get_mu_viscosity(material, bulk_temperature, pressure)?;
+
+        let kinematic_viscosity = viscosity / bulk_density;
+        let hydraulic_diameter = self.get_hydraulic_diameter();
+        let g = Acceleration::new::<meter_per_second_squared>(
+            GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED);
+
+        let grashof: Ratio = g * (bulk_density - wall_density) / bulk_density
+            * hydraulic_diameter * hydraulic_diameter * hydraulic_diameter
+            / kinematic_viscosity / kinematic_viscosity;
+
+        Ok(grashof)
+    }
+
+    /// gets the data needed for mixed convection corrections 
+    /// based on the current bulk temperature and incline angle
+    #[inline]
+    pub fn get_mixed_convection_data(&mut self,
+        reynolds: Ratio,
+        prandtl_bulk: Ratio,
+        wall_temperature: ThermodynamicTemperature) 
+        -> Result<MixedConvectionData, TuasLibError>{
+
+        Ok(MixedConvectionData {
+            reynolds,
+            prandtl_bulk,
+            grashof: self.get_grashof(wall_temperature)?,
+            incline_angle: self.get_incline_angle(),
+        })
+    }
+
+    /// gets the nusselt number as in get_nusselt, but corrected 
+    /// for mixed convection 
+    ///
+    /// this is meant for low reynolds number flows such as 
+    /// natural circulation, where buoyancy distorts the velocity 
+    /// profile. Any nusselt correlation may be used, the correction 
+    /// is applied on top of it
+    #[inline]
+    pub fn get_nusselt_mixed_convection(&mut self,
+        reynolds: Ratio, 
+        prandtl_bulk: Ratio,
+        prandtl_wall: Ratio,
+        wall_temperature: ThermodynamicTemperature) -> Result<Ratio, TuasLibError>{
+
+        let forced_convection_nusselt = self.get_nusselt(
+            reynolds, prandtl_bulk, prandtl_wall)?;
+
+        let correction_factor = self.get_mixed_convection_data(
+            reynolds, prandtl_bulk, wall_temperature)?
+            .try_get_nusselt_correction_factor(forced_convection_nusselt)?;
+
+        Ok(forced_convection_nusselt * correction_factor)
+    }
+
+    /// gets the nusselt number for prebuilt components, which know 
+    /// the wall temperature 
+    ///
+    /// if mixed_convection_correction is set, this is 
+    /// get_nusselt_mixed_convection, otherwise it is get_nusselt 
+    /// and the wall temperature is not used
+    #[inline]
+    pub fn get_nusselt_with_wall_temperature(&mut self,
+        reynolds: Ratio, 
+        prandtl_bulk: Ratio,
+        prandtl_wall: Ratio,
+        wall_temperature: ThermodynamicTemperature) -> Result<Ratio, TuasLibError>{
+
+        if self.mixed_convection_correction {
+            return self.get_nusselt_mixed_convection(
+                reynolds, prandtl_bulk, prandtl_wall, wall_temperature);
+        }
+
+        self.get_nusselt(reynolds, prandtl_bulk, prandtl_wall)
     }
 }
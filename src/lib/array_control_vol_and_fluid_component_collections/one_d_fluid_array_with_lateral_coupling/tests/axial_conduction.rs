@@ -0,0 +1,145 @@
+/// checks the effective axial conductivity ratio of each model
+///
+/// for turbulent flow at Re = 10000 and Pr = 5 (Pe = 50000),
+/// the smooth pipe churchill friction factor is about 0.0310, so:
+///
+/// k_eff / k = 1 + 5.05 (50000) sqrt(0.0310/8) = 15720
+///
+/// at Re = 3150 (halfway through transition), only half the
+/// turbulent term is added
+#[cfg(test)]
+#[test]
+pub fn axial_conduction_model_conductivity_ratios(){
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::axial_conduction::FluidAxialConductionModel;
+    use crate::fluid_mechanics_correlations::churchill_friction_factor::darcy;
+
+    let ratio_of = |value: f64| Ratio::new::<ratio>(value);
+
+    let get_conductivity_ratio = |model: FluidAxialConductionModel,
+        reynolds: f64, prandtl: f64| -> f64 {
+        model.get_effective_conductivity_ratio(
+            ratio_of(reynolds), ratio_of(prandtl)).unwrap().get::<ratio>()
+    };
+
+    // default is molecular conduction for Pe < 100 only
+    let default_model = FluidAxialConductionModel::default();
+    assert_eq!(default_model, FluidAxialConductionModel::LowPecletNumberMolecular);
+    assert_eq!(get_conductivity_ratio(default_model, 50.0, 1.0), 1.0);
+    assert_eq!(get_conductivity_ratio(default_model, -50.0, 1.0), 1.0);
+    assert_eq!(get_conductivity_ratio(default_model, 500.0, 1.0), 0.0);
+
+    assert_eq!(get_conductivity_ratio(
+            FluidAxialConductionModel::Neglected, 0.0, 5.0), 0.0);
+    assert_eq!(get_conductivity_ratio(
+            FluidAxialConductionModel::Molecular, 1.0e4, 5.0), 1.0);
+
+    let turbulent_model = FluidAxialConductionModel::MolecularAndTurbulentDiffusion;
+
+    // laminar flow is molecular conduction only
+    assert_eq!(get_conductivity_ratio(turbulent_model, 1000.0, 5.0), 1.0);
+
+    approx::assert_relative_eq!(
+        get_conductivity_ratio(turbulent_model, 1.0e4, 5.0),
+        15720.0, max_relative = 1e-3);
+
+    let transition_darcy = darcy(3150.0, 0.0).unwrap();
+    approx::assert_relative_eq!(
+        get_conductivity_ratio(turbulent_model, 3150.0, 5.0),
+        1.0 + 0.5 * 5.05 * 3150.0 * 5.0 * (transition_darcy/8.0).sqrt(),
+        max_relative = 1e-9);
+
+    // reverse flow gives the same result
+    assert_eq!(get_conductivity_ratio(turbulent_model, -1.0e4, 5.0),
+        get_conductivity_ratio(turbulent_model, 1.0e4, 5.0));
+}
+
+/// for a stagnant and adiabatic fluid array with a hot half and
+/// a cold half, axial conduction should even out the temperatures
+/// while conserving energy
+///
+/// when axial conduction is neglected, the temperatures should
+/// stay as they are
+#[cfg(test)]
+#[test]
+pub fn stagnant_fluid_array_axial_conduction(){
+    use uom::si::angle::degree;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::pressure::atmosphere;
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::time::second;
+
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::axial_conduction::FluidAxialConductionModel;
+
+    let hot_temperature = ThermodynamicTemperature::new::<degree_celsius>(100.0);
+    let cold_temperature = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+    let number_of_nodes = 10;
+
+    let new_stagnant_array = |axial_conduction_model: FluidAxialConductionModel|
+        -> FluidArray {
+        let mut fluid_array = FluidArray::new_cylinder(
+            Length::new::<meter>(0.1),
+            Length::new::<meter>(0.05),
+            cold_temperature,
+            Pressure::new::<atmosphere>(1.0),
+            SolidMaterial::SteelSS304L,
+            LiquidMaterial::TherminolVP1,
+            Ratio::new::<ratio>(0.0),
+            number_of_nodes - 2,
+            Angle::new::<degree>(0.0));
+
+        let mut initial_temperature_vector = vec![cold_temperature; number_of_nodes];
+        initial_temperature_vector[..number_of_nodes/2].fill(hot_temperature);
+        fluid_array.set_temperature_vector(initial_temperature_vector).unwrap();
+        fluid_array.axial_conduction_model = axial_conduction_model;
+
+        fluid_array
+    };
+
+    let timestep = Time::new::<second>(1.0);
+
+    let mut neglected_array = new_stagnant_array(FluidAxialConductionModel::Neglected);
+    let mut molecular_array = new_stagnant_array(FluidAxialConductionModel::Molecular);
+
+    for _ in 0..100 {
+        neglected_array.advance_timestep(timestep).unwrap();
+        molecular_array.advance_timestep(timestep).unwrap();
+    }
+
+    let neglected_temperature_vector = neglected_array.get_temperature_vector().unwrap();
+    let molecular_temperature_vector = molecular_array.get_temperature_vector().unwrap();
+
+    // without axial conduction, nothing changes
+    approx::assert_abs_diff_eq!(
+        neglected_temperature_vector[0].get::<degree_celsius>(),
+        100.0, epsilon = 1e-9);
+    approx::assert_abs_diff_eq!(
+        neglected_temperature_vector[number_of_nodes-1].get::<degree_celsius>(),
+        50.0, epsilon = 1e-9);
+
+    // with axial conduction, heat flows from the hot half to
+    // the cold half, so the hottest and coldest nodes are the ends
+    let hot_end_temperature = molecular_temperature_vector[0].get::<degree_celsius>();
+    let cold_end_temperature = molecular_temperature_vector[number_of_nodes-1]
+        .get::<degree_celsius>();
+
+    assert!(hot_end_temperature < 100.0);
+    assert!(cold_end_temperature > 50.0);
+    for node_pair in molecular_temperature_vector.windows(2) {
+        assert!(node_pair[0] > node_pair[1]);
+    }
+
+    // the array is adiabatic, so the mean temperature hardly
+    // changes (rho cp of therminol varies slightly with temperature)
+    let mean_temperature: f64 = molecular_temperature_vector.iter()
+        .map(|temperature| temperature.get::<degree_celsius>())
+        .sum::<f64>() / number_of_nodes as f64;
+
+    approx::assert_abs_diff_eq!(mean_temperature, 75.0, epsilon = 0.5);
+}
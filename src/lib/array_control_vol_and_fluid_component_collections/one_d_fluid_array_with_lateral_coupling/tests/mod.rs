@@ -32,3 +32,8 @@ pub mod joint_mixing;
 ///
 ///
 pub mod parasitic_heat_loss;
+
+
+/// checks the axial conduction models, 
+/// including axial conduction in stagnant fluid arrays
+pub mod axial_conduction;
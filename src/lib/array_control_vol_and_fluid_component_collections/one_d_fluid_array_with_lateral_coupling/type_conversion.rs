@@ -24,6 +24,12 @@ impl TryFrom<FluidComponent> for FluidArray {
                 // type conversion error
                 Err(TuasLibError::TypeConversionErrorHeatTransferEntity)
             },
+            FluidComponent::FlowDiode(_) => {
+                Err(TuasLibError::TypeConversionErrorHeatTransferEntity)
+            },
+            FluidComponent::ControlValve(_) => {
+                Err(TuasLibError::TypeConversionErrorHeatTransferEntity)
+            },
         }
     }
 }
@@ -66,6 +66,44 @@ impl SolidColumn {
         Ok(())
     }
     /// connects an adjacent solid or fluid node laterally 
+    /// with a thermal conductance for each node
+    ///
+    /// this is for conductances which vary from node to node, 
+    /// such as linearised radiation conductances which depend on 
+    /// the node temperature
+    pub fn lateral_link_new_temperature_vector_conductance_vector(&mut self,
+    thermal_conductance_vec: Vec<ThermalConductance>,
+    temperature_vec: Vec<ThermodynamicTemperature>) 
+        -> Result<(), TuasLibError>{
+
+        let number_of_temperature_nodes = self.len();
+
+        if temperature_vec.len() !=  number_of_temperature_nodes ||
+            thermal_conductance_vec.len() != number_of_temperature_nodes {
+            let shape_error = ShapeError::from_kind(
+                ErrorKind::IncompatibleShape
+            );
+
+            let linalg_error = LinalgError::Shape(shape_error);
+
+            return Err(TuasLibError::LinalgError
+                (linalg_error));
+
+        }
+
+        let temperature_arr: Array1<ThermodynamicTemperature> 
+        = Array1::from_vec(temperature_vec);
+
+        self.lateral_adjacent_array_temperature_vector.push(temperature_arr);
+
+        let conductance_arr: Array1<ThermalConductance> 
+        = Array1::from_vec(thermal_conductance_vec);
+
+        self.lateral_adjacent_array_conductance_vector.push(conductance_arr);
+
+        Ok(())
+    }
+    /// connects an adjacent solid or fluid node laterally 
     /// with a given power source with an axial power distribution
     #[inline]
     pub fn lateral_link_new_power_vector(&mut self,
@@ -5,7 +5,7 @@ use ndarray::*;
 use uom::si::power::watt;
 
 use crate::array_control_vol_and_fluid_component_collections::conductance_array_functions::advance_timestep_for_specified_conductance_array_cv;
-use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+use crate::array_control_vol_and_fluid_component_collections::conductance_array_functions::set_end_single_cvs_to_array_temperatures;
 use crate::tuas_lib_error::TuasLibError;
 use crate::control_volume_dimensions::UNIT_AREA_SQ_METER_FOR_ONE_DIMENSIONAL_CALCS;
 
@@ -88,56 +88,12 @@ impl CartesianConduction1DArray {
         //
 
         // I'm calculating the inner and outer CV's new enthalpy
-        // at the current timestep 
-        //
-        // This code block deals with setting temperature and 
-        // enthalpies for the two nested control volumes
-        {
-            let inner_node_enthalpy_next_timestep: AvailableEnergy = 
-            try_get_h(
-                self.inner_single_cv.material_control_volume,
-                new_temperature_array[0],
-                self.inner_single_cv.pressure_control_volume)?;
-
-            self.inner_single_cv.current_timestep_control_volume_specific_enthalpy 
-                = inner_node_enthalpy_next_timestep;
-
-            // do the same for the outer node
-            let outer_node_enthalpy_next_timestep: AvailableEnergy = 
-            try_get_h(
-                self.outer_single_cv.material_control_volume,
-                new_temperature_array[total_number_of_nodes-1],
-                self.outer_single_cv.pressure_control_volume)?;
-
-            self.outer_single_cv.current_timestep_control_volume_specific_enthalpy 
-                = outer_node_enthalpy_next_timestep;
-
-            // I also need to update the TOld vector 
-            // This will ensure that the current temperature of the single 
-            // cv node is equal to that of the matrix
-
-            // technically speaking, this isn't necessary here, as I'm already 
-            // returning the new temperature vector
-            // I'll set it explicitly in the advance_timestep function
-            //
-            //*TOld = T.mapv(
-            //    |temperature_value| {
-            //        return temperature_value;
-            //    }
-            //);
-            // set liquid cv mass for both cvs,
-            // and clear out both the rate_enthalpy_change_vector  
-            // and the max_timestep_vector
-            // probably also need to update error types in future
-            // so I don't keep using unwrap
-            self.inner_single_cv.set_liquid_cv_mass_from_temperature()?;
-
-            self.outer_single_cv.set_liquid_cv_mass_from_temperature()?;
-
-            self.inner_single_cv.clear_vectors()?;
-            self.outer_single_cv.clear_vectors()?;
-            
-        }
+        // at the current timestep, and setting their temperatures 
+        // to those of the array
+        set_end_single_cvs_to_array_temperatures(
+            &mut self.inner_single_cv,
+            &mut self.outer_single_cv,
+            &new_temperature_array)?;
 
         self.temperature_array_current_timestep = new_temperature_array;
         Ok(())
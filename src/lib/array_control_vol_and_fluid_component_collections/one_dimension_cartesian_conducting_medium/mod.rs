@@ -290,3 +290,7 @@ pub mod preprocessing;
 /// it's the bulk of calculation
 pub mod calculation;
 
+/// tests for the cartesian array against analytical solutions
+/// for conduction through a slab
+#[cfg(test)]
+pub mod tests;
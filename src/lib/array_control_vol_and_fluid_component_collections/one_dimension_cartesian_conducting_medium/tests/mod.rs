@@ -0,0 +1,3 @@
+/// steady state conduction through a slab, where the 
+/// temperature profile should be linear
+pub mod steady_state_slab_conduction;
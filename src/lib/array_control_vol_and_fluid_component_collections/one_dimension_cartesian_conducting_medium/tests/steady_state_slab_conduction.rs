@@ -0,0 +1,78 @@
+/// a 10 cm slab with constant properties, with 1000 W added 
+/// to the inner node and 1000 W removed from the outer node
+///
+/// at steady state, the same 1000 W is conducted through every 
+/// thermal resistor, so the temperature profile is linear with
+///
+/// T[i] - T[i+1] = Q delta_x / (k A) = 0.5 K
+///
+/// for 10 nodes, delta_x = 1 cm, k = 20 W/(m K) and A is the 
+/// 1 m^2 basis area. No net heat is added, so the bulk temperature 
+/// stays at the initial temperature
+#[test]
+pub fn slab_steady_state_linear_temperature_profile(){
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_cartesian_conducting_medium::CartesianConduction1DArray;
+    use crate::boussinesq_thermophysical_properties::SolidMaterial;
+
+    fn cp(_temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(700.0)
+    }
+    fn k(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+        ThermalConductivity::new::<watt_per_meter_kelvin>(20.0)
+    }
+    fn rho(_temperature: ThermodynamicTemperature) -> MassDensity {
+        MassDensity::new::<kilogram_per_cubic_meter>(1800.0)
+    }
+
+    let constant_property_solid = SolidMaterial::CustomSolid(
+        (ThermodynamicTemperature::new::<kelvin>(250.0),
+        ThermodynamicTemperature::new::<kelvin>(2500.0)),
+        cp, k, rho, Length::new::<meter>(0.0));
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+    let heat_rate = Power::new::<watt>(1000.0);
+
+    let mut slab = CartesianConduction1DArray::new(
+        constant_property_solid.into(),
+        initial_temperature,
+        Pressure::new::<atmosphere>(1.0),
+        8,
+        Length::new::<meter>(0.1)).unwrap();
+
+    // the slowest mode decays with L^2/(PI^2 alpha), about 64 s
+    for _ in 0..2000 {
+        slab.inner_single_cv.rate_enthalpy_change_vector.push(heat_rate);
+        slab.outer_single_cv.rate_enthalpy_change_vector.push(-heat_rate);
+
+        slab.advance_timestep(Time::new::<second>(1.0)).unwrap();
+    }
+
+    let temperatures = slab.get_temperature_vector().unwrap();
+
+    for pair in temperatures.windows(2) {
+        approx::assert_relative_eq!(
+            pair[0].get::<kelvin>() - pair[1].get::<kelvin>(),
+            0.5,
+            max_relative = 1e-6);
+    }
+
+    approx::assert_relative_eq!(
+        slab.get_bulk_temperature().unwrap().get::<kelvin>(),
+        400.0,
+        max_relative = 1e-9);
+
+    // the boundary cvs are what get linked to other heat transfer 
+    // entities, so they must have the array temperatures
+    assert_eq!(slab.inner_single_cv.temperature, temperatures[0]);
+    assert_eq!(slab.outer_single_cv.temperature, *temperatures.last().unwrap());
+}
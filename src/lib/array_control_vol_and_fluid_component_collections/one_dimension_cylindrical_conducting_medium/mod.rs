@@ -0,0 +1,109 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::array_control_vol_and_fluid_component_collections::one_dimension_radial_conducting_medium::RadialConduction1DArray;
+use crate::array_control_vol_and_fluid_component_collections::one_dimension_radial_conducting_medium::RadialConductionGeometry;
+use crate::control_volume_dimensions::InnerDiameterThermalConduction;
+use crate::control_volume_dimensions::OuterDiameterThermalConduction;
+use crate::single_control_vol::SingleCVNode;
+use crate::boussinesq_thermophysical_properties::Material;
+
+use crate::tuas_lib_error::TuasLibError;
+
+
+/// for 1D Cylindrical Conduction array,
+/// it is essentially an array control volume of one homogeneous
+/// material, shaped like a hollow or solid cylinder of a given
+/// length
+///
+/// conduction is in the radial direction only, so this is meant
+/// for things like thick pipe walls, heater rods or fuel rods
+///
+/// the cylinder is split into several cylindrical shells of equal
+/// thickness delta_r, each with a temperature node at its
+/// middle. Unlike the cartesian array, the volume of each shell
+/// (and therefore its thermal inertia) goes with r, and so does
+/// the area through which heat is conducted
+///
+/// the calculations are shared with the spherical array, see
+/// RadialConduction1DArray
+pub type CylindricalConduction1DArray = RadialConduction1DArray<CylindricalGeometry>;
+
+/// cylindrical shells of a given axial length for the
+/// RadialConduction1DArray
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct CylindricalGeometry {
+    /// axial length of the cylinder
+    pub axial_length: Length,
+}
+
+impl RadialConductionGeometry for CylindricalGeometry {
+
+    /// V = PI (r_b^2 - r_a^2) L
+    fn get_shell_volume(&self, r_a: Length, r_b: Length) -> Volume {
+        PI * (r_b * r_b - r_a * r_a) * self.axial_length
+    }
+
+    /// A = 2 PI r L
+    fn get_face_area(&self, radius: Length) -> Area {
+        2.0 * PI * radius * self.axial_length
+    }
+
+    /// R = ln(r_b/r_a) / (2 PI k L)
+    fn get_shell_thermal_resistance(&self, r_a: Length, r_b: Length,
+        thermal_conductivity: ThermalConductivity) -> ThermalResistance {
+        (r_b/r_a).get::<ratio>().ln()
+            /(2.0 * PI * thermal_conductivity * self.axial_length)
+    }
+
+    fn new_shell_single_cv(&self, r_a: Length, r_b: Length,
+        material: Material,
+        initial_temperature: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<SingleCVNode, TuasLibError> {
+
+        SingleCVNode::new_cylindrical_shell(
+            self.axial_length,
+            InnerDiameterThermalConduction::from(2.0 * r_a),
+            OuterDiameterThermalConduction::from(2.0 * r_b),
+            material,
+            initial_temperature,
+            pressure)
+    }
+}
+
+/// here, i mostly do constructors
+impl CylindricalConduction1DArray {
+
+    /// constructs a new instance of the CylindricalConduction1DArray
+    ///
+    /// for a solid rod, set the inner radius to zero
+    pub fn new(material: Material,
+    initial_uniform_temperature: ThermodynamicTemperature,
+    uniform_pressure: Pressure,
+    inner_nodes: usize,
+    inner_radius: Length,
+    outer_radius: Length,
+    axial_length: Length) -> Result<Self,TuasLibError> {
+
+        if axial_length.value <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "cylindrical array needs a positive axial length"
+                .to_string()));
+        }
+
+        Self::new_with_geometry(CylindricalGeometry { axial_length },
+            material,
+            initial_uniform_temperature,
+            uniform_pressure,
+            inner_nodes,
+            inner_radius,
+            outer_radius)
+    }
+}
+
+/// tests for the cylindrical array against analytical solutions
+/// for radial conduction
+#[cfg(test)]
+pub mod tests;
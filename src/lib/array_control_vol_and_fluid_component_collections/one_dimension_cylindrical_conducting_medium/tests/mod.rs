@@ -0,0 +1,7 @@
+/// a thick walled pipe between two constant temperature boundaries
+/// should approach the analytical logarithmic temperature profile
+///
+/// T(r) = T_a - (T_a - T_b) ln(r/r_a)/ln(r_b/r_a)
+///
+/// and a heater rod linked to a SingleCVNode should conserve energy
+pub mod thick_wall_and_heater_rod_conduction;
@@ -0,0 +1,185 @@
+/// a 2 m long pipe wall with 5 cm inner radius and 
+/// 10 cm outer radius, with its inner surface linked to 
+/// 400 K fluid and outer surface to 300 K ambient, 
+/// both with a conductance of 200 W/K
+///
+/// at steady state, heat flows through three resistances in series,
+/// the inner conductance, the whole wall,
+///
+/// R_wall = ln(r_o/r_i)/(2 PI k L)
+///
+/// and the outer conductance. The nodes should follow the 
+/// logarithmic profile between the inner and outer surface 
+/// temperatures exactly, since the shell and half shell 
+/// resistances add up to the same ln(r_b/r_a) for constant k
+#[test]
+pub fn thick_wall_pipe_steady_state_log_profile(){
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_cylindrical_conducting_medium::CylindricalConduction1DArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::tests::fuel_pebble_conduction::constant_property_graphite;
+    use crate::boundary_conditions::BCType;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+
+    let inner_radius = Length::new::<meter>(0.05);
+    let outer_radius = Length::new::<meter>(0.10);
+    let pipe_length = Length::new::<meter>(2.0);
+    let surface_conductance = ThermalConductance::new::<watt_per_kelvin>(200.0);
+
+    let mut pipe_wall = CylindricalConduction1DArray::new(
+        constant_property_graphite().into(),
+        ThermodynamicTemperature::new::<kelvin>(350.0),
+        Pressure::new::<atmosphere>(1.0),
+        8,
+        inner_radius,
+        outer_radius,
+        pipe_length).unwrap();
+
+    approx::assert_relative_eq!(
+        pipe_wall.get_total_volume().value,
+        PI * (0.1_f64.powi(2) - 0.05_f64.powi(2)) * 2.0,
+        max_relative = 1e-12);
+
+    let timestep = Time::new::<second>(5.0);
+
+    for _ in 0..3000 {
+        let mut inner_fluid: HeatTransferEntity = BCType::new_const_temperature(
+            ThermodynamicTemperature::new::<kelvin>(400.0)).into();
+        let mut ambient: HeatTransferEntity = BCType::new_const_temperature(
+            ThermodynamicTemperature::new::<kelvin>(300.0)).into();
+
+        pipe_wall.link_inner_surface_to_heat_transfer_entity(
+            &mut inner_fluid,
+            HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                surface_conductance)).unwrap();
+        pipe_wall.link_outer_surface_to_heat_transfer_entity(
+            &mut ambient,
+            HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                surface_conductance)).unwrap();
+
+        pipe_wall.advance_timestep(timestep).unwrap();
+    }
+
+    let temperatures = pipe_wall.get_temperature_vector().unwrap();
+    let node_radii = pipe_wall.get_node_radii();
+
+    let wall_resistance: f64 = (0.10_f64/0.05).ln()
+        /(2.0 * PI * 20.0 * 2.0);
+    let heat_flow: f64 = 100.0/(2.0/200.0 + wall_resistance);
+
+    let inner_surface_temperature: f64 = 400.0 - heat_flow/200.0;
+    let outer_surface_temperature: f64 = 300.0 + heat_flow/200.0;
+
+    for (temperature, radius) in temperatures.iter().zip(node_radii.iter()) {
+        let analytical_temperature: f64 = inner_surface_temperature
+            - (inner_surface_temperature - outer_surface_temperature)
+            * (radius.get::<meter>()/0.05).ln()
+            / (0.10_f64/0.05).ln();
+
+        approx::assert_relative_eq!(
+            temperature.get::<kelvin>(),
+            analytical_temperature,
+            max_relative = 1e-6);
+    }
+
+    // the axial length must be positive
+    assert!(CylindricalConduction1DArray::new(
+            constant_property_graphite().into(),
+            ThermodynamicTemperature::new::<kelvin>(350.0),
+            Pressure::new::<atmosphere>(1.0),
+            8,
+            inner_radius,
+            outer_radius,
+            Length::new::<meter>(0.0)).is_err());
+}
+
+/// a 1 cm radius, 0.5 m long heater rod generating 300 W 
+/// within its inner 8 mm, with its outer surface linked to 
+/// a lump of the same material (a SingleCVNode) of 1 litre
+///
+/// nothing else is linked, so all the heat generated over 
+/// 200 s should end up in the rod and the lump
+///
+/// Q t = rho cp (V_rod (T_rod - T_0) + V_lump (T_lump - T_0))
+#[test]
+pub fn heater_rod_linked_to_single_cv_energy_balance(){
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_cylindrical_conducting_medium::CylindricalConduction1DArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::tests::fuel_pebble_conduction::constant_property_graphite;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+    use crate::single_control_vol::SingleCVNode;
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(500.0);
+    let pressure = Pressure::new::<atmosphere>(1.0);
+
+    let mut heater_rod = CylindricalConduction1DArray::new(
+        constant_property_graphite().into(),
+        initial_temperature,
+        pressure,
+        6,
+        Length::new::<meter>(0.0),
+        Length::new::<meter>(0.01),
+        Length::new::<meter>(0.5)).unwrap();
+
+    heater_rod.set_heat_generation(Power::new::<watt>(300.0));
+    heater_rod.set_heat_generation_within_radius(
+        Length::new::<meter>(0.008)).unwrap();
+
+    let lump_side_length = Length::new::<meter>(0.1);
+    let lump: SingleCVNode = SingleCVNode::new_block(
+        lump_side_length, lump_side_length, lump_side_length,
+        constant_property_graphite().into(),
+        initial_temperature,
+        pressure).unwrap();
+    let mut lump: HeatTransferEntity = lump.into();
+
+    let timestep = Time::new::<second>(0.5);
+
+    for _ in 0..400 {
+        heater_rod.link_outer_surface_to_heat_transfer_entity(
+            &mut lump,
+            HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                ThermalConductance::new::<watt_per_kelvin>(5.0))).unwrap();
+
+        heater_rod.advance_timestep(timestep).unwrap();
+
+        let mut lump_cv: SingleCVNode = lump.try_into().unwrap();
+        lump_cv.advance_timestep(timestep).unwrap();
+        lump = lump_cv.into();
+    }
+
+    let lump_cv: SingleCVNode = lump.try_into().unwrap();
+    let rod_temperature: f64 = heater_rod.get_bulk_temperature().unwrap().get::<kelvin>();
+    let lump_temperature: f64 = lump_cv.temperature.get::<kelvin>();
+
+    let rod_volume: f64 = PI * 0.01_f64.powi(2) * 0.5;
+    let lump_volume: f64 = 0.1_f64.powi(3);
+
+    approx::assert_relative_eq!(
+        1800.0 * 700.0 * (rod_volume * (rod_temperature - 500.0)
+            + lump_volume * (lump_temperature - 500.0)),
+        300.0 * 200.0,
+        max_relative = 1e-6);
+
+    // the lump should have picked up some of the heat,
+    // and the centre of the rod should be hotter than its surface
+    assert!(lump_temperature > 500.0);
+    let temperatures = heater_rod.get_temperature_vector().unwrap();
+    assert!(heater_rod.get_centre_temperature() > *temperatures.last().unwrap());
+}
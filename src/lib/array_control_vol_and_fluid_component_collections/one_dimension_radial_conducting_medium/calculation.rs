@@ -0,0 +1,66 @@
+use super::RadialConduction1DArray;
+use super::RadialConductionGeometry;
+use uom::si::f64::*;
+use ndarray::*;
+
+use crate::array_control_vol_and_fluid_component_collections::conductance_array_functions::advance_timestep_for_specified_conductance_array_cv;
+use crate::array_control_vol_and_fluid_component_collections::conductance_array_functions::set_end_single_cvs_to_array_temperatures;
+use crate::array_control_vol_and_fluid_component_collections::one_dimension_cartesian_conducting_medium::CartesianConduction1DArray;
+use crate::tuas_lib_error::TuasLibError;
+
+impl<G: RadialConductionGeometry> RadialConduction1DArray<G> {
+    /// calculates the temperature array for the next timestep
+    /// and updates the temperatures and enthalpies of current timestep
+    /// to be that of the next timestep
+    ///
+    /// this uses the same solver as the cartesian array, just with
+    /// the radial volume fractions and conductances
+    pub fn advance_timestep(
+        &mut self, timestep: Time) -> Result<(), TuasLibError>{
+
+        let inner_nodes = self.inner_nodes;
+
+        // unlike the cartesian array, we use the actual volume
+        // rather than a basis area
+        let total_volume: Volume = self.get_total_volume();
+
+        let mut conductance_array: Array1<ThermalConductance> =
+        self.get_current_timestep_conductance_array()?;
+
+        // rho cp is calculated the same way regardless of geometry
+        let volumetric_heat_capacity_array =
+        CartesianConduction1DArray::get_current_timestep_rho_cp(
+            self.material_control_volume,
+            &self.temperature_array_current_timestep,
+            self.pressure_control_volume
+        )?;
+
+        let new_temperature_array: Array1<ThermodynamicTemperature> =
+        advance_timestep_for_specified_conductance_array_cv(
+            &mut self.inner_single_cv,
+            &mut self.outer_single_cv,
+            inner_nodes,
+            timestep,
+            total_volume,
+            self.heat_generation,
+            &self.power_distribution_array,
+            &self.temperature_array_current_timestep,
+            &mut conductance_array,
+            &self.volume_fraction_array,
+            &volumetric_heat_capacity_array
+        )?;
+
+        self.temperature_array_next_timestep =
+            new_temperature_array.clone();
+
+        set_end_single_cvs_to_array_temperatures(
+            &mut self.inner_single_cv,
+            &mut self.outer_single_cv,
+            &new_temperature_array)?;
+
+        self.temperature_array_current_timestep = new_temperature_array;
+        Ok(())
+    }
+
+
+}
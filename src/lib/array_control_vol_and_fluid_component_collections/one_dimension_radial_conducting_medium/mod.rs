@@ -0,0 +1,355 @@
+use std::fmt::Debug;
+
+use approx::assert_relative_eq;
+use ndarray::*;
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::ConstZero;
+
+use crate::single_control_vol::SingleCVNode;
+use crate::boussinesq_thermophysical_properties::Material;
+
+use crate::tuas_lib_error::TuasLibError;
+
+
+/// the geometry specific parts of a radial conduction array,
+/// these are the only things which differ between spheres and
+/// cylinders
+///
+/// everything else (face radii, volume fractions, conductance
+/// arrays, timestepping and linking) is done the same way
+/// by the RadialConduction1DArray
+pub trait RadialConductionGeometry: Debug + Clone + Copy + PartialEq + Default {
+
+    /// volume of the shell between radius r_a and r_b
+    fn get_shell_volume(&self, r_a: Length, r_b: Length) -> Volume;
+
+    /// area through which heat is conducted at a given radius
+    fn get_face_area(&self, radius: Length) -> Area;
+
+    /// conduction resistance of the shell between radius r_a
+    /// and r_b for a constant thermal conductivity
+    fn get_shell_thermal_resistance(&self, r_a: Length, r_b: Length,
+        thermal_conductivity: ThermalConductivity) -> ThermalResistance;
+
+    /// makes a SingleCVNode for the shell between r_a and r_b,
+    /// this is used for the inner and outer single cvs
+    fn new_shell_single_cv(&self, r_a: Length, r_b: Length,
+        material: Material,
+        initial_temperature: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<SingleCVNode, TuasLibError>;
+}
+
+/// for 1D Radial Conduction arrays,
+/// it is essentially an array control volume of one homogeneous
+/// material, shaped like a hollow or solid sphere or cylinder
+///
+/// conduction is in the radial direction only, and the shape
+/// specific parts are given by the geometry G
+///
+/// the body is split into several shells of equal
+/// thickness delta_r, each with a temperature node at its
+/// middle. Unlike the cartesian array, the volume of each shell
+/// (and therefore its thermal inertia) grows with r, and so does
+/// the area through which heat is conducted
+///
+/// the scheme used is the same as the CartesianConduction1DArray,
+/// implicit Euler for the new temperatures, with material properties
+/// taken at the current timestep temperatures
+///
+/// heat generation (eg. fission heat in a fuel pebble) can be
+/// added within a given radius
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct RadialConduction1DArray<G: RadialConductionGeometry> {
+
+    /// represents the inner (lower r) control volume end
+    ///
+    /// for a solid sphere or rod, this is the node nearest the centre
+    pub inner_single_cv: SingleCVNode,
+
+    /// represents the outer (higher r) control volume end
+    pub outer_single_cv: SingleCVNode,
+
+    // number of ADDITIONAL nodes within the array
+    // in addition to the inner and outer single cvs
+    inner_nodes: usize,
+
+    // inner radius, zero for a solid sphere or rod
+    inner_radius: Length,
+
+    // outer radius
+    outer_radius: Length,
+
+    // shape of the array
+    geometry: G,
+
+    // volume fraction array
+    volume_fraction_array: Array1<f64>,
+
+    /// temperature array current timestep
+    pub temperature_array_current_timestep: Array1<ThermodynamicTemperature>,
+
+    // temperature_array_next timestep
+    temperature_array_next_timestep: Array1<ThermodynamicTemperature>,
+
+    /// control volume material
+    pub material_control_volume: Material,
+
+    /// control volume pressure
+    pub pressure_control_volume: Pressure,
+
+    // heat generated within the whole array
+    heat_generation: Power,
+
+    // fraction of the heat generated within each node
+    power_distribution_array: Array1<f64>,
+}
+
+/// here, i mostly do constructors
+impl<G: RadialConductionGeometry> RadialConduction1DArray<G> {
+
+    /// constructs a new radial array of a given geometry
+    ///
+    /// for a solid sphere or rod, set the inner radius to zero
+    pub fn new_with_geometry(geometry: G,
+    material: Material,
+    initial_uniform_temperature: ThermodynamicTemperature,
+    uniform_pressure: Pressure,
+    inner_nodes: usize,
+    inner_radius: Length,
+    outer_radius: Length) -> Result<Self,TuasLibError> {
+
+        if inner_radius.value < 0.0 || outer_radius <= inner_radius {
+            return Err(TuasLibError::GenericStringError(
+                "radial array needs 0 <= inner radius < outer radius"
+                .to_string()));
+        }
+
+        let number_of_temperature_nodes: usize = inner_nodes + 2;
+
+        // the face radii and volume fractions only depend on
+        // the geometry, so I set those first
+        let mut array_to_return = Self {
+            inner_nodes,
+            inner_radius,
+            outer_radius,
+            geometry,
+            material_control_volume: material,
+            pressure_control_volume: uniform_pressure,
+            ..Default::default()
+        };
+
+        let vol_frac_array =
+            array_to_return.construct_volume_fraction_array()?;
+
+        array_to_return.volume_fraction_array = vol_frac_array.clone();
+
+        // no heat generation by default, but if heat is added,
+        // it is spread evenly throughout the volume
+        array_to_return.heat_generation = Power::ZERO;
+        array_to_return.power_distribution_array = vol_frac_array;
+
+        // set the temperature arrays
+        let mut initial_temperature_array:
+        Array1<ThermodynamicTemperature> =
+            Array::default(number_of_temperature_nodes);
+
+        initial_temperature_array.fill(initial_uniform_temperature);
+
+        array_to_return.temperature_array_current_timestep =
+            initial_temperature_array.clone();
+
+        array_to_return.temperature_array_next_timestep =
+            initial_temperature_array;
+
+        // the inner and outer single cvs are the innermost and
+        // outermost shells. The mesh lengthscale is the
+        // shell thickness rather than the radius
+        let face_radii: Array1<Length> = array_to_return.get_face_radii();
+        let delta_r: Length = array_to_return.get_shell_thickness();
+
+        let shell_cv = |r_inner: Length, r_outer: Length|
+            -> Result<SingleCVNode, TuasLibError> {
+
+            let mut single_cv = geometry.new_shell_single_cv(
+                r_inner,
+                r_outer,
+                material,
+                initial_uniform_temperature,
+                uniform_pressure)?;
+
+            single_cv.mesh_stability_lengthscale_vector = vec![delta_r];
+
+            Ok(single_cv)
+        };
+
+        array_to_return.inner_single_cv =
+            shell_cv(face_radii[0], face_radii[1])?;
+        array_to_return.outer_single_cv =
+            shell_cv(
+                face_radii[number_of_temperature_nodes - 1],
+                face_radii[number_of_temperature_nodes])?;
+
+        Ok(array_to_return)
+    }
+
+    /// thickness of each shell
+    ///
+    /// delta_r = (r_o - r_i)/number of temperature nodes
+    #[inline]
+    pub fn get_shell_thickness(&self) -> Length {
+        (self.outer_radius - self.inner_radius)/
+            (self.inner_nodes + 2) as f64
+    }
+
+    /// radii of the faces between the shells,
+    /// from the inner radius to the outer radius
+    ///
+    /// surf1 [0]    [1]     [2]     [3]    surf2
+    /// |------*------|------x------|------x------|------*------|
+    /// r_i                                                     r_o
+    ///
+    /// each shell is delta_r thick, and the temperature node
+    /// sits at the middle of each shell
+    pub (in crate) fn get_face_radii(&self) -> Array1<Length> {
+
+        let number_of_temperature_nodes: usize =
+        self.inner_nodes + 2;
+
+        let delta_r: Length = self.get_shell_thickness();
+
+        Array1::from_shape_fn(number_of_temperature_nodes + 1,
+            |face_index| {
+                self.inner_radius + face_index as f64 * delta_r
+            })
+    }
+
+    /// total volume of the array, from the inner
+    /// to the outer radius
+    #[inline]
+    pub fn get_total_volume(&self) -> Volume {
+        self.geometry.get_shell_volume(self.inner_radius, self.outer_radius)
+    }
+
+    /// area of the outer surface, eg. for working out a
+    /// convective conductance hA
+    #[inline]
+    pub fn get_outer_surface_area(&self) -> Area {
+        self.geometry.get_face_area(self.outer_radius)
+    }
+
+    /// constructor method which helps set the volume fraction array
+    ///
+    /// the volume fraction of each node is the volume of its
+    /// shell (between the face radii r_i and r_(i+1)) over
+    /// the total volume. So the outer shells have more thermal
+    /// inertia than the inner ones
+    pub (in crate) fn construct_volume_fraction_array(&mut self)
+    -> Result<Array1<f64>, TuasLibError> {
+
+        let face_radii: Array1<Length> = self.get_face_radii();
+        let total_volume: Volume = self.get_total_volume();
+
+        let volume_fraction_array: Array1<f64> =
+        face_radii.windows(2).into_iter()
+            .map(|faces| {
+                (self.geometry.get_shell_volume(faces[0], faces[1])
+                    /total_volume).value
+            })
+            .collect();
+
+        // assert if they add up to 1.0
+
+        let vol_fraction_sum: f64 = volume_fraction_array.sum();
+        assert_relative_eq!(
+            1.0,
+            vol_fraction_sum,
+            epsilon = 0.001);
+
+        Ok(volume_fraction_array)
+    }
+
+    /// sets the heat generated within the array,
+    /// eg. the power of a fuel pebble or heater rod
+    ///
+    /// the heat is spread according to the power distribution
+    /// array, which by default is evenly spread throughout the volume
+    #[inline]
+    pub fn set_heat_generation(&mut self, heat_generation: Power){
+        self.heat_generation = heat_generation;
+    }
+
+    /// spreads the heat generation evenly by volume within the
+    /// given radius, and no heat is generated outside it
+    ///
+    /// for a fuel pebble, this is the radius of the fuelled zone,
+    /// the graphite shell outside it does not generate heat
+    ///
+    /// shells which straddle this radius get a share of the heat
+    /// based on the volume within the radius
+    pub fn set_heat_generation_within_radius(&mut self,
+        heated_outer_radius: Length) -> Result<(), TuasLibError> {
+
+        if heated_outer_radius <= self.inner_radius {
+            return Err(TuasLibError::GenericStringError(
+                "heated radius must be more than the inner radius"
+                .to_string()));
+        }
+
+        let heated_outer_radius = heated_outer_radius.min(self.outer_radius);
+        let face_radii: Array1<Length> = self.get_face_radii();
+
+        let heated_volume: Volume = self.geometry.get_shell_volume(
+            self.inner_radius, heated_outer_radius);
+
+        self.power_distribution_array =
+        face_radii.windows(2).into_iter()
+            .map(|faces| {
+                let r_inner = faces[0].min(heated_outer_radius);
+                let r_outer = faces[1].min(heated_outer_radius);
+
+                (self.geometry.get_shell_volume(r_inner, r_outer)
+                    /heated_volume).value
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    /// gets bulk temperature of the array cv based on volume fraction
+    ///
+    /// this is weighted towards the outer shells
+    #[inline]
+    pub fn get_bulk_temperature(&mut self) ->
+    Result<ThermodynamicTemperature,TuasLibError>{
+
+        let vol_averaged_temperature_kelvin: f64 =
+        self.temperature_array_current_timestep.iter()
+            .zip(self.volume_fraction_array.iter())
+            .map(|(temperature_reference, vol_fraction)| {
+                vol_fraction * temperature_reference.get::<kelvin>()
+            })
+            .sum();
+
+        Ok(ThermodynamicTemperature::new
+            ::<kelvin>(vol_averaged_temperature_kelvin))
+    }
+
+}
+
+
+/// Functions or methods to retrieve temperature and other such
+/// data from the array_cv, and to link its surfaces
+pub mod postprocessing;
+
+
+/// Functions or methods to get timestep and other such quantiies
+/// for calculations
+///
+/// helps to set up quantities used in calculation step
+pub mod preprocessing;
+
+
+/// Contains functions which advance the timestep
+/// it's the bulk of calculation
+pub mod calculation;
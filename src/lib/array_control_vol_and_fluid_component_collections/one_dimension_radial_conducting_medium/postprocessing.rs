@@ -0,0 +1,123 @@
+use super::RadialConduction1DArray;
+use super::RadialConductionGeometry;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::pre_built_components::heat_transfer_entities::preprocessing::link_single_cv_to_heat_transfer_entity;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::tuas_lib_error::TuasLibError;
+use ndarray::*;
+use uom::si::f64::*;
+
+impl<G: RadialConductionGeometry> RadialConduction1DArray<G> {
+
+    /// returns a clone of the temperature_array_current_timestep
+    #[inline]
+    pub fn get_temperature_vector(&mut self) ->
+    Result<Vec<ThermodynamicTemperature>, TuasLibError> {
+        Ok(self.temperature_array_current_timestep.to_vec())
+    }
+
+    /// returns the radius of each temperature node,
+    /// these are in the middle of each shell
+    #[inline]
+    pub fn get_node_radii(&self) -> Vec<Length> {
+        let face_radii: Array1<Length> = self.get_face_radii();
+
+        face_radii.windows(2).into_iter()
+            .map(|faces| 0.5 * (faces[0] + faces[1]))
+            .collect()
+    }
+
+    /// returns the temperature of the innermost node
+    ///
+    /// for a solid sphere or rod, this is the centreline
+    /// temperature (strictly, the temperature at half a shell
+    /// thickness from the centre)
+    #[inline]
+    pub fn get_centre_temperature(&self) -> ThermodynamicTemperature {
+        self.temperature_array_current_timestep[0]
+    }
+
+    /// links the inner surface of a hollow sphere or cylinder
+    /// (the inner_single_cv) to another HeatTransferEntity,
+    /// such as a boundary condition or a SingleCVNode
+    ///
+    /// for a pipe wall, this is where the fluid within the pipe
+    /// is linked
+    ///
+    /// the inner node is half a shell away from the inner surface,
+    /// so the half shell resistance is put in series with the
+    /// conductance of the interaction
+    ///
+    /// a solid sphere or rod has nothing to link at its centre,
+    /// so this returns an error
+    pub fn link_inner_surface_to_heat_transfer_entity(&mut self,
+        other_entity: &mut HeatTransferEntity,
+        interaction: HeatTransferInteractionType) -> Result<(), TuasLibError> {
+
+        let interaction = add_half_shell_resistance_in_series(
+            interaction,
+            self.get_inner_half_shell_resistance()?)?;
+
+        link_single_cv_to_heat_transfer_entity(&mut self.inner_single_cv,
+            other_entity,
+            interaction)
+    }
+
+    /// links the outer surface (the outer_single_cv)
+    /// to another HeatTransferEntity, such as a boundary condition
+    /// or a SingleCVNode
+    ///
+    /// the outer node is half a shell away from the outer surface,
+    /// so the half shell resistance is put in series with the
+    /// conductance of the interaction
+    pub fn link_outer_surface_to_heat_transfer_entity(&mut self,
+        other_entity: &mut HeatTransferEntity,
+        interaction: HeatTransferInteractionType) -> Result<(), TuasLibError> {
+
+        let interaction = add_half_shell_resistance_in_series(
+            interaction,
+            self.get_outer_half_shell_resistance()?)?;
+
+        link_single_cv_to_heat_transfer_entity(&mut self.outer_single_cv,
+            other_entity,
+            interaction)
+    }
+}
+
+/// puts the half shell resistance between a boundary node and the
+/// surface in series with the conductance of the interaction
+///
+/// heat additions and heat fluxes go straight into the boundary
+/// node, so those are returned unchanged. Other interactions
+/// do not have a single conductance to add the resistance to,
+/// so those return an error
+pub (in crate) fn add_half_shell_resistance_in_series(
+    interaction: HeatTransferInteractionType,
+    half_shell_resistance: ThermalResistance)
+-> Result<HeatTransferInteractionType, TuasLibError> {
+
+    let surface_conductance: ThermalConductance = match interaction {
+        HeatTransferInteractionType::UserSpecifiedThermalConductance(
+            conductance) => conductance,
+        HeatTransferInteractionType::UserSpecifiedConvectionResistance(
+            convection_data) => {
+            let surf_area: Area = convection_data.surf_area.into();
+            convection_data.heat_transfer_coeff * surf_area
+        },
+        HeatTransferInteractionType::UserSpecifiedHeatAddition |
+        HeatTransferInteractionType::UserSpecifiedHeatFluxCustomArea(_) |
+        HeatTransferInteractionType::UserSpecifiedHeatFluxCylindricalOuterArea(_,_) |
+        HeatTransferInteractionType::UserSpecifiedHeatFluxCylindricalInnerArea(_,_) => {
+            return Ok(interaction);
+        },
+        _ => {
+            return Err(TuasLibError::GenericStringError(
+                "surfaces of radial arrays can only be linked with a user \
+                specified conductance, convection resistance or heat addition"
+                .to_string()));
+        },
+    };
+
+    Ok(HeatTransferInteractionType::UserSpecifiedThermalConductance(
+        (surface_conductance.recip() + half_shell_resistance).recip()))
+}
@@ -0,0 +1,191 @@
+use super::RadialConduction1DArray;
+use super::RadialConductionGeometry;
+use ndarray::*;
+use uom::si::f64::*;
+use crate::boussinesq_thermophysical_properties::thermal_conductivity::try_get_kappa_thermal_conductivity;
+use crate::boussinesq_thermophysical_properties::Material;
+use crate::tuas_lib_error::TuasLibError;
+use crate::boussinesq_thermophysical_properties::thermal_diffusivity::try_get_alpha_thermal_diffusivity;
+
+
+impl<G: RadialConductionGeometry> RadialConduction1DArray<G> {
+
+    /// gets the maximum timestep from the one dimensional
+    /// control volume for radial conduction
+    ///
+    /// this is the same as the cartesian array, except that the
+    /// mesh lengthscale is the shell thickness delta_r
+    pub fn get_max_timestep(&mut self,
+    max_temperature_change: TemperatureInterval) -> Result<Time,TuasLibError>{
+
+        let control_vol_pressure: Pressure = self.pressure_control_volume;
+        let control_vol_material: Material = self.material_control_volume;
+
+        // for the minimum conduction timescale, we need the
+        // maximum alpha
+        let mut thermal_diffusivity_coeff: DiffusionCoefficient =
+            try_get_alpha_thermal_diffusivity(control_vol_material,
+                self.get_bulk_temperature()?,
+                control_vol_pressure)?;
+
+        for temperature_reference in
+            self.temperature_array_current_timestep.iter() {
+
+                let alpha = try_get_alpha_thermal_diffusivity(
+                    control_vol_material,
+                    *temperature_reference,
+                    control_vol_pressure)?;
+
+                thermal_diffusivity_coeff = thermal_diffusivity_coeff.max(alpha);
+            }
+
+        let delta_r: Length = self.get_shell_thickness();
+
+        // implicit schemes are used, so the threshold for stability
+        // is about 1.0, out of some caution, let me use 0.8
+        let max_mesh_fourier_number: f64 = 0.8;
+
+        let max_conduction_timescale: Time = max_mesh_fourier_number *
+        delta_r *
+        delta_r /
+        thermal_diffusivity_coeff;
+
+        // we also need to take into account the timescales of each of
+        // the control volume at the boundaries
+
+        let max_timestep_outer_cv: Time =
+        self.outer_single_cv.get_max_timestep(max_temperature_change)?;
+
+        let max_timestep_inner_cv: Time =
+        self.inner_single_cv.get_max_timestep(max_temperature_change)?;
+
+        let maximum_timestep: Time = max_conduction_timescale
+            .min(max_timestep_outer_cv)
+            .min(max_timestep_inner_cv);
+
+        Ok(maximum_timestep)
+    }
+
+    /// This returns a conductance array
+    /// based on the same diagram as the cartesian array:
+    ///
+    /// Tmax            T[0]           T[1]          T[n-1]         T_ambient
+    /// [ignored]       T_innersingleCV             T_outersingleCV [ignored]
+    ///   | --- H[0] --- | --- H[1] --- | --- H[2] --- |  --- H[n] --- |
+    ///        [ignored]                                    [ignore]
+    ///
+    /// H[0] and H[n] are zero
+    ///
+    /// each conductance H[i+1] is made of two shell resistances
+    /// in series, from the middle of shell i to the face between
+    /// the shells (using k of node i), and from that face to the
+    /// middle of shell i+1 (using k of node i+1)
+    ///
+    /// for a solid sphere or rod, the innermost node is at delta_r/2
+    /// rather than at the centre, so there is no singularity
+    pub (in crate)
+    fn get_current_timestep_conductance_array(&self) ->
+    Result<Array1<ThermalConductance>,TuasLibError> {
+
+        let number_of_temperature_nodes: usize =
+        self.temperature_array_current_timestep.len();
+
+        let face_radii: Array1<Length> = self.get_face_radii();
+
+        let mut thermal_conductivity_vector: Vec<ThermalConductivity> = vec![];
+
+        for temperature_reference in
+            self.temperature_array_current_timestep.iter() {
+
+                thermal_conductivity_vector.push(
+                    try_get_kappa_thermal_conductivity(
+                        self.material_control_volume,
+                        *temperature_reference,
+                        self.pressure_control_volume)?);
+            }
+
+        // node radii are in the middle of each shell
+        let node_radius = |node_index: usize| -> Length {
+            0.5 * (face_radii[node_index] + face_radii[node_index + 1])
+        };
+
+        let mut thermal_conductance_array: Array1<ThermalConductance>
+        = Array::zeros(number_of_temperature_nodes + 1);
+
+        for index in 0..number_of_temperature_nodes - 1 {
+
+            let face_radius: Length = face_radii[index + 1];
+
+            let total_thermal_resistance: ThermalResistance =
+            self.geometry.get_shell_thermal_resistance(
+                node_radius(index),
+                face_radius,
+                thermal_conductivity_vector[index])
+            + self.geometry.get_shell_thermal_resistance(
+                face_radius,
+                node_radius(index + 1),
+                thermal_conductivity_vector[index + 1]);
+
+            thermal_conductance_array[index + 1] =
+                total_thermal_resistance.recip();
+        }
+
+        // ok done!
+        Ok(thermal_conductance_array)
+    }
+
+    /// conduction resistance between the outer node and the
+    /// outer surface
+    ///
+    /// the outer node sits at the middle of the outermost shell,
+    /// so heat leaving through the outer surface must first pass
+    /// through the outer half of that shell, from r_o - delta_r/2
+    /// to r_o
+    ///
+    /// k is taken at the outer node temperature
+    pub fn get_outer_half_shell_resistance(&self)
+    -> Result<ThermalResistance, TuasLibError> {
+
+        let outer_node_temperature: ThermodynamicTemperature =
+        *self.temperature_array_current_timestep.last().ok_or(
+            TuasLibError::GenericStringError(
+                "temperature array is empty".to_string()))?;
+
+        let thermal_conductivity: ThermalConductivity =
+        try_get_kappa_thermal_conductivity(
+            self.material_control_volume,
+            outer_node_temperature,
+            self.pressure_control_volume)?;
+
+        Ok(self.geometry.get_shell_thermal_resistance(
+            self.outer_radius - 0.5 * self.get_shell_thickness(),
+            self.outer_radius,
+            thermal_conductivity))
+    }
+
+    /// conduction resistance between the inner surface and the
+    /// inner node, from r_i to r_i + delta_r/2
+    ///
+    /// k is taken at the inner node temperature. A solid sphere
+    /// or rod has no inner surface, so this returns an error
+    pub fn get_inner_half_shell_resistance(&self)
+    -> Result<ThermalResistance, TuasLibError> {
+
+        if self.inner_radius.value <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "a solid sphere or rod has no inner surface to link to"
+                .to_string()));
+        }
+
+        let thermal_conductivity: ThermalConductivity =
+        try_get_kappa_thermal_conductivity(
+            self.material_control_volume,
+            self.temperature_array_current_timestep[0],
+            self.pressure_control_volume)?;
+
+        Ok(self.geometry.get_shell_thermal_resistance(
+            self.inner_radius,
+            self.inner_radius + 0.5 * self.get_shell_thickness(),
+            thermal_conductivity))
+    }
+}
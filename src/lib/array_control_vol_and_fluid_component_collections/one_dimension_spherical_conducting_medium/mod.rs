@@ -0,0 +1,96 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+
+use crate::array_control_vol_and_fluid_component_collections::one_dimension_radial_conducting_medium::RadialConduction1DArray;
+use crate::array_control_vol_and_fluid_component_collections::one_dimension_radial_conducting_medium::RadialConductionGeometry;
+use crate::single_control_vol::SingleCVNode;
+use crate::boussinesq_thermophysical_properties::Material;
+
+use crate::tuas_lib_error::TuasLibError;
+
+
+/// for 1D Spherical Conduction array,
+/// it is essentially an array control volume of one homogeneous
+/// material, shaped like a hollow or solid sphere
+///
+/// conduction is in the radial direction only, so this is meant
+/// for things like fuel pebbles or TRISO particle layers
+///
+/// the sphere is split into several spherical shells of equal
+/// thickness delta_r, each with a temperature node at its
+/// middle. Unlike the cartesian array, the volume of each shell
+/// (and therefore its thermal inertia) goes with r^2, and so does
+/// the area through which heat is conducted
+///
+/// the calculations are shared with the cylindrical array, see
+/// RadialConduction1DArray
+pub type SphericalConduction1DArray = RadialConduction1DArray<SphericalGeometry>;
+
+/// spherical shells for the RadialConduction1DArray
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct SphericalGeometry;
+
+impl RadialConductionGeometry for SphericalGeometry {
+
+    /// V = 4/3 PI (r_b^3 - r_a^3)
+    fn get_shell_volume(&self, r_a: Length, r_b: Length) -> Volume {
+        4.0/3.0 * PI * (r_b * r_b * r_b - r_a * r_a * r_a)
+    }
+
+    /// A = 4 PI r^2
+    fn get_face_area(&self, radius: Length) -> Area {
+        4.0 * PI * radius * radius
+    }
+
+    /// R = (1/r_a - 1/r_b) / (4 PI k)
+    fn get_shell_thermal_resistance(&self, r_a: Length, r_b: Length,
+        thermal_conductivity: ThermalConductivity) -> ThermalResistance {
+        (r_a.recip() - r_b.recip())/(4.0 * PI * thermal_conductivity)
+    }
+
+    /// the inner and outer shells have different volumes,
+    /// so I make a sphere of equivalent volume for each of them
+    fn new_shell_single_cv(&self, r_a: Length, r_b: Length,
+        material: Material,
+        initial_temperature: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<SingleCVNode, TuasLibError> {
+
+        let equivalent_diameter: Length =
+            (6.0 * self.get_shell_volume(r_a, r_b)/PI).cbrt();
+
+        SingleCVNode::new_sphere(
+            equivalent_diameter,
+            material,
+            initial_temperature,
+            pressure)
+    }
+}
+
+/// here, i mostly do constructors
+impl SphericalConduction1DArray {
+
+    /// constructs a new instance of the SphericalConduction1DArray
+    ///
+    /// for a solid sphere, set the inner radius to zero
+    pub fn new(material: Material,
+    initial_uniform_temperature: ThermodynamicTemperature,
+    uniform_pressure: Pressure,
+    inner_nodes: usize,
+    inner_radius: Length,
+    outer_radius: Length) -> Result<Self,TuasLibError> {
+
+        Self::new_with_geometry(SphericalGeometry,
+            material,
+            initial_uniform_temperature,
+            uniform_pressure,
+            inner_nodes,
+            inner_radius,
+            outer_radius)
+    }
+}
+
+/// tests for the spherical array against analytical solutions
+/// for radial conduction
+#[cfg(test)]
+pub mod tests;
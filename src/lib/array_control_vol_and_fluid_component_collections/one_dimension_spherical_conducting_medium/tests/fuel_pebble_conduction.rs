@@ -0,0 +1,206 @@
+use uom::si::f64::*;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+
+/// graphite-like pebble material with constant properties,
+/// so that analytical solutions apply
+pub fn constant_property_graphite() -> crate::boussinesq_thermophysical_properties::SolidMaterial {
+    use uom::si::length::meter;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::SolidMaterial;
+
+    fn cp(_temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(700.0)
+    }
+    fn k(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+        ThermalConductivity::new::<watt_per_meter_kelvin>(20.0)
+    }
+    fn rho(_temperature: ThermodynamicTemperature) -> MassDensity {
+        MassDensity::new::<kilogram_per_cubic_meter>(1800.0)
+    }
+
+    SolidMaterial::CustomSolid(
+        (ThermodynamicTemperature::new::<kelvin>(250.0),
+        ThermodynamicTemperature::new::<kelvin>(2500.0)),
+        cp, k, rho, Length::new::<meter>(0.0))
+}
+
+/// a 6 cm pebble generating 500 W, cooled by a convective
+/// conductance of h = 1000 W/(m^2 K) over its surface
+/// to 900 K coolant
+///
+/// at steady state, all 500 W leaves through the surface,
+/// so the surface is at
+///
+/// T_s = 900 K + Q/(hA) = 944.2 K
+///
+/// and the centre is hotter than the surface by
+///
+/// T_c - T_s = q''' R^2/(6k) = Q/(8 PI k R) = 33.2 K
+///
+/// with the parabolic profile
+///
+/// T(r) = T_s + (T_c - T_s)(1 - r^2/R^2)
+///
+/// the outer and innermost nodes are at half a shell thickness
+/// from the surface and centre, so I compare against the
+/// analytical temperature at those radii
+#[test]
+pub fn solid_pebble_steady_state_temperature_profile(){
+    use std::f64::consts::PI;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::temperature_interval::kelvin as interval_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::SphericalConduction1DArray;
+    use crate::boundary_conditions::BCType;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+
+    let pebble_radius = Length::new::<meter>(0.03);
+    let coolant_temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+    let pebble_power = Power::new::<watt>(500.0);
+
+    let mut pebble = SphericalConduction1DArray::new(
+        constant_property_graphite().into(),
+        coolant_temperature,
+        Pressure::new::<atmosphere>(1.0),
+        18,
+        Length::new::<meter>(0.0),
+        pebble_radius).unwrap();
+
+    pebble.set_heat_generation(pebble_power);
+
+    let surface_area: Area = pebble.get_outer_surface_area();
+    approx::assert_relative_eq!(
+        surface_area.value,
+        4.0 * PI * 0.03_f64.powi(2),
+        max_relative = 1e-12);
+    let convective_conductance: ThermalConductance =
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(1000.0)
+        * surface_area;
+
+    // the max timestep is limited by the explicit stability
+    // criterion of the boundary cvs, but the array itself is implicit,
+    // so I use a larger timestep to save time
+    let max_timestep = pebble.get_max_timestep(
+        TemperatureInterval::new::<interval_kelvin>(10.0)).unwrap();
+    assert!(max_timestep.get::<second>() > 0.0);
+    let timestep = Time::new::<second>(0.5);
+
+    for _ in 0..1200 {
+        let mut coolant: HeatTransferEntity =
+            BCType::new_const_temperature(coolant_temperature).into();
+
+        pebble.link_outer_surface_to_heat_transfer_entity(
+            &mut coolant,
+            HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                convective_conductance)).unwrap();
+
+        pebble.advance_timestep(timestep).unwrap();
+    }
+
+    let temperatures = pebble.get_temperature_vector().unwrap();
+    let node_radii = pebble.get_node_radii();
+
+    let surface_temperature: f64 = 900.0
+        + (pebble_power/convective_conductance).get::<interval_kelvin>();
+    let centre_temperature: f64 = surface_temperature
+        + 500.0/(8.0 * PI * 20.0 * 0.03);
+
+    let analytical_temperature = |radius: Length| -> f64 {
+        surface_temperature + (centre_temperature - surface_temperature)
+            * (1.0 - (radius.get::<meter>()/0.03).powi(2))
+    };
+
+    // temperature rises above the coolant
+    approx::assert_relative_eq!(
+        temperatures.last().unwrap().get::<kelvin>() - 900.0,
+        analytical_temperature(*node_radii.last().unwrap()) - 900.0,
+        max_relative = 0.01);
+
+    approx::assert_relative_eq!(
+        pebble.get_centre_temperature().get::<kelvin>() - 900.0,
+        analytical_temperature(node_radii[0]) - 900.0,
+        max_relative = 0.01);
+
+    // a solid pebble has no inner surface to link to
+    let mut coolant: HeatTransferEntity =
+        BCType::new_const_temperature(coolant_temperature).into();
+    assert!(pebble.link_inner_surface_to_heat_transfer_entity(
+            &mut coolant,
+            HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                convective_conductance)).is_err());
+
+    // temperatures should drop monotonically from centre to surface
+    assert!(temperatures.windows(2).all(|pair| pair[0] > pair[1]));
+}
+
+/// a 6 cm pebble with a 5 cm diameter fuelled zone, adiabatic,
+/// so all the heat generated goes into raising its temperature
+///
+/// Q t = rho cp V (T_bulk - T_initial)
+///
+/// which is 200 W for 100 s in a 0.2036 kg pebble, or 140.3 K
+///
+/// no heat is generated in the unfuelled graphite shell,
+/// so the outer shell is colder than the fuelled zone
+#[test]
+pub fn adiabatic_fuel_pebble_energy_balance(){
+    use std::f64::consts::PI;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::SphericalConduction1DArray;
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(600.0);
+
+    let mut pebble = SphericalConduction1DArray::new(
+        constant_property_graphite().into(),
+        initial_temperature,
+        Pressure::new::<atmosphere>(1.0),
+        10,
+        Length::new::<meter>(0.0),
+        Length::new::<meter>(0.03)).unwrap();
+
+    pebble.set_heat_generation(Power::new::<watt>(200.0));
+    pebble.set_heat_generation_within_radius(
+        Length::new::<meter>(0.025)).unwrap();
+
+    // heated radius must be outside the inner radius
+    assert!(pebble.set_heat_generation_within_radius(
+            Length::new::<meter>(0.0)).is_err());
+
+    for _ in 0..200 {
+        pebble.advance_timestep(Time::new::<second>(0.5)).unwrap();
+    }
+
+    let pebble_heat_capacity: f64 = 1800.0 * 700.0
+        * 4.0/3.0 * PI * 0.03_f64.powi(3);
+
+    approx::assert_relative_eq!(
+        pebble.get_bulk_temperature().unwrap().get::<kelvin>(),
+        600.0 + 200.0 * 100.0/pebble_heat_capacity,
+        max_relative = 1e-9);
+
+    let temperatures = pebble.get_temperature_vector().unwrap();
+    assert!(temperatures[0] > temperatures[11]);
+
+    // a solid sphere array cannot have its inner radius larger
+    // than its outer radius
+    assert!(SphericalConduction1DArray::new(
+            constant_property_graphite().into(),
+            initial_temperature,
+            Pressure::new::<atmosphere>(1.0),
+            10,
+            Length::new::<meter>(0.03),
+            Length::new::<meter>(0.02)).is_err());
+}
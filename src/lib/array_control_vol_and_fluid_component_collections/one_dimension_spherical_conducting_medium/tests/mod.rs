@@ -0,0 +1,9 @@
+/// a solid sphere with heat generation, cooled at its outer 
+/// surface, should approach the analytical steady state 
+/// temperature profile
+///
+/// T(r) - T_s = q''' (R^2 - r^2)/(6k)
+///
+/// and heat generated in an adiabatic sphere should all go into 
+/// raising its temperature
+pub mod fuel_pebble_conduction;
@@ -55,7 +55,8 @@
 //
 // Btw, I have no affiliation with the Rust foundation.
 use uom::si::f64::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::pressure::pascal;
 use uom::si::mass_density::kilogram_per_cubic_meter;
 use uom::si::dynamic_viscosity::pascal_second;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
@@ -302,6 +303,33 @@ pub fn get_temperature_from_enthalpy(
 }
 
 
+/// function to obtain dowtherm A vapour pressure
+/// given a temperature
+///
+/// this is a Clausius-Clapeyron fit 
+///
+/// ln (P/Pa) = 22.587 - 5865 K/T
+///
+/// through the normal boiling point (257.1 C, 101.325 kPa) 
+/// and the vapour pressure at 400 C (10.6 bar) from the 
+/// Dowtherm A datasheet. At 200 and 300 C, it is within 10% 
+/// of the tabulated values. Within the 20-180C range, the vapour 
+/// pressure is less than a quarter of an atmosphere, so this 
+/// is mainly for NPSH checks.
+///
+/// Therminol VP-1 is the same eutectic mixture of 
+/// biphenyl and diphenyl oxide, so this is used for VP-1 as well
+pub fn get_dowtherm_a_vapour_pressure(
+    fluid_temp: ThermodynamicTemperature) -> Result<Pressure,TuasLibError> {
+
+    range_check_dowtherm_a(fluid_temp)?;
+
+    let temperature_kelvin = fluid_temp.get::<kelvin>();
+    let vapour_pressure_pascal = (22.587 - 5865.0/temperature_kelvin).exp();
+
+    Ok(Pressure::new::<pascal>(vapour_pressure_pascal))
+}
+
 /// function checks if a fluid temperature falls in a range (20-180C)
 ///
 /// If it falls outside this range, it will panic
@@ -57,6 +57,7 @@
 use uom::si::f64::*;
 use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
 use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::pressure::millimeter_of_mercury;
 use uom::si::dynamic_viscosity::centipoise;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
 use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
@@ -531,6 +532,32 @@ pub fn get_temperature_from_enthalpy(
 
 }
 
+/// function to obtain flibe vapour pressure
+/// given a temperature
+///
+/// from Cantor's correlation as reported in 
+/// Romatoski and Hu (2017), 
+///
+/// log10 (P/mmHg) = 9.024 - 10040 K/T
+///
+/// this gives about 13 Pa at 1000 K, so the vapour pressure 
+/// of FLiBe is negligible compared to cover gas pressures
+///
+/// Romatoski, R. R., & Hu, L. W. (2017). Fluoride salt coolant properties 
+/// for nuclear reactor applications: A review. Annals 
+/// of Nuclear Energy, 109, 635-647.
+pub fn get_flibe_vapour_pressure(
+    fluid_temp: ThermodynamicTemperature) -> Result<Pressure,TuasLibError> {
+
+    range_check_flibe_salt(fluid_temp)?;
+
+    let temperature_kelvin = fluid_temp.get::<kelvin>();
+    let vapour_pressure_mm_hg = 10.0_f64.powf(
+        9.024 - 10040.0/temperature_kelvin);
+
+    Ok(Pressure::new::<millimeter_of_mercury>(vapour_pressure_mm_hg))
+}
+
 /// function checks if a fluid temperature falls in a range 
 ///
 /// If it falls outside this range, it will panic
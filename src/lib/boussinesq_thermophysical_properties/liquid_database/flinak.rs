@@ -57,6 +57,7 @@
 use uom::si::f64::*;
 use uom::si::thermodynamic_temperature::kelvin;
 use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::pressure::pascal;
 use uom::si::dynamic_viscosity::centipoise;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
 use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
@@ -289,6 +290,20 @@ pub fn get_temperature_from_enthalpy(
         new::<kelvin>(fluid_temperature_degrees_kelvin));
 
 }
+/// function to obtain flinak vapour pressure
+/// given a temperature
+///
+/// FLiNaK has a vapour pressure of order 1 Pa or less over its 
+/// range of applicability (940 - 1073 K). This is negligible 
+/// compared to cover gas pressures, so zero is returned
+pub fn get_flinak_vapour_pressure(
+    fluid_temp: ThermodynamicTemperature) -> Result<Pressure,TuasLibError> {
+
+    range_check_flinak_salt(fluid_temp)?;
+
+    Ok(Pressure::new::<pascal>(0.0))
+}
+
 /// function checks if a fluid temperature falls in a range 
 ///
 /// If it falls outside this range, it will panic
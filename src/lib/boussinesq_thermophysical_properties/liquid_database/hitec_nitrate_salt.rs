@@ -57,6 +57,7 @@
 use uom::si::f64::*;
 use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
 use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::pressure::pascal;
 use uom::si::dynamic_viscosity::pascal_second;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
 use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
@@ -491,6 +492,19 @@ pub fn get_temperature_from_enthalpy(
 
 }
 
+/// function to obtain HITEC vapour pressure
+/// given a temperature
+///
+/// nitrate salts have a negligible vapour pressure below 
+/// 800 K compared to cover gas pressures, so zero is returned
+pub fn get_hitec_vapour_pressure(
+    fluid_temp: ThermodynamicTemperature) -> Result<Pressure,TuasLibError> {
+
+    range_check_hitec_salt(fluid_temp)?;
+
+    Ok(Pressure::new::<pascal>(0.0))
+}
+
 /// function checks if a fluid temperature falls in a range 
 ///
 /// If it falls outside this range, it will panic
@@ -258,6 +258,9 @@ pub mod prandtl;
 /// surface roughness 
 pub mod solid_material_surface_roughness;
 
+/// vapour pressure of liquids, for NPSH checks
+pub mod vapour_pressure;
+
 
 /// functions for temperature ranges
 /// this gives the max or min temperatures for each material
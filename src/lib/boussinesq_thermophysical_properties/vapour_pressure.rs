@@ -0,0 +1,154 @@
+use uom::si::f64::Pressure;
+use uom::si::f64::ThermodynamicTemperature;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::liquid_database::dowtherm_a::get_dowtherm_a_vapour_pressure;
+use super::liquid_database::flibe::get_flibe_vapour_pressure;
+use super::liquid_database::flinak::get_flinak_vapour_pressure;
+use super::liquid_database::hitec_nitrate_salt::get_hitec_vapour_pressure;
+use super::LiquidMaterial;
+use super::Material;
+use super::LiquidMaterial::*;
+
+/// returns the saturation (vapour) pressure of a liquid
+/// given a material and temperature
+///
+/// this is used for NPSH (net positive suction head) checks,
+/// solids do not have a vapour pressure here and will
+/// return an error
+///
+/// example:
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::kilopascal;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use tuas_boussinesq_solver::
+/// boussinesq_thermophysical_properties::vapour_pressure::try_get_vapour_pressure;
+///
+/// use tuas_boussinesq_solver::
+/// boussinesq_thermophysical_properties::LiquidMaterial::DowthermA;
+///
+/// use tuas_boussinesq_solver::
+/// boussinesq_thermophysical_properties::Material;
+///
+/// let dowtherm_a = Material::Liquid(DowthermA);
+/// let temperature = ThermodynamicTemperature::new::<degree_celsius>(150.0);
+///
+/// let vapour_pressure: Pressure =
+/// try_get_vapour_pressure(dowtherm_a, temperature).unwrap();
+///
+/// // well below atmospheric pressure
+/// assert!(vapour_pressure.get::<kilopascal>() < 10.0);
+///
+/// ```
+#[inline]
+pub fn try_get_vapour_pressure(material: Material,
+    temperature: ThermodynamicTemperature) -> Result<Pressure, TuasLibError> {
+
+    match material {
+        Material::Solid(_) => {
+            Err(TuasLibError::TypeConversionErrorMaterial)
+        },
+        Material::Liquid(liquid_material) => {
+            liquid_material.try_get_vapour_pressure(temperature)
+        },
+    }
+}
+
+impl Material {
+    /// returns the vapour pressure of the material,
+    /// only liquids have a vapour pressure
+    pub fn try_get_vapour_pressure(&self,
+        temperature: ThermodynamicTemperature) -> Result<Pressure, TuasLibError>{
+
+        try_get_vapour_pressure(*self, temperature)
+    }
+}
+
+impl LiquidMaterial {
+    /// returns the liquid vapour pressure in a result enum
+    ///
+    /// there is no vapour pressure data for YD325 and custom
+    /// liquids, so these will return an error. For these,
+    /// supply the vapour pressure directly to the NPSH functions
+    #[inline]
+    pub fn try_get_vapour_pressure(&self,
+        fluid_temp: ThermodynamicTemperature,)
+        -> Result<Pressure, TuasLibError>{
+
+        let vapour_pressure: Pressure = match self {
+            DowthermA => get_dowtherm_a_vapour_pressure(fluid_temp)?,
+            TherminolVP1 => get_dowtherm_a_vapour_pressure(fluid_temp)?,
+            HITEC => get_hitec_vapour_pressure(fluid_temp)?,
+            FLiBe => get_flibe_vapour_pressure(fluid_temp)?,
+            FLiNaK => get_flinak_vapour_pressure(fluid_temp)?,
+            YD325 => {
+                return Err(TuasLibError::GenericStringError(
+                    "vapour pressure data not available for YD325".to_string()));
+            },
+            CustomLiquid(..) => {
+                return Err(TuasLibError::GenericStringError(
+                    "vapour pressure data not available for custom liquids"
+                    .to_string()));
+            },
+        };
+
+        Ok(vapour_pressure)
+    }
+}
+
+/// vapour pressure at the normal boiling point of Dowtherm A
+/// should be one atmosphere, and salts should have negligible
+/// vapour pressures
+#[test]
+pub fn vapour_pressure_of_liquids() -> Result<(), TuasLibError>{
+
+    use uom::si::pressure::{atmosphere, pascal};
+    use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+
+    // the fit goes through 257.1 C, but this is outside the
+    // range check so we test the correlation directly
+    let temperature_kelvin = 257.1 + 273.15;
+    let normal_boiling_pressure = Pressure::new::<pascal>(
+        (22.587 - 5865.0_f64/temperature_kelvin).exp());
+
+    approx::assert_relative_eq!(
+        normal_boiling_pressure.get::<atmosphere>(),
+        1.0,
+        max_relative = 0.01);
+
+    // vapour pressure increases with temperature
+    let dowtherm_a = Material::Liquid(DowthermA);
+    let p_vap_low = try_get_vapour_pressure(dowtherm_a,
+        ThermodynamicTemperature::new::<degree_celsius>(80.0))?;
+    let p_vap_high = try_get_vapour_pressure(dowtherm_a,
+        ThermodynamicTemperature::new::<degree_celsius>(180.0))?;
+
+    assert!(p_vap_high > p_vap_low);
+    assert!(p_vap_high.get::<atmosphere>() < 0.25);
+
+    // therminol VP1 is the same as dowtherm A
+    assert_eq!(p_vap_high,
+        TherminolVP1.try_get_vapour_pressure(
+            ThermodynamicTemperature::new::<degree_celsius>(180.0))?);
+
+    // flibe about 13 Pa at 1000 K
+    let p_vap_flibe = FLiBe.try_get_vapour_pressure(
+        ThermodynamicTemperature::new::<kelvin>(1000.0))?;
+    approx::assert_relative_eq!(
+        p_vap_flibe.get::<pascal>(),
+        12.85,
+        max_relative = 0.01);
+
+    // no data, solids and out of range temperatures are errors
+    assert!(YD325.try_get_vapour_pressure(
+        ThermodynamicTemperature::new::<degree_celsius>(80.0)).is_err());
+    assert!(try_get_vapour_pressure(
+        Material::Solid(super::SolidMaterial::SteelSS304L),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0)).is_err());
+    assert!(dowtherm_a.try_get_vapour_pressure(
+        ThermodynamicTemperature::new::<degree_celsius>(300.0)).is_err());
+
+    Ok(())
+}
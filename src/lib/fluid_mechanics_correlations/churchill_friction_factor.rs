@@ -3,6 +3,8 @@ extern crate peroxide;
 use peroxide::prelude::*;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::heat_transfer_correlations::correlation_validity::{
+    CorrelationValidityEnvelope, CorrelationValidityRange};
 
 // This library was developed for use in my PhD thesis under supervision 
 // of Professor Per F. Peterson. It is part of a thermal hydraulics
@@ -122,6 +124,20 @@ fn fanning(reynolds_number: f64, roughness_ratio: f64) -> Result<f64,TuasLibErro
     return Ok(fanning_friction_factor);
 }
 
+/// validity envelope of the churchill correlation
+///
+/// it covers laminar, transition and turbulent flow, but 
+/// the turbulent terms are fitted to the moody chart, which only 
+/// goes up to Re = 1e8
+///
+/// Churchill, S. W. (1977). Friction-factor equation spans all 
+/// fluid-flow regimes. Chemical Engineering, 84(24), 91-92.
+pub const CHURCHILL_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new_upper_bound(1.0e8),
+        ..CorrelationValidityEnvelope::new_unbounded("Churchill")
+    };
+
 #[inline]
 /// calculates darcy friction factor using churchill correlation
 pub fn darcy(reynolds_number: f64, roughness_ratio: f64) -> 
@@ -129,6 +145,42 @@ Result<f64,TuasLibError> {
     return Ok(4.0*fanning(reynolds_number, roughness_ratio)?);
 }
 
+/// calculates darcy friction factor using churchill correlation,
+/// but with the laminar term replaced by that of a non circular 
+/// duct with a given laminar darcy fRe product
+///
+/// the turbulent terms are unchanged, so the hydraulic diameter 
+/// approximation is used in turbulent flow
+///
+/// for a circular pipe, fRe = 64 and this is the same as darcy
+pub fn darcy_with_laminar_friction_reynolds_product(reynolds_number: f64, 
+    roughness_ratio: f64,
+    laminar_darcy_friction_reynolds_product: f64) -> 
+Result<f64,TuasLibError> {
+
+    if reynolds_number <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re <= 0.0 for churchill friction factor".to_string()));
+    }
+
+    if roughness_ratio < 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "roughness_ratio<0.0".to_string()));
+    }
+
+    // the laminar term in the churchill correlation is (8/Re)^12 
+    // which gives f_darcy = 64/Re, so it is scaled by fRe/64
+    let laminar_term = (laminar_darcy_friction_reynolds_product/8.0
+        /reynolds_number).powf(12.0);
+
+    let captial_a_term = churchill_friction_captial_a(reynolds_number,roughness_ratio);
+    let captial_b_term = churchill_friction_captial_b(reynolds_number);
+
+    let turbulent_term = (1.0/(captial_a_term+captial_b_term)).powf(3.0/2.0);
+
+    Ok(8.0 * (laminar_term + turbulent_term).powf(1.0/12.0))
+}
+
 /// calculates moody friction factor using churchill correlation
 /// basically same as darcy
 pub fn moody(reynolds_number: f64, roughness_ratio: f64) -> 
@@ -0,0 +1,359 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::angle::degree;
+use uom::si::length::inch;
+use uom::ConstZero;
+
+use crate::tuas_lib_error::TuasLibError;
+
+// Fittings catalogue using Reynolds number dependent K factors
+//
+// Most fittings use the 3-K method of Darby:
+//
+// K = K_1/Re + K_i (1 + K_d/D_n^0.3)
+//
+// where D_n is the internal diameter in inches
+//
+// Darby, R. (2001). Chemical engineering fluid mechanics,
+// revised and expanded. CRC Press.
+//
+// Entrances and exits use the 2-K method of Hooper, and
+// sudden contractions and expansions use the Hooper
+// area ratio correlations
+//
+// Hooper, W. B. (1981). The two-K method predicts head losses
+// in pipe fittings. Chem. Eng, 24, 96-100.
+//
+// Hooper, W. B. (1988). Calculate head loss caused by change
+// in pipe size. Chemical Engineering, 95(16), 89.
+//
+// All K values are based on the velocity in the component
+// the fittings are placed in. For contractions and expansions,
+// this is the upstream (in the forward flow direction) velocity.
+
+/// a pipe fitting which contributes a Reynolds number dependent
+/// form loss K
+#[derive(Clone, PartialEq, Copy, Debug)]
+pub enum PipeFitting {
+    /// flanged or welded elbow with a bend radius to
+    /// diameter ratio (r/D) and a bend angle
+    ///
+    /// Darby's 3-K constants for 45, 90 and 180 degree elbows and
+    /// r/D of 1, 2, 4 and 6 are interpolated linearly, and
+    /// are clamped outside of this range
+    Elbow {
+        /// bend radius to diameter ratio (r/D)
+        bend_radius_to_diameter: Ratio,
+        /// bend angle
+        angle: Angle
+    },
+    /// flanged or welded standard tee, flow through the run
+    TeeThroughRun,
+    /// flanged or welded standard tee, flow through the branch
+    /// (tee used as an elbow)
+    TeeThroughBranch,
+    /// sudden contraction, flow from the component into a smaller
+    /// pipe, with the small to large diameter ratio (d/D) supplied
+    SuddenContraction {
+        /// small to large diameter ratio (d/D)
+        diameter_ratio: Ratio
+    },
+    /// sudden expansion, flow from the component into a larger
+    /// pipe, with the small to large diameter ratio (d/D) supplied
+    SuddenExpansion {
+        /// small to large diameter ratio (d/D)
+        diameter_ratio: Ratio
+    },
+    /// sharp edged (flush) entrance from a tank
+    SharpEntrance,
+    /// rounded entrance from a tank, r/D >= 0.15
+    RoundedEntrance,
+    /// inward projecting (Borda) entrance from a tank
+    ProjectingEntrance,
+    /// pipe exit into a tank
+    Exit,
+    /// user supplied 3-K constants (K_1, K_i, K_d)
+    Custom3K(Ratio, Ratio, Ratio),
+}
+
+/// linear interpolation within a table, clamped at the end points
+fn clamped_table_interpolation(x: f64, table: &[(f64, f64)]) -> f64 {
+
+    if x <= table[0].0 {
+        return table[0].1;
+    }
+
+    for window in table.windows(2) {
+        let (x_0, y_0) = window[0];
+        let (x_1, y_1) = window[1];
+
+        if x <= x_1 {
+            return y_0 + (y_1 - y_0) * (x - x_0) / (x_1 - x_0);
+        }
+    }
+
+    table[table.len() - 1].1
+}
+
+impl PipeFitting {
+
+    /// returns the 3-K constants (K_1, K_i, K_d) for fittings which
+    /// use the 3-K or 2-K method, contractions and expansions
+    /// return None
+    pub fn three_k_constants(&self) -> Option<(f64, f64, f64)> {
+
+        match self {
+            PipeFitting::Elbow { bend_radius_to_diameter, angle } => {
+                let radius_ratio = bend_radius_to_diameter.get::<ratio>();
+                let angle_degrees = angle.get::<degree>().abs();
+
+                // Darby's flanged/welded 90 degree elbow K_i
+                let k_i_ninety_degrees = clamped_table_interpolation(
+                    radius_ratio,
+                    &[(1.0, 0.091), (2.0, 0.056), (4.0, 0.066), (6.0, 0.075)]);
+
+                // K_1 for 45, 90 and 180 degree elbows
+                let k_one = clamped_table_interpolation(
+                    angle_degrees,
+                    &[(0.0, 0.0), (45.0, 500.0), (90.0, 800.0), (180.0, 1000.0)]);
+
+                // ratios of 45 and 180 degree K_i to the 90 degree K_i,
+                // Darby (2001) gives about 0.78 for 45 degree elbows
+                let angle_factor = clamped_table_interpolation(
+                    angle_degrees,
+                    &[(0.0, 0.0), (45.0, 0.78), (90.0, 1.0), (180.0, 1.32)]);
+
+                Some((k_one, k_i_ninety_degrees * angle_factor, 4.0))
+            },
+            PipeFitting::TeeThroughRun => Some((150.0, 0.05, 4.0)),
+            PipeFitting::TeeThroughBranch => Some((800.0, 0.28, 4.0)),
+            PipeFitting::SharpEntrance => Some((160.0, 0.5, 0.0)),
+            PipeFitting::RoundedEntrance => Some((160.0, 0.1, 0.0)),
+            PipeFitting::ProjectingEntrance => Some((160.0, 1.0, 0.0)),
+            PipeFitting::Exit => Some((0.0, 1.0, 0.0)),
+            PipeFitting::Custom3K(k_one, k_i, k_d) => {
+                Some((k_one.get::<ratio>(), k_i.get::<ratio>(), k_d.get::<ratio>()))
+            },
+            PipeFitting::SuddenContraction { .. } => None,
+            PipeFitting::SuddenExpansion { .. } => None,
+        }
+    }
+
+    /// gets the form loss K of this fitting alone
+    ///
+    /// needs the Reynolds number, darcy friction factor and diameter
+    /// of the component (the friction factor is only used for
+    /// contractions and expansions)
+    pub fn get_form_loss_k(&self,
+        reynolds_number: Ratio,
+        darcy_friction_factor: Ratio,
+        diameter: Length) -> Result<Ratio, TuasLibError> {
+
+        let mut fittings = FittingsFormLoss::default();
+        fittings.add_fitting(*self, diameter)?;
+
+        Ok(fittings.get_form_loss_k(reynolds_number, darcy_friction_factor))
+    }
+}
+
+/// a collection of fittings summed into a form which can be
+/// evaluated quickly at any Reynolds number
+///
+/// Since the 3-K method is linear in each constant, all 3-K fittings
+/// sum to K = K_1/Re + K_inf once the diameter is known.
+/// Contractions and expansions are summed by their area ratio terms
+/// so the laminar and turbulent Hooper forms can be kept.
+///
+/// This is Copy so that it can be stored within
+/// DimensionlessDarcyLossCorrelations
+#[derive(Clone, PartialEq, Copy, Debug)]
+pub struct FittingsFormLoss {
+    /// sum of K_1 over all 3-K fittings
+    pub k_one: Ratio,
+    /// sum of K_i (1 + K_d/D_n^0.3) over all 3-K fittings
+    pub k_infinity: Ratio,
+    /// sum of (1/beta^4 - 1) over contractions
+    pub contraction_laminar_area_term: Ratio,
+    /// sum of (1/beta^2)(1/beta^2 - 1) over contractions
+    pub contraction_turbulent_area_term: Ratio,
+    /// sum of (1 - beta^4) over expansions
+    pub expansion_laminar_area_term: Ratio,
+    /// sum of (1 - beta^2)^2 over expansions
+    pub expansion_turbulent_area_term: Ratio,
+}
+
+impl Default for FittingsFormLoss {
+    /// no fittings, K = 0
+    fn default() -> Self {
+        Self {
+            k_one: Ratio::ZERO,
+            k_infinity: Ratio::ZERO,
+            contraction_laminar_area_term: Ratio::ZERO,
+            contraction_turbulent_area_term: Ratio::ZERO,
+            expansion_laminar_area_term: Ratio::ZERO,
+            expansion_turbulent_area_term: Ratio::ZERO,
+        }
+    }
+}
+
+impl FittingsFormLoss {
+
+    /// builds the form loss from a list of fittings placed in
+    /// a component of a given internal diameter
+    pub fn new(fittings: &[PipeFitting], diameter: Length) ->
+    Result<Self, TuasLibError> {
+
+        let mut fittings_form_loss = Self::default();
+
+        for fitting in fittings {
+            fittings_form_loss.add_fitting(*fitting, diameter)?;
+        }
+
+        Ok(fittings_form_loss)
+    }
+
+    /// adds a fitting to the collection
+    pub fn add_fitting(&mut self,
+        fitting: PipeFitting,
+        diameter: Length) -> Result<(), TuasLibError> {
+
+        if diameter.value <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "fitting diameter <= 0.0".to_string()));
+        }
+
+        if let Some((k_one, k_i, k_d)) = fitting.three_k_constants() {
+            let diameter_inches = diameter.get::<inch>();
+
+            self.k_one += Ratio::new::<ratio>(k_one);
+            self.k_infinity += Ratio::new::<ratio>(
+                k_i * (1.0 + k_d / diameter_inches.powf(0.3)));
+
+            return Ok(());
+        }
+
+        match fitting {
+            PipeFitting::SuddenContraction { diameter_ratio } => {
+                let beta = check_diameter_ratio(diameter_ratio)?;
+                let beta_squared_inverse = 1.0/beta.powi(2);
+
+                self.contraction_laminar_area_term += Ratio::new::<ratio>(
+                    beta_squared_inverse.powi(2) - 1.0);
+                self.contraction_turbulent_area_term += Ratio::new::<ratio>(
+                    beta_squared_inverse * (beta_squared_inverse - 1.0));
+            },
+            PipeFitting::SuddenExpansion { diameter_ratio } => {
+                let beta = check_diameter_ratio(diameter_ratio)?;
+
+                self.expansion_laminar_area_term += Ratio::new::<ratio>(
+                    1.0 - beta.powi(4));
+                self.expansion_turbulent_area_term += Ratio::new::<ratio>(
+                    (1.0 - beta.powi(2)).powi(2));
+            },
+            _ => unreachable!("3-K fittings are handled above"),
+        }
+
+        Ok(())
+    }
+
+    /// adds all fittings of another collection to this one
+    ///
+    /// both must be based on the same component velocity
+    pub fn combine(&self, other: &FittingsFormLoss) -> Self {
+        Self {
+            k_one: self.k_one + other.k_one,
+            k_infinity: self.k_infinity + other.k_infinity,
+            contraction_laminar_area_term:
+                self.contraction_laminar_area_term
+                + other.contraction_laminar_area_term,
+            contraction_turbulent_area_term:
+                self.contraction_turbulent_area_term
+                + other.contraction_turbulent_area_term,
+            expansion_laminar_area_term:
+                self.expansion_laminar_area_term
+                + other.expansion_laminar_area_term,
+            expansion_turbulent_area_term:
+                self.expansion_turbulent_area_term
+                + other.expansion_turbulent_area_term,
+        }
+    }
+
+    /// gets the total form loss K at a given Reynolds number
+    ///
+    /// the darcy friction factor is used only by the turbulent
+    /// Hooper contraction and expansion correlations
+    ///
+    /// the convention is to disregard directionality, so
+    /// negative Reynolds numbers give the same K as positive ones
+    ///
+    /// the laminar Hooper K is larger than the turbulent one at the
+    /// regime switch, so past the switch the loss (K Re^2) is held
+    /// at its laminar value until the turbulent branch catches up.
+    /// This keeps K continuous and Be(Re) monotonic for the Re from Be
+    /// inversion
+    pub fn get_form_loss_k(&self,
+        reynolds_number: Ratio,
+        darcy_friction_factor: Ratio) -> Ratio {
+
+        let reynolds = reynolds_number.get::<ratio>().abs();
+
+        if reynolds == 0.0 {
+            return Ratio::ZERO;
+        }
+
+        let friction_factor = darcy_friction_factor.get::<ratio>();
+
+        let three_k_fittings = self.k_one.get::<ratio>() / reynolds
+            + self.k_infinity.get::<ratio>();
+
+        // Hooper (1988) contraction, laminar below Re = 2500
+        let contraction = get_hooper_k_across_regime_switch(
+            reynolds,
+            2500.0,
+            |reynolds| (1.2 + 160.0/reynolds)
+                * self.contraction_laminar_area_term.get::<ratio>(),
+            (0.6 + 0.48 * friction_factor)
+                * self.contraction_turbulent_area_term.get::<ratio>());
+
+        // Hooper (1988) expansion, laminar below Re = 4000
+        let expansion = get_hooper_k_across_regime_switch(
+            reynolds,
+            4000.0,
+            |_| 2.0 * self.expansion_laminar_area_term.get::<ratio>(),
+            (1.0 + 0.8 * friction_factor)
+                * self.expansion_turbulent_area_term.get::<ratio>());
+
+        Ratio::new::<ratio>(three_k_fittings + contraction + expansion)
+    }
+}
+
+/// laminar K below the switch Reynolds number, above it, the larger
+/// of the turbulent K and the laminar K at the switch scaled by
+/// (Re_switch/Re)^2, so that the loss K Re^2 does not drop
+fn get_hooper_k_across_regime_switch(
+    reynolds: f64,
+    switch_reynolds: f64,
+    laminar_k: impl Fn(f64) -> f64,
+    turbulent_k: f64) -> f64 {
+
+    if reynolds < switch_reynolds {
+        return laminar_k(reynolds);
+    }
+
+    let laminar_k_held_at_switch = laminar_k(switch_reynolds)
+        * (switch_reynolds/reynolds).powi(2);
+
+    turbulent_k.max(laminar_k_held_at_switch)
+}
+
+fn check_diameter_ratio(diameter_ratio: Ratio) -> Result<f64, TuasLibError> {
+    let beta = diameter_ratio.get::<ratio>();
+
+    if beta <= 0.0 || beta > 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "small to large diameter ratio must be between 0 and 1"
+            .to_string()));
+    }
+
+    Ok(beta)
+}
@@ -65,6 +65,24 @@ pub mod custom_fldk;
 /// eg Reynold's number
 pub mod dimensionalisation;
 
+/// contains packed bed pressure drop correlations
+/// (Ergun, KTA pebble bed, Carman-Kozeny)
+/// written in terms of the Reynolds number based on
+/// hydraulic diameter so that they fit the
+/// Be = 0.5 * (fLDK) * Re^2 framework
+pub mod packed_bed_friction_factor;
+
+/// contains a catalogue of pipe fittings (elbows, tees, 
+/// contractions, expansions, entrances and exits) 
+/// with Reynolds number dependent form losses 
+/// using the 2-K and 3-K methods
+pub mod form_loss_fittings;
+
+/// contains laminar friction factor and Nusselt number corrections 
+/// for non circular ducts (annuli, rectangular ducts and parallel 
+/// plates) which use the hydraulic diameter
+pub mod non_circular_ducts;
+
 
 
 ///// Contains structs or classes which
@@ -0,0 +1,234 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::tuas_lib_error::TuasLibError;
+
+// Laminar friction factors and Nusselt numbers for non circular ducts
+//
+// Using the hydraulic diameter with circular pipe correlations works
+// reasonably well in turbulent flow, but in laminar flow, the
+// fRe product and Nusselt numbers depend strongly on duct shape.
+//
+// Shah, R. K., & London, A. L. (1978). Laminar flow forced
+// convection in ducts: a source book for compact heat exchanger
+// analytical data. Academic press.
+//
+// Kays, W. M., Crawford, M. E., & Weigand, B. (2005). Convective
+// heat and mass transfer (4th ed.). McGraw-Hill.
+
+/// which wall of an annulus is heated,
+/// the other wall is assumed insulated
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AnnulusHeatedWall {
+    /// inner wall heated, outer wall insulated
+    #[default]
+    InnerWall,
+    /// outer wall heated, inner wall insulated
+    OuterWall,
+}
+
+/// cross sectional shape of a duct, used to correct laminar
+/// friction factors and Nusselt numbers when the hydraulic
+/// diameter is used with circular pipe correlations
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DuctShape {
+    /// circular pipe, no corrections are applied
+    #[default]
+    CircularPipe,
+    /// concentric annulus
+    Annulus {
+        /// inner to outer diameter ratio (r* = D_i/D_o)
+        radius_ratio: Ratio,
+        /// which wall is heated
+        heated_wall: AnnulusHeatedWall,
+    },
+    /// rectangular duct, all four walls heated
+    Rectangular {
+        /// short side to long side ratio (alpha* <= 1)
+        aspect_ratio: Ratio,
+    },
+    /// infinite parallel plates, both walls heated
+    ParallelPlates,
+}
+
+/// laminar fully developed darcy fRe for a circular pipe
+pub const CIRCULAR_PIPE_LAMINAR_DARCY_FRICTION_REYNOLDS_PRODUCT: f64 = 64.0;
+
+/// laminar fully developed Nusselt number for a circular pipe
+/// with uniform heat flux, the same value used by the
+/// Gnielinski laminar regime in this library
+pub const CIRCULAR_PIPE_LAMINAR_NUSSELT_UNIFORM_HEAT_FLUX: f64 = 4.354;
+
+/// Kays and Crawford Table 8-3, fully developed laminar Nusselt
+/// numbers for annuli with one wall at uniform heat flux and the
+/// other wall insulated
+///
+/// (r*, Nu_inner, Nu_outer)
+const ANNULUS_LAMINAR_NUSSELT_TABLE: [(f64, f64, f64); 7] = [
+    (0.05, 17.81, 4.792),
+    (0.10, 11.91, 4.834),
+    (0.20, 8.499, 4.883),
+    (0.40, 6.583, 4.979),
+    (0.60, 5.912, 5.099),
+    (0.80, 5.580, 5.240),
+    (1.00, 5.385, 5.385),
+];
+
+/// laminar Nusselt number for an annulus with one wall heated
+/// (uniform heat flux) and the other insulated, interpolated
+/// linearly from Kays and Crawford Table 8-3
+///
+/// r* is clamped to between 0.05 and 1
+pub fn annulus_laminar_nusselt_uniform_heat_flux(
+    radius_ratio: f64,
+    heated_wall: AnnulusHeatedWall) -> Result<f64, TuasLibError> {
+
+    if radius_ratio <= 0.0 || radius_ratio > 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "annulus radius ratio must be between 0 and 1".to_string()));
+    }
+
+    let table = ANNULUS_LAMINAR_NUSSELT_TABLE;
+    let pick = |row: (f64, f64, f64)| -> f64 {
+        match heated_wall {
+            AnnulusHeatedWall::InnerWall => row.1,
+            AnnulusHeatedWall::OuterWall => row.2,
+        }
+    };
+
+    if radius_ratio <= table[0].0 {
+        return Ok(pick(table[0]));
+    }
+
+    for window in table.windows(2) {
+        let (r_0, r_1) = (window[0].0, window[1].0);
+        if radius_ratio <= r_1 {
+            let nu_0 = pick(window[0]);
+            let nu_1 = pick(window[1]);
+            return Ok(nu_0 + (nu_1 - nu_0) * (radius_ratio - r_0) / (r_1 - r_0));
+        }
+    }
+
+    Ok(pick(table[table.len() - 1]))
+}
+
+impl DuctShape {
+
+    /// laminar fully developed darcy friction factor Reynolds
+    /// number product (f_darcy Re) based on hydraulic diameter
+    pub fn laminar_darcy_friction_reynolds_product(&self) ->
+    Result<Ratio, TuasLibError> {
+
+        let f_re: f64 = match self {
+            DuctShape::CircularPipe => {
+                CIRCULAR_PIPE_LAMINAR_DARCY_FRICTION_REYNOLDS_PRODUCT
+            },
+            DuctShape::Annulus { radius_ratio, .. } => {
+                let r = radius_ratio.get::<ratio>();
+
+                if r <= 0.0 || r > 1.0 {
+                    return Err(TuasLibError::GenericStringError(
+                        "annulus radius ratio must be between 0 and 1"
+                        .to_string()));
+                }
+
+                // r* = 1 is the parallel plate limit
+                if r == 1.0 {
+                    96.0
+                } else {
+                    // exact solution, Shah and London
+                    64.0 * (1.0 - r).powi(2)
+                        / (1.0 + r * r + (1.0 - r * r) / r.ln())
+                }
+            },
+            DuctShape::Rectangular { aspect_ratio } => {
+                let a = check_aspect_ratio(*aspect_ratio)?;
+
+                // Shah and London, fanning fRe = 24 (...)
+                96.0 * (1.0 - 1.3553 * a + 1.9467 * a.powi(2)
+                    - 1.7012 * a.powi(3) + 0.9564 * a.powi(4)
+                    - 0.2537 * a.powi(5))
+            },
+            DuctShape::ParallelPlates => 96.0,
+        };
+
+        Ok(Ratio::new::<ratio>(f_re))
+    }
+
+    /// laminar fully developed Nusselt number (uniform heat flux)
+    /// based on hydraulic diameter
+    pub fn laminar_nusselt_uniform_heat_flux(&self) ->
+    Result<Ratio, TuasLibError> {
+
+        let nusselt: f64 = match self {
+            DuctShape::CircularPipe => {
+                CIRCULAR_PIPE_LAMINAR_NUSSELT_UNIFORM_HEAT_FLUX
+            },
+            DuctShape::Annulus { radius_ratio, heated_wall } => {
+                annulus_laminar_nusselt_uniform_heat_flux(
+                    radius_ratio.get::<ratio>(), *heated_wall)?
+            },
+            DuctShape::Rectangular { aspect_ratio } => {
+                let a = check_aspect_ratio(*aspect_ratio)?;
+
+                // Shah and London, H1 boundary condition
+                8.235 * (1.0 - 2.0421 * a + 3.0853 * a.powi(2)
+                    - 2.4765 * a.powi(3) + 1.0578 * a.powi(4)
+                    - 0.1861 * a.powi(5))
+            },
+            DuctShape::ParallelPlates => 8.235,
+        };
+
+        Ok(Ratio::new::<ratio>(nusselt))
+    }
+
+    /// the weight given to the laminar shape correction,
+    /// 1 below Re = 2300 and 0 above Re = 4000 with
+    /// linear interpolation in between, consistent with the
+    /// Gnielinski transition region used in this library
+    pub fn laminar_weight(reynolds_number: Ratio) -> f64 {
+        let reynolds = reynolds_number.get::<ratio>().abs();
+
+        if reynolds <= 2300.0 {
+            return 1.0;
+        }
+        if reynolds >= 4000.0 {
+            return 0.0;
+        }
+
+        (4000.0 - reynolds) / (4000.0 - 2300.0)
+    }
+
+    /// multiplier to apply to a circular pipe Nusselt correlation
+    /// evaluated with the hydraulic diameter
+    ///
+    /// in laminar flow, this is Nu_shape/Nu_pipe, in turbulent flow
+    /// it is 1, and it is blended linearly in between
+    pub fn nusselt_shape_factor(&self, reynolds_number: Ratio) ->
+    Result<Ratio, TuasLibError> {
+
+        if let DuctShape::CircularPipe = self {
+            return Ok(Ratio::new::<ratio>(1.0));
+        }
+
+        let laminar_ratio = self.laminar_nusselt_uniform_heat_flux()?
+            .get::<ratio>()
+            / CIRCULAR_PIPE_LAMINAR_NUSSELT_UNIFORM_HEAT_FLUX;
+
+        let weight = Self::laminar_weight(reynolds_number);
+
+        Ok(Ratio::new::<ratio>(weight * laminar_ratio + (1.0 - weight)))
+    }
+}
+
+fn check_aspect_ratio(aspect_ratio: Ratio) -> Result<f64, TuasLibError> {
+    let a = aspect_ratio.get::<ratio>();
+
+    if !(0.0..=1.0).contains(&a) {
+        return Err(TuasLibError::GenericStringError(
+            "rectangular duct aspect ratio must be between 0 and 1 \
+            (short side over long side)".to_string()));
+    }
+
+    Ok(a)
+}
@@ -0,0 +1,328 @@
+use crate::tuas_lib_error::TuasLibError;
+use roots::*;
+
+// Packed bed pressure drop correlations are usually written in
+// terms of the particle Reynolds number based on superficial
+// velocity:
+//
+// Re_p = rho U_s d_p / mu
+//
+// whereas the rest of the library uses the Reynolds number based
+// on hydraulic diameter Re_D = rho U D_h / mu, with U being
+// mass flowrate divided by flow area and density.
+//
+// If the flow area supplied to the fluid component is the
+// empty bed cross sectional area, then U = U_s and
+//
+// Re_p = Re_D * (d_p/D_h)
+//
+// All functions here take in Re_D, so that the
+// Be = 0.5 * (fLDK) * Re_D^2 framework used everywhere else
+// can be kept for packed beds.
+
+fn check_packed_bed_inputs(porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<(), TuasLibError> {
+
+    if porosity <= 0.0 || porosity >= 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "packed bed porosity must be between 0 and 1".to_string()));
+    }
+
+    if length_to_diameter_ratio <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "lengthToDiameterRatio<=0.0".to_string()));
+    }
+
+    if diameter_to_particle_diameter_ratio <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "diameterToParticleDiameterRatio<=0.0".to_string()));
+    }
+
+    Ok(())
+}
+
+/// calculates fLDK for a packed bed using the Ergun equation
+///
+/// Ergun, S., & Orning, A. A. (1949). Fluid flow through
+/// randomly packed columns and fluidized beds. Industrial
+/// & Engineering Chemistry, 41(6), 1179-1184.
+///
+/// Delta P / L = 150 mu (1-e)^2 U_s / (e^3 d_p^2)
+/// + 1.75 (1-e) rho U_s^2 / (e^3 d_p)
+///
+/// When put into the form Be_D = 0.5 (fLDK) Re_D^2,
+///
+/// fLDK = (L/D_h) * [300 (1-e)^2/e^3 (D_h/d_p)^2 / Re_D
+/// + 3.5 (1-e)/e^3 (D_h/d_p)]
+///
+/// Re_D must be positive and nonzero
+/// ```rust
+/// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+///     packed_bed_friction_factor::ergun_fldk;
+///
+/// let fldk = ergun_fldk(500.0, 0.39, 100.0, 1.0).unwrap();
+///
+/// approx::assert_relative_eq!(fldk,
+///     100.0*(300.0*0.61*0.61/0.39_f64.powi(3)/500.0
+///     + 3.5*0.61/0.39_f64.powi(3)),
+///     max_relative = 1e-9);
+/// ```
+pub fn ergun_fldk(reynolds_number: f64,
+    porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    if reynolds_number <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re <= 0 for Ergun fLDK".to_string()));
+    }
+
+    check_packed_bed_inputs(porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio)?;
+
+    let (viscous_coeff, inertial_coeff) = ergun_bejan_coefficients(
+        porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio);
+
+    // Be = a Re + b Re^2
+    // fLDK = 2 Be/Re^2 = 2a/Re + 2b
+    let fldk = 2.0 * viscous_coeff / reynolds_number + 2.0 * inertial_coeff;
+
+    Ok(fldk)
+}
+
+/// the Ergun equation is a quadratic in Re_D,
+/// Be_D = a Re_D + b Re_D^2
+///
+/// this returns (a, b)
+fn ergun_bejan_coefficients(porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> (f64, f64) {
+
+    let solid_fraction = 1.0 - porosity;
+    let porosity_cubed = porosity.powi(3);
+
+    let viscous_coeff = 150.0 * solid_fraction * solid_fraction
+        / porosity_cubed
+        * length_to_diameter_ratio
+        * diameter_to_particle_diameter_ratio.powi(2);
+
+    let inertial_coeff = 1.75 * solid_fraction
+        / porosity_cubed
+        * length_to_diameter_ratio
+        * diameter_to_particle_diameter_ratio;
+
+    (viscous_coeff, inertial_coeff)
+}
+
+/// calculates Re_D given a Be_D for the Ergun equation
+///
+/// Since the Ergun equation is a quadratic in Re_D,
+/// this is done analytically rather than by root finding:
+///
+/// Re_D = [-a + sqrt(a^2 + 4 b |Be_D|)] / (2b)
+///
+/// negative Be_D values give negative Re_D values
+/// (the bed is assumed symmetrical)
+pub fn ergun_reynolds_from_bejan(bejan_number_d: f64,
+    porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    check_packed_bed_inputs(porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio)?;
+
+    if bejan_number_d == 0.0 {
+        return Ok(0.0);
+    }
+
+    let (viscous_coeff, inertial_coeff) = ergun_bejan_coefficients(
+        porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio);
+
+    let bejan_abs = bejan_number_d.abs();
+
+    // the form 2|Be|/(a + sqrt(a^2 + 4b|Be|)) is used rather than
+    // the textbook quadratic formula to avoid cancellation errors
+    // at low Be
+    let discriminant = viscous_coeff * viscous_coeff
+        + 4.0 * inertial_coeff * bejan_abs;
+
+    let reynolds_abs = 2.0 * bejan_abs / (viscous_coeff + discriminant.sqrt());
+
+    if bejan_number_d < 0.0 {
+        return Ok(-reynolds_abs);
+    }
+
+    Ok(reynolds_abs)
+}
+
+/// calculates fLDK for a packed bed using the Carman-Kozeny equation
+///
+/// Carman, P. C. (1937). Fluid flow through granular beds.
+/// Trans. Inst. Chem. Eng., 15, 150-166.
+///
+/// Delta P / L = 180 mu (1-e)^2 U_s / (e^3 d_p^2)
+///
+/// This is for creeping (laminar) flow only, Re_p/(1-e) < 10 or so.
+///
+/// fLDK = (L/D_h) * 360 (1-e)^2/e^3 (D_h/d_p)^2 / Re_D
+pub fn carman_kozeny_fldk(reynolds_number: f64,
+    porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    if reynolds_number <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re <= 0 for Carman-Kozeny fLDK".to_string()));
+    }
+
+    check_packed_bed_inputs(porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio)?;
+
+    let viscous_coeff = carman_kozeny_bejan_coefficient(
+        porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio);
+
+    Ok(2.0 * viscous_coeff / reynolds_number)
+}
+
+/// Be_D = a Re_D for Carman-Kozeny, this returns a
+fn carman_kozeny_bejan_coefficient(porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> f64 {
+
+    let solid_fraction = 1.0 - porosity;
+
+    180.0 * solid_fraction * solid_fraction
+        / porosity.powi(3)
+        * length_to_diameter_ratio
+        * diameter_to_particle_diameter_ratio.powi(2)
+}
+
+/// calculates Re_D given a Be_D for the Carman-Kozeny equation
+///
+/// Be_D is linear in Re_D, so Re_D = Be_D/a
+pub fn carman_kozeny_reynolds_from_bejan(bejan_number_d: f64,
+    porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    check_packed_bed_inputs(porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio)?;
+
+    let viscous_coeff = carman_kozeny_bejan_coefficient(
+        porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio);
+
+    Ok(bejan_number_d / viscous_coeff)
+}
+
+/// calculates fLDK for a pebble bed using the KTA 3102.3 correlation
+///
+/// KTA (1981). Reactor core design of high-temperature gas-cooled
+/// reactors Part 3: Loss of pressure through friction in pebble
+/// bed cores. KTA 3102.3.
+///
+/// Delta P / L = psi (1-e)/e^3 (1/d_p) (rho U_s^2 / 2)
+///
+/// psi = 320/(Re_p/(1-e)) + 6/(Re_p/(1-e))^0.1
+///
+/// valid for 1 < Re_p/(1-e) < 1e5 and 0.36 < e < 0.42
+///
+/// fLDK = psi (1-e)/e^3 (L/D_h) (D_h/d_p)
+pub fn kta_pebble_bed_fldk(reynolds_number: f64,
+    porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    if reynolds_number <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re <= 0 for KTA pebble bed fLDK".to_string()));
+    }
+
+    check_packed_bed_inputs(porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio)?;
+
+    let solid_fraction = 1.0 - porosity;
+
+    let particle_reynolds = reynolds_number
+        / diameter_to_particle_diameter_ratio;
+
+    let modified_reynolds = particle_reynolds / solid_fraction;
+
+    let psi = 320.0 / modified_reynolds
+        + 6.0 / modified_reynolds.powf(0.1);
+
+    let fldk = psi * solid_fraction / porosity.powi(3)
+        * length_to_diameter_ratio
+        * diameter_to_particle_diameter_ratio;
+
+    Ok(fldk)
+}
+
+/// calculates Re_D given a Be_D for the KTA pebble bed correlation
+///
+/// Be_D increases monotonically with Re_D, so a bracketed
+/// Brent Dekker root find from Re_D = 0 to 1e12 is used
+pub fn kta_pebble_bed_reynolds_from_bejan(bejan_number_d: f64,
+    porosity: f64,
+    length_to_diameter_ratio: f64,
+    diameter_to_particle_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    check_packed_bed_inputs(porosity,
+        length_to_diameter_ratio,
+        diameter_to_particle_diameter_ratio)?;
+
+    if bejan_number_d == 0.0 {
+        return Ok(0.0);
+    }
+
+    let bejan_abs = bejan_number_d.abs();
+
+    let pressure_drop_root = |reynolds: f64| -> f64 {
+        if reynolds == 0.0 {
+            return bejan_abs;
+        }
+        let fldk = kta_pebble_bed_fldk(reynolds,
+            porosity,
+            length_to_diameter_ratio,
+            diameter_to_particle_diameter_ratio).unwrap();
+
+        bejan_abs - 0.5 * fldk * reynolds * reynolds
+    };
+
+    let mut convergency = SimpleConvergency {
+        eps:1e-9f64,
+        max_iter:100
+    };
+
+    let reynolds_abs = find_root_brent(0.0,
+        1.0e12,
+        pressure_drop_root,
+        &mut convergency);
+
+    let reynolds_abs = match reynolds_abs {
+        Ok(reynolds) => reynolds,
+        Err(_) => {
+            return Err(TuasLibError::GenericStringError(
+                "KTA pebble bed Re from Be did not converge".to_string()));
+        },
+    };
+
+    if bejan_number_d < 0.0 {
+        return Ok(-reynolds_abs);
+    }
+
+    Ok(reynolds_abs)
+}
@@ -0,0 +1,102 @@
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::pressure::pascal;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::dynamic_viscosity::pascal_second;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// specific gas constant for dry air, J/(kg K)
+const DRY_AIR_GAS_CONSTANT: f64 = 287.05;
+
+/// air properties are fitted from about 200 K to 1200 K,
+/// outside this range, an error is returned
+fn range_check_air(air_temperature: ThermodynamicTemperature)
+    -> Result<(), TuasLibError> {
+
+    let temperature_kelvin = air_temperature.get::<kelvin>();
+
+    if !(200.0..=1200.0).contains(&temperature_kelvin) {
+        return Err(TuasLibError::GenericStringError(
+            "air temperature must be between 200 K and 1200 K".to_string()));
+    }
+
+    Ok(())
+}
+
+/// density of dry air using the ideal gas law
+pub fn try_get_air_density(air_temperature: ThermodynamicTemperature,
+    air_pressure: Pressure) -> Result<MassDensity, TuasLibError> {
+
+    range_check_air(air_temperature)?;
+
+    Ok(MassDensity::new::<kilogram_per_cubic_meter>(
+        air_pressure.get::<pascal>()
+        / DRY_AIR_GAS_CONSTANT
+        / air_temperature.get::<kelvin>()))
+}
+
+/// dynamic viscosity of dry air using Sutherland's law
+///
+/// mu = mu_0 (T/T_0)^1.5 (T_0 + S)/(T + S)
+///
+/// mu_0 = 1.716e-5 Pa s, T_0 = 273.15 K, S = 110.4 K
+pub fn try_get_air_dynamic_viscosity(air_temperature: ThermodynamicTemperature)
+    -> Result<DynamicViscosity, TuasLibError> {
+
+    range_check_air(air_temperature)?;
+
+    let temperature_kelvin = air_temperature.get::<kelvin>();
+
+    Ok(DynamicViscosity::new::<pascal_second>(
+        1.716e-5 * (temperature_kelvin/273.15).powf(1.5)
+        * (273.15 + 110.4) / (temperature_kelvin + 110.4)))
+}
+
+/// thermal conductivity of dry air using Sutherland's law
+///
+/// k = k_0 (T/T_0)^1.5 (T_0 + S)/(T + S)
+///
+/// k_0 = 0.0241 W/(m K), T_0 = 273.15 K, S = 194 K
+pub fn try_get_air_thermal_conductivity(air_temperature: ThermodynamicTemperature)
+    -> Result<ThermalConductivity, TuasLibError> {
+
+    range_check_air(air_temperature)?;
+
+    let temperature_kelvin = air_temperature.get::<kelvin>();
+
+    Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+        0.0241 * (temperature_kelvin/273.15).powf(1.5)
+        * (273.15 + 194.0) / (temperature_kelvin + 194.0)))
+}
+
+/// specific heat capacity of dry air
+///
+/// cp = 1002.5 + 275e-6 (T - 200)^2 J/(kg K)
+///
+/// this is within about 3% of tabulated values up to 1000 K
+pub fn try_get_air_specific_heat_capacity(air_temperature: ThermodynamicTemperature)
+    -> Result<SpecificHeatCapacity, TuasLibError> {
+
+    range_check_air(air_temperature)?;
+
+    let temperature_kelvin = air_temperature.get::<kelvin>();
+
+    Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+        1002.5 + 275e-6 * (temperature_kelvin - 200.0).powi(2)))
+}
+
+/// prandtl number of dry air
+pub fn try_get_air_prandtl(air_temperature: ThermodynamicTemperature)
+    -> Result<Ratio, TuasLibError> {
+
+    let cp = try_get_air_specific_heat_capacity(air_temperature)?;
+    let mu = try_get_air_dynamic_viscosity(air_temperature)?;
+    let k = try_get_air_thermal_conductivity(air_temperature)?;
+
+    let prandtl: Ratio = cp * mu / k;
+
+    Ok(prandtl)
+}
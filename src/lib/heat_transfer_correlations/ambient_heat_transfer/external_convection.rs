@@ -0,0 +1,124 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// Churchill-Chu correlation for natural convection from a
+/// horizontal cylinder, valid for Ra_D up to 1e12
+///
+/// Nu_D = {0.60 + 0.387 Ra_D^(1/6) / [1 + (0.559/Pr)^(9/16)]^(8/27)}^2
+///
+/// Churchill, S. W., & Chu, H. H. (1975). Correlating equations for
+/// laminar and turbulent free convection from a horizontal cylinder.
+/// International journal of heat and mass transfer, 18(9), 1049-1053.
+pub fn churchill_chu_horizontal_cylinder_nusselt(
+    rayleigh_diameter: Ratio,
+    prandtl: Ratio) -> Result<Ratio, TuasLibError> {
+
+    let rayleigh = rayleigh_diameter.get::<ratio>();
+    let prandtl = prandtl.get::<ratio>();
+
+    if rayleigh < 0.0 || prandtl <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Rayleigh number must not be negative and Prandtl \
+            number must be positive".to_string()));
+    }
+
+    let prandtl_function = (1.0 + (0.559/prandtl).powf(9.0/16.0))
+        .powf(8.0/27.0);
+
+    let nusselt = (0.60 + 0.387 * rayleigh.powf(1.0/6.0)
+        / prandtl_function).powi(2);
+
+    Ok(Ratio::new::<ratio>(nusselt))
+}
+
+/// Churchill-Chu correlation for natural convection from a
+/// vertical plate, valid over all Ra_L
+///
+/// Nu_L = {0.825 + 0.387 Ra_L^(1/6) / [1 + (0.492/Pr)^(9/16)]^(8/27)}^2
+///
+/// Churchill, S. W., & Chu, H. H. (1975). Correlating equations for
+/// laminar and turbulent free convection from a vertical plate.
+/// International journal of heat and mass transfer, 18(11), 1323-1329.
+pub fn churchill_chu_vertical_plate_nusselt(
+    rayleigh_length: Ratio,
+    prandtl: Ratio) -> Result<Ratio, TuasLibError> {
+
+    let rayleigh = rayleigh_length.get::<ratio>();
+    let prandtl = prandtl.get::<ratio>();
+
+    if rayleigh < 0.0 || prandtl <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Rayleigh number must not be negative and Prandtl \
+            number must be positive".to_string()));
+    }
+
+    let prandtl_function = (1.0 + (0.492/prandtl).powf(9.0/16.0))
+        .powf(8.0/27.0);
+
+    let nusselt = (0.825 + 0.387 * rayleigh.powf(1.0/6.0)
+        / prandtl_function).powi(2);
+
+    Ok(Ratio::new::<ratio>(nusselt))
+}
+
+/// natural convection from a vertical cylinder
+///
+/// the vertical plate correlation (based on the cylinder height)
+/// is used with Cebeci's curvature correction for thin cylinders
+///
+/// Nu_cyl/Nu_plate = 1 + 0.3 [32^0.5 Gr_L^(-1/4) L/D]^0.909
+///
+/// the correction goes to one when D >> L Gr_L^(-1/4),
+/// ie. when the boundary layer is thin compared to the diameter
+pub fn vertical_cylinder_nusselt(
+    rayleigh_length: Ratio,
+    prandtl: Ratio,
+    height_to_diameter_ratio: Ratio) -> Result<Ratio, TuasLibError> {
+
+    let plate_nusselt = churchill_chu_vertical_plate_nusselt(
+        rayleigh_length, prandtl)?.get::<ratio>();
+
+    let grashof = rayleigh_length.get::<ratio>() / prandtl.get::<ratio>();
+
+    // no flow, no boundary layer, no correction
+    if grashof <= 0.0 {
+        return Ok(Ratio::new::<ratio>(plate_nusselt));
+    }
+
+    let curvature_parameter = 32.0_f64.sqrt() * grashof.powf(-0.25)
+        * height_to_diameter_ratio.get::<ratio>();
+
+    let curvature_correction = 1.0 + 0.3 * curvature_parameter.powf(0.909);
+
+    Ok(Ratio::new::<ratio>(plate_nusselt * curvature_correction))
+}
+
+/// Churchill-Bernstein correlation for forced crossflow over a
+/// cylinder, valid for Re_D Pr > 0.2
+///
+/// Nu_D = 0.3 + 0.62 Re^(1/2) Pr^(1/3) / [1 + (0.4/Pr)^(2/3)]^(1/4)
+///        [1 + (Re/282000)^(5/8)]^(4/5)
+///
+/// Churchill, S. W., & Bernstein, M. (1977). A correlating equation
+/// for forced convection from gases and liquids to a circular
+/// cylinder in crossflow. Journal of Heat Transfer 99(2), 300-306.
+pub fn churchill_bernstein_cylinder_crossflow_nusselt(
+    reynolds_diameter: Ratio,
+    prandtl: Ratio) -> Result<Ratio, TuasLibError> {
+
+    let reynolds = reynolds_diameter.get::<ratio>().abs();
+    let prandtl = prandtl.get::<ratio>();
+
+    if prandtl <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Prandtl number must be positive".to_string()));
+    }
+
+    let nusselt = 0.3 + 0.62 * reynolds.sqrt() * prandtl.powf(1.0/3.0)
+        / (1.0 + (0.4/prandtl).powf(2.0/3.0)).powf(0.25)
+        * (1.0 + (reynolds/282000.0).powf(5.0/8.0)).powf(0.8);
+
+    Ok(Ratio::new::<ratio>(nusselt))
+}
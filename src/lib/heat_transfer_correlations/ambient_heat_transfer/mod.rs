@@ -0,0 +1,149 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::pressure::atmosphere;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::acceleration::meter_per_second_squared;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use self::air_properties::*;
+use self::external_convection::*;
+
+/// temperature dependent heat transfer coefficients from a
+/// component outer surface to ambient air
+///
+/// prebuilt components use a fixed heat_transfer_to_ambient by
+/// default. If one of these models is set on the component, the
+/// heat transfer coefficient is instead calculated every timestep
+/// from the outer surface temperature and ambient temperature
+///
+/// air properties are taken at the film temperature,
+/// (T_surface + T_ambient)/2 and atmospheric pressure
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum AmbientHeatTransferModel {
+
+    /// natural convection from a horizontal cylinder
+    /// (Churchill-Chu), based on the outer diameter
+    HorizontalCylinderNaturalConvection,
+
+    /// natural convection from a vertical cylinder of a given
+    /// height (Churchill-Chu vertical plate with a curvature
+    /// correction for thin cylinders)
+    VerticalCylinderNaturalConvection(Length),
+
+    /// natural convection from a vertical plate of a given
+    /// height (Churchill-Chu)
+    VerticalPlateNaturalConvection(Length),
+
+    /// forced crossflow over a cylinder due to wind of a given
+    /// speed (Churchill-Bernstein)
+    ///
+    /// this is combined with natural convection from a horizontal
+    /// cylinder as
+    ///
+    /// Nu = (Nu_forced^3 + Nu_natural^3)^(1/3)
+    ///
+    /// so that at low wind speeds, the natural convection
+    /// heat transfer coefficient is recovered
+    CylinderCrossflowWithWind(Velocity),
+}
+
+impl AmbientHeatTransferModel {
+
+    /// calculates the heat transfer coefficient from the outer
+    /// surface to ambient air
+    ///
+    /// the outer diameter is the diameter of the outermost layer
+    /// (pipe shell or insulation), it is not used for vertical plates
+    pub fn try_get_heat_transfer_coefficient(&self,
+        surface_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+        outer_diameter: Length) -> Result<HeatTransfer, TuasLibError> {
+
+        let film_temperature = ThermodynamicTemperature::new::<kelvin>(
+            0.5 * (surface_temperature.get::<kelvin>()
+                + ambient_temperature.get::<kelvin>()));
+
+        let air_pressure = Pressure::new::<atmosphere>(1.0);
+        let air_density = try_get_air_density(film_temperature, air_pressure)?;
+        let air_viscosity = try_get_air_dynamic_viscosity(film_temperature)?;
+        let air_thermal_conductivity =
+            try_get_air_thermal_conductivity(film_temperature)?;
+        let air_prandtl = try_get_air_prandtl(film_temperature)?;
+
+        let kinematic_viscosity = air_viscosity / air_density;
+
+        // ideal gas thermal expansion coefficient is 1/T
+        let temperature_difference_kelvin = (surface_temperature.get::<kelvin>()
+            - ambient_temperature.get::<kelvin>()).abs();
+        let beta_delta_t = Ratio::new::<ratio>(
+            temperature_difference_kelvin / film_temperature.get::<kelvin>());
+
+        let g = Acceleration::new::<meter_per_second_squared>(9.81);
+
+        let rayleigh_number = |characteristic_length: Length| -> Ratio {
+            let grashof: Ratio = g * beta_delta_t
+                * characteristic_length * characteristic_length
+                * characteristic_length
+                / kinematic_viscosity / kinematic_viscosity;
+            grashof * air_prandtl
+        };
+
+        let (nusselt, characteristic_length): (Ratio, Length) = match self {
+            AmbientHeatTransferModel::HorizontalCylinderNaturalConvection => {
+                let nusselt = churchill_chu_horizontal_cylinder_nusselt(
+                    rayleigh_number(outer_diameter), air_prandtl)?;
+                (nusselt, outer_diameter)
+            },
+            AmbientHeatTransferModel::VerticalCylinderNaturalConvection(height) => {
+                let nusselt = vertical_cylinder_nusselt(
+                    rayleigh_number(*height),
+                    air_prandtl,
+                    *height/outer_diameter)?;
+                (nusselt, *height)
+            },
+            AmbientHeatTransferModel::VerticalPlateNaturalConvection(height) => {
+                let nusselt = churchill_chu_vertical_plate_nusselt(
+                    rayleigh_number(*height), air_prandtl)?;
+                (nusselt, *height)
+            },
+            AmbientHeatTransferModel::CylinderCrossflowWithWind(wind_speed) => {
+                let reynolds: Ratio = air_density * *wind_speed
+                    * outer_diameter / air_viscosity;
+
+                let forced_nusselt = churchill_bernstein_cylinder_crossflow_nusselt(
+                    reynolds, air_prandtl)?.get::<ratio>();
+                let natural_nusselt = churchill_chu_horizontal_cylinder_nusselt(
+                    rayleigh_number(outer_diameter), air_prandtl)?.get::<ratio>();
+
+                let nusselt = (forced_nusselt.powi(3)
+                    + natural_nusselt.powi(3)).powf(1.0/3.0);
+
+                (Ratio::new::<ratio>(nusselt), outer_diameter)
+            },
+        };
+
+        if characteristic_length.value <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "characteristic length for ambient heat transfer \
+                must be more than 0".to_string()));
+        }
+
+        Ok(nusselt * air_thermal_conductivity / characteristic_length)
+    }
+}
+
+/// dry air properties near atmospheric pressure for ambient
+/// heat transfer
+pub mod air_properties;
+
+/// natural and forced convection correlations for external
+/// surfaces such as cylinders and plates
+pub mod external_convection;
+
+/// linearised radiation from component outer surfaces 
+/// to the surroundings
+pub mod radiation_to_surroundings;
+
+/// unit tests for ambient heat transfer
+pub mod unit_test_ambient_heat_transfer;
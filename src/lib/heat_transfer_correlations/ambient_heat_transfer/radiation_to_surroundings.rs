@@ -0,0 +1,79 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::heat_flux_density::watt_per_square_meter;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// Stefan-Boltzmann constant, W/(m^2 K^4)
+pub const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.670374419e-8;
+
+/// radiation from a grey outer surface to large surroundings
+/// (view factor of one)
+///
+/// q'' = epsilon sigma (T_s^4 - T_sur^4)
+///
+/// for the implicit solvers, this is linearised into a heat
+/// transfer coefficient using the surface temperature of the
+/// previous timestep
+///
+/// h_rad = epsilon sigma (T_s + T_sur)(T_s^2 + T_sur^2)
+///
+/// so that q'' = h_rad (T_s - T_sur)
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct RadiationToSurroundings {
+    /// emissivity of the outer surface
+    pub emissivity: Ratio,
+    /// temperature of the surroundings (eg. walls of the room
+    /// or enclosure)
+    pub surroundings_temperature: ThermodynamicTemperature,
+}
+
+impl RadiationToSurroundings {
+
+    /// creates a new radiation boundary, the emissivity must be
+    /// more than zero and not more than one
+    pub fn new(emissivity: Ratio,
+        surroundings_temperature: ThermodynamicTemperature)
+        -> Result<Self, TuasLibError> {
+
+        let emissivity_value = emissivity.get::<ratio>();
+
+        if emissivity_value <= 0.0 || emissivity_value > 1.0 {
+            return Err(TuasLibError::GenericStringError(
+                "emissivity must be more than 0 and at most 1".to_string()));
+        }
+
+        Ok(Self {
+            emissivity,
+            surroundings_temperature,
+        })
+    }
+
+    /// linearised radiation heat transfer coefficient at a given
+    /// surface temperature
+    pub fn get_linearised_heat_transfer_coefficient(&self,
+        surface_temperature: ThermodynamicTemperature) -> HeatTransfer {
+
+        let t_s = surface_temperature.get::<kelvin>();
+        let t_sur = self.surroundings_temperature.get::<kelvin>();
+
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(
+            self.emissivity.get::<ratio>() * STEFAN_BOLTZMANN_CONSTANT
+            * (t_s + t_sur) * (t_s * t_s + t_sur * t_sur))
+    }
+
+    /// radiative heat flux from the surface to the surroundings
+    /// (not linearised)
+    pub fn get_radiative_heat_flux(&self,
+        surface_temperature: ThermodynamicTemperature) -> HeatFluxDensity {
+
+        let t_s = surface_temperature.get::<kelvin>();
+        let t_sur = self.surroundings_temperature.get::<kelvin>();
+
+        HeatFluxDensity::new::<watt_per_square_meter>(
+            self.emissivity.get::<ratio>() * STEFAN_BOLTZMANN_CONSTANT
+            * (t_s.powi(4) - t_sur.powi(4)))
+    }
+}
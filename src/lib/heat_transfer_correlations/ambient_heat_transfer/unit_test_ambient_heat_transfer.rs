@@ -0,0 +1,351 @@
+/// air properties at 300 K and 1 atm compared to tabulated values
+/// (Incropera, Table A.4), density is compared to the ideal gas 
+/// value of 1.177 kg/m^3
+#[test]
+pub fn air_properties_at_300_kelvin() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+    use super::air_properties::*;
+
+    let air_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+
+    approx::assert_relative_eq!(
+        try_get_air_density(air_temperature,
+            Pressure::new::<atmosphere>(1.0))?
+        .get::<kilogram_per_cubic_meter>(),
+        1.177,
+        max_relative = 0.01);
+    approx::assert_relative_eq!(
+        try_get_air_dynamic_viscosity(air_temperature)?
+        .get::<pascal_second>(),
+        184.6e-7,
+        max_relative = 0.01);
+    approx::assert_relative_eq!(
+        try_get_air_thermal_conductivity(air_temperature)?
+        .get::<watt_per_meter_kelvin>(),
+        26.3e-3,
+        max_relative = 0.01);
+    approx::assert_relative_eq!(
+        try_get_air_specific_heat_capacity(air_temperature)?
+        .get::<joule_per_kilogram_kelvin>(),
+        1007.0,
+        max_relative = 0.01);
+    approx::assert_relative_eq!(
+        try_get_air_prandtl(air_temperature)?.get::<ratio>(),
+        0.707,
+        max_relative = 0.02);
+
+    assert!(try_get_air_prandtl(
+        ThermodynamicTemperature::new::<kelvin>(1500.0)).is_err());
+
+    Ok(())
+}
+
+/// hand calculated Nusselt numbers for Ra = 1e6, Re = 1e4 and Pr = 0.7
+#[test]
+pub fn external_convection_nusselt_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::external_convection::*;
+
+    let rayleigh = Ratio::new::<ratio>(1e6);
+    let reynolds = Ratio::new::<ratio>(1e4);
+    let prandtl = Ratio::new::<ratio>(0.7);
+
+    approx::assert_relative_eq!(
+        churchill_chu_horizontal_cylinder_nusselt(rayleigh, prandtl)?
+        .get::<ratio>(),
+        14.5102,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        churchill_chu_vertical_plate_nusselt(rayleigh, prandtl)?
+        .get::<ratio>(),
+        16.5304,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        churchill_bernstein_cylinder_crossflow_nusselt(reynolds, prandtl)?
+        .get::<ratio>(),
+        53.3278,
+        max_relative = 1e-5);
+
+    // thin vertical cylinders have higher Nusselt numbers than
+    // plates, thick ones approach the plate
+    let thin_cylinder_nusselt = vertical_cylinder_nusselt(
+        rayleigh, prandtl, Ratio::new::<ratio>(50.0))?;
+    let thick_cylinder_nusselt = vertical_cylinder_nusselt(
+        rayleigh, prandtl, Ratio::new::<ratio>(0.01))?;
+
+    assert!(thin_cylinder_nusselt.get::<ratio>() > 16.5304 * 1.5);
+    approx::assert_relative_eq!(
+        thick_cylinder_nusselt.get::<ratio>(),
+        16.5304,
+        max_relative = 0.01);
+
+    assert!(churchill_chu_vertical_plate_nusselt(
+        Ratio::new::<ratio>(-1.0), prandtl).is_err());
+
+    Ok(())
+}
+
+/// natural convection heat transfer coefficients from a hot pipe
+/// should be a few W/(m^2 K), and wind should increase them
+#[test]
+pub fn ambient_heat_transfer_coefficient_from_hot_pipe() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::velocity::meter_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use super::AmbientHeatTransferModel;
+
+    let surface_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    let outer_diameter = Length::new::<meter>(0.05);
+
+    let htc = |model: AmbientHeatTransferModel| -> Result<f64,
+        crate::tuas_lib_error::TuasLibError> {
+        Ok(model.try_get_heat_transfer_coefficient(
+                surface_temperature,
+                ambient_temperature,
+                outer_diameter)?
+            .get::<watt_per_square_meter_kelvin>())
+    };
+
+    let horizontal_htc = htc(
+        AmbientHeatTransferModel::HorizontalCylinderNaturalConvection)?;
+    let vertical_htc = htc(
+        AmbientHeatTransferModel::VerticalCylinderNaturalConvection(
+            Length::new::<meter>(1.0)))?;
+    let plate_htc = htc(
+        AmbientHeatTransferModel::VerticalPlateNaturalConvection(
+            Length::new::<meter>(1.0)))?;
+    let no_wind_htc = htc(
+        AmbientHeatTransferModel::CylinderCrossflowWithWind(
+            Velocity::new::<meter_per_second>(0.0)))?;
+    let windy_htc = htc(
+        AmbientHeatTransferModel::CylinderCrossflowWithWind(
+            Velocity::new::<meter_per_second>(5.0)))?;
+
+    assert!(horizontal_htc > 5.0 && horizontal_htc < 10.0);
+    assert!(plate_htc > 3.0 && plate_htc < 8.0);
+    assert!(vertical_htc > plate_htc);
+    assert!(windy_htc > 3.0 * horizontal_htc);
+
+    // without wind, only natural convection remains, which is
+    // slightly more than the horizontal cylinder due to the
+    // 0.3 term in Churchill-Bernstein
+    assert!(no_wind_htc >= horizontal_htc);
+    approx::assert_relative_eq!(
+        no_wind_htc,
+        horizontal_htc,
+        max_relative = 0.02);
+
+    // isothermal surfaces still have a conduction limit
+    let isothermal_htc = AmbientHeatTransferModel::
+        HorizontalCylinderNaturalConvection
+        .try_get_heat_transfer_coefficient(
+            ambient_temperature,
+            ambient_temperature,
+            outer_diameter)?;
+    assert!(isothermal_htc.get::<watt_per_square_meter_kelvin>() > 0.0);
+
+    Ok(())
+}
+
+/// a bare pipe with a natural convection model should use the
+/// calculated heat transfer coefficient instead of the fixed one
+#[test]
+pub fn bare_pipe_with_natural_convection_to_ambient() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+    use super::AmbientHeatTransferModel;
+
+    let id = Length::new::<meter>(0.02);
+    let od = Length::new::<meter>(0.025);
+    let fixed_htc = HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0);
+
+    let mut bare_pipe = NonInsulatedFluidComponent::new_bare_pipe(
+        ThermodynamicTemperature::new::<degree_celsius>(100.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        Pressure::new::<atmosphere>(1.0),
+        Pressure::new::<atmosphere>(1.0),
+        Area::new::<square_meter>(std::f64::consts::PI * 0.01 * 0.01),
+        Angle::new::<degree>(0.0),
+        Ratio::new::<ratio>(0.0),
+        id,
+        od,
+        Length::new::<meter>(1.0),
+        id,
+        Length::new::<millimeter>(0.015),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        fixed_htc,
+        2);
+
+    assert_eq!(bare_pipe.try_get_heat_transfer_to_ambient()?, fixed_htc);
+
+    let natural_convection = AmbientHeatTransferModel::
+        HorizontalCylinderNaturalConvection;
+    let mut options = bare_pipe.get_options().clone();
+    options.ambient_heat_transfer_model = Some(natural_convection);
+    bare_pipe.set_options(options);
+
+    let expected_htc = natural_convection.try_get_heat_transfer_coefficient(
+        ThermodynamicTemperature::new::<degree_celsius>(100.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        od)?;
+
+    approx::assert_relative_eq!(
+        bare_pipe.try_get_heat_transfer_to_ambient()?
+        .get::<watt_per_square_meter_kelvin>(),
+        expected_htc.get::<watt_per_square_meter_kelvin>(),
+        max_relative = 1e-9);
+
+    // the lateral connections should run with the model set
+    bare_pipe.lateral_and_miscellaneous_connections_no_wall_correction(
+        MassRate::ZERO, Power::ZERO)?;
+
+    Ok(())
+}
+
+/// the linearised radiation heat transfer coefficient should give 
+/// the exact radiative heat flux, and emissivity must be between 
+/// 0 and 1
+#[test]
+pub fn linearised_radiation_matches_radiative_heat_flux() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::heat_flux_density::watt_per_square_meter;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use super::radiation_to_surroundings::*;
+
+    let surroundings_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let surface_temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+
+    let radiation = RadiationToSurroundings::new(
+        Ratio::new::<ratio>(0.8), surroundings_temperature)?;
+
+    // q'' = 0.8 * 5.670374419e-8 * (400^4 - 300^4) = 793.85 W/m^2
+    let heat_flux = radiation.get_radiative_heat_flux(surface_temperature);
+    approx::assert_relative_eq!(
+        heat_flux.get::<watt_per_square_meter>(),
+        793.852,
+        max_relative = 1e-5);
+
+    let h_rad = radiation.get_linearised_heat_transfer_coefficient(
+        surface_temperature);
+    approx::assert_relative_eq!(
+        h_rad.get::<watt_per_square_meter_kelvin>() * 100.0,
+        heat_flux.get::<watt_per_square_meter>(),
+        max_relative = 1e-9);
+
+    assert!(RadiationToSurroundings::new(
+        Ratio::new::<ratio>(0.0), surroundings_temperature).is_err());
+    assert!(RadiationToSurroundings::new(
+        Ratio::new::<ratio>(1.1), surroundings_temperature).is_err());
+
+    Ok(())
+}
+
+/// radiation from a bare pipe adds a conductance to the 
+/// surroundings for every pipe shell node
+#[test]
+pub fn bare_pipe_with_radiation_to_surroundings() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::ConstZero;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+    use super::radiation_to_surroundings::RadiationToSurroundings;
+
+    let id = Length::new::<meter>(0.02);
+    let od = Length::new::<meter>(0.025);
+    let inner_nodes = 2;
+
+    let mut bare_pipe = NonInsulatedFluidComponent::new_bare_pipe(
+        ThermodynamicTemperature::new::<degree_celsius>(100.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        Pressure::new::<atmosphere>(1.0),
+        Pressure::new::<atmosphere>(1.0),
+        Area::new::<square_meter>(std::f64::consts::PI * 0.01 * 0.01),
+        Angle::new::<degree>(0.0),
+        Ratio::new::<ratio>(0.0),
+        id,
+        od,
+        Length::new::<meter>(1.0),
+        id,
+        Length::new::<millimeter>(0.015),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+        inner_nodes);
+
+    let radiation = RadiationToSurroundings::new(
+        Ratio::new::<ratio>(0.9),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0))?;
+
+    let conductance_vector = bare_pipe
+        .get_radiation_to_surroundings_nodal_conductance_vector(radiation)?;
+
+    assert_eq!(conductance_vector.len(), inner_nodes + 2);
+
+    // the pipe shell is thin steel, so the conductance is limited 
+    // by radiation, h_rad A for each node
+    let h_rad = radiation.get_linearised_heat_transfer_coefficient(
+        ThermodynamicTemperature::new::<degree_celsius>(100.0));
+    let node_area: Area = std::f64::consts::PI * od 
+        * Length::new::<meter>(1.0) / (inner_nodes + 2) as f64;
+    let radiation_limited_conductance: ThermalConductance = h_rad * node_area;
+
+    for conductance in conductance_vector.iter() {
+        assert!(*conductance < radiation_limited_conductance);
+        approx::assert_relative_eq!(
+            conductance.get::<watt_per_kelvin>(),
+            radiation_limited_conductance.get::<watt_per_kelvin>(),
+            max_relative = 0.01);
+    }
+
+    let mut options = bare_pipe.get_options().clone();
+    options.radiation_to_surroundings = Some(radiation);
+    bare_pipe.set_options(options);
+
+    bare_pipe.lateral_and_miscellaneous_connections_no_wall_correction(
+        MassRate::ZERO, Power::ZERO)?;
+
+    Ok(())
+}
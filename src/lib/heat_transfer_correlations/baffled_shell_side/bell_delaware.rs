@@ -0,0 +1,599 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::ratio::ratio;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{BaffledShellGeometry, BaffledShellSide, TubeLayout};
+
+// Bell-Delaware method as given by Taborek in the Heat Exchanger
+// Design Handbook
+//
+// the crossflow reynolds number is based on the tube outer
+// diameter and the crossflow area at the shell centreline
+//
+// Re_s = m d_o / (mu S_m)
+//
+// below Re_s = 100, flow is treated as laminar for the
+// correction factors
+
+/// Taborek coefficients for ideal tube bank j and f factors,
+/// returned as [a1, a2, a3, a4, b1, b2, b3, b4]
+///
+/// the rotated triangular layout uses the triangular
+/// layout coefficients
+pub fn taborek_ideal_tube_bank_coefficients(tube_layout: TubeLayout,
+    crossflow_reynolds: f64) -> [f64; 8] {
+
+    let re = crossflow_reynolds;
+
+    match tube_layout {
+        TubeLayout::Triangular30 | TubeLayout::RotatedTriangular60 => {
+            let (a1, a2, b1, b2) = if re >= 1.0e4 {
+                (0.321, -0.388, 0.372, -0.123)
+            } else if re >= 1.0e3 {
+                (0.321, -0.388, 0.486, -0.152)
+            } else if re >= 1.0e2 {
+                (0.593, -0.477, 4.570, -0.476)
+            } else if re >= 10.0 {
+                (1.360, -0.657, 45.100, -0.973)
+            } else {
+                (1.400, -0.667, 48.000, -1.000)
+            };
+            [a1, a2, 1.450, 0.519, b1, b2, 7.00, 0.500]
+        },
+        TubeLayout::RotatedSquare45 => {
+            let (a1, a2, b1, b2) = if re >= 1.0e4 {
+                (0.370, -0.396, 0.303, -0.126)
+            } else if re >= 1.0e3 {
+                (0.370, -0.396, 0.333, -0.136)
+            } else if re >= 1.0e2 {
+                (0.730, -0.500, 3.500, -0.476)
+            } else if re >= 10.0 {
+                (0.498, -0.656, 26.200, -0.913)
+            } else {
+                (1.550, -0.667, 32.000, -1.000)
+            };
+            [a1, a2, 1.930, 0.500, b1, b2, 6.59, 0.520]
+        },
+        TubeLayout::Square90 => {
+            let (a1, a2, b1, b2) = if re >= 1.0e4 {
+                (0.370, -0.395, 0.391, -0.148)
+            } else if re >= 1.0e3 {
+                (0.107, -0.266, 0.0815, 0.022)
+            } else if re >= 1.0e2 {
+                (0.408, -0.460, 6.0900, -0.602)
+            } else if re >= 10.0 {
+                (0.900, -0.631, 32.1000, -0.963)
+            } else {
+                (0.970, -0.667, 35.0000, -1.000)
+            };
+            [a1, a2, 1.187, 0.370, b1, b2, 6.30, 0.378]
+        },
+    }
+}
+
+impl BaffledShellGeometry {
+
+    /// central angle of the baffle cut in radians,
+    /// theta_ds = 2 arccos(1 - 2 B_c)
+    #[inline]
+    pub fn get_baffle_cut_angle(&self) -> f64 {
+        2.0 * (1.0 - 2.0 * self.baffle_cut.get::<ratio>()).acos()
+    }
+
+    /// central angle of the baffle cut measured on the central
+    /// tube limit diameter in radians,
+    /// theta_ctl = 2 arccos(D_s (1 - 2 B_c) / D_ctl)
+    #[inline]
+    pub fn get_tube_limit_baffle_cut_angle(&self) -> f64 {
+        let cut_edge_ratio = (self.shell_id.get::<meter>()
+            * (1.0 - 2.0 * self.baffle_cut.get::<ratio>())
+            / self.get_central_tube_limit_diameter().get::<meter>())
+            .clamp(-1.0, 1.0);
+
+        2.0 * cut_edge_ratio.acos()
+    }
+
+    /// fraction of tubes in one baffle window,
+    /// F_w = (theta_ctl - sin theta_ctl)/(2 pi)
+    #[inline]
+    pub fn get_window_tube_fraction(&self) -> Ratio {
+        let theta_ctl = self.get_tube_limit_baffle_cut_angle();
+        Ratio::new::<ratio>((theta_ctl - theta_ctl.sin())/(2.0 * PI))
+    }
+
+    /// fraction of tubes in pure crossflow between baffle tips,
+    /// F_c = 1 - 2 F_w
+    #[inline]
+    pub fn get_crossflow_tube_fraction(&self) -> Ratio {
+        Ratio::new::<ratio>(1.0) - 2.0 * self.get_window_tube_fraction()
+    }
+
+    /// net flow area through one baffle window,
+    /// gross window area less the area taken up by tubes
+    ///
+    /// S_w = D_s^2/8 (theta_ds - sin theta_ds) - N_t F_w pi d_o^2/4
+    #[inline]
+    pub fn get_window_flow_area(&self) -> Area {
+        let theta_ds = self.get_baffle_cut_angle();
+        let shell_id = self.shell_id;
+        let tube_od = self.tube_od;
+
+        let gross_window_area: Area = shell_id * shell_id / 8.0
+            * (theta_ds - theta_ds.sin());
+        let window_tube_area: Area = self.number_of_tubes as f64
+            * self.get_window_tube_fraction().get::<ratio>()
+            * PI * tube_od * tube_od / 4.0;
+
+        gross_window_area - window_tube_area
+    }
+
+    /// hydraulic diameter of the baffle window,
+    /// D_w = 4 S_w / (pi d_o N_t F_w + theta_ds D_s)
+    #[inline]
+    pub fn get_window_hydraulic_diameter(&self) -> Length {
+        let window_tubes = self.number_of_tubes as f64
+            * self.get_window_tube_fraction().get::<ratio>();
+
+        4.0 * self.get_window_flow_area()
+            / (PI * self.tube_od * window_tubes
+                + self.get_baffle_cut_angle() * self.shell_id)
+    }
+
+    /// number of tube rows crossed between baffle tips,
+    /// N_c = D_s (1 - 2 B_c) / L_pp
+    #[inline]
+    pub fn get_number_of_crossflow_rows(&self) -> f64 {
+        (self.shell_id * (1.0 - 2.0 * self.baffle_cut.get::<ratio>())
+            / self.get_row_pitch()).get::<ratio>()
+    }
+
+    /// effective number of tube rows crossed in each window,
+    /// N_cw = 0.8/L_pp (D_s B_c - (D_s - D_ctl)/2)
+    #[inline]
+    pub fn get_number_of_window_rows(&self) -> f64 {
+        let window_depth = self.shell_id * self.baffle_cut.get::<ratio>()
+            - (self.shell_id - self.get_central_tube_limit_diameter()) / 2.0;
+
+        (0.8 * window_depth / self.get_row_pitch()).get::<ratio>().max(0.0)
+    }
+
+    /// shell to baffle leakage area,
+    /// S_sb = pi/2 D_s L_sb (1 - theta_ds/(2 pi))
+    #[inline]
+    pub fn get_shell_to_baffle_leakage_area(&self) -> Area {
+        PI / 2.0 * self.shell_id * self.shell_to_baffle_clearance
+            * (1.0 - self.get_baffle_cut_angle()/(2.0 * PI))
+    }
+
+    /// tube to baffle leakage area,
+    /// S_tb = pi/4 ((d_o + L_tb)^2 - d_o^2) N_t (1 - F_w)
+    #[inline]
+    pub fn get_tube_to_baffle_leakage_area(&self) -> Area {
+        let hole_diameter = self.tube_od + self.tube_to_baffle_clearance;
+
+        PI / 4.0 * (hole_diameter * hole_diameter - self.tube_od * self.tube_od)
+            * self.number_of_tubes as f64
+            * (1.0 - self.get_window_tube_fraction().get::<ratio>())
+    }
+
+    /// bundle to shell bypass area, S_b = L_bc L_bb
+    #[inline]
+    pub fn get_bundle_bypass_area(&self) -> Area {
+        self.central_baffle_spacing * self.bundle_to_shell_clearance
+    }
+
+    /// crossflow reynolds number, Re_s = m d_o/(mu S_m)
+    #[inline]
+    pub fn get_crossflow_reynolds(&self, mass_flowrate: MassRate,
+        fluid_viscosity: DynamicViscosity) -> Ratio {
+        mass_flowrate * self.tube_od
+            / (fluid_viscosity * self.get_crossflow_area())
+    }
+
+    /// ideal tube bank colburn j factor
+    ///
+    /// j_i = a1 (1.33/(L_tp/d_o))^a Re_s^a2
+    /// a = a3/(1 + 0.14 Re_s^a4)
+    #[inline]
+    pub fn get_ideal_colburn_j_factor(&self, crossflow_reynolds: f64) -> f64 {
+        let re = crossflow_reynolds.abs();
+        let [a1, a2, a3, a4, _, _, _, _] =
+            taborek_ideal_tube_bank_coefficients(self.tube_layout, re);
+        let pitch_ratio = self.get_pitch_to_diameter_ratio().get::<ratio>();
+
+        let a = a3/(1.0 + 0.14 * re.powf(a4));
+
+        a1 * (1.33/pitch_ratio).powf(a) * re.powf(a2)
+    }
+
+    /// ideal tube bank friction factor
+    ///
+    /// f_i = b1 (1.33/(L_tp/d_o))^b Re_s^b2
+    /// b = b3/(1 + 0.14 Re_s^b4)
+    #[inline]
+    pub fn get_ideal_friction_factor(&self, crossflow_reynolds: f64) -> f64 {
+        let re = crossflow_reynolds.abs();
+        let [_, _, _, _, b1, b2, b3, b4] =
+            taborek_ideal_tube_bank_coefficients(self.tube_layout, re);
+        let pitch_ratio = self.get_pitch_to_diameter_ratio().get::<ratio>();
+
+        let b = b3/(1.0 + 0.14 * re.powf(b4));
+
+        b1 * (1.33/pitch_ratio).powf(b) * re.powf(b2)
+    }
+
+    /// returns the leakage area ratios (r_s, r_lm)
+    ///
+    /// r_s = S_sb/(S_sb + S_tb)
+    /// r_lm = (S_sb + S_tb)/S_m
+    fn get_leakage_ratios(&self) -> (f64, f64) {
+        let shell_leakage = self.get_shell_to_baffle_leakage_area();
+        let total_leakage = shell_leakage
+            + self.get_tube_to_baffle_leakage_area();
+
+        if total_leakage.get::<uom::si::area::square_meter>() <= 0.0 {
+            return (0.0, 0.0);
+        }
+
+        ((shell_leakage/total_leakage).get::<ratio>(),
+        (total_leakage/self.get_crossflow_area()).get::<ratio>())
+    }
+
+    /// returns the bypass term F_sbp (1 - (2 r_ss)^(1/3)),
+    /// which is zero when there are enough sealing strips
+    fn get_bypass_exponent_term(&self) -> f64 {
+        let sealing_strip_ratio = self.number_of_sealing_strip_pairs as f64
+            / self.get_number_of_crossflow_rows();
+
+        if sealing_strip_ratio >= 0.5 {
+            return 0.0;
+        }
+
+        let bypass_fraction = (self.get_bundle_bypass_area()
+            / self.get_crossflow_area()).get::<ratio>();
+
+        bypass_fraction * (1.0 - (2.0 * sealing_strip_ratio).cbrt())
+    }
+
+    /// baffle cut and spacing correction, J_c = 0.55 + 0.72 F_c
+    #[inline]
+    pub fn get_baffle_cut_correction_factor(&self) -> Ratio {
+        Ratio::new::<ratio>(0.55
+            + 0.72 * self.get_crossflow_tube_fraction().get::<ratio>())
+    }
+
+    /// baffle leakage correction for heat transfer
+    ///
+    /// J_l = 0.44 (1 - r_s) + (1 - 0.44 (1 - r_s)) exp(-2.2 r_lm)
+    #[inline]
+    pub fn get_leakage_correction_factor(&self) -> Ratio {
+        let (r_s, r_lm) = self.get_leakage_ratios();
+
+        Ratio::new::<ratio>(0.44 * (1.0 - r_s)
+            + (1.0 - 0.44 * (1.0 - r_s)) * (-2.2 * r_lm).exp())
+    }
+
+    /// bundle bypass correction for heat transfer
+    ///
+    /// J_b = exp(-C_bh F_sbp (1 - (2 r_ss)^(1/3)))
+    ///
+    /// C_bh = 1.35 for laminar flow (Re_s < 100), 1.25 otherwise
+    #[inline]
+    pub fn get_bypass_correction_factor(&self,
+        crossflow_reynolds: f64) -> Ratio {
+        let c_bh = if crossflow_reynolds.abs() < 100.0 { 1.35 } else { 1.25 };
+
+        Ratio::new::<ratio>((-c_bh * self.get_bypass_exponent_term()).exp())
+    }
+
+    /// unequal end baffle spacing correction for heat transfer
+    ///
+    /// J_s = ((N_b - 1) + L_i^(1-n) + L_o^(1-n))
+    ///     / ((N_b - 1) + L_i + L_o)
+    ///
+    /// L_i = L_bi/L_bc, L_o = L_bo/L_bc, n = 0.6 for turbulent
+    /// flow and 1/3 for laminar flow (Re_s < 100)
+    #[inline]
+    pub fn get_unequal_spacing_correction_factor(&self,
+        crossflow_reynolds: f64) -> Ratio {
+        let n = if crossflow_reynolds.abs() < 100.0 { 1.0/3.0 } else { 0.6 };
+
+        let inlet_ratio = (self.inlet_baffle_spacing
+            / self.central_baffle_spacing).get::<ratio>();
+        let outlet_ratio = (self.outlet_baffle_spacing
+            / self.central_baffle_spacing).get::<ratio>();
+        let central_baffles = self.number_of_baffles as f64 - 1.0;
+
+        Ratio::new::<ratio>(
+            (central_baffles + inlet_ratio.powf(1.0 - n)
+                + outlet_ratio.powf(1.0 - n))
+            / (central_baffles + inlet_ratio + outlet_ratio))
+    }
+
+    /// adverse temperature gradient correction for laminar flow
+    ///
+    /// J_r = 1 for Re_s >= 100
+    /// J_r = (10/N_c,total)^0.18 for Re_s <= 20, but not below 0.4
+    ///
+    /// N_c,total = (N_b + 1)(N_c + N_cw), and J_r is linearly
+    /// interpolated in between
+    #[inline]
+    pub fn get_laminar_correction_factor(&self,
+        crossflow_reynolds: f64) -> Ratio {
+        let re = crossflow_reynolds.abs();
+
+        if re >= 100.0 {
+            return Ratio::new::<ratio>(1.0);
+        }
+
+        let total_rows = (self.number_of_baffles as f64 + 1.0)
+            * (self.get_number_of_crossflow_rows()
+                + self.get_number_of_window_rows());
+
+        let laminar_factor = (10.0/total_rows).powf(0.18).clamp(0.4, 1.0);
+
+        if re <= 20.0 {
+            return Ratio::new::<ratio>(laminar_factor);
+        }
+
+        Ratio::new::<ratio>(laminar_factor
+            + (re - 20.0)/80.0 * (1.0 - laminar_factor))
+    }
+
+    /// baffle leakage correction for pressure drop
+    ///
+    /// R_l = exp(-1.33 (1 + r_s) r_lm^p), p = 0.8 - 0.15 (1 + r_s)
+    #[inline]
+    pub fn get_leakage_pressure_drop_factor(&self) -> Ratio {
+        let (r_s, r_lm) = self.get_leakage_ratios();
+        let p = 0.8 - 0.15 * (1.0 + r_s);
+
+        Ratio::new::<ratio>((-1.33 * (1.0 + r_s) * r_lm.powf(p)).exp())
+    }
+
+    /// bundle bypass correction for pressure drop
+    ///
+    /// R_b = exp(-C_bp F_sbp (1 - (2 r_ss)^(1/3)))
+    ///
+    /// C_bp = 4.5 for laminar flow (Re_s < 100), 3.7 otherwise
+    #[inline]
+    pub fn get_bypass_pressure_drop_factor(&self,
+        crossflow_reynolds: f64) -> Ratio {
+        let c_bp = if crossflow_reynolds.abs() < 100.0 { 4.5 } else { 3.7 };
+
+        Ratio::new::<ratio>((-c_bp * self.get_bypass_exponent_term()).exp())
+    }
+
+    /// end zone baffle spacing correction for pressure drop,
+    /// summed over both end zones
+    ///
+    /// R_s = (L_bc/L_bo)^(2-n) + (L_bc/L_bi)^(2-n)
+    ///
+    /// n = 0.2 for turbulent flow and 1 for laminar flow (Re_s < 100)
+    #[inline]
+    pub fn get_end_zone_pressure_drop_factor(&self,
+        crossflow_reynolds: f64) -> Ratio {
+        let n = if crossflow_reynolds.abs() < 100.0 { 1.0 } else { 0.2 };
+
+        let inlet_ratio = (self.central_baffle_spacing
+            / self.inlet_baffle_spacing).get::<ratio>();
+        let outlet_ratio = (self.central_baffle_spacing
+            / self.outlet_baffle_spacing).get::<ratio>();
+
+        Ratio::new::<ratio>(inlet_ratio.powf(2.0 - n)
+            + outlet_ratio.powf(2.0 - n))
+    }
+
+    /// product of all Bell-Delaware heat transfer correction factors,
+    /// J_c J_l J_b J_s J_r
+    #[inline]
+    pub fn get_total_heat_transfer_correction_factor(&self,
+        crossflow_reynolds: f64) -> Ratio {
+        self.get_baffle_cut_correction_factor()
+            * self.get_leakage_correction_factor()
+            * self.get_bypass_correction_factor(crossflow_reynolds)
+            * self.get_unequal_spacing_correction_factor(crossflow_reynolds)
+            * self.get_laminar_correction_factor(crossflow_reynolds)
+    }
+
+    /// shell side heat transfer coefficient using the
+    /// Bell-Delaware method
+    ///
+    /// h = j_i c_p (m/S_m) Pr^(-2/3) (Pr_bulk/Pr_wall)^0.14
+    ///     J_c J_l J_b J_s J_r
+    pub fn try_get_bell_delaware_heat_transfer_coefficient(&self,
+        mass_flowrate: MassRate,
+        fluid_viscosity: DynamicViscosity,
+        fluid_thermal_conductivity: ThermalConductivity,
+        prandtl_bulk: Ratio,
+        prandtl_wall: Ratio) -> Result<HeatTransfer, TuasLibError> {
+
+        let nusselt_tube_od = self.own_reference_shell_side()
+            .try_get_bell_delaware_nusselt(
+                self.get_crossflow_reynolds(mass_flowrate, fluid_viscosity),
+                prandtl_bulk,
+                prandtl_wall)?;
+
+        Ok(nusselt_tube_od * fluid_thermal_conductivity / self.tube_od)
+    }
+
+    /// shell side pressure drop (excluding nozzles) using the
+    /// Bell-Delaware method
+    ///
+    /// dp = (N_b - 1) dp_bi R_b R_l + N_b dp_wi R_l
+    ///     + dp_bi (1 + N_cw/N_c) R_b R_s
+    ///
+    /// the wall viscosity correction is not applied
+    pub fn try_get_bell_delaware_pressure_drop(&self,
+        mass_flowrate: MassRate,
+        fluid_density: MassDensity,
+        fluid_viscosity: DynamicViscosity) -> Result<Pressure, TuasLibError> {
+
+        let crossflow_area = self.get_crossflow_area();
+        let fldk = self.own_reference_shell_side()
+            .try_get_bell_delaware_fldk(
+                self.get_crossflow_reynolds(mass_flowrate, fluid_viscosity))?;
+
+        Ok(fldk * mass_flowrate * mass_flowrate
+            / (2.0 * fluid_density * crossflow_area * crossflow_area))
+    }
+
+    /// uses the crossflow area and tube od as reference
+    /// flow area and hydraulic diameter
+    #[inline]
+    pub(crate) fn own_reference_shell_side(&self) -> BaffledShellSide {
+        BaffledShellSide {
+            geometry: *self,
+            reference_flow_area: self.get_crossflow_area(),
+            reference_hydraulic_diameter: self.tube_od,
+        }
+    }
+}
+
+impl BaffledShellSide {
+
+    /// converts the reference reynolds number into the
+    /// Bell-Delaware crossflow reynolds number
+    ///
+    /// Re_s = Re (A/S_m) (d_o/D_h)
+    #[inline]
+    pub fn get_crossflow_reynolds(&self, reynolds: Ratio) -> Ratio {
+        reynolds
+            * (self.reference_flow_area / self.geometry.get_crossflow_area())
+            * (self.geometry.tube_od / self.reference_hydraulic_diameter)
+    }
+
+    /// shell side nusselt number based on the reference hydraulic
+    /// diameter using the Bell-Delaware method
+    ///
+    /// Nu_do = j_i Re_s Pr^(1/3) (Pr_bulk/Pr_wall)^0.14 J_c J_l J_b J_s J_r
+    ///
+    /// Nu = Nu_do D_h/d_o
+    ///
+    /// the crossflow reynolds number is not allowed to go below 1
+    /// so that some heat transfer remains at zero flow
+    pub fn try_get_bell_delaware_nusselt(&self,
+        reynolds: Ratio,
+        prandtl_bulk: Ratio,
+        prandtl_wall: Ratio) -> Result<Ratio, TuasLibError> {
+
+        self.geometry.try_validate()?;
+
+        let prandtl_bulk = prandtl_bulk.get::<ratio>();
+        let prandtl_wall = prandtl_wall.get::<ratio>();
+
+        if prandtl_bulk <= 0.0 || prandtl_wall <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "prandtl numbers must be positive for shell side \
+                correlations".to_string()));
+        }
+
+        let crossflow_reynolds = self.get_crossflow_reynolds(reynolds)
+            .get::<ratio>().abs().max(1.0);
+
+        let ideal_j = self.geometry
+            .get_ideal_colburn_j_factor(crossflow_reynolds);
+        let correction_factor = self.geometry
+            .get_total_heat_transfer_correction_factor(crossflow_reynolds)
+            .get::<ratio>();
+
+        let nusselt_tube_od = ideal_j * crossflow_reynolds
+            * prandtl_bulk.cbrt()
+            * (prandtl_bulk/prandtl_wall).powf(0.14)
+            * correction_factor;
+
+        Ok(Ratio::new::<ratio>(nusselt_tube_od)
+            * (self.reference_hydraulic_diameter / self.geometry.tube_od))
+    }
+
+    /// shell side (f L/D + K) based on the reference flow area
+    /// using the Bell-Delaware method, such that
+    ///
+    /// dp = (f L/D + K) m^2/(2 rho A^2)
+    ///
+    /// crossflow section between baffle tips:
+    /// dp_bi = 2 f_i N_c (m/S_m)^2/rho
+    ///
+    /// baffle window, turbulent (Re_s >= 100):
+    /// dp_wi = (2 + 0.6 N_cw) (m^2/(S_m S_w))/(2 rho)
+    ///
+    /// baffle window, laminar (Re_s < 100):
+    /// dp_wi = 26 mu G_w/rho (N_cw/(L_tp - d_o) + L_bc/D_w^2)
+    ///     + 2 G_w^2/(2 rho)
+    ///
+    /// G_w = m/sqrt(S_m S_w)
+    pub fn try_get_bell_delaware_fldk(&self,
+        reynolds: Ratio) -> Result<Ratio, TuasLibError> {
+
+        self.geometry.try_validate()?;
+
+        let reference_reynolds = reynolds.get::<ratio>().abs();
+
+        if reference_reynolds == 0.0 {
+            return Ok(Ratio::new::<ratio>(0.0));
+        }
+
+        let geometry = &self.geometry;
+        let crossflow_reynolds = self.get_crossflow_reynolds(reynolds)
+            .get::<ratio>().abs();
+
+        let crossflow_area = geometry.get_crossflow_area();
+        let window_area = geometry.get_window_flow_area();
+
+        if window_area.get::<uom::si::area::square_meter>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "baffle window flow area must be positive, check the \
+                number of tubes and baffle cut".to_string()));
+        }
+
+        // A/S_m and A^2/(S_m S_w)
+        let crossflow_area_ratio = (self.reference_flow_area
+            / crossflow_area).get::<ratio>();
+        let window_area_ratio = (self.reference_flow_area
+            * self.reference_flow_area
+            / (crossflow_area * window_area)).get::<ratio>();
+
+        let crossflow_rows = geometry.get_number_of_crossflow_rows();
+        let window_rows = geometry.get_number_of_window_rows();
+
+        let ideal_crossflow_fldk = 4.0
+            * geometry.get_ideal_friction_factor(crossflow_reynolds)
+            * crossflow_rows * crossflow_area_ratio * crossflow_area_ratio;
+
+        let ideal_window_fldk = if crossflow_reynolds >= 100.0 {
+            (2.0 + 0.6 * window_rows) * window_area_ratio
+        } else {
+            let window_hydraulic_diameter =
+                geometry.get_window_hydraulic_diameter();
+            let viscous_length_term: Ratio = self.reference_hydraulic_diameter
+                * (window_rows / (geometry.tube_pitch - geometry.tube_od)
+                    + geometry.central_baffle_spacing
+                    / (window_hydraulic_diameter * window_hydraulic_diameter));
+
+            52.0 * window_area_ratio.sqrt()
+                * viscous_length_term.get::<ratio>() / reference_reynolds
+                + 2.0 * window_area_ratio
+        };
+
+        let leakage_factor = geometry
+            .get_leakage_pressure_drop_factor().get::<ratio>();
+        let bypass_factor = geometry
+            .get_bypass_pressure_drop_factor(crossflow_reynolds).get::<ratio>();
+        let end_zone_factor = geometry
+            .get_end_zone_pressure_drop_factor(crossflow_reynolds).get::<ratio>();
+        let number_of_baffles = geometry.number_of_baffles as f64;
+
+        let crossflow_fldk = (number_of_baffles - 1.0) * ideal_crossflow_fldk
+            * bypass_factor * leakage_factor;
+        let window_fldk = number_of_baffles * ideal_window_fldk * leakage_factor;
+        let end_zone_fldk = ideal_crossflow_fldk
+            * (1.0 + window_rows/crossflow_rows)
+            * bypass_factor * end_zone_factor;
+
+        Ok(Ratio::new::<ratio>(crossflow_fldk + window_fldk + end_zone_fldk))
+    }
+}
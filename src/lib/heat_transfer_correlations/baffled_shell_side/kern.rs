@@ -0,0 +1,139 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{BaffledShellGeometry, BaffledShellSide, TubeLayout};
+
+// Kern method for the shell side of baffled heat exchangers
+//
+// the shell side reynolds number is based on the equivalent
+// diameter and the crossflow area at the shell centreline
+//
+// Re_e = m D_e / (mu A_s)
+//
+// Kern, D. Q. (1950). Process heat transfer. McGraw-Hill.
+
+impl BaffledShellGeometry {
+
+    /// Kern equivalent diameter based on the wetted perimeter
+    /// of the tubes in a unit cell
+    ///
+    /// square layouts: D_e = 4 (L_tp^2 - pi d_o^2/4)/(pi d_o)
+    ///
+    /// triangular layouts:
+    /// D_e = 4 (sqrt(3)/4 L_tp^2 - pi d_o^2/8)/(pi d_o/2)
+    #[inline]
+    pub fn get_kern_equivalent_diameter(&self) -> Length {
+        let pitch = self.tube_pitch;
+        let tube_od = self.tube_od;
+
+        match self.tube_layout {
+            TubeLayout::Triangular30 | TubeLayout::RotatedTriangular60 => {
+                4.0 * (3.0_f64.sqrt()/4.0 * pitch * pitch
+                    - PI * tube_od * tube_od / 8.0)
+                    / (PI * tube_od / 2.0)
+            },
+            TubeLayout::RotatedSquare45 | TubeLayout::Square90 => {
+                4.0 * (pitch * pitch - PI * tube_od * tube_od / 4.0)
+                    / (PI * tube_od)
+            },
+        }
+    }
+
+    /// Kern crossflow area, A_s = D_s (L_tp - d_o) L_bc / L_tp
+    #[inline]
+    pub fn get_kern_crossflow_area(&self) -> Area {
+        self.shell_id * (self.tube_pitch - self.tube_od)
+            * self.central_baffle_spacing / self.tube_pitch
+    }
+}
+
+impl BaffledShellSide {
+
+    /// converts the reference reynolds number into the
+    /// Kern reynolds number
+    ///
+    /// Re_e = Re (A/A_s) (D_e/D_h)
+    #[inline]
+    pub fn get_kern_reynolds(&self, reynolds: Ratio) -> Ratio {
+        reynolds
+            * (self.reference_flow_area / self.geometry.get_kern_crossflow_area())
+            * (self.geometry.get_kern_equivalent_diameter()
+                / self.reference_hydraulic_diameter)
+    }
+
+    /// shell side nusselt number based on the reference hydraulic
+    /// diameter using the Kern method
+    ///
+    /// Nu_e = 0.36 Re_e^0.55 Pr^(1/3) (Pr_bulk/Pr_wall)^0.14
+    ///
+    /// Nu = Nu_e D_h/D_e
+    ///
+    /// meant for 2000 < Re_e < 1e6, the reynolds number is not
+    /// allowed to go below 1 so that some heat transfer remains
+    /// at zero flow
+    pub fn try_get_kern_nusselt(&self,
+        reynolds: Ratio,
+        prandtl_bulk: Ratio,
+        prandtl_wall: Ratio) -> Result<Ratio, TuasLibError> {
+
+        self.geometry.try_validate()?;
+
+        let prandtl_bulk = prandtl_bulk.get::<ratio>();
+        let prandtl_wall = prandtl_wall.get::<ratio>();
+
+        if prandtl_bulk <= 0.0 || prandtl_wall <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "prandtl numbers must be positive for shell side \
+                correlations".to_string()));
+        }
+
+        let kern_reynolds = self.get_kern_reynolds(reynolds)
+            .get::<ratio>().abs().max(1.0);
+
+        let nusselt_equivalent_diameter = 0.36 * kern_reynolds.powf(0.55)
+            * prandtl_bulk.cbrt()
+            * (prandtl_bulk/prandtl_wall).powf(0.14);
+
+        Ok(Ratio::new::<ratio>(nusselt_equivalent_diameter)
+            * (self.reference_hydraulic_diameter
+                / self.geometry.get_kern_equivalent_diameter()))
+    }
+
+    /// shell side (f L/D + K) based on the reference flow area
+    /// using the Kern method
+    ///
+    /// dp = f (m/A_s)^2 D_s (N_b + 1)/(2 rho D_e)
+    ///
+    /// f = exp(0.576 - 0.19 ln Re_e)
+    ///
+    /// so that (f L/D + K) = f (N_b + 1) (D_s/D_e) (A/A_s)^2
+    ///
+    /// the wall viscosity correction is not applied
+    pub fn try_get_kern_fldk(&self,
+        reynolds: Ratio) -> Result<Ratio, TuasLibError> {
+
+        self.geometry.try_validate()?;
+
+        if reynolds.get::<ratio>() == 0.0 {
+            return Ok(Ratio::new::<ratio>(0.0));
+        }
+
+        let kern_reynolds = self.get_kern_reynolds(reynolds)
+            .get::<ratio>().abs();
+
+        let kern_friction_factor = (0.576 - 0.19 * kern_reynolds.ln()).exp();
+
+        let area_ratio = (self.reference_flow_area
+            / self.geometry.get_kern_crossflow_area()).get::<ratio>();
+        let diameter_ratio = (self.geometry.shell_id
+            / self.geometry.get_kern_equivalent_diameter()).get::<ratio>();
+
+        Ok(Ratio::new::<ratio>(kern_friction_factor
+            * (self.geometry.number_of_baffles as f64 + 1.0)
+            * diameter_ratio * area_ratio * area_ratio))
+    }
+}
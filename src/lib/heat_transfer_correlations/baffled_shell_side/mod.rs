@@ -0,0 +1,291 @@
+//! shell side heat transfer and pressure drop for segmentally
+//! baffled shell and tube heat exchangers
+//!
+//! the shell side of a simple shell and tube heat exchanger is
+//! normally described by a hydraulic diameter, flow area and a
+//! calibrated nusselt correlation. For baffled exchangers, the
+//! shell side flow is mostly crossflow over the tube bundle with
+//! leakage and bypass streams, so the heat transfer and pressure
+//! drop depend on the baffle and bundle geometry
+//!
+//! two methods are provided here:
+//!
+//! 1. Bell-Delaware method (Taborek's version in the Heat
+//! Exchanger Design Handbook), ideal tube bank j and f factors
+//! are corrected for baffle cut, leakage, bypass, unequal baffle
+//! spacing and laminar flow
+//!
+//! 2. Kern method, a simpler equivalent diameter method which is
+//! fine for first estimates
+//!
+//! Taborek, J. (1983). Shell-and-tube heat exchangers. Heat
+//! Exchanger Design Handbook, 3, 3-3.
+//!
+//! Kern, D. Q. (1950). Process heat transfer. McGraw-Hill.
+//!
+//! Both methods calculate reynolds and nusselt numbers based on
+//! their own lengthscales (tube outer diameter and equivalent
+//! diameter respectively). The fluid arrays and shell and tube
+//! heat exchangers in this library use a reference hydraulic
+//! diameter and flow area for the shell side, so [BaffledShellSide]
+//! converts the reference reynolds number into the reynolds number
+//! of each method and converts the nusselt numbers and
+//! (f L/D + K) back to the reference lengthscales
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::ratio::ratio;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// tube layout angle with respect to the crossflow direction
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum TubeLayout {
+    /// triangular layout (30 degrees)
+    #[default]
+    Triangular30,
+    /// rotated triangular layout (60 degrees)
+    RotatedTriangular60,
+    /// rotated square layout (45 degrees)
+    RotatedSquare45,
+    /// square layout (90 degrees)
+    Square90,
+}
+
+impl TubeLayout {
+
+    /// ratio of the tube row spacing in the flow direction
+    /// to the tube pitch (L_pp/L_tp)
+    #[inline]
+    pub fn get_row_pitch_to_tube_pitch_ratio(&self) -> f64 {
+        match self {
+            TubeLayout::Triangular30 => 0.866,
+            TubeLayout::RotatedTriangular60 => 0.5,
+            TubeLayout::RotatedSquare45 => 0.707,
+            TubeLayout::Square90 => 1.0,
+        }
+    }
+
+    /// ratio of the effective tube pitch for crossflow area
+    /// to the tube pitch (L_tp,eff/L_tp)
+    #[inline]
+    pub fn get_effective_pitch_to_tube_pitch_ratio(&self) -> f64 {
+        match self {
+            TubeLayout::Triangular30 => 1.0,
+            TubeLayout::RotatedTriangular60 => 0.866,
+            TubeLayout::RotatedSquare45 => 0.707,
+            TubeLayout::Square90 => 1.0,
+        }
+    }
+}
+
+/// geometry of a segmentally baffled shell with a single
+/// tube pass bundle of plain tubes
+///
+/// clearances are diametral clearances, ie. the difference
+/// between the two diameters
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct BaffledShellGeometry {
+    /// shell inner diameter (D_s)
+    pub shell_id: Length,
+    /// tube outer diameter (d_o)
+    pub tube_od: Length,
+    /// tube centre to centre pitch (L_tp)
+    pub tube_pitch: Length,
+    /// tube layout angle
+    pub tube_layout: TubeLayout,
+    /// number of tubes in the bundle
+    pub number_of_tubes: u32,
+    /// baffle cut as a fraction of the shell inner diameter (B_c)
+    pub baffle_cut: Ratio,
+    /// central baffle spacing (L_bc)
+    pub central_baffle_spacing: Length,
+    /// inlet baffle spacing (L_bi)
+    pub inlet_baffle_spacing: Length,
+    /// outlet baffle spacing (L_bo)
+    pub outlet_baffle_spacing: Length,
+    /// number of baffles (N_b)
+    pub number_of_baffles: u32,
+    /// shell to baffle diametral clearance (L_sb)
+    pub shell_to_baffle_clearance: Length,
+    /// tube to baffle hole diametral clearance (L_tb)
+    pub tube_to_baffle_clearance: Length,
+    /// shell to outer tube limit diametral clearance (L_bb)
+    pub bundle_to_shell_clearance: Length,
+    /// number of sealing strip pairs (N_ss)
+    pub number_of_sealing_strip_pairs: u32,
+}
+
+/// a baffled shell geometry together with the reference flow
+/// area and hydraulic diameter used by the shell side fluid array
+///
+/// the reference reynolds number is:
+///
+/// Re = m D_h / (A mu)
+///
+/// and nusselt numbers and (f L/D + K) returned here are based
+/// on D_h and A, so that they can be used in the same way as
+/// any other nusselt or loss correlation for the shell side
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct BaffledShellSide {
+    /// baffle and bundle geometry
+    pub geometry: BaffledShellGeometry,
+    /// reference flow area of the shell side fluid array
+    pub reference_flow_area: Area,
+    /// reference hydraulic diameter of the shell side fluid array
+    pub reference_hydraulic_diameter: Length,
+}
+
+/// data for shell side nusselt number correlations of baffled
+/// shells, the reynolds number is based on the reference
+/// hydraulic diameter and flow area
+///
+/// the wall viscosity correction (mu/mu_wall)^0.14 is
+/// approximated by (Pr_bulk/Pr_wall)^0.14
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct BaffledShellSideNusseltData {
+    /// baffled shell geometry and reference lengthscales
+    pub shell_side: BaffledShellSide,
+    /// reynolds number based on the reference hydraulic diameter
+    /// and flow area
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+    /// wall prandtl number
+    pub prandtl_wall: Ratio,
+}
+
+impl BaffledShellSideNusseltData {
+
+    /// nusselt number (based on the reference hydraulic diameter)
+    /// using the Bell-Delaware method
+    #[inline]
+    pub fn get_nusselt_bell_delaware(&self) -> Result<Ratio, TuasLibError> {
+        self.shell_side.try_get_bell_delaware_nusselt(
+            self.reynolds, self.prandtl_bulk, self.prandtl_wall)
+    }
+
+    /// nusselt number (based on the reference hydraulic diameter)
+    /// using the Kern method
+    #[inline]
+    pub fn get_nusselt_kern(&self) -> Result<Ratio, TuasLibError> {
+        self.shell_side.try_get_kern_nusselt(
+            self.reynolds, self.prandtl_bulk, self.prandtl_wall)
+    }
+}
+
+impl BaffledShellGeometry {
+
+    /// checks that the geometry is physically sensible
+    /// for the Bell-Delaware and Kern methods
+    pub fn try_validate(&self) -> Result<(), TuasLibError> {
+
+        let lengths = [
+            self.shell_id,
+            self.tube_od,
+            self.tube_pitch,
+            self.central_baffle_spacing,
+            self.inlet_baffle_spacing,
+            self.outlet_baffle_spacing,
+        ];
+
+        if lengths.iter().any(|length| length.get::<meter>() <= 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "baffled shell diameters, pitch and baffle spacings \
+                must be positive".to_string()));
+        }
+
+        let clearances = [
+            self.shell_to_baffle_clearance,
+            self.tube_to_baffle_clearance,
+            self.bundle_to_shell_clearance,
+        ];
+
+        if clearances.iter().any(|length| length.get::<meter>() < 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "baffled shell clearances must not be negative"
+                .to_string()));
+        }
+
+        if self.tube_pitch <= self.tube_od {
+            return Err(TuasLibError::GenericStringError(
+                "tube pitch must be larger than tube outer diameter"
+                .to_string()));
+        }
+
+        let baffle_cut = self.baffle_cut.get::<ratio>();
+        if baffle_cut <= 0.0 || baffle_cut >= 0.5 {
+            return Err(TuasLibError::GenericStringError(
+                "baffle cut must be between 0 and 0.5 of the shell \
+                inner diameter".to_string()));
+        }
+
+        if self.number_of_tubes == 0 || self.number_of_baffles == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "baffled shell needs at least one tube and one baffle"
+                .to_string()));
+        }
+
+        // the baffle cut edge must lie within the tube bundle
+        // so that there are tubes in the window
+        let central_tube_limit = self.get_central_tube_limit_diameter()
+            .get::<meter>();
+        if central_tube_limit <= 0.0 ||
+            self.shell_id.get::<meter>() * (1.0 - 2.0 * baffle_cut)
+                >= central_tube_limit {
+            return Err(TuasLibError::GenericStringError(
+                "baffle cut edge must lie within the tube bundle"
+                .to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// outer tube limit diameter, D_otl = D_s - L_bb
+    #[inline]
+    pub fn get_outer_tube_limit_diameter(&self) -> Length {
+        self.shell_id - self.bundle_to_shell_clearance
+    }
+
+    /// diameter of the circle through the outermost tube
+    /// centres, D_ctl = D_otl - d_o
+    #[inline]
+    pub fn get_central_tube_limit_diameter(&self) -> Length {
+        self.get_outer_tube_limit_diameter() - self.tube_od
+    }
+
+    /// pitch to diameter ratio, L_tp/d_o
+    #[inline]
+    pub fn get_pitch_to_diameter_ratio(&self) -> Ratio {
+        self.tube_pitch / self.tube_od
+    }
+
+    /// tube row spacing in the flow direction, L_pp
+    #[inline]
+    pub fn get_row_pitch(&self) -> Length {
+        self.tube_pitch * self.tube_layout.get_row_pitch_to_tube_pitch_ratio()
+    }
+
+    /// crossflow area at the shell centreline for the
+    /// central baffle spacing
+    ///
+    /// S_m = L_bc (L_bb + D_ctl/L_tp,eff (L_tp - d_o))
+    #[inline]
+    pub fn get_crossflow_area(&self) -> Area {
+        let effective_pitch = self.tube_pitch
+            * self.tube_layout.get_effective_pitch_to_tube_pitch_ratio();
+
+        self.central_baffle_spacing * (self.bundle_to_shell_clearance
+            + (self.get_central_tube_limit_diameter()/effective_pitch)
+            * (self.tube_pitch - self.tube_od))
+    }
+}
+
+/// Bell-Delaware method for shell side heat transfer
+/// and pressure drop
+pub mod bell_delaware;
+
+/// Kern method for shell side heat transfer and pressure drop
+pub mod kern;
+
+/// unit tests for baffled shell side correlations
+pub mod unit_test_baffled_shell_side;
@@ -0,0 +1,409 @@
+/// baffled shell based on the dimensions of Du's heat exchanger
+/// (19 tubes of 14 mm od in a 100 mm id shell, 1.95 m long)
+/// with a square layout and 12 equally spaced baffles
+#[cfg(test)]
+fn square_layout_test_geometry() -> super::BaffledShellGeometry {
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::ratio::ratio;
+    use super::{BaffledShellGeometry, TubeLayout};
+
+    BaffledShellGeometry {
+        shell_id: Length::new::<meter>(0.1),
+        tube_od: Length::new::<millimeter>(14.0),
+        tube_pitch: Length::new::<millimeter>(19.0),
+        tube_layout: TubeLayout::Square90,
+        number_of_tubes: 19,
+        baffle_cut: Ratio::new::<ratio>(0.25),
+        central_baffle_spacing: Length::new::<meter>(0.15),
+        inlet_baffle_spacing: Length::new::<meter>(0.15),
+        outlet_baffle_spacing: Length::new::<meter>(0.15),
+        number_of_baffles: 12,
+        shell_to_baffle_clearance: Length::new::<millimeter>(3.0),
+        tube_to_baffle_clearance: Length::new::<millimeter>(0.8),
+        bundle_to_shell_clearance: Length::new::<millimeter>(10.0),
+        number_of_sealing_strip_pairs: 0,
+    }
+}
+
+/// hand calculated geometry and correction factors
+///
+/// D_otl = 90 mm, D_ctl = 76 mm
+/// S_m = 0.15 (0.01 + 0.076/0.019 (0.019 - 0.014)) = 0.0045 m^2
+/// theta_ctl = 2 acos(0.05/0.076), F_w = 0.113735
+/// J_c = 0.55 + 0.72 (1 - 2 F_w) = 1.106221
+/// S_w = 0.0012028 m^2
+/// N_c = 0.05/0.019 = 2.631579, N_cw = 0.8/0.019 (0.025 - 0.012) = 0.547368
+/// S_sb = 3.14159e-4 m^2, S_tb = 3.04712e-4 m^2, J_l = 0.795484
+/// J_b = exp(-1.25 (0.15 x 0.01)/0.0045) = 0.659241
+///
+/// for Re_s = 1e4, a = 1.187/(1 + 0.14 Re_s^0.37),
+/// j_i = 0.37 (1.33/(19/14))^a Re_s^(-0.395) = 9.687453e-3
+#[test]
+pub fn bell_delaware_geometry_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::ratio::ratio;
+    use uom::si::area::square_meter;
+
+    let geometry = square_layout_test_geometry();
+    geometry.try_validate()?;
+
+    approx::assert_relative_eq!(
+        geometry.get_crossflow_area().get::<square_meter>(),
+        0.0045,
+        max_relative = 1e-9);
+    approx::assert_relative_eq!(
+        geometry.get_window_tube_fraction().get::<ratio>(),
+        0.113735,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_baffle_cut_correction_factor().get::<ratio>(),
+        1.106221,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_window_flow_area().get::<square_meter>(),
+        0.0012028,
+        max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        geometry.get_number_of_crossflow_rows(),
+        2.631579,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_number_of_window_rows(),
+        0.547368,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_shell_to_baffle_leakage_area().get::<square_meter>(),
+        3.14159e-4,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_tube_to_baffle_leakage_area().get::<square_meter>(),
+        3.04712e-4,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_leakage_correction_factor().get::<ratio>(),
+        0.795484,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_bypass_correction_factor(1.0e4).get::<ratio>(),
+        0.659241,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_ideal_colburn_j_factor(1.0e4),
+        9.687453e-3,
+        max_relative = 1e-5);
+
+    // equal baffle spacing, so no end zone heat transfer correction
+    // and the end zones have the same crossflow pressure drop as
+    // the central baffles
+    approx::assert_relative_eq!(
+        geometry.get_unequal_spacing_correction_factor(1.0e4).get::<ratio>(),
+        1.0,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        geometry.get_end_zone_pressure_drop_factor(1.0e4).get::<ratio>(),
+        2.0,
+        max_relative = 1e-12);
+
+    Ok(())
+}
+
+/// leakage and bypass streams reduce the shell side heat transfer
+/// and pressure drop, sealing strips recover some of it
+#[test]
+pub fn bell_delaware_leakage_and_bypass_trends() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::length::millimeter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::ratio::ratio;
+    use super::BaffledShellGeometry;
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(2.0);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let viscosity = DynamicViscosity::new::<pascal_second>(1.0e-3);
+    let conductivity = ThermalConductivity::new::<watt_per_meter_kelvin>(0.6);
+    let prandtl = Ratio::new::<ratio>(7.0);
+
+    let heat_transfer_coefficient = |geometry: &BaffledShellGeometry| {
+        geometry.try_get_bell_delaware_heat_transfer_coefficient(
+            mass_flowrate, viscosity, conductivity, prandtl, prandtl)
+            .unwrap()
+    };
+    let pressure_drop = |geometry: &BaffledShellGeometry| {
+        geometry.try_get_bell_delaware_pressure_drop(
+            mass_flowrate, density, viscosity).unwrap()
+    };
+
+    let reference = square_layout_test_geometry();
+
+    let leaky = BaffledShellGeometry {
+        shell_to_baffle_clearance: Length::new::<millimeter>(6.0),
+        tube_to_baffle_clearance: Length::new::<millimeter>(1.6),
+        ..reference
+    };
+
+    let sealed = BaffledShellGeometry {
+        number_of_sealing_strip_pairs: 2,
+        ..reference
+    };
+
+    assert!(heat_transfer_coefficient(&leaky) < heat_transfer_coefficient(&reference));
+    assert!(pressure_drop(&leaky) < pressure_drop(&reference));
+
+    // N_ss/N_c = 2/2.63 > 0.5, so bypass is fully sealed
+    assert_eq!(sealed.get_bypass_correction_factor(1.0e4).get::<ratio>(), 1.0);
+    assert!(heat_transfer_coefficient(&sealed) > heat_transfer_coefficient(&reference));
+    assert!(pressure_drop(&sealed) > pressure_drop(&reference));
+
+    // total correction should be in the usual range of 0.4 to 1.0
+    // for a reasonable design
+    let crossflow_reynolds = reference.get_crossflow_reynolds(
+        mass_flowrate, viscosity).get::<ratio>();
+    let total_correction = reference
+        .get_total_heat_transfer_correction_factor(crossflow_reynolds)
+        .get::<ratio>();
+    assert!(total_correction > 0.4 && total_correction < 1.0);
+
+    // pressure drop should increase with flowrate
+    // both in laminar and turbulent flow
+    let mut previous_pressure_drop = Pressure::new::<uom::si::pressure::pascal>(0.0);
+    for flowrate in [1.0e-3, 1.0e-2, 0.1, 1.0, 10.0] {
+        let pressure_drop = reference.try_get_bell_delaware_pressure_drop(
+            MassRate::new::<kilogram_per_second>(flowrate),
+            density, viscosity)?;
+        assert!(pressure_drop > previous_pressure_drop);
+        previous_pressure_drop = pressure_drop;
+    }
+
+    Ok(())
+}
+
+/// the nusselt number and (f L/D + K) depend on the reference
+/// lengthscales, but the heat transfer coefficient and pressure
+/// drop for a given mass flowrate should not
+#[test]
+pub fn baffled_shell_side_reference_lengthscale_conversion() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::BaffledShellSide;
+
+    let geometry = square_layout_test_geometry();
+    let number_of_tubes = geometry.number_of_tubes as f64;
+
+    // Du's shell side flow area and hydraulic diameter
+    let shell_side = BaffledShellSide {
+        geometry,
+        reference_flow_area: PI * 0.25 * (geometry.shell_id * geometry.shell_id
+            - number_of_tubes * geometry.tube_od * geometry.tube_od),
+        reference_hydraulic_diameter: (geometry.shell_id * geometry.shell_id
+            - number_of_tubes * geometry.tube_od * geometry.tube_od)
+            / (geometry.shell_id + number_of_tubes * geometry.tube_od),
+    };
+
+    let own_reference = BaffledShellSide {
+        geometry,
+        reference_flow_area: geometry.get_crossflow_area(),
+        reference_hydraulic_diameter: geometry.tube_od,
+    };
+
+    let prandtl_bulk = Ratio::new::<ratio>(7.0);
+    let prandtl_wall = Ratio::new::<ratio>(5.0);
+
+    // Re = m D_h/(A mu), so for the same m/mu,
+    // Re_own = Re (A/S_m)(d_o/D_h)
+    for reynolds in [50.0, 5.0e3, 5.0e4] {
+        let reynolds = Ratio::new::<ratio>(reynolds);
+        let own_reynolds = shell_side.get_crossflow_reynolds(reynolds);
+
+        // h = Nu k/D
+        let h_over_k = shell_side.try_get_bell_delaware_nusselt(
+            reynolds, prandtl_bulk, prandtl_wall)?
+            / shell_side.reference_hydraulic_diameter;
+        let own_h_over_k = own_reference.try_get_bell_delaware_nusselt(
+            own_reynolds, prandtl_bulk, prandtl_wall)?
+            / own_reference.reference_hydraulic_diameter;
+
+        approx::assert_relative_eq!(h_over_k.value, own_h_over_k.value,
+            max_relative = 1e-9);
+
+        // dp = fldk m^2/(2 rho A^2)
+        let dp_scale = shell_side.try_get_bell_delaware_fldk(reynolds)?
+            / (shell_side.reference_flow_area * shell_side.reference_flow_area);
+        let own_dp_scale = own_reference.try_get_bell_delaware_fldk(own_reynolds)?
+            / (own_reference.reference_flow_area * own_reference.reference_flow_area);
+
+        approx::assert_relative_eq!(dp_scale.value, own_dp_scale.value,
+            max_relative = 1e-9);
+
+        // same for Kern
+        let kern_h_over_k = shell_side.try_get_kern_nusselt(
+            reynolds, prandtl_bulk, prandtl_wall)?
+            / shell_side.reference_hydraulic_diameter;
+        let own_kern_h_over_k = own_reference.try_get_kern_nusselt(
+            own_reynolds, prandtl_bulk, prandtl_wall)?
+            / own_reference.reference_hydraulic_diameter;
+
+        approx::assert_relative_eq!(kern_h_over_k.value, own_kern_h_over_k.value,
+            max_relative = 1e-9);
+    }
+
+    Ok(())
+}
+
+/// hand calculated Kern method
+///
+/// D_e = 4 (0.019^2 - pi 0.014^2/4)/(pi 0.014) = 0.0188314 m
+/// A_s = 0.1 (0.019 - 0.014) 0.15/0.019 = 3.947368e-3 m^2
+///
+/// with the reference flow area A_s and hydraulic diameter D_e,
+/// Re_e = 1e4, Pr = 7:
+/// Nu_e = 0.36 1e4^0.55 7^(1/3) = 109.1445
+/// f = exp(0.576 - 0.19 ln 1e4) = 0.309139
+/// fldk = f (12 + 1) (0.1/0.0188314) = 21.3410
+#[test]
+pub fn kern_method_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+    use super::BaffledShellSide;
+
+    let geometry = square_layout_test_geometry();
+
+    approx::assert_relative_eq!(
+        geometry.get_kern_equivalent_diameter().get::<meter>(),
+        0.0188314,
+        max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        geometry.get_kern_crossflow_area().get::<square_meter>(),
+        3.947368e-3,
+        max_relative = 1e-6);
+
+    let shell_side = BaffledShellSide {
+        geometry,
+        reference_flow_area: geometry.get_kern_crossflow_area(),
+        reference_hydraulic_diameter: geometry.get_kern_equivalent_diameter(),
+    };
+
+    let reynolds = Ratio::new::<ratio>(1.0e4);
+    let prandtl = Ratio::new::<ratio>(7.0);
+
+    approx::assert_relative_eq!(
+        shell_side.try_get_kern_nusselt(reynolds, prandtl, prandtl)?
+        .get::<ratio>(),
+        109.1445,
+        max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        shell_side.try_get_kern_fldk(reynolds)?.get::<ratio>(),
+        21.3410,
+        max_relative = 1e-4);
+
+    Ok(())
+}
+
+/// unphysical geometries should be rejected
+#[test]
+pub fn baffled_shell_geometry_validation() {
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::millimeter;
+    use super::BaffledShellGeometry;
+
+    let reference = square_layout_test_geometry();
+
+    let half_cut = BaffledShellGeometry {
+        baffle_cut: Ratio::new::<ratio>(0.5),
+        ..reference
+    };
+    assert!(half_cut.try_validate().is_err());
+
+    let touching_tubes = BaffledShellGeometry {
+        tube_pitch: Length::new::<millimeter>(14.0),
+        ..reference
+    };
+    assert!(touching_tubes.try_validate().is_err());
+
+    // a small bundle in a large shell leaves the baffle cut
+    // edge outside the tube bundle
+    let small_bundle = BaffledShellGeometry {
+        bundle_to_shell_clearance: Length::new::<millimeter>(40.0),
+        ..reference
+    };
+    assert!(small_bundle.try_validate().is_err());
+}
+
+/// setting the Bell-Delaware shell side on Du's heat exchanger
+/// should change the shell side fluid array pressure drop to
+/// the Bell-Delaware pressure drop
+#[test]
+pub fn sthe_bell_delaware_shell_side_setup() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::length::millimeter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+    use super::BaffledShellGeometry;
+
+    let mut sthe = SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+    let geometry = square_layout_test_geometry();
+
+    // geometry must match the heat exchanger
+    let mismatched_geometry = BaffledShellGeometry {
+        tube_od: Length::new::<millimeter>(16.0),
+        ..geometry
+    };
+    assert!(sthe.try_set_bell_delaware_shell_side(mismatched_geometry).is_err());
+
+    sthe.try_set_bell_delaware_shell_side(geometry)?;
+
+    assert!(matches!(sthe.shell_side_nusselt_correlation_to_tubes,
+        NusseltCorrelation::ShellSideBellDelaware(_)));
+    assert!(matches!(sthe.shell_side_custom_component_loss_correlation,
+        DimensionlessDarcyLossCorrelations::BellDelawareShellSide(_)));
+
+    let shell_side_fluid_array: FluidArray =
+        sthe.shell_side_fluid_array.clone().try_into()?;
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+    let fluid_array_pressure_loss = shell_side_fluid_array
+        .get_pressure_loss_immutable(mass_flowrate);
+    let bell_delaware_pressure_loss = geometry
+        .try_get_bell_delaware_pressure_drop(
+            mass_flowrate,
+            shell_side_fluid_array.get_fluid_density_immutable(),
+            shell_side_fluid_array.get_fluid_viscosity_immutable())?;
+
+    approx::assert_relative_eq!(
+        fluid_array_pressure_loss.value,
+        bell_delaware_pressure_loss.value,
+        max_relative = 1e-6);
+
+    // the shell side to tube conductance should now use
+    // the Bell-Delaware nusselt number
+    sthe.set_shell_side_total_mass_flowrate(mass_flowrate);
+    let conductance = sthe
+        .get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(true)?;
+    assert!(conductance.value > 0.0);
+
+    sthe.try_set_kern_shell_side(geometry)?;
+
+    assert!(matches!(sthe.shell_side_nusselt_correlation_to_tubes,
+        NusseltCorrelation::ShellSideKern(_)));
+
+    Ok(())
+}
@@ -0,0 +1,384 @@
+//! validity envelopes for nusselt and friction factor correlations
+//!
+//! correlations are fitted to data over a limited range of reynolds
+//! number, prandtl number (or peclet number for liquid metals) and
+//! length to diameter ratio. Outside this range, they still return
+//! a number, but it is an extrapolation.
+//!
+//! each correlation declares its envelope as a constant next to
+//! where it is implemented, for example
+//! [DITTUS_BOELTER_VALIDITY_ENVELOPE](crate::heat_transfer_correlations::nusselt_number_correlations::pipe_correlations::DITTUS_BOELTER_VALIDITY_ENVELOPE).
+//!
+//! prebuilt components carry a [CorrelationValidityMonitor]
+//! which checks every correlation evaluation against its envelope
+//! and applies a [CorrelationValidityPolicy]:
+//!
+//! 1. ignore: just keep count (default, so existing simulations
+//!    behave as before)
+//! 2. warn once: print a warning to stderr the first time each
+//!    correlation is extrapolated
+//! 3. error: return an error as soon as a correlation is extrapolated
+//!
+//! in all cases, the monitor keeps a report of how often and how far
+//! each correlation was extrapolated, which can be read out after
+//! a transient
+//!
+//! ```rust
+//! use uom::si::f64::*;
+//! use uom::si::ratio::ratio;
+//! use tuas_boussinesq_solver::heat_transfer_correlations::
+//! correlation_validity::{CorrelationValidityMonitor, CorrelationValidityPolicy};
+//! use tuas_boussinesq_solver::heat_transfer_correlations::
+//! nusselt_number_correlations::pipe_correlations::DITTUS_BOELTER_VALIDITY_ENVELOPE;
+//!
+//! let mut monitor = CorrelationValidityMonitor::new(
+//!     CorrelationValidityPolicy::Ignore);
+//!
+//! // Re = 5000 is below the Re = 10000 lower bound
+//! monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+//!     Ratio::new::<ratio>(5000.0),
+//!     Ratio::new::<ratio>(7.0),
+//!     Ratio::new::<ratio>(50.0)).unwrap();
+//!
+//! let record = &monitor.extrapolation_report[0];
+//! assert_eq!(record.number_of_extrapolations, 1);
+//! approx::assert_relative_eq!(record.max_reynolds_extrapolation, 0.5);
+//! ```
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::tuas_lib_error::TuasLibError;
+
+/// range of a single dimensionless group in which a correlation
+/// is valid, bounds are inclusive
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct CorrelationValidityRange {
+    /// lower bound of the range
+    pub lower_bound: f64,
+    /// upper bound of the range
+    pub upper_bound: f64,
+}
+
+impl CorrelationValidityRange {
+
+    /// range with no bounds at all
+    pub const UNBOUNDED: Self = Self::new(f64::NEG_INFINITY, f64::INFINITY);
+
+    /// creates a new range
+    pub const fn new(lower_bound: f64, upper_bound: f64) -> Self {
+        Self { lower_bound, upper_bound }
+    }
+
+    /// range with only a lower bound
+    pub const fn new_lower_bound(lower_bound: f64) -> Self {
+        Self::new(lower_bound, f64::INFINITY)
+    }
+
+    /// range with only an upper bound
+    pub const fn new_upper_bound(upper_bound: f64) -> Self {
+        Self::new(f64::NEG_INFINITY, upper_bound)
+    }
+
+    /// how far the value lies outside the range, relative to
+    /// the bound it exceeds
+    ///
+    /// zero within the range, 0.5 for Re = 5000 with a
+    /// lower bound of Re = 10000, 1.0 for Re = 2e6 with an
+    /// upper bound of Re = 1e6
+    ///
+    /// for a bound of zero, the absolute distance is returned
+    pub fn get_relative_extrapolation(&self, value: f64) -> f64 {
+
+        let (bound, distance) =
+            if value < self.lower_bound {
+                (self.lower_bound, self.lower_bound - value)
+            } else if value > self.upper_bound {
+                (self.upper_bound, value - self.upper_bound)
+            } else {
+                return 0.0;
+            };
+
+        if bound == 0.0 {
+            return distance;
+        }
+
+        distance/bound.abs()
+    }
+}
+
+/// validity envelope of a correlation
+///
+/// the peclet number Pe = Re Pr is checked separately as liquid
+/// metal correlations are fitted in terms of Pe
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct CorrelationValidityEnvelope {
+    /// name of the correlation, used as the key in the
+    /// extrapolation report
+    pub correlation_name: &'static str,
+    /// valid reynolds number range
+    pub reynolds: CorrelationValidityRange,
+    /// valid prandtl number range
+    pub prandtl: CorrelationValidityRange,
+    /// valid peclet number range
+    pub peclet: CorrelationValidityRange,
+    /// valid length to diameter range
+    pub length_to_diameter: CorrelationValidityRange,
+}
+
+impl CorrelationValidityEnvelope {
+
+    /// envelope with no bounds, use the struct update syntax
+    /// to set the bounds that matter
+    pub const fn new_unbounded(correlation_name: &'static str) -> Self {
+        Self {
+            correlation_name,
+            reynolds: CorrelationValidityRange::UNBOUNDED,
+            prandtl: CorrelationValidityRange::UNBOUNDED,
+            peclet: CorrelationValidityRange::UNBOUNDED,
+            length_to_diameter: CorrelationValidityRange::UNBOUNDED,
+        }
+    }
+
+    /// returns true if the reynolds, prandtl and length to
+    /// diameter ratio are all within the envelope
+    pub fn is_within_envelope(&self,
+        reynolds: Ratio,
+        prandtl: Ratio,
+        length_to_diameter: Ratio) -> bool {
+
+        let reynolds = reynolds.get::<ratio>();
+        let prandtl = prandtl.get::<ratio>();
+
+        self.reynolds.get_relative_extrapolation(reynolds) == 0.0
+            && self.prandtl.get_relative_extrapolation(prandtl) == 0.0
+            && self.peclet.get_relative_extrapolation(reynolds * prandtl) == 0.0
+            && self.length_to_diameter.get_relative_extrapolation(
+                length_to_diameter.get::<ratio>()) == 0.0
+    }
+}
+
+/// what to do when a correlation is evaluated outside
+/// its validity envelope
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum CorrelationValidityPolicy {
+    /// only record the extrapolation in the report
+    #[default]
+    Ignore,
+    /// record the extrapolation and print a warning to stderr
+    /// the first time each correlation is extrapolated
+    WarnOnce,
+    /// return an error
+    Error,
+}
+
+/// how often and how far a single correlation was extrapolated
+///
+/// relative extrapolations are as in
+/// [CorrelationValidityRange::get_relative_extrapolation]
+#[derive(Debug,Clone,PartialEq)]
+pub struct CorrelationExtrapolationRecord {
+    /// name of the correlation
+    pub correlation_name: &'static str,
+    /// number of times the correlation was checked
+    pub number_of_evaluations: usize,
+    /// number of times the correlation was outside its envelope
+    pub number_of_extrapolations: usize,
+    /// largest relative extrapolation in reynolds number
+    pub max_reynolds_extrapolation: f64,
+    /// largest relative extrapolation in prandtl number
+    pub max_prandtl_extrapolation: f64,
+    /// largest relative extrapolation in peclet number
+    pub max_peclet_extrapolation: f64,
+    /// largest relative extrapolation in length to diameter ratio
+    pub max_length_to_diameter_extrapolation: f64,
+    /// whether a warning was already printed for this correlation
+    pub warning_issued: bool,
+}
+
+impl CorrelationExtrapolationRecord {
+
+    fn new(correlation_name: &'static str) -> Self {
+        Self {
+            correlation_name,
+            number_of_evaluations: 0,
+            number_of_extrapolations: 0,
+            max_reynolds_extrapolation: 0.0,
+            max_prandtl_extrapolation: 0.0,
+            max_peclet_extrapolation: 0.0,
+            max_length_to_diameter_extrapolation: 0.0,
+            warning_issued: false,
+        }
+    }
+
+    /// fraction of evaluations which were extrapolations
+    pub fn get_extrapolated_fraction(&self) -> Ratio {
+        if self.number_of_evaluations == 0 {
+            return Ratio::new::<ratio>(0.0);
+        }
+        Ratio::new::<ratio>(
+            self.number_of_extrapolations as f64 /
+            self.number_of_evaluations as f64)
+    }
+}
+
+/// checks correlation evaluations against their validity envelopes
+/// and keeps a report of extrapolations, one per component
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct CorrelationValidityMonitor {
+    /// what to do when a correlation is extrapolated
+    pub policy: CorrelationValidityPolicy,
+    /// one record per correlation checked, in the order
+    /// they were first checked
+    pub extrapolation_report: Vec<CorrelationExtrapolationRecord>,
+}
+
+impl CorrelationValidityMonitor {
+
+    /// creates a monitor with an empty report
+    pub fn new(policy: CorrelationValidityPolicy) -> Self {
+        Self {
+            policy,
+            extrapolation_report: vec![],
+        }
+    }
+
+    /// checks the reynolds number, prandtl number and length to
+    /// diameter ratio against the envelope, records the result
+    /// and applies the policy
+    pub fn try_check(&mut self,
+        envelope: &CorrelationValidityEnvelope,
+        reynolds: Ratio,
+        prandtl: Ratio,
+        length_to_diameter: Ratio) -> Result<(), TuasLibError> {
+
+        let reynolds = reynolds.get::<ratio>();
+        let prandtl = prandtl.get::<ratio>();
+        let length_to_diameter = length_to_diameter.get::<ratio>();
+
+        let reynolds_extrapolation = envelope.reynolds
+            .get_relative_extrapolation(reynolds);
+        let prandtl_extrapolation = envelope.prandtl
+            .get_relative_extrapolation(prandtl);
+        let peclet_extrapolation = envelope.peclet
+            .get_relative_extrapolation(reynolds * prandtl);
+        let length_to_diameter_extrapolation = envelope.length_to_diameter
+            .get_relative_extrapolation(length_to_diameter);
+
+        let policy = self.policy;
+        let record = self.get_record_mut(envelope.correlation_name);
+        record.number_of_evaluations += 1;
+
+        let is_extrapolated = reynolds_extrapolation > 0.0
+            || prandtl_extrapolation > 0.0
+            || peclet_extrapolation > 0.0
+            || length_to_diameter_extrapolation > 0.0;
+
+        if !is_extrapolated {
+            return Ok(());
+        }
+
+        record.number_of_extrapolations += 1;
+        record.max_reynolds_extrapolation =
+            record.max_reynolds_extrapolation.max(reynolds_extrapolation);
+        record.max_prandtl_extrapolation =
+            record.max_prandtl_extrapolation.max(prandtl_extrapolation);
+        record.max_peclet_extrapolation =
+            record.max_peclet_extrapolation.max(peclet_extrapolation);
+        record.max_length_to_diameter_extrapolation =
+            record.max_length_to_diameter_extrapolation
+            .max(length_to_diameter_extrapolation);
+
+        let message = format!(
+            "{} correlation evaluated outside its validity envelope \
+            at Re = {:.4e}, Pr = {:.4e}, L/D = {:.4e}",
+            envelope.correlation_name, reynolds, prandtl, length_to_diameter);
+
+        match policy {
+            CorrelationValidityPolicy::Ignore => (),
+            CorrelationValidityPolicy::WarnOnce => {
+                if !record.warning_issued {
+                    eprintln!("warning: {}", message);
+                    record.warning_issued = true;
+                }
+            },
+            CorrelationValidityPolicy::Error => {
+                return Err(TuasLibError::GenericStringError(message));
+            },
+        }
+
+        Ok(())
+    }
+
+    /// checks a nusselt correlation, correlations without a
+    /// declared envelope are not recorded
+    pub fn try_check_nusselt_correlation(&mut self,
+        nusselt_correlation: &NusseltCorrelation,
+        reynolds: Ratio,
+        prandtl: Ratio,
+        length_to_diameter: Ratio) -> Result<(), TuasLibError> {
+
+        match nusselt_correlation.get_validity_envelope() {
+            Some(envelope) => self.try_check(&envelope,
+                reynolds, prandtl, length_to_diameter),
+            None => Ok(()),
+        }
+    }
+
+    /// checks a friction factor correlation, correlations without a
+    /// declared envelope are not recorded
+    ///
+    /// friction factors do not depend on prandtl number, so
+    /// the peclet number is not checked
+    pub fn try_check_friction_correlation(&mut self,
+        loss_correlation: &DimensionlessDarcyLossCorrelations,
+        reynolds: Ratio,
+        length_to_diameter: Ratio) -> Result<(), TuasLibError> {
+
+        match loss_correlation.get_validity_envelope() {
+            Some(envelope) => self.try_check(&envelope,
+                reynolds, Ratio::new::<ratio>(1.0), length_to_diameter),
+            None => Ok(()),
+        }
+    }
+
+    /// returns the record for a correlation by name, if it
+    /// has been checked before
+    pub fn get_record(&self, correlation_name: &str)
+        -> Option<&CorrelationExtrapolationRecord> {
+        self.extrapolation_report.iter()
+            .find(|record| record.correlation_name == correlation_name)
+    }
+
+    /// total number of extrapolations over all correlations
+    pub fn get_total_extrapolations(&self) -> usize {
+        self.extrapolation_report.iter()
+            .map(|record| record.number_of_extrapolations)
+            .sum()
+    }
+
+    /// clears the report, for example at the start of a new transient
+    pub fn clear_report(&mut self) {
+        self.extrapolation_report.clear();
+    }
+
+    fn get_record_mut(&mut self, correlation_name: &'static str)
+        -> &mut CorrelationExtrapolationRecord {
+
+        let index = match self.extrapolation_report.iter()
+            .position(|record| record.correlation_name == correlation_name) {
+                Some(index) => index,
+                None => {
+                    self.extrapolation_report.push(
+                        CorrelationExtrapolationRecord::new(correlation_name));
+                    self.extrapolation_report.len() - 1
+                },
+            };
+
+        &mut self.extrapolation_report[index]
+    }
+}
+
+/// unit tests for correlation validity envelopes and monitors
+pub mod unit_test_correlation_validity;
@@ -0,0 +1,191 @@
+/// relative extrapolations of single ranges, and the envelopes
+/// declared by the correlations
+///
+/// lower bound Re = 10000, Re = 5000 is 50% below
+/// upper bound Re = 1e6, Re = 2e6 is 100% above
+#[test]
+pub fn relative_extrapolation_and_declared_envelopes() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::CorrelationValidityRange;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::GnielinskiData;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::pipe_correlations::{
+        DITTUS_BOELTER_VALIDITY_ENVELOPE, GNIELINSKI_VALIDITY_ENVELOPE};
+    use crate::fluid_mechanics_correlations::churchill_friction_factor::CHURCHILL_VALIDITY_ENVELOPE;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+
+    let range = CorrelationValidityRange::new(1.0e4, 1.0e6);
+
+    approx::assert_relative_eq!(range.get_relative_extrapolation(5.0e3), 0.5);
+    approx::assert_relative_eq!(range.get_relative_extrapolation(2.0e6), 1.0);
+    assert_eq!(range.get_relative_extrapolation(1.0e4), 0.0);
+    assert_eq!(range.get_relative_extrapolation(5.0e5), 0.0);
+    assert_eq!(CorrelationValidityRange::UNBOUNDED
+        .get_relative_extrapolation(1.0e30), 0.0);
+
+    let ratio_of = |value: f64| Ratio::new::<ratio>(value);
+
+    // Dittus Boelter: Re >= 10000, 0.6 <= Pr <= 160, L/D >= 10
+    assert!(DITTUS_BOELTER_VALIDITY_ENVELOPE.is_within_envelope(
+            ratio_of(2.0e4), ratio_of(7.0), ratio_of(50.0)));
+    assert!(!DITTUS_BOELTER_VALIDITY_ENVELOPE.is_within_envelope(
+            ratio_of(2.0e4), ratio_of(7.0), ratio_of(5.0)));
+    assert!(!DITTUS_BOELTER_VALIDITY_ENVELOPE.is_within_envelope(
+            ratio_of(2.0e4), ratio_of(200.0), ratio_of(50.0)));
+
+    // gnielinski type nusselt correlations declare the
+    // gnielinski envelope, fixed nusselt numbers do not
+    let gnielinski = NusseltCorrelation::PipeGnielinskiGeneric(
+        GnielinskiData::default());
+    assert_eq!(gnielinski.get_validity_envelope(),
+        Some(GNIELINSKI_VALIDITY_ENVELOPE));
+    assert_eq!(NusseltCorrelation::FixedNusselt(ratio_of(10.0))
+        .get_validity_envelope(), None);
+
+    // pipes use the churchill friction factor
+    let pipe_loss = DimensionlessDarcyLossCorrelations::Pipe(
+        ratio_of(1.0e-5), ratio_of(100.0), ratio_of(0.0));
+    assert_eq!(pipe_loss.get_validity_envelope(),
+        Some(CHURCHILL_VALIDITY_ENVELOPE));
+    assert!(!CHURCHILL_VALIDITY_ENVELOPE.is_within_envelope(
+            ratio_of(2.0e8), ratio_of(1.0), ratio_of(100.0)));
+
+    Ok(())
+}
+
+/// the report keeps counts and the largest extrapolation for
+/// every policy, warnings are only issued once and errors are
+/// returned immediately
+#[test]
+pub fn monitor_policies_and_report() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::{CorrelationValidityMonitor, CorrelationValidityPolicy};
+    use crate::heat_transfer_correlations::nusselt_number_correlations::pipe_correlations::{
+        DITTUS_BOELTER_VALIDITY_ENVELOPE, GNIELINSKI_TURBULENT_VALIDITY_ENVELOPE};
+
+    let ratio_of = |value: f64| Ratio::new::<ratio>(value);
+
+    let mut monitor = CorrelationValidityMonitor::default();
+    assert_eq!(monitor.policy, CorrelationValidityPolicy::Ignore);
+
+    // within envelope, then Re = 8000 (20% low), then
+    // Re = 5000 and Pr = 320 (50% low and 100% high)
+    monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+        ratio_of(2.0e4), ratio_of(7.0), ratio_of(50.0))?;
+    monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+        ratio_of(8.0e3), ratio_of(7.0), ratio_of(50.0))?;
+    monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+        ratio_of(5.0e3), ratio_of(320.0), ratio_of(50.0))?;
+    monitor.try_check(&GNIELINSKI_TURBULENT_VALIDITY_ENVELOPE,
+        ratio_of(1.0e4), ratio_of(7.0), ratio_of(50.0))?;
+
+    let dittus_boelter_record = monitor.get_record("Dittus Boelter").unwrap();
+    assert_eq!(dittus_boelter_record.number_of_evaluations, 3);
+    assert_eq!(dittus_boelter_record.number_of_extrapolations, 2);
+    approx::assert_relative_eq!(
+        dittus_boelter_record.max_reynolds_extrapolation, 0.5);
+    approx::assert_relative_eq!(
+        dittus_boelter_record.max_prandtl_extrapolation, 1.0);
+    approx::assert_relative_eq!(
+        dittus_boelter_record.get_extrapolated_fraction().get::<ratio>(),
+        2.0/3.0);
+    assert!(!dittus_boelter_record.warning_issued);
+
+    let gnielinski_record = monitor.get_record("Gnielinski turbulent").unwrap();
+    assert_eq!(gnielinski_record.number_of_evaluations, 1);
+    assert_eq!(gnielinski_record.number_of_extrapolations, 0);
+    assert_eq!(monitor.get_total_extrapolations(), 2);
+
+    // warn once only flags the record once
+    monitor.policy = CorrelationValidityPolicy::WarnOnce;
+    monitor.clear_report();
+    for _ in 0..3 {
+        monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+            ratio_of(5.0e3), ratio_of(7.0), ratio_of(50.0))?;
+    }
+    let dittus_boelter_record = monitor.get_record("Dittus Boelter").unwrap();
+    assert!(dittus_boelter_record.warning_issued);
+    assert_eq!(dittus_boelter_record.number_of_extrapolations, 3);
+
+    // error policy returns an error, but still records it
+    monitor.policy = CorrelationValidityPolicy::Error;
+    assert!(monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+            ratio_of(2.0e4), ratio_of(7.0), ratio_of(50.0)).is_ok());
+    assert!(monitor.try_check(&DITTUS_BOELTER_VALIDITY_ENVELOPE,
+            ratio_of(5.0e3), ratio_of(7.0), ratio_of(50.0)).is_err());
+    assert_eq!(monitor.get_total_extrapolations(), 4);
+
+    Ok(())
+}
+
+/// a non insulated component records into its monitor during the 
+/// lateral connections only, calling the public conductance 
+/// getters afterwards should not count the evaluations again
+#[test]
+pub fn non_insulated_component_records_lateral_connections_only() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+
+    let id = Length::new::<meter>(0.02);
+
+    let mut pipe = NonInsulatedFluidComponent::new_bare_pipe(
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        Pressure::new::<atmosphere>(1.0),
+        Pressure::new::<atmosphere>(1.0),
+        Area::new::<square_meter>(std::f64::consts::PI * 0.01 * 0.01),
+        Angle::new::<degree>(90.0),
+        Ratio::new::<ratio>(0.0),
+        id,
+        Length::new::<meter>(0.025),
+        Length::new::<meter>(1.0),
+        id,
+        Length::new::<millimeter>(0.015),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+        2);
+
+    let get_total_evaluations = |pipe: &NonInsulatedFluidComponent| -> usize {
+        pipe.get_options().correlation_validity_monitor
+            .extrapolation_report.iter()
+            .map(|record| record.number_of_evaluations)
+            .sum()
+    };
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.18);
+    pipe.lateral_and_miscellaneous_connections_no_wall_correction(
+        mass_flowrate, Power::new::<watt>(0.0))?;
+
+    let evaluations_after_lateral_connection = get_total_evaluations(&pipe);
+    assert!(evaluations_after_lateral_connection > 0);
+
+    let correct_prandtl_for_wall_temperatures = false;
+    pipe.get_fluid_array_node_pipe_shell_conductance(
+        correct_prandtl_for_wall_temperatures)?;
+    pipe.get_fluid_array_node_pipe_shell_conductance_vector(
+        correct_prandtl_for_wall_temperatures)?;
+
+    assert_eq!(get_total_evaluations(&pipe), 
+        evaluations_after_lateral_connection);
+
+    Ok(())
+}
@@ -0,0 +1,562 @@
+//! effectiveness-NTU and LMTD methods for steady state
+//! heat exchanger rating and sizing
+//!
+//! the effectiveness is the ratio of the heat transfer rate to
+//! the maximum possible heat transfer rate
+//!
+//! epsilon = Q / (C_min (T_hot,in - T_cold,in))
+//!
+//! and the number of transfer units is
+//!
+//! NTU = UA / C_min
+//!
+//! where C = m c_p is the heat capacity rate of each stream.
+//! The capacity rate ratio C_r = C_min/C_max is used throughout.
+//!
+//! correlations are from:
+//!
+//! Incropera, F. P., DeWitt, D. P., Bergman, T. L., & Lavine,
+//! A. S. (2007). Fundamentals of heat and mass transfer (6th ed.).
+//! Wiley. Table 11.3 and 11.4
+//!
+//! the crossflow correlation with both fluids unmixed is the
+//! approximate correlation found in the same reference
+use roots::{find_root_brent, SimpleConvergency};
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::power::watt;
+use uom::si::thermal_conductance::watt_per_kelvin;
+use uom::si::temperature_interval;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::heat_transfer_correlations::thermal_resistance::subtract_two_thermodynamic_temperatures;
+use crate::tuas_lib_error::TuasLibError;
+
+/// flow arrangement of a two stream heat exchanger
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum HeatExchangerFlowArrangement {
+    /// both streams flow in opposite directions
+    #[default]
+    CounterFlow,
+    /// both streams flow in the same direction
+    ParallelFlow,
+    /// shell and tube heat exchanger with the given number of
+    /// shell passes, each shell pass has an even number of
+    /// tube passes (eg. 1-2, 1-4 exchangers have one shell pass)
+    ShellAndTube(u32),
+    /// single pass crossflow with both fluids unmixed
+    CrossFlowBothUnmixed,
+    /// single pass crossflow with the C_max fluid mixed and
+    /// the C_min fluid unmixed
+    CrossFlowCmaxMixed,
+    /// single pass crossflow with the C_min fluid mixed and
+    /// the C_max fluid unmixed
+    CrossFlowCminMixed,
+    /// single pass crossflow with both fluids mixed
+    CrossFlowBothMixed,
+}
+
+fn check_capacity_rate_ratio(capacity_rate_ratio: f64) -> Result<(), TuasLibError> {
+    if !(0.0..=1.0).contains(&capacity_rate_ratio) {
+        return Err(TuasLibError::GenericStringError(
+            "capacity rate ratio C_min/C_max must be between 0 and 1"
+            .to_string()));
+    }
+    Ok(())
+}
+
+impl HeatExchangerFlowArrangement {
+
+    /// effectiveness given the number of transfer units
+    /// and capacity rate ratio C_r = C_min/C_max
+    ///
+    /// counter flow:
+    /// epsilon = (1 - exp(-N (1 - C_r)))/(1 - C_r exp(-N (1 - C_r)))
+    /// epsilon = N/(1 + N) for C_r = 1
+    ///
+    /// parallel flow:
+    /// epsilon = (1 - exp(-N (1 + C_r)))/(1 + C_r)
+    ///
+    /// shell and tube, one shell pass, S = (1 + C_r^2)^0.5:
+    /// epsilon_1 = 2 (1 + C_r + S (1 + exp(-N_1 S))/(1 - exp(-N_1 S)))^(-1)
+    ///
+    /// for n shell passes with N_1 = N/n,
+    /// epsilon = ((1 - epsilon_1 C_r)/(1 - epsilon_1))^n - 1)
+    ///     / (((1 - epsilon_1 C_r)/(1 - epsilon_1))^n - C_r)
+    ///
+    /// crossflow, both unmixed:
+    /// epsilon = 1 - exp(N^0.22/C_r (exp(-C_r N^0.78) - 1))
+    ///
+    /// crossflow, C_max mixed:
+    /// epsilon = 1/C_r (1 - exp(-C_r (1 - exp(-N))))
+    ///
+    /// crossflow, C_min mixed:
+    /// epsilon = 1 - exp(-1/C_r (1 - exp(-C_r N)))
+    ///
+    /// crossflow, both mixed:
+    /// epsilon = (1/(1 - exp(-N)) + C_r/(1 - exp(-C_r N)) - 1/N)^(-1)
+    ///
+    /// for C_r = 0, all arrangements give epsilon = 1 - exp(-N)
+    pub fn try_get_effectiveness(&self,
+        number_of_transfer_units: Ratio,
+        capacity_rate_ratio: Ratio) -> Result<Ratio, TuasLibError> {
+
+        let ntu = number_of_transfer_units.get::<ratio>();
+        let c_r = capacity_rate_ratio.get::<ratio>();
+
+        check_capacity_rate_ratio(c_r)?;
+
+        if ntu < 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "number of transfer units must not be negative"
+                .to_string()));
+        }
+
+        if ntu == 0.0 {
+            return Ok(Ratio::new::<ratio>(0.0));
+        }
+
+        // one stream is condensing or boiling, or has an
+        // infinite capacity rate
+        if c_r < 1e-12 {
+            return Ok(Ratio::new::<ratio>(1.0 - (-ntu).exp()));
+        }
+
+        let effectiveness = match self {
+            HeatExchangerFlowArrangement::CounterFlow => {
+                counter_flow_effectiveness(ntu, c_r)
+            },
+            HeatExchangerFlowArrangement::ParallelFlow => {
+                (1.0 - (-ntu * (1.0 + c_r)).exp())/(1.0 + c_r)
+            },
+            HeatExchangerFlowArrangement::ShellAndTube(shell_passes) => {
+                let shell_passes = check_shell_passes(*shell_passes)?;
+                let s = (1.0 + c_r * c_r).sqrt();
+                let ntu_per_shell = ntu/shell_passes;
+                let exp_term = (-ntu_per_shell * s).exp();
+
+                let single_shell_effectiveness = 2.0
+                    / (1.0 + c_r + s * (1.0 + exp_term)/(1.0 - exp_term));
+
+                multiple_shell_effectiveness(single_shell_effectiveness,
+                    c_r, shell_passes)
+            },
+            HeatExchangerFlowArrangement::CrossFlowBothUnmixed => {
+                1.0 - (ntu.powf(0.22)/c_r
+                    * ((-c_r * ntu.powf(0.78)).exp() - 1.0)).exp()
+            },
+            HeatExchangerFlowArrangement::CrossFlowCmaxMixed => {
+                (1.0 - (-c_r * (1.0 - (-ntu).exp())).exp())/c_r
+            },
+            HeatExchangerFlowArrangement::CrossFlowCminMixed => {
+                1.0 - (-(1.0 - (-c_r * ntu).exp())/c_r).exp()
+            },
+            HeatExchangerFlowArrangement::CrossFlowBothMixed => {
+                (1.0/(1.0 - (-ntu).exp())
+                    + c_r/(1.0 - (-c_r * ntu).exp())
+                    - 1.0/ntu).recip()
+            },
+        };
+
+        Ok(Ratio::new::<ratio>(effectiveness))
+    }
+
+    /// number of transfer units required to reach a given
+    /// effectiveness with a capacity rate ratio C_r = C_min/C_max
+    ///
+    /// analytical inverses are used where available, the
+    /// crossflow arrangements with both fluids mixed or both
+    /// unmixed are inverted numerically
+    ///
+    /// returns an error if the effectiveness cannot be reached
+    /// with this flow arrangement
+    pub fn try_get_number_of_transfer_units(&self,
+        effectiveness: Ratio,
+        capacity_rate_ratio: Ratio) -> Result<Ratio, TuasLibError> {
+
+        let epsilon = effectiveness.get::<ratio>();
+        let c_r = capacity_rate_ratio.get::<ratio>();
+
+        check_capacity_rate_ratio(c_r)?;
+
+        let unreachable_error = || TuasLibError::GenericStringError(
+            "effectiveness cannot be reached with this heat exchanger \
+            flow arrangement and capacity rate ratio".to_string());
+
+        if !(0.0..1.0).contains(&epsilon) {
+            return Err(unreachable_error());
+        }
+
+        if epsilon == 0.0 {
+            return Ok(Ratio::new::<ratio>(0.0));
+        }
+
+        if c_r < 1e-12 {
+            return Ok(Ratio::new::<ratio>(-(1.0 - epsilon).ln()));
+        }
+
+        let ntu = match self {
+            HeatExchangerFlowArrangement::CounterFlow => {
+                counter_flow_ntu(epsilon, c_r)
+            },
+            HeatExchangerFlowArrangement::ParallelFlow => {
+                let log_argument = 1.0 - epsilon * (1.0 + c_r);
+                if log_argument <= 0.0 {
+                    return Err(unreachable_error());
+                }
+                -log_argument.ln()/(1.0 + c_r)
+            },
+            HeatExchangerFlowArrangement::ShellAndTube(shell_passes) => {
+                let shell_passes = check_shell_passes(*shell_passes)?;
+
+                // effectiveness of a single shell pass
+                let single_shell_effectiveness = if (1.0 - c_r).abs() < 1e-12 {
+                    epsilon/(shell_passes - (shell_passes - 1.0) * epsilon)
+                } else {
+                    let f = ((epsilon * c_r - 1.0)/(epsilon - 1.0))
+                        .powf(1.0/shell_passes);
+                    (f - 1.0)/(f - c_r)
+                };
+
+                let s = (1.0 + c_r * c_r).sqrt();
+                let e = (2.0/single_shell_effectiveness - (1.0 + c_r))/s;
+
+                if e <= 1.0 {
+                    return Err(unreachable_error());
+                }
+
+                -((e - 1.0)/(e + 1.0)).ln()/s * shell_passes
+            },
+            HeatExchangerFlowArrangement::CrossFlowCmaxMixed => {
+                let log_argument = 1.0 + (1.0 - epsilon * c_r).ln()/c_r;
+                if epsilon * c_r >= 1.0 || log_argument <= 0.0 {
+                    return Err(unreachable_error());
+                }
+                -log_argument.ln()
+            },
+            HeatExchangerFlowArrangement::CrossFlowCminMixed => {
+                let log_argument = 1.0 + c_r * (1.0 - epsilon).ln();
+                if log_argument <= 0.0 {
+                    return Err(unreachable_error());
+                }
+                -log_argument.ln()/c_r
+            },
+            HeatExchangerFlowArrangement::CrossFlowBothUnmixed |
+            HeatExchangerFlowArrangement::CrossFlowBothMixed => {
+                self.numerical_number_of_transfer_units(epsilon, c_r)
+                    .ok_or_else(unreachable_error)?
+            },
+        };
+
+        Ok(Ratio::new::<ratio>(ntu))
+    }
+
+    /// LMTD correction factor F, so that Q = F UA LMTD_counterflow
+    ///
+    /// F = NTU_counterflow/NTU_arrangement at the same
+    /// effectiveness and capacity rate ratio
+    pub fn try_get_lmtd_correction_factor(&self,
+        effectiveness: Ratio,
+        capacity_rate_ratio: Ratio) -> Result<Ratio, TuasLibError> {
+
+        if effectiveness.get::<ratio>() == 0.0 {
+            return Ok(Ratio::new::<ratio>(1.0));
+        }
+
+        let counter_flow_ntu = HeatExchangerFlowArrangement::CounterFlow
+            .try_get_number_of_transfer_units(effectiveness, capacity_rate_ratio)?;
+        let arrangement_ntu = self
+            .try_get_number_of_transfer_units(effectiveness, capacity_rate_ratio)?;
+
+        Ok(counter_flow_ntu/arrangement_ntu)
+    }
+
+    fn numerical_number_of_transfer_units(&self,
+        effectiveness: f64, capacity_rate_ratio: f64) -> Option<f64> {
+
+        let effectiveness_error = |ntu: f64| -> f64 {
+            self.try_get_effectiveness(
+                Ratio::new::<ratio>(ntu),
+                Ratio::new::<ratio>(capacity_rate_ratio))
+                .map(|epsilon| epsilon.get::<ratio>() - effectiveness)
+                .unwrap_or(f64::NAN)
+        };
+
+        let maximum_ntu: f64 = 1.0e3;
+
+        // effectiveness is not always monotonic with NTU, for
+        // crossflow with both fluids mixed, it peaks at a finite NTU
+        // and then falls. So we march upwards in small steps to
+        // bracket the smallest NTU that reaches the effectiveness
+        let mut lower_bound_ntu: f64 = 0.0;
+        let mut upper_bound_ntu: f64 = 0.05;
+
+        while effectiveness_error(upper_bound_ntu) <= 0.0 {
+            lower_bound_ntu = upper_bound_ntu;
+            upper_bound_ntu *= 1.1;
+
+            if upper_bound_ntu > maximum_ntu {
+                return None;
+            }
+        }
+
+        let mut convergency = SimpleConvergency {
+            eps: 1e-12_f64,
+            max_iter: 200,
+        };
+
+        find_root_brent(lower_bound_ntu, upper_bound_ntu,
+            effectiveness_error, &mut convergency).ok()
+    }
+}
+
+fn check_shell_passes(shell_passes: u32) -> Result<f64, TuasLibError> {
+    if shell_passes == 0 {
+        return Err(TuasLibError::GenericStringError(
+            "shell and tube heat exchangers need at least one shell pass"
+            .to_string()));
+    }
+    Ok(shell_passes as f64)
+}
+
+fn counter_flow_effectiveness(ntu: f64, c_r: f64) -> f64 {
+    if (1.0 - c_r).abs() < 1e-12 {
+        return ntu/(1.0 + ntu);
+    }
+
+    let exp_term = (-ntu * (1.0 - c_r)).exp();
+
+    (1.0 - exp_term)/(1.0 - c_r * exp_term)
+}
+
+fn counter_flow_ntu(epsilon: f64, c_r: f64) -> f64 {
+    if (1.0 - c_r).abs() < 1e-12 {
+        return epsilon/(1.0 - epsilon);
+    }
+
+    ((1.0 - epsilon * c_r)/(1.0 - epsilon)).ln()/(1.0 - c_r)
+}
+
+fn multiple_shell_effectiveness(single_shell_effectiveness: f64,
+    c_r: f64, shell_passes: f64) -> f64 {
+
+    let epsilon_1 = single_shell_effectiveness;
+
+    if (1.0 - c_r).abs() < 1e-12 {
+        return shell_passes * epsilon_1/(1.0 + (shell_passes - 1.0) * epsilon_1);
+    }
+
+    let ratio_term = ((1.0 - epsilon_1 * c_r)/(1.0 - epsilon_1))
+        .powf(shell_passes);
+
+    (ratio_term - 1.0)/(ratio_term - c_r)
+}
+
+/// steady state rating results for a two stream heat exchanger
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct HeatExchangerRating {
+    /// heat transferred from the hot stream to the cold stream
+    pub heat_transfer_rate: Power,
+    /// effectiveness, Q/Q_max
+    pub effectiveness: Ratio,
+    /// number of transfer units, UA/C_min
+    pub number_of_transfer_units: Ratio,
+    /// capacity rate ratio, C_min/C_max
+    pub capacity_rate_ratio: Ratio,
+    /// LMTD correction factor for the flow arrangement
+    pub lmtd_correction_factor: Ratio,
+    /// hot stream inlet temperature
+    pub hot_inlet_temperature: ThermodynamicTemperature,
+    /// hot stream outlet temperature
+    pub hot_outlet_temperature: ThermodynamicTemperature,
+    /// cold stream inlet temperature
+    pub cold_inlet_temperature: ThermodynamicTemperature,
+    /// cold stream outlet temperature
+    pub cold_outlet_temperature: ThermodynamicTemperature,
+}
+
+impl HeatExchangerRating {
+
+    /// log mean temperature difference based on counter flow
+    /// terminal temperature differences
+    ///
+    /// multiplying this by the LMTD correction factor and UA
+    /// gives the heat transfer rate
+    pub fn get_counter_flow_log_mean_temperature_difference(&self)
+        -> TemperatureInterval {
+
+        let delta_t_a = subtract_two_thermodynamic_temperatures(
+            self.hot_inlet_temperature, self.cold_outlet_temperature)
+            .get::<temperature_interval::kelvin>();
+        let delta_t_b = subtract_two_thermodynamic_temperatures(
+            self.hot_outlet_temperature, self.cold_inlet_temperature)
+            .get::<temperature_interval::kelvin>();
+
+        // equal terminal temperature differences (C_r = 1)
+        let lmtd = if (delta_t_a - delta_t_b).abs() < 1e-9 * delta_t_a.abs().max(1.0) {
+            0.5 * (delta_t_a + delta_t_b)
+        } else {
+            (delta_t_a - delta_t_b)/(delta_t_a/delta_t_b).ln()
+        };
+
+        TemperatureInterval::new::<temperature_interval::kelvin>(lmtd)
+    }
+}
+
+/// capacity rates sorted as (C_min, C_max, C_r)
+fn sort_capacity_rates(hot_capacity_rate: ThermalConductance,
+    cold_capacity_rate: ThermalConductance)
+    -> Result<(ThermalConductance, ThermalConductance, Ratio), TuasLibError> {
+
+    if hot_capacity_rate.get::<watt_per_kelvin>() <= 0.0 ||
+        cold_capacity_rate.get::<watt_per_kelvin>() <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "heat capacity rates (m c_p) must be positive".to_string()));
+    }
+
+    let c_min = hot_capacity_rate.min(cold_capacity_rate);
+    let c_max = hot_capacity_rate.max(cold_capacity_rate);
+
+    Ok((c_min, c_max, c_min/c_max))
+}
+
+/// rates a heat exchanger of known UA using the
+/// effectiveness-NTU method
+///
+/// the capacity rates are C = m c_p for each stream
+///
+/// Q = epsilon C_min (T_hot,in - T_cold,in)
+pub fn try_rate_heat_exchanger(
+    flow_arrangement: HeatExchangerFlowArrangement,
+    overall_conductance: ThermalConductance,
+    hot_inlet_temperature: ThermodynamicTemperature,
+    hot_capacity_rate: ThermalConductance,
+    cold_inlet_temperature: ThermodynamicTemperature,
+    cold_capacity_rate: ThermalConductance) -> Result<HeatExchangerRating, TuasLibError> {
+
+    if hot_inlet_temperature < cold_inlet_temperature {
+        return Err(TuasLibError::GenericStringError(
+            "hot inlet temperature must not be colder than \
+            cold inlet temperature".to_string()));
+    }
+
+    let (c_min, _c_max, capacity_rate_ratio) =
+        sort_capacity_rates(hot_capacity_rate, cold_capacity_rate)?;
+
+    let number_of_transfer_units: Ratio = overall_conductance/c_min;
+
+    let effectiveness = flow_arrangement.try_get_effectiveness(
+        number_of_transfer_units, capacity_rate_ratio)?;
+
+    let maximum_temperature_difference = hot_inlet_temperature.get::<kelvin>()
+        - cold_inlet_temperature.get::<kelvin>();
+
+    let heat_transfer_rate_watts = effectiveness.get::<ratio>()
+        * c_min.get::<watt_per_kelvin>() * maximum_temperature_difference;
+
+    let hot_outlet_temperature = ThermodynamicTemperature::new::<kelvin>(
+        hot_inlet_temperature.get::<kelvin>()
+        - heat_transfer_rate_watts/hot_capacity_rate.get::<watt_per_kelvin>());
+    let cold_outlet_temperature = ThermodynamicTemperature::new::<kelvin>(
+        cold_inlet_temperature.get::<kelvin>()
+        + heat_transfer_rate_watts/cold_capacity_rate.get::<watt_per_kelvin>());
+
+    let lmtd_correction_factor = if effectiveness.get::<ratio>() < 1.0 {
+        flow_arrangement.try_get_lmtd_correction_factor(
+            effectiveness, capacity_rate_ratio)?
+    } else {
+        Ratio::new::<ratio>(1.0)
+    };
+
+    Ok(HeatExchangerRating {
+        heat_transfer_rate: Power::new::<watt>(heat_transfer_rate_watts),
+        effectiveness,
+        number_of_transfer_units,
+        capacity_rate_ratio,
+        lmtd_correction_factor,
+        hot_inlet_temperature,
+        hot_outlet_temperature,
+        cold_inlet_temperature,
+        cold_outlet_temperature,
+    })
+}
+
+/// overall conductance UA required to transfer a given
+/// heat transfer rate (sizing)
+///
+/// UA = NTU(epsilon, C_r) C_min, epsilon = Q/(C_min (T_hot,in - T_cold,in))
+pub fn try_get_required_overall_conductance(
+    flow_arrangement: HeatExchangerFlowArrangement,
+    heat_transfer_rate: Power,
+    hot_inlet_temperature: ThermodynamicTemperature,
+    hot_capacity_rate: ThermalConductance,
+    cold_inlet_temperature: ThermodynamicTemperature,
+    cold_capacity_rate: ThermalConductance) -> Result<ThermalConductance, TuasLibError> {
+
+    let (c_min, _c_max, capacity_rate_ratio) =
+        sort_capacity_rates(hot_capacity_rate, cold_capacity_rate)?;
+
+    let maximum_temperature_difference = hot_inlet_temperature.get::<kelvin>()
+        - cold_inlet_temperature.get::<kelvin>();
+
+    if maximum_temperature_difference <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "hot inlet temperature must be hotter than \
+            cold inlet temperature for sizing".to_string()));
+    }
+
+    let effectiveness = Ratio::new::<ratio>(
+        heat_transfer_rate.get::<watt>()
+        / (c_min.get::<watt_per_kelvin>() * maximum_temperature_difference));
+
+    let number_of_transfer_units = flow_arrangement
+        .try_get_number_of_transfer_units(effectiveness, capacity_rate_ratio)?;
+
+    Ok(number_of_transfer_units * c_min)
+}
+
+/// effectiveness and capacity rate ratio (epsilon, C_r) from the 
+/// inlet and outlet temperatures of both streams, 
+/// eg. from experimental data
+///
+/// from the energy balance, C_hot/C_cold = dT_cold/dT_hot, 
+/// so the C_min stream is the one with the larger temperature 
+/// change, and
+///
+/// epsilon = dT_large/(T_hot,in - T_cold,in)
+/// C_r = dT_small/dT_large
+pub fn try_get_effectiveness_and_capacity_rate_ratio_from_temperatures(
+    hot_inlet_temperature: ThermodynamicTemperature,
+    hot_outlet_temperature: ThermodynamicTemperature,
+    cold_inlet_temperature: ThermodynamicTemperature,
+    cold_outlet_temperature: ThermodynamicTemperature) 
+    -> Result<(Ratio, Ratio), TuasLibError> {
+
+    let maximum_temperature_difference = hot_inlet_temperature.get::<kelvin>()
+        - cold_inlet_temperature.get::<kelvin>();
+    let hot_temperature_change = hot_inlet_temperature.get::<kelvin>()
+        - hot_outlet_temperature.get::<kelvin>();
+    let cold_temperature_change = cold_outlet_temperature.get::<kelvin>()
+        - cold_inlet_temperature.get::<kelvin>();
+
+    if maximum_temperature_difference <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "hot inlet temperature must be hotter than \
+            cold inlet temperature".to_string()));
+    }
+
+    if hot_temperature_change <= 0.0 || cold_temperature_change <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "hot stream must cool down and cold stream must \
+            heat up".to_string()));
+    }
+
+    let larger_temperature_change = hot_temperature_change.max(cold_temperature_change);
+    let smaller_temperature_change = hot_temperature_change.min(cold_temperature_change);
+
+    Ok((Ratio::new::<ratio>(larger_temperature_change/maximum_temperature_difference),
+        Ratio::new::<ratio>(smaller_temperature_change/larger_temperature_change)))
+}
+
+/// unit tests for effectiveness-NTU methods
+pub mod unit_test_effectiveness_ntu;
@@ -0,0 +1,197 @@
+/// hand calculated effectiveness for NTU = 1, C_r = 0.5
+///
+/// counter flow: (1 - exp(-0.5))/(1 - 0.5 exp(-0.5)) = 0.564733
+/// 1-2 shell and tube: S = 1.118034,
+/// 2/(1.5 + S (1 + exp(-S))/(1 - exp(-S))) = 0.539940
+/// parallel flow: (1 - exp(-1.5))/1.5 = 0.517913
+/// crossflow both unmixed: 1 - exp(2 (exp(-0.5) - 1)) = 0.544764
+#[test]
+pub fn effectiveness_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::HeatExchangerFlowArrangement;
+
+    let ntu = Ratio::new::<ratio>(1.0);
+    let c_r = Ratio::new::<ratio>(0.5);
+
+    let cases = [
+        (HeatExchangerFlowArrangement::CounterFlow, 0.564733),
+        (HeatExchangerFlowArrangement::ShellAndTube(1), 0.539940),
+        (HeatExchangerFlowArrangement::ParallelFlow, 0.517913),
+        (HeatExchangerFlowArrangement::CrossFlowBothUnmixed, 0.544764),
+    ];
+
+    for (arrangement, expected_effectiveness) in cases {
+        approx::assert_relative_eq!(
+            arrangement.try_get_effectiveness(ntu, c_r)?.get::<ratio>(),
+            expected_effectiveness,
+            max_relative = 1e-5);
+    }
+
+    Ok(())
+}
+
+/// NTU from effectiveness should invert the effectiveness
+/// relations for every flow arrangement
+#[test]
+pub fn number_of_transfer_units_round_trip() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::HeatExchangerFlowArrangement;
+
+    let arrangements = [
+        HeatExchangerFlowArrangement::CounterFlow,
+        HeatExchangerFlowArrangement::ParallelFlow,
+        HeatExchangerFlowArrangement::ShellAndTube(1),
+        HeatExchangerFlowArrangement::ShellAndTube(3),
+        HeatExchangerFlowArrangement::CrossFlowBothUnmixed,
+        HeatExchangerFlowArrangement::CrossFlowCmaxMixed,
+        HeatExchangerFlowArrangement::CrossFlowCminMixed,
+        HeatExchangerFlowArrangement::CrossFlowBothMixed,
+    ];
+
+    for arrangement in arrangements {
+        for c_r in [0.0, 0.25, 0.75, 1.0] {
+            for ntu in [0.1, 0.8, 2.5] {
+                let c_r = Ratio::new::<ratio>(c_r);
+                let effectiveness = arrangement.try_get_effectiveness(
+                    Ratio::new::<ratio>(ntu), c_r)?;
+
+                approx::assert_relative_eq!(
+                    arrangement.try_get_number_of_transfer_units(
+                        effectiveness, c_r)?.get::<ratio>(),
+                    ntu,
+                    max_relative = 1e-6);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// counter flow is the best arrangement and parallel flow
+/// the worst, the LMTD correction factor reflects this
+#[test]
+pub fn flow_arrangement_ordering_and_limits() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use super::HeatExchangerFlowArrangement;
+
+    let ntu = Ratio::new::<ratio>(2.0);
+    let c_r = Ratio::new::<ratio>(0.8);
+
+    let counter_flow = HeatExchangerFlowArrangement::CounterFlow
+        .try_get_effectiveness(ntu, c_r)?;
+    let two_shell_passes = HeatExchangerFlowArrangement::ShellAndTube(2)
+        .try_get_effectiveness(ntu, c_r)?;
+    let one_shell_pass = HeatExchangerFlowArrangement::ShellAndTube(1)
+        .try_get_effectiveness(ntu, c_r)?;
+    let parallel_flow = HeatExchangerFlowArrangement::ParallelFlow
+        .try_get_effectiveness(ntu, c_r)?;
+
+    assert!(counter_flow > two_shell_passes);
+    assert!(two_shell_passes > one_shell_pass);
+    assert!(one_shell_pass > parallel_flow);
+
+    // counter flow has no LMTD correction
+    approx::assert_relative_eq!(
+        HeatExchangerFlowArrangement::CounterFlow
+        .try_get_lmtd_correction_factor(counter_flow, c_r)?.get::<ratio>(),
+        1.0,
+        max_relative = 1e-12);
+
+    let shell_and_tube_correction = HeatExchangerFlowArrangement::ShellAndTube(1)
+        .try_get_lmtd_correction_factor(one_shell_pass, c_r)?.get::<ratio>();
+    assert!(shell_and_tube_correction > 0.0 && shell_and_tube_correction < 1.0);
+
+    // parallel flow cannot exceed epsilon = 1/(1 + C_r)
+    assert!(HeatExchangerFlowArrangement::ParallelFlow
+        .try_get_number_of_transfer_units(Ratio::new::<ratio>(0.6), c_r)
+        .is_err());
+
+    // with C_r = 0, the arrangement does not matter
+    let c_r_zero = Ratio::new::<ratio>(0.0);
+    approx::assert_relative_eq!(
+        HeatExchangerFlowArrangement::CrossFlowBothMixed
+        .try_get_effectiveness(ntu, c_r_zero)?.get::<ratio>(),
+        1.0 - (-2.0_f64).exp(),
+        max_relative = 1e-12);
+
+    Ok(())
+}
+
+/// rating results should satisfy the energy balance on both
+/// streams and Q = F UA LMTD, sizing should return the same UA
+#[test]
+pub fn rating_energy_balance_and_sizing() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::power::watt;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::temperature_interval::kelvin;
+    use super::{HeatExchangerFlowArrangement, try_rate_heat_exchanger,
+        try_get_required_overall_conductance};
+
+    let arrangement = HeatExchangerFlowArrangement::ShellAndTube(1);
+    let overall_conductance = ThermalConductance::new::<watt_per_kelvin>(500.0);
+    let hot_inlet = ThermodynamicTemperature::new::<degree_celsius>(200.0);
+    let cold_inlet = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+    let hot_capacity_rate = ThermalConductance::new::<watt_per_kelvin>(400.0);
+    let cold_capacity_rate = ThermalConductance::new::<watt_per_kelvin>(1000.0);
+
+    let rating = try_rate_heat_exchanger(arrangement, overall_conductance,
+        hot_inlet, hot_capacity_rate, cold_inlet, cold_capacity_rate)?;
+
+    let heat_rate = rating.heat_transfer_rate.get::<watt>();
+
+    // NTU = 500/400, C_r = 0.4
+    approx::assert_relative_eq!(
+        rating.number_of_transfer_units.get::<ratio>(), 1.25,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        rating.capacity_rate_ratio.get::<ratio>(), 0.4,
+        max_relative = 1e-12);
+
+    approx::assert_relative_eq!(
+        heat_rate,
+        400.0 * (rating.hot_inlet_temperature.get::<degree_celsius>()
+            - rating.hot_outlet_temperature.get::<degree_celsius>()),
+        max_relative = 1e-9);
+    approx::assert_relative_eq!(
+        heat_rate,
+        1000.0 * (rating.cold_outlet_temperature.get::<degree_celsius>()
+            - rating.cold_inlet_temperature.get::<degree_celsius>()),
+        max_relative = 1e-9);
+
+    // Q = F UA LMTD
+    let lmtd = rating.get_counter_flow_log_mean_temperature_difference();
+    approx::assert_relative_eq!(
+        heat_rate,
+        rating.lmtd_correction_factor.get::<ratio>() * 500.0
+            * lmtd.get::<kelvin>(),
+        max_relative = 1e-6);
+
+    let required_conductance = try_get_required_overall_conductance(
+        arrangement, rating.heat_transfer_rate,
+        hot_inlet, hot_capacity_rate, cold_inlet, cold_capacity_rate)?;
+
+    approx::assert_relative_eq!(
+        required_conductance.get::<watt_per_kelvin>(), 500.0,
+        max_relative = 1e-6);
+
+    // duties above Q_max are impossible
+    assert!(try_get_required_overall_conductance(
+        arrangement, Power::new::<watt>(400.0 * 150.0 * 1.01),
+        hot_inlet, hot_capacity_rate, cold_inlet, cold_capacity_rate).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,228 @@
+//! fouling and contact resistances for layered components
+//!
+//! these are area specific thermal resistances (m^2 K/W) which sit
+//! in series with the convective and conduction resistances between
+//! two nodes. For a node with heat transfer area A, the nodal
+//! conductance becomes
+//!
+//! 1/G_new = 1/G + R_f''/A
+//!
+//! fouling grows with operating time. The asymptotic growth model
+//! is from:
+//!
+//! Kern, D. Q., & Seaton, R. E. (1959). A theoretical analysis of
+//! thermal surface fouling. British Chemical Engineering, 4(5),
+//! 258-262.
+//!
+//! R_f''(t) = R_f0'' + R_f_inf'' (1 - exp(-t/tau))
+//!
+//! for contact (or gap) resistances, the contact conductance h_c
+//! is usually given, in which case R_c'' = 1/h_c
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::thermal_conductance::watt_per_kelvin;
+use uom::si::{ISQ, SI, Quantity};
+use uom::typenum::{N1, P1, P3, Z0};
+use uom::ConstZero;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// area specific thermal resistance in m^2 K/W
+///
+/// this is the reciprocal of a heat transfer coefficient,
+/// so R'' = 1.0/h
+pub type AreaSpecificThermalResistance =
+    Quantity<ISQ<Z0, N1, P3, Z0, P1, Z0, Z0>, SI<f64>, f64>;
+
+/// how the fouling factor grows with operating time
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum FoulingGrowthModel {
+    /// fouling factor stays at its initial value
+    #[default]
+    Constant,
+
+    /// fouling factor increases linearly, by the given fouling
+    /// factor over every given period of operating time
+    Linear(AreaSpecificThermalResistance, Time),
+
+    /// fouling factor approaches the initial value plus the given
+    /// asymptotic fouling factor with the given time constant
+    /// (Kern-Seaton model)
+    Asymptotic(AreaSpecificThermalResistance, Time),
+}
+
+/// fouling resistance on a wetted surface
+///
+/// keeps track of its own operating time, which prebuilt components
+/// advance every timestep. For long term studies, the operating time
+/// can be set directly instead of simulating the whole time
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct FoulingResistance {
+    /// fouling factor at zero operating time
+    pub initial_fouling_factor: AreaSpecificThermalResistance,
+
+    /// growth of the fouling factor with operating time
+    pub growth_model: FoulingGrowthModel,
+
+    /// operating time since the surface was clean
+    /// (or at its initial fouling factor)
+    pub operating_time: Time,
+}
+
+impl FoulingResistance {
+
+    /// fouling resistance which does not change with time
+    pub fn new_constant(fouling_factor: AreaSpecificThermalResistance) -> Self {
+        Self {
+            initial_fouling_factor: fouling_factor,
+            growth_model: FoulingGrowthModel::Constant,
+            operating_time: Time::ZERO,
+        }
+    }
+
+    /// fouling resistance which increases by fouling_factor_increase
+    /// every growth_period of operating time
+    pub fn new_linear_growth(initial_fouling_factor: AreaSpecificThermalResistance,
+        fouling_factor_increase: AreaSpecificThermalResistance,
+        growth_period: Time) -> Self {
+        Self {
+            initial_fouling_factor,
+            growth_model: FoulingGrowthModel::Linear(
+                fouling_factor_increase, growth_period),
+            operating_time: Time::ZERO,
+        }
+    }
+
+    /// fouling resistance which grows asymptotically
+    /// (Kern-Seaton model)
+    pub fn new_asymptotic_growth(initial_fouling_factor: AreaSpecificThermalResistance,
+        asymptotic_fouling_factor: AreaSpecificThermalResistance,
+        time_constant: Time) -> Self {
+        Self {
+            initial_fouling_factor,
+            growth_model: FoulingGrowthModel::Asymptotic(
+                asymptotic_fouling_factor, time_constant),
+            operating_time: Time::ZERO,
+        }
+    }
+
+    /// advances the operating time of the fouled surface
+    pub fn advance_operating_time(&mut self, timestep: Time) {
+        self.operating_time += timestep;
+    }
+
+    /// returns the fouling factor at the current operating time
+    pub fn try_get_fouling_factor(&self) ->
+        Result<AreaSpecificThermalResistance, TuasLibError> {
+
+        let growth: AreaSpecificThermalResistance = match self.growth_model {
+            FoulingGrowthModel::Constant => {
+                AreaSpecificThermalResistance::ZERO
+            },
+            FoulingGrowthModel::Linear(fouling_factor_increase, growth_period) => {
+                if growth_period.value <= 0.0 {
+                    return Err(TuasLibError::GenericStringError(
+                        "fouling growth period must be positive".to_string()));
+                }
+                fouling_factor_increase *
+                    (self.operating_time/growth_period).get::<ratio>()
+            },
+            FoulingGrowthModel::Asymptotic(asymptotic_fouling_factor, time_constant) => {
+                if time_constant.value <= 0.0 {
+                    return Err(TuasLibError::GenericStringError(
+                        "fouling time constant must be positive".to_string()));
+                }
+                let time_ratio: f64 = (self.operating_time/time_constant).get::<ratio>();
+                asymptotic_fouling_factor * (1.0 - (-time_ratio).exp())
+            },
+        };
+
+        let fouling_factor = self.initial_fouling_factor + growth;
+
+        if fouling_factor.value < 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "fouling factor cannot be negative".to_string()));
+        }
+
+        Ok(fouling_factor)
+    }
+}
+
+/// adds an area specific resistance (fouling or contact) in series
+/// with a nodal conductance
+///
+/// 1/G_new = 1/G + R''/A
+pub fn try_add_area_specific_resistance_in_series(
+    nodal_conductance: ThermalConductance,
+    area_specific_resistance: AreaSpecificThermalResistance,
+    heat_transfer_area: Area) -> Result<ThermalConductance, TuasLibError> {
+
+    if area_specific_resistance.value < 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "area specific thermal resistance cannot be negative".to_string()));
+    }
+
+    if heat_transfer_area.value <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "heat transfer area must be positive".to_string()));
+    }
+
+    if nodal_conductance.get::<watt_per_kelvin>() < 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "nodal conductance cannot be negative".to_string()));
+    }
+
+    // zero conductance (eg. adiabatic surfaces) stays zero
+    if nodal_conductance.get::<watt_per_kelvin>() == 0.0 {
+        return Ok(nodal_conductance);
+    }
+
+    let total_resistance: ThermalResistance = 1.0/nodal_conductance
+        + area_specific_resistance/heat_transfer_area;
+
+    Ok(1.0/total_resistance)
+}
+
+/// adds an optional fouling resistance in series with a nodal
+/// conductance, if there is no fouling, the conductance is
+/// returned as is
+pub fn try_add_fouling_resistance_in_series(
+    nodal_conductance: ThermalConductance,
+    fouling_resistance: Option<FoulingResistance>,
+    heat_transfer_area: Area) -> Result<ThermalConductance, TuasLibError> {
+
+    match fouling_resistance {
+        Some(fouling_resistance) => try_add_area_specific_resistance_in_series(
+            nodal_conductance,
+            fouling_resistance.try_get_fouling_factor()?,
+            heat_transfer_area),
+        None => Ok(nodal_conductance),
+    }
+}
+
+/// adds an optional contact resistance, given as a contact
+/// conductance h_c, in series with a nodal conductance,
+/// if there is no contact resistance, the conductance is
+/// returned as is
+pub fn try_add_contact_resistance_in_series(
+    nodal_conductance: ThermalConductance,
+    contact_conductance: Option<HeatTransfer>,
+    heat_transfer_area: Area) -> Result<ThermalConductance, TuasLibError> {
+
+    match contact_conductance {
+        Some(contact_conductance) => {
+            if contact_conductance.value <= 0.0 {
+                return Err(TuasLibError::GenericStringError(
+                    "contact conductance must be positive".to_string()));
+            }
+            try_add_area_specific_resistance_in_series(
+                nodal_conductance,
+                1.0/contact_conductance,
+                heat_transfer_area)
+        },
+        None => Ok(nodal_conductance),
+    }
+}
+
+/// unit tests for fouling and contact resistances
+pub mod unit_test_fouling_and_contact_resistance;
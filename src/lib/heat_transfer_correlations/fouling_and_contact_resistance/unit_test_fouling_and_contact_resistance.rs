@@ -0,0 +1,123 @@
+/// fouling factors for each growth model checked against
+/// hand calculations
+///
+/// initial fouling factor 0.0001 m^2 K/W
+/// linear: +0.0002 m^2 K/W every 1000 h, at 2500 h: 0.0006 m^2 K/W
+/// asymptotic: +0.0004 m^2 K/W, tau = 1000 h,
+/// at 1000 h: 0.0001 + 0.0004 (1 - exp(-1)) = 0.000352848 m^2 K/W
+#[test]
+pub fn fouling_growth_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::time::hour;
+    use super::{AreaSpecificThermalResistance, FoulingResistance};
+
+    let fouling_factor = |square_meter_kelvin_per_watt: f64|
+        -> AreaSpecificThermalResistance {
+        1.0/HeatTransfer::new::<watt_per_square_meter_kelvin>(
+            1.0/square_meter_kelvin_per_watt)
+    };
+
+    let constant = FoulingResistance::new_constant(fouling_factor(0.0001));
+    let mut linear = FoulingResistance::new_linear_growth(
+        fouling_factor(0.0001), fouling_factor(0.0002),
+        Time::new::<hour>(1000.0));
+    let mut asymptotic = FoulingResistance::new_asymptotic_growth(
+        fouling_factor(0.0001), fouling_factor(0.0004),
+        Time::new::<hour>(1000.0));
+
+    // at zero operating time, all fouling factors are the initial one
+    approx::assert_relative_eq!(
+        linear.try_get_fouling_factor()?.value, 0.0001,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        asymptotic.try_get_fouling_factor()?.value, 0.0001,
+        max_relative = 1e-12);
+
+    linear.advance_operating_time(Time::new::<hour>(2000.0));
+    linear.advance_operating_time(Time::new::<hour>(500.0));
+    asymptotic.operating_time = Time::new::<hour>(1000.0);
+
+    approx::assert_relative_eq!(
+        constant.try_get_fouling_factor()?.value, 0.0001,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        linear.try_get_fouling_factor()?.value, 0.0006,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        asymptotic.try_get_fouling_factor()?.value, 0.000352848,
+        max_relative = 1e-6);
+
+    // long after the time constant, the asymptote is reached
+    asymptotic.operating_time = Time::new::<hour>(1.0e5);
+    approx::assert_relative_eq!(
+        asymptotic.try_get_fouling_factor()?.value, 0.0005,
+        max_relative = 1e-9);
+
+    // zero time constants are not allowed
+    let bad_asymptotic = FoulingResistance::new_asymptotic_growth(
+        fouling_factor(0.0001), fouling_factor(0.0004),
+        Time::new::<hour>(0.0));
+    assert!(bad_asymptotic.try_get_fouling_factor().is_err());
+
+    Ok(())
+}
+
+/// fouling and contact resistances in series with a nodal
+/// conductance
+///
+/// G = 10 W/K, A = 0.5 m^2
+/// fouling: R'' = 0.002 m^2 K/W, 1/G_new = 0.1 + 0.004 = 0.104 K/W
+/// contact: h_c = 500 W/m^2 K, 1/G_new = 0.1 + 0.004 = 0.104 K/W
+#[test]
+pub fn resistances_in_series_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use super::{FoulingResistance, try_add_fouling_resistance_in_series,
+        try_add_contact_resistance_in_series};
+
+    let nodal_conductance = ThermalConductance::new::<watt_per_kelvin>(10.0);
+    let area = Area::new::<square_meter>(0.5);
+
+    let fouling = FoulingResistance::new_constant(
+        1.0/HeatTransfer::new::<watt_per_square_meter_kelvin>(500.0));
+
+    let fouled_conductance = try_add_fouling_resistance_in_series(
+        nodal_conductance, Some(fouling), area)?;
+    approx::assert_relative_eq!(
+        fouled_conductance.get::<watt_per_kelvin>(), 1.0/0.104,
+        max_relative = 1e-12);
+
+    let contact_conductance = try_add_contact_resistance_in_series(
+        nodal_conductance,
+        Some(HeatTransfer::new::<watt_per_square_meter_kelvin>(500.0)),
+        area)?;
+    approx::assert_relative_eq!(
+        contact_conductance.get::<watt_per_kelvin>(), 1.0/0.104,
+        max_relative = 1e-12);
+
+    // no fouling or contact resistance leaves the conductance as is
+    assert_eq!(try_add_fouling_resistance_in_series(
+            nodal_conductance, None, area)?, nodal_conductance);
+    assert_eq!(try_add_contact_resistance_in_series(
+            nodal_conductance, None, area)?, nodal_conductance);
+
+    // adiabatic surfaces stay adiabatic
+    let zero_conductance = ThermalConductance::new::<watt_per_kelvin>(0.0);
+    assert_eq!(try_add_fouling_resistance_in_series(
+            zero_conductance, Some(fouling), area)?, zero_conductance);
+
+    // zero contact conductance is a perfect insulator, not allowed
+    assert!(try_add_contact_resistance_in_series(
+            nodal_conductance,
+            Some(HeatTransfer::new::<watt_per_square_meter_kelvin>(0.0)),
+            area).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,466 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::angle::radian;
+use roots::{find_root_brent, SimpleConvergency};
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::tuas_lib_error::TuasLibError;
+
+/// whether buoyancy near the wall acts with or against the
+/// direction of flow
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum BuoyancyOrientation {
+    /// buoyancy acts in the flow direction near the wall,
+    /// eg. heated upflow or cooled downflow
+    Aided,
+    /// buoyancy acts against the flow direction near the wall,
+    /// eg. heated downflow or cooled upflow
+    Opposed,
+    /// no buoyancy component along the flow direction,
+    /// eg. horizontal flow, isothermal flow or no flow
+    Neutral,
+}
+
+/// contains the data needed for mixed convection corrections
+///
+/// the grashof number is based on the density difference
+/// between the bulk fluid and the fluid at the wall temperature
+///
+/// Gr = g (rho_bulk - rho_wall)/rho_bulk D^3 / nu^2
+///
+/// so it is positive when the wall heats the fluid and negative
+/// when the wall cools the fluid. Only the component of gravity
+/// along the flow direction is considered, using the incline angle
+/// (same convention as fluid arrays, 90 degrees is vertically
+/// upwards for positive flow)
+///
+/// the reynolds number is signed, negative reynolds numbers
+/// denote reverse flow
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct MixedConvectionData {
+    /// reynolds number based on hydraulic diameter, signed
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+    /// grashof number based on hydraulic diameter and the
+    /// bulk to wall density difference, signed
+    pub grashof: Ratio,
+    /// incline angle of the flow path
+    pub incline_angle: Angle,
+}
+
+impl MixedConvectionData {
+
+    /// grashof number based on the gravity component along the
+    /// flow path (always positive)
+    #[inline]
+    pub fn get_axial_grashof(&self) -> Ratio {
+        let sine_angle = self.incline_angle.get::<radian>().sin();
+
+        Ratio::new::<ratio>(
+            (self.grashof.get::<ratio>() * sine_angle).abs())
+    }
+
+    /// richardson number, Gr/Re^2, based on the axial grashof number
+    ///
+    /// returns an error if there is no flow, since
+    /// the flow is then purely natural convection
+    #[inline]
+    pub fn try_get_richardson(&self) -> Result<Ratio, TuasLibError> {
+        let reynolds = self.reynolds.get::<ratio>();
+
+        if reynolds == 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "richardson number undefined for zero reynolds number"
+                .to_string()));
+        }
+
+        Ok(self.get_axial_grashof() / (reynolds * reynolds))
+    }
+
+    /// determines if buoyancy aids or opposes the flow near the wall
+    ///
+    /// heated fluid near the wall rises, so heated upflow
+    /// is aided while heated downflow is opposed
+    #[inline]
+    pub fn get_buoyancy_orientation(&self) -> BuoyancyOrientation {
+        let sine_angle = self.incline_angle.get::<radian>().sin();
+
+        // positive when the flow goes upwards
+        let upward_flow_indicator = self.reynolds.get::<ratio>() * sine_angle;
+        let wall_buoyancy_indicator = self.grashof.get::<ratio>();
+
+        let orientation_indicator = upward_flow_indicator
+            * wall_buoyancy_indicator;
+
+        if orientation_indicator > 0.0 {
+            BuoyancyOrientation::Aided
+        } else if orientation_indicator < 0.0 {
+            BuoyancyOrientation::Opposed
+        } else {
+            BuoyancyOrientation::Neutral
+        }
+    }
+
+    /// Jackson-Hall buoyancy parameter for turbulent mixed convection
+    ///
+    /// Bo* = 8 x 10^4 Gr* / (Re^3.425 Pr^0.8)
+    ///
+    /// Gr* is the heat flux based grashof number, g beta q D^4/(k nu^2),
+    /// whereas the grashof number in this struct is based on the
+    /// bulk to wall temperature (density) difference. Since
+    /// q = h (T_wall - T_bulk), Gr* = Nu Gr, so the nusselt number
+    /// is needed here
+    #[inline]
+    pub fn get_jackson_hall_buoyancy_parameter(&self,
+        nusselt: Ratio) -> Ratio {
+        let reynolds = self.reynolds.get::<ratio>().abs();
+        let prandtl = self.prandtl_bulk.get::<ratio>();
+        let heat_flux_grashof = nusselt.get::<ratio>()
+            * self.get_axial_grashof().get::<ratio>();
+
+        Ratio::new::<ratio>(
+            8.0e4 * heat_flux_grashof
+            / (reynolds.powf(3.425) * prandtl.powf(0.8)))
+    }
+
+    /// ratio of the mixed convection nusselt number to the
+    /// forced convection nusselt number
+    ///
+    /// for turbulent flow (Re > 4000), the Jackson-Hall correction
+    /// is used:
+    ///
+    /// aided: Nu/Nu_F = |1 - Bo* (Nu/Nu_F)^-2|^0.46
+    /// opposed: Nu/Nu_F = (1 + Bo* (Nu/Nu_F)^-2)^0.46
+    ///
+    /// Bo* depends on the mixed convection nusselt number through
+    /// Gr*, so this is implicit in Nu, see
+    /// get_jackson_hall_nusselt_ratio for how it is solved.
+    /// Aided flow shows heat transfer impairment,
+    /// the factor is not allowed to go below 0.1 so that
+    /// some heat transfer remains
+    ///
+    /// for laminar flow (Re < 2300), Churchill's combining rule is
+    /// used with the forced convection nusselt number:
+    ///
+    /// Nu^3 = |Nu_F^3 +/- Nu_N^3|
+    ///
+    /// where Nu_N = 0.13 (Gr Pr)^(1/3) is the natural convection
+    /// nusselt number along the wall, + for aided flow and - for
+    /// opposed flow. For opposed flow, the factor is not allowed to
+    /// go below 0.1 either
+    ///
+    /// in between, the correction factor is linearly interpolated
+    ///
+    /// Jackson, J. D., Cotton, M. A., & Axcell, B. P. (1989). Studies
+    /// of mixed convection in vertical tubes. International journal
+    /// of heat and fluid flow, 10(1), 2-15.
+    ///
+    /// Churchill, S. W. (1977). A comprehensive correlating equation
+    /// for laminar, assisting, forced and free convection. AIChE
+    /// Journal, 23(1), 10-16.
+    pub fn try_get_nusselt_correction_factor(&self,
+        forced_convection_nusselt: Ratio) -> Result<Ratio, TuasLibError> {
+
+        let orientation = self.get_buoyancy_orientation();
+
+        if orientation == BuoyancyOrientation::Neutral {
+            return Ok(Ratio::new::<ratio>(1.0));
+        }
+
+        let prandtl = self.prandtl_bulk.get::<ratio>();
+        let forced_nusselt = forced_convection_nusselt.get::<ratio>();
+
+        if prandtl <= 0.0 || forced_nusselt <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "prandtl and forced convection nusselt numbers must \
+                be positive for mixed convection".to_string()));
+        }
+
+        let minimum_factor: f64 = 0.1;
+        let reynolds = self.reynolds.get::<ratio>().abs();
+
+        let turbulent_factor = |reynolds: f64| -> Result<f64, TuasLibError> {
+            // buoyancy parameter based on the forced convection
+            // nusselt number
+            let buoyancy_parameter = MixedConvectionData {
+                reynolds: Ratio::new::<ratio>(reynolds),
+                ..*self
+            }.get_jackson_hall_buoyancy_parameter(forced_convection_nusselt)
+            .get::<ratio>();
+
+            Ok(get_jackson_hall_nusselt_ratio(buoyancy_parameter,
+                orientation)?.max(minimum_factor))
+        };
+
+        let laminar_factor = || -> f64 {
+            let natural_nusselt = 0.13 *
+                (self.get_axial_grashof().get::<ratio>() * prandtl)
+                .powf(1.0/3.0);
+
+            let mixed_nusselt_cubed = match orientation {
+                BuoyancyOrientation::Aided => {
+                    forced_nusselt.powi(3) + natural_nusselt.powi(3)
+                },
+                _ => forced_nusselt.powi(3) - natural_nusselt.powi(3),
+            };
+
+            (mixed_nusselt_cubed.abs().powf(1.0/3.0) / forced_nusselt)
+                .max(minimum_factor)
+        };
+
+        let correction_factor: f64 = if reynolds > 4000.0 {
+            turbulent_factor(reynolds)?
+        } else if reynolds < 2300.0 {
+            laminar_factor()
+        } else {
+            let gamma = (reynolds - 2300.0)/(4000.0 - 2300.0);
+
+            (1.0 - gamma) * laminar_factor()
+                + gamma * turbulent_factor(4000.0)?
+        };
+
+        Ok(Ratio::new::<ratio>(correction_factor))
+    }
+
+    /// ratio of the mixed convection darcy friction factor to the
+    /// forced convection darcy friction factor
+    ///
+    /// buoyancy changes the near wall velocity gradient, which
+    /// changes the wall shear stress in much the same way as the
+    /// heat transfer, so by analogy, the nusselt correction factor
+    /// is used with the fully developed laminar nusselt number
+    /// of 4.354 as the forced convection reference
+    #[inline]
+    pub fn try_get_darcy_correction_factor(&self)
+        -> Result<Ratio, TuasLibError> {
+
+        self.try_get_nusselt_correction_factor(Ratio::new::<ratio>(4.354))
+    }
+}
+
+/// solves the Jackson-Hall correlation for phi = Nu/Nu_F
+///
+/// with Gr* = Nu Gr and Nu = phi Nu_F, Bo* (Nu/Nu_F)^-2 = B/phi where
+/// B = 8 x 10^4 Nu_F Gr / (Re^3.425 Pr^0.8) is the buoyancy parameter
+/// based on the forced convection nusselt number, so:
+///
+/// aided: phi = |1 - B/phi|^0.46
+/// opposed: phi = (1 + B/phi)^0.46
+///
+/// for opposed flow, the root lies between 1 and (1 + B)^0.46
+///
+/// for aided flow, with n = 1/0.46 + 1, the impaired branch
+/// (B < phi) is phi - phi^n = B, which only has a solution up to
+/// B = phi_max (1 - 1/n) where phi_max = (1/n)^(1/(n-1)).
+/// Beyond that, heat transfer recovers and the branch is
+/// phi^n + phi = B. The nusselt number jumps from about 0.59 Nu_F
+/// to 0.38 Nu_F where the branches switch, this is where the
+/// correlation predicts the worst impairment anyway
+fn get_jackson_hall_nusselt_ratio(buoyancy_parameter: f64,
+    orientation: BuoyancyOrientation) -> Result<f64, TuasLibError> {
+
+    if buoyancy_parameter == 0.0 {
+        return Ok(1.0);
+    }
+
+    let b = buoyancy_parameter;
+    let exponent: f64 = 1.0/0.46 + 1.0;
+
+    let mut convergency = SimpleConvergency {
+        eps: 1e-12_f64,
+        max_iter: 100,
+    };
+
+    let nusselt_ratio_result = match orientation {
+        BuoyancyOrientation::Aided => {
+            let turning_point: f64 = (1.0/exponent).powf(1.0/(exponent - 1.0));
+            let max_impaired_buoyancy_parameter: f64 =
+                turning_point * (1.0 - 1.0/exponent);
+
+            if b <= max_impaired_buoyancy_parameter {
+                let impaired_branch = |phi: f64| -> f64 {
+                    phi - phi.powf(exponent) - b
+                };
+                find_root_brent(turning_point, 1.0,
+                    impaired_branch, &mut convergency)
+            } else {
+                let recovered_branch = |phi: f64| -> f64 {
+                    phi.powf(exponent) + phi - b
+                };
+                find_root_brent(0.0, b,
+                    recovered_branch, &mut convergency)
+            }
+        },
+        BuoyancyOrientation::Opposed => {
+            let opposed_root = |phi: f64| -> f64 {
+                phi - (1.0 + b/phi).powf(0.46)
+            };
+            find_root_brent(1.0, (1.0 + b).powf(0.46),
+                opposed_root, &mut convergency)
+        },
+        BuoyancyOrientation::Neutral => Ok(1.0),
+    };
+
+    nusselt_ratio_result.map_err(|_| TuasLibError::GenericStringError(
+        "jackson-hall mixed convection correction did not converge"
+        .to_string()))
+}
+
+/// wraps any forced convection nusselt correlation with a
+/// mixed convection correction
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct MixedConvectionNusselt {
+    /// forced convection nusselt correlation
+    pub forced_convection_correlation: NusseltCorrelation,
+    /// buoyancy data
+    pub mixed_convection_data: MixedConvectionData,
+}
+
+impl MixedConvectionNusselt {
+
+    /// gets the forced convection nusselt number and multiplies it
+    /// by the mixed convection correction factor
+    pub fn try_get_nusselt(&self) -> Result<Ratio, TuasLibError> {
+
+        let forced_convection_nusselt =
+            self.forced_convection_correlation.try_get_nusselt()?;
+
+        let correction_factor = self.mixed_convection_data
+            .try_get_nusselt_correction_factor(forced_convection_nusselt)?;
+
+        Ok(forced_convection_nusselt * correction_factor)
+    }
+}
+
+/// wraps any forced convection darcy loss correlation with a
+/// mixed convection correction
+///
+/// note that the whole (f L/D + K) term is scaled, so form
+/// losses are corrected along with the friction factor
+///
+/// the correction depends on the reynolds number (both its
+/// magnitude and direction), so apart from
+/// fldk_based_on_darcy_friction_factor, the reynolds number in
+/// the mixed convection data is replaced by the one supplied
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct MixedConvectionDarcyLoss {
+    /// forced convection loss correlation
+    pub forced_convection_loss: DimensionlessDarcyLossCorrelations,
+    /// buoyancy data
+    pub mixed_convection_data: MixedConvectionData,
+}
+
+impl MixedConvectionDarcyLoss {
+
+    /// gets (f L/D + K) corrected for mixed convection, the
+    /// reynolds number in the mixed convection data is used
+    pub fn fldk_based_on_darcy_friction_factor(&self)
+        -> Result<Ratio, TuasLibError> {
+
+        let forced_convection_fldk = self.forced_convection_loss
+            .fldk_based_on_darcy_friction_factor(
+                self.mixed_convection_data.reynolds)?;
+
+        let correction_factor = self.mixed_convection_data
+            .try_get_darcy_correction_factor()?;
+
+        Ok(forced_convection_fldk * correction_factor)
+    }
+
+    /// obtains the bejan number, Be = 0.5 (f L/D + K) Re^2,
+    /// corrected for mixed convection
+    ///
+    /// as with the forced convection correlations, the bejan
+    /// number is negative for reverse flow
+    pub fn get_bejan_number_from_reynolds(&self, reynolds_input: Ratio)
+        -> Result<Ratio, TuasLibError> {
+
+        if reynolds_input == Ratio::new::<ratio>(0.0) {
+            return Ok(reynolds_input);
+        }
+
+        let corrected_loss = MixedConvectionDarcyLoss {
+            mixed_convection_data: MixedConvectionData {
+                reynolds: reynolds_input,
+                ..self.mixed_convection_data
+            },
+            ..*self
+        };
+
+        let bejan_number = 0.5
+            * corrected_loss.fldk_based_on_darcy_friction_factor()?
+            * reynolds_input * reynolds_input.abs();
+
+        Ok(bejan_number)
+    }
+
+    /// obtains a reynolds number from a given bejan number
+    ///
+    /// the correction factor depends on the reynolds number,
+    /// so this is always done by root finding
+    pub fn get_reynolds_number_from_bejan(&self, bejan_input: Ratio)
+        -> Result<Ratio, TuasLibError> {
+
+        let upper_limit: f64 = 1.0e12;
+        let lower_limit: f64 = -upper_limit;
+
+        let bejan_root = |reynolds: f64| -> f64 {
+            let rhs_bejan = self.get_bejan_number_from_reynolds(
+                Ratio::new::<ratio>(reynolds)).unwrap();
+
+            bejan_input.get::<ratio>() - rhs_bejan.get::<ratio>()
+        };
+
+        let mut convergency = SimpleConvergency {
+            eps: 1e-8_f64,
+            max_iter: 70,
+        };
+
+        let reynolds_number = find_root_brent(upper_limit,
+            lower_limit,
+            bejan_root,
+            &mut convergency)
+            .map_err(|_| TuasLibError::GenericStringError(
+                "could not find reynolds number for mixed convection \
+                darcy loss".to_string()))?;
+
+        Ok(Ratio::new::<ratio>(reynolds_number))
+    }
+
+    /// pressure loss from Re, corrected for mixed convection
+    pub fn get_pressure_loss_from_reynolds(&self,
+        reynolds_input: Ratio,
+        hydraulic_diameter: Length,
+        fluid_density: MassDensity,
+        fluid_viscosity: DynamicViscosity) -> Result<Pressure, TuasLibError> {
+
+        let bejan_number = self.get_bejan_number_from_reynolds(
+            reynolds_input)?;
+
+        Ok(fluid_viscosity * fluid_viscosity * bejan_number
+            / (hydraulic_diameter * hydraulic_diameter)
+            / fluid_density)
+    }
+
+    /// Re from pressure loss, corrected for mixed convection
+    pub fn get_reynolds_from_pressure_loss(&self,
+        pressure_loss_input: Pressure,
+        hydraulic_diameter: Length,
+        fluid_density: MassDensity,
+        fluid_viscosity: DynamicViscosity) -> Result<Ratio, TuasLibError> {
+
+        let bejan_input: Ratio = pressure_loss_input
+            * hydraulic_diameter
+            * hydraulic_diameter
+            * fluid_density
+            / fluid_viscosity
+            / fluid_viscosity;
+
+        self.get_reynolds_number_from_bejan(bejan_input)
+    }
+}
+
+/// unit tests for mixed convection corrections
+pub mod unit_test_mixed_convection;
@@ -0,0 +1,486 @@
+/// heated upflow is aided, heated downflow and cooled upflow
+/// are opposed, horizontal flow is not corrected
+#[test]
+pub fn buoyancy_orientation_from_flow_and_incline_angle() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::angle::degree;
+    use super::{BuoyancyOrientation, MixedConvectionData};
+
+    let heated_upflow = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(500.0),
+        prandtl_bulk: Ratio::new::<ratio>(10.0),
+        grashof: Ratio::new::<ratio>(1.0e5),
+        incline_angle: Angle::new::<degree>(90.0),
+    };
+
+    assert_eq!(heated_upflow.get_buoyancy_orientation(),
+        BuoyancyOrientation::Aided);
+
+    // reverse flow in an upward inclined pipe is downflow
+    let heated_downflow = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(-500.0),
+        ..heated_upflow
+    };
+    assert_eq!(heated_downflow.get_buoyancy_orientation(),
+        BuoyancyOrientation::Opposed);
+
+    let cooled_upflow = MixedConvectionData {
+        grashof: Ratio::new::<ratio>(-1.0e5),
+        ..heated_upflow
+    };
+    assert_eq!(cooled_upflow.get_buoyancy_orientation(),
+        BuoyancyOrientation::Opposed);
+
+    // cooled downflow is aided
+    let cooled_downflow = MixedConvectionData {
+        incline_angle: Angle::new::<degree>(-90.0),
+        ..cooled_upflow
+    };
+    assert_eq!(cooled_downflow.get_buoyancy_orientation(),
+        BuoyancyOrientation::Aided);
+
+    let horizontal_flow = MixedConvectionData {
+        incline_angle: Angle::new::<degree>(0.0),
+        ..heated_upflow
+    };
+    assert_eq!(horizontal_flow.get_buoyancy_orientation(),
+        BuoyancyOrientation::Neutral);
+    assert_eq!(horizontal_flow.try_get_nusselt_correction_factor(
+            Ratio::new::<ratio>(4.354))?.get::<ratio>(), 1.0);
+
+    // Ri = Gr/Re^2 = 1e5/500^2 = 0.4
+    approx::assert_relative_eq!(
+        heated_upflow.try_get_richardson()?.get::<ratio>(),
+        0.4,
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// hand calculated correction factors
+///
+/// turbulent, Re = 5000, Pr = 5, Nu_F = 40, Gr = 1e6:
+/// B = 8e4 Nu_F Gr/(Re^3.425 Pr^0.8) = 0.18924
+/// aided: phi = |1 - B/phi|^0.46, phi = 0.89670
+/// opposed: phi = (1 + B/phi)^0.46, phi = 1.07728
+///
+/// and with Gr = 1e7, B = 1.89235 is past the impaired branch:
+/// aided: phi = 0.97364 (phi^3.174 + phi = B)
+/// opposed: phi = 1.46456
+///
+/// laminar, Re = 500, Pr = 10, Nu_F = 4.354:
+/// Gr = 1e5, Nu_N = 0.13 (1e6)^(1/3) = 13, aided
+/// Nu/Nu_F = (4.354^3 + 13^3)^(1/3)/4.354 = 3.02269
+/// Gr = 1e3, Nu_N = 2.80077, opposed
+/// Nu/Nu_F = (4.354^3 - 2.80077^3)^(1/3)/4.354 = 0.90198
+#[test]
+pub fn mixed_convection_correction_factor_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::angle::degree;
+    use super::MixedConvectionData;
+
+    let turbulent_nusselt = Ratio::new::<ratio>(40.0);
+
+    let turbulent_aided = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(5000.0),
+        prandtl_bulk: Ratio::new::<ratio>(5.0),
+        grashof: Ratio::new::<ratio>(1.0e6),
+        incline_angle: Angle::new::<degree>(90.0),
+    };
+    let turbulent_opposed = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(-5000.0),
+        ..turbulent_aided
+    };
+
+    approx::assert_relative_eq!(
+        turbulent_aided.get_jackson_hall_buoyancy_parameter(turbulent_nusselt)
+        .get::<ratio>(),
+        0.18924,
+        max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        turbulent_aided.try_get_nusselt_correction_factor(turbulent_nusselt)?
+        .get::<ratio>(),
+        0.89670,
+        max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        turbulent_opposed.try_get_nusselt_correction_factor(turbulent_nusselt)?
+        .get::<ratio>(),
+        1.07728,
+        max_relative = 1e-4);
+
+    let strongly_aided = MixedConvectionData {
+        grashof: Ratio::new::<ratio>(1.0e7),
+        ..turbulent_aided
+    };
+    let strongly_opposed = MixedConvectionData {
+        grashof: Ratio::new::<ratio>(1.0e7),
+        ..turbulent_opposed
+    };
+
+    approx::assert_relative_eq!(
+        strongly_aided.try_get_nusselt_correction_factor(turbulent_nusselt)?
+        .get::<ratio>(),
+        0.97364,
+        max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        strongly_opposed.try_get_nusselt_correction_factor(turbulent_nusselt)?
+        .get::<ratio>(),
+        1.46456,
+        max_relative = 1e-4);
+
+    let forced_nusselt = Ratio::new::<ratio>(4.354);
+
+    let laminar_aided = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(500.0),
+        prandtl_bulk: Ratio::new::<ratio>(10.0),
+        grashof: Ratio::new::<ratio>(1.0e5),
+        incline_angle: Angle::new::<degree>(90.0),
+    };
+    let laminar_opposed = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(-500.0),
+        grashof: Ratio::new::<ratio>(1.0e3),
+        ..laminar_aided
+    };
+
+    approx::assert_relative_eq!(
+        laminar_aided.try_get_nusselt_correction_factor(forced_nusselt)?
+        .get::<ratio>(),
+        3.02269,
+        max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        laminar_opposed.try_get_nusselt_correction_factor(forced_nusselt)?
+        .get::<ratio>(),
+        0.90198,
+        max_relative = 1e-4);
+
+    // the darcy correction uses Nu_F = 4.354 as reference
+    approx::assert_relative_eq!(
+        laminar_aided.try_get_darcy_correction_factor()?.get::<ratio>(),
+        3.02269,
+        max_relative = 1e-4);
+
+    Ok(())
+}
+
+/// the wrappers should multiply any forced convection
+/// correlation by the correction factor
+#[test]
+pub fn mixed_convection_wraps_forced_convection_correlations() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::angle::degree;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+    use super::{MixedConvectionData, MixedConvectionNusselt, MixedConvectionDarcyLoss};
+
+    let mixed_convection_data = MixedConvectionData {
+        reynolds: Ratio::new::<ratio>(500.0),
+        prandtl_bulk: Ratio::new::<ratio>(10.0),
+        grashof: Ratio::new::<ratio>(1.0e5),
+        incline_angle: Angle::new::<degree>(90.0),
+    };
+
+    let fixed_nusselt = Ratio::new::<ratio>(8.0);
+    let mixed_convection_nusselt = MixedConvectionNusselt {
+        forced_convection_correlation: NusseltCorrelation::FixedNusselt(fixed_nusselt),
+        mixed_convection_data,
+    };
+
+    let expected_nusselt = fixed_nusselt * mixed_convection_data
+        .try_get_nusselt_correction_factor(fixed_nusselt)?;
+
+    approx::assert_relative_eq!(
+        mixed_convection_nusselt.try_get_nusselt()?.get::<ratio>(),
+        expected_nusselt.get::<ratio>(),
+        max_relative = 1e-12);
+
+    let forced_convection_loss = DimensionlessDarcyLossCorrelations::new_pipe(
+        Length::new::<uom::si::length::meter>(1.0),
+        Length::new::<uom::si::length::millimeter>(0.015),
+        Length::new::<uom::si::length::meter>(0.02),
+        Ratio::new::<ratio>(0.0));
+
+    let mixed_convection_loss = MixedConvectionDarcyLoss {
+        forced_convection_loss,
+        mixed_convection_data,
+    };
+
+    // laminar fLD = 64/Re L/D = 64/500 * 50 = 6.4
+    let expected_fldk = 6.4 * mixed_convection_data
+        .try_get_darcy_correction_factor()?.get::<ratio>();
+
+    approx::assert_relative_eq!(
+        mixed_convection_loss.fldk_based_on_darcy_friction_factor()?
+        .get::<ratio>(),
+        expected_fldk,
+        max_relative = 1e-3);
+
+    // Be = 0.5 fLDK Re^2, and the root finder should recover Re
+    let bejan_number = mixed_convection_loss.get_bejan_number_from_reynolds(
+        Ratio::new::<ratio>(500.0))?;
+
+    approx::assert_relative_eq!(
+        bejan_number.get::<ratio>(),
+        0.5 * expected_fldk * 500.0 * 500.0,
+        max_relative = 1e-3);
+    approx::assert_relative_eq!(
+        mixed_convection_loss.get_reynolds_number_from_bejan(bejan_number)?
+        .get::<ratio>(),
+        500.0,
+        max_relative = 1e-6);
+
+    Ok(())
+}
+
+/// with a heated wall, buoyancy aided flow (upflow) and opposed 
+/// flow (downflow) should move (f L/D + K) in opposite directions 
+/// from the forced convection value once the fluid array option is 
+/// switched on
+///
+/// the pressure loss and mass flowrate should also be consistent 
+/// with each other
+#[test]
+pub fn aided_and_opposed_flow_move_fldk_in_opposite_directions() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+
+    let id = Length::new::<meter>(0.02);
+
+    let mut fluid_array: FluidArray = NonInsulatedFluidComponent::new_bare_pipe(
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        Pressure::new::<atmosphere>(1.0),
+        Pressure::new::<atmosphere>(1.0),
+        Area::new::<square_meter>(std::f64::consts::PI * 0.01 * 0.01),
+        Angle::new::<degree>(90.0),
+        Ratio::new::<ratio>(0.0),
+        id,
+        Length::new::<meter>(0.025),
+        Length::new::<meter>(1.0),
+        id,
+        Length::new::<millimeter>(0.015),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+        2).pipe_fluid_array.try_into()?;
+
+    // the wall is only slightly hotter than the fluid, otherwise 
+    // natural convection dominates and the opposed flow correction 
+    // goes back above one
+    let wall_temperature = ThermodynamicTemperature::new::<degree_celsius>(20.1);
+    let upflow = MassRate::new::<kilogram_per_second>(0.005);
+    let downflow = -upflow;
+
+    // the wall temperature is not used with the option switched off
+    let forced_upflow_loss = fluid_array
+        .get_pressure_loss_with_wall_temperature_immutable(
+            upflow, wall_temperature);
+    let forced_downflow_loss = fluid_array
+        .get_pressure_loss_with_wall_temperature_immutable(
+            downflow, wall_temperature);
+
+    assert_eq!(forced_upflow_loss, 
+        fluid_array.get_pressure_loss_immutable(upflow));
+
+    fluid_array.mixed_convection_correction = true;
+
+    let aided_loss = fluid_array
+        .get_pressure_loss_with_wall_temperature_immutable(
+            upflow, wall_temperature);
+    let opposed_loss = fluid_array
+        .get_pressure_loss_with_wall_temperature_immutable(
+            downflow, wall_temperature);
+
+    // pressure loss goes with fLDK at the same reynolds number, 
+    // and downflow has a negative pressure loss
+    let aided_fldk_ratio = aided_loss/forced_upflow_loss;
+    let opposed_fldk_ratio = opposed_loss/forced_downflow_loss;
+
+    assert!(aided_fldk_ratio.get::<ratio>() > 1.0);
+    assert!(opposed_fldk_ratio.get::<ratio>() < 1.0);
+
+    // and the mass flowrate should be recovered from the 
+    // pressure loss
+    approx::assert_relative_eq!(
+        fluid_array
+        .get_mass_flowrate_from_pressure_loss_with_wall_temperature_immutable(
+            aided_loss, wall_temperature)
+        .get::<kilogram_per_second>(),
+        upflow.get::<kilogram_per_second>(),
+        max_relative = 1e-6);
+    approx::assert_relative_eq!(
+        fluid_array
+        .get_mass_flowrate_from_pressure_loss_with_wall_temperature_immutable(
+            opposed_loss, wall_temperature)
+        .get::<kilogram_per_second>(),
+        downflow.get::<kilogram_per_second>(),
+        max_relative = 1e-6);
+
+    Ok(())
+}
+
+/// a heated vertical pipe in upflow should have a higher nusselt
+/// number with the mixed convection correction, and a horizontal
+/// pipe should be unaffected
+#[test]
+pub fn fluid_array_mixed_convection_nusselt() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+
+    let id = Length::new::<meter>(0.02);
+
+    let vertical_pipe = |incline_angle: Angle| {
+        NonInsulatedFluidComponent::new_bare_pipe(
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            ThermodynamicTemperature::new::<degree_celsius>(80.0),
+            Pressure::new::<atmosphere>(1.0),
+            Pressure::new::<atmosphere>(1.0),
+            Area::new::<square_meter>(std::f64::consts::PI * 0.01 * 0.01),
+            incline_angle,
+            Ratio::new::<ratio>(0.0),
+            id,
+            Length::new::<meter>(0.025),
+            Length::new::<meter>(1.0),
+            id,
+            Length::new::<millimeter>(0.015),
+            SolidMaterial::SteelSS304L,
+            LiquidMaterial::TherminolVP1,
+            HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+            2)
+    };
+
+    let mut vertical_fluid_array: FluidArray =
+        vertical_pipe(Angle::new::<degree>(90.0))
+        .pipe_fluid_array.try_into()?;
+    let mut horizontal_fluid_array: FluidArray =
+        vertical_pipe(Angle::new::<degree>(0.0))
+        .pipe_fluid_array.try_into()?;
+
+    let wall_temperature = ThermodynamicTemperature::new::<degree_celsius>(60.0);
+    let reynolds = Ratio::new::<ratio>(300.0);
+    let prandtl = Ratio::new::<ratio>(20.0);
+
+    // hot wall, so the grashof number is positive
+    assert!(vertical_fluid_array.get_grashof(wall_temperature)?
+        .get::<ratio>() > 0.0);
+
+    let forced_nusselt = vertical_fluid_array.get_nusselt(
+        reynolds, prandtl, prandtl)?;
+    let mixed_nusselt = vertical_fluid_array.get_nusselt_mixed_convection(
+        reynolds, prandtl, prandtl, wall_temperature)?;
+
+    assert!(mixed_nusselt > forced_nusselt);
+
+    let horizontal_forced_nusselt = horizontal_fluid_array.get_nusselt(
+        reynolds, prandtl, prandtl)?;
+    let horizontal_mixed_nusselt = horizontal_fluid_array
+        .get_nusselt_mixed_convection(
+            reynolds, prandtl, prandtl, wall_temperature)?;
+
+    approx::assert_relative_eq!(
+        horizontal_mixed_nusselt.get::<ratio>(),
+        horizontal_forced_nusselt.get::<ratio>(),
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// a heated vertical bare pipe in upflow should have a higher fluid 
+/// to pipe shell conductance once the mixed convection correction 
+/// is switched on for its fluid array
+#[test]
+pub fn non_insulated_component_with_mixed_convection_correction() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::atmosphere;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+    use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+
+    let id = Length::new::<meter>(0.02);
+
+    let mut heated_pipe = NonInsulatedFluidComponent::new_bare_pipe(
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        Pressure::new::<atmosphere>(1.0),
+        Pressure::new::<atmosphere>(1.0),
+        Area::new::<square_meter>(std::f64::consts::PI * 0.01 * 0.01),
+        Angle::new::<degree>(90.0),
+        Ratio::new::<ratio>(0.0),
+        id,
+        Length::new::<meter>(0.025),
+        Length::new::<meter>(1.0),
+        id,
+        Length::new::<millimeter>(0.015),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::TherminolVP1,
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+        2);
+
+    // laminar upflow with a hot wall
+    heated_pipe.set_mass_flowrate(MassRate::new::<kilogram_per_second>(0.005));
+
+    let wall_temperature = ThermodynamicTemperature::new::<degree_celsius>(60.0);
+    let mut pipe_shell: SolidColumn = heated_pipe.pipe_shell.clone().try_into()?;
+    let number_of_nodes = pipe_shell.get_temperature_vector()?.len();
+    pipe_shell.set_temperature_vector(vec![wall_temperature; number_of_nodes])?;
+    heated_pipe.pipe_shell.set(pipe_shell.into())?;
+
+    let correct_prandtl_for_wall_temperatures = false;
+    let forced_convection_conductance = heated_pipe
+        .get_fluid_array_node_pipe_shell_conductance(
+            correct_prandtl_for_wall_temperatures)?;
+
+    let mut fluid_array: FluidArray = heated_pipe.pipe_fluid_array.clone().try_into()?;
+    fluid_array.mixed_convection_correction = true;
+    heated_pipe.pipe_fluid_array.set(fluid_array.into())?;
+
+    let mixed_convection_conductance = heated_pipe
+        .get_fluid_array_node_pipe_shell_conductance(
+            correct_prandtl_for_wall_temperatures)?;
+
+    assert!(mixed_convection_conductance > forced_convection_conductance);
+
+    Ok(())
+}
@@ -3,14 +3,46 @@ pub mod nusselt_number_correlations;
 
 /// basic calculation functions for thermal resistance
 pub mod thermal_resistance;
+/// builder for networks of convection, conduction, contact
+/// and radiation resistances in series and parallel
+pub mod thermal_resistance_network;
 
 /// view factor functions for radiative heat transfer
 pub mod view_factors;
 
+/// grey diffuse radiation exchange between N surfaces
+/// in an enclosure, using radiosity
+pub mod radiation_enclosure;
+
+/// natural and forced convection heat transfer coefficients 
+/// from component outer surfaces to ambient air
+pub mod ambient_heat_transfer;
+
+/// mixed convection corrections for forced convection 
+/// nusselt numbers and friction factors
+pub mod mixed_convection;
+
+/// Bell-Delaware and Kern methods for the shell side of 
+/// segmentally baffled shell and tube heat exchangers
+pub mod baffled_shell_side;
+
 
 /// calculations for parallel piped heat exchangers 
 pub mod parallel_heat_exchangers;
 
+/// effectiveness-NTU and LMTD methods for steady state 
+/// heat exchanger rating and sizing
+pub mod effectiveness_ntu;
+/// fouling and contact resistances in series with
+/// nodal conductances of layered components
+pub mod fouling_and_contact_resistance;
+/// effective thermal conductivity of packed pebble beds
+/// (Zehner-Schlunder and Zehner-Bauer-Schlunder)
+pub mod pebble_bed_conductivity;
+/// validity envelopes of correlations, and monitoring of 
+/// extrapolation outside these envelopes during simulations
+pub mod correlation_validity;
+
 /// heat transfer interactions between different shapes 
 /// of control volumes are calculated here
 pub mod heat_transfer_interactions;
@@ -0,0 +1,190 @@
+use crate::fluid_mechanics_correlations::non_circular_ducts::{annulus_laminar_nusselt_uniform_heat_flux, AnnulusHeatedWall};
+use crate::tuas_lib_error::TuasLibError;
+use crate::heat_transfer_correlations::correlation_validity::{
+    CorrelationValidityEnvelope, CorrelationValidityRange};
+
+// Gnielinski correlation for concentric annular ducts
+//
+// Gnielinski, V. (2009). Heat transfer coefficients for turbulent
+// flow in concentric annular ducts. Heat Transfer Engineering,
+// 30(6), 431-436.
+//
+// The circular pipe Gnielinski correlation is used with a modified
+// Reynolds number for the friction factor and with the
+// Petukhov-Roizen wall factors which account for which wall
+// of the annulus is heated.
+
+fn check_annulus_radius_ratio(radius_ratio: f64) -> Result<(), TuasLibError> {
+
+    if radius_ratio <= 0.0 || radius_ratio >= 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "annulus radius ratio must be between 0 and 1 \
+            (exclusive) for annulus correlations".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Petukhov-Roizen wall factor for turbulent heat transfer in
+/// concentric annuli, a is the inner to outer diameter ratio
+///
+/// inner wall heated, outer wall insulated: F = 0.75 a^(-0.17)
+/// outer wall heated, inner wall insulated: F = 0.9 - 0.15 a^0.6
+pub fn petukhov_roizen_annulus_wall_factor(radius_ratio: f64,
+    heated_wall: AnnulusHeatedWall) -> Result<f64, TuasLibError> {
+
+    check_annulus_radius_ratio(radius_ratio)?;
+
+    let wall_factor = match heated_wall {
+        AnnulusHeatedWall::InnerWall => 0.75 * radius_ratio.powf(-0.17),
+        AnnulusHeatedWall::OuterWall => 0.9 - 0.15 * radius_ratio.powf(0.6),
+    };
+
+    Ok(wall_factor)
+}
+
+/// darcy friction factor for turbulent flow in concentric annuli
+///
+/// f = (1.8 log10(Re*) - 1.5)^(-2)
+///
+/// Re* = Re [(1 + a^2) ln a + (1 - a^2)] / [(1 - a)^2 ln a]
+pub fn gnielinski_annulus_darcy_friction_factor(reynolds: f64,
+    radius_ratio: f64) -> Result<f64, TuasLibError> {
+
+    check_annulus_radius_ratio(radius_ratio)?;
+
+    if reynolds <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "reynolds number must be positive for annulus \
+            friction factor".to_string()));
+    }
+
+    let a = radius_ratio;
+    let ln_a = a.ln();
+
+    let modified_reynolds = reynolds
+        * ((1.0 + a * a) * ln_a + (1.0 - a * a))
+        / ((1.0 - a).powi(2) * ln_a);
+
+    Ok((1.8 * modified_reynolds.log10() - 1.5).powi(-2))
+}
+
+/// Gnielinski correlation for turbulent flow in concentric annuli
+/// with one wall heated and the other insulated
+///
+/// Nu = (f/8) (Re - 1000) Pr / (k_1 + 12.7 (f/8)^0.5 (Pr^(2/3) - 1))
+///      [1 + (D_h/L)^(2/3)] F_ann (Pr_bulk/Pr_wall)^0.11
+///
+/// k_1 = 1.07 + 900/Re - 0.63/(1 + 10 Pr)
+///
+/// Reynolds and Nusselt numbers are based on the hydraulic
+/// diameter D_h = D_o - D_i
+///
+/// ```rust
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// nusselt_number_correlations::annulus_correlations::
+/// gnielinski_annulus_turbulent_nusselt;
+/// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+/// non_circular_ducts::AnnulusHeatedWall;
+///
+/// let inner_wall_nusselt = gnielinski_annulus_turbulent_nusselt(
+///     1.0e4, 5.0, 5.0, 100.0, 0.5, AnnulusHeatedWall::InnerWall).unwrap();
+/// let outer_wall_nusselt = gnielinski_annulus_turbulent_nusselt(
+///     1.0e4, 5.0, 5.0, 100.0, 0.5, AnnulusHeatedWall::OuterWall).unwrap();
+///
+/// // the inner wall of an annulus has better heat transfer
+/// assert!(inner_wall_nusselt > outer_wall_nusselt);
+/// ```
+pub fn gnielinski_annulus_turbulent_nusselt(
+    reynolds: f64,
+    prandtl_bulk: f64,
+    prandtl_wall: f64,
+    length_to_diameter: f64,
+    radius_ratio: f64,
+    heated_wall: AnnulusHeatedWall) -> Result<f64, TuasLibError> {
+
+    if prandtl_bulk <= 0.0 || prandtl_wall <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "prandtl numbers must be positive for annulus \
+            correlations".to_string()));
+    }
+
+    if length_to_diameter <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "length to diameter ratio must be positive for annulus \
+            correlations".to_string()));
+    }
+
+    let darcy_friction_factor = gnielinski_annulus_darcy_friction_factor(
+        reynolds, radius_ratio)?;
+
+    let wall_factor = petukhov_roizen_annulus_wall_factor(
+        radius_ratio, heated_wall)?;
+
+    let f_over_8 = darcy_friction_factor / 8.0;
+
+    let k_1 = 1.07 + 900.0 / reynolds - 0.63 / (1.0 + 10.0 * prandtl_bulk);
+
+    let nusselt_fully_developed = f_over_8 * (reynolds - 1000.0) * prandtl_bulk
+        / (k_1 + 12.7 * f_over_8.sqrt() * (prandtl_bulk.powf(2.0/3.0) - 1.0));
+
+    let developing_flow_factor = 1.0 + length_to_diameter.powf(-2.0/3.0);
+
+    let prandtl_correction = (prandtl_bulk / prandtl_wall).powf(0.11);
+
+    Ok(nusselt_fully_developed * developing_flow_factor
+        * wall_factor * prandtl_correction)
+}
+
+/// validity envelope of the Gnielinski annulus correlation 
+/// including the laminar and transition regimes
+///
+/// Re <= 1e6, 0.1 <= Pr <= 1000 (Gnielinski, 2009)
+pub const GNIELINSKI_ANNULUS_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new_upper_bound(1.0e6),
+        prandtl: CorrelationValidityRange::new(0.1, 1000.0),
+        ..CorrelationValidityEnvelope::new_unbounded("Gnielinski annulus")
+    };
+
+/// Gnielinski annulus correlation for laminar, transition and
+/// turbulent flow
+///
+/// laminar flow (Re < 2300) uses the fully developed Nusselt
+/// number for uniform heat flux on the heated wall
+/// (Kays and Crawford), turbulent flow (Re > 4000) uses the
+/// Gnielinski annulus correlation, and the transition region
+/// is linearly interpolated
+pub fn gnielinski_annulus_nusselt_interpolated(
+    reynolds: f64,
+    prandtl_bulk: f64,
+    prandtl_wall: f64,
+    length_to_diameter: f64,
+    radius_ratio: f64,
+    heated_wall: AnnulusHeatedWall) -> Result<f64, TuasLibError> {
+
+    check_annulus_radius_ratio(radius_ratio)?;
+
+    let reynolds = reynolds.abs();
+
+    if reynolds > 4000_f64 {
+        return gnielinski_annulus_turbulent_nusselt(
+            reynolds, prandtl_bulk, prandtl_wall,
+            length_to_diameter, radius_ratio, heated_wall);
+    }
+
+    let laminar_nusselt = annulus_laminar_nusselt_uniform_heat_flux(
+        radius_ratio, heated_wall)?;
+
+    if reynolds < 2300_f64 {
+        return Ok(laminar_nusselt);
+    }
+
+    let turbulent_nusselt = gnielinski_annulus_turbulent_nusselt(
+        4000_f64, prandtl_bulk, prandtl_wall,
+        length_to_diameter, radius_ratio, heated_wall)?;
+
+    let gamma = (reynolds - 2300_f64)/(4000_f64 - 2300_f64);
+
+    Ok((1_f64 - gamma) * laminar_nusselt + gamma * turbulent_nusselt)
+}
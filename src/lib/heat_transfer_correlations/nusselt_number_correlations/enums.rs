@@ -3,6 +3,17 @@ use uom::si::{f64::*, ratio::ratio};
 use crate::tuas_lib_error::TuasLibError;
 
 use super::input_structs::{NusseltPrandtlReynoldsData, WakaoData, GnielinskiData};
+use super::input_structs::{LiquidMetalPipeData, LiquidMetalRodBundleData, GnielinskiAnnulusData};
+use crate::fluid_mechanics_correlations::non_circular_ducts::{annulus_laminar_nusselt_uniform_heat_flux, AnnulusHeatedWall};
+use crate::heat_transfer_correlations::baffled_shell_side::BaffledShellSideNusseltData;
+use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityEnvelope;
+use super::pipe_correlations::{GNIELINSKI_VALIDITY_ENVELOPE, 
+    GNIELINSKI_TURBULENT_VALIDITY_ENVELOPE, LAMINAR_FULLY_DEVELOPED_VALIDITY_ENVELOPE};
+use super::liquid_metal_correlations::{LYON_MARTINELLI_VALIDITY_ENVELOPE, 
+    SEBAN_SHIMAZAKI_VALIDITY_ENVELOPE, SKUPINSKI_VALIDITY_ENVELOPE, 
+    CHENG_TAK_VALIDITY_ENVELOPE, USHAKOV_VALIDITY_ENVELOPE, MIKITYUK_VALIDITY_ENVELOPE};
+use super::annulus_correlations::GNIELINSKI_ANNULUS_VALIDITY_ENVELOPE;
+use super::input_structs::WAKAO_VALIDITY_ENVELOPE;
 
 /// Contains a collection of nusselt number correlations for use 
 ///
@@ -170,6 +181,99 @@ pub enum NusseltCorrelation {
     /// reynolds number you want to supply
     ReynoldsPrandtl(NusseltPrandtlReynoldsData),
 
+    /// Gnielinski correlation for concentric annuli with 
+    /// Petukhov-Roizen wall factors for laminar, turbulent and 
+    /// transition region
+    ///
+    /// the heated wall (inner or outer) is selected in the 
+    /// input data, the other wall is assumed insulated
+    ///
+    /// laminar flow uses the fully developed annulus Nusselt 
+    /// numbers for uniform heat flux (Kays and Crawford), 
+    /// so no further duct shape correction is needed
+    AnnulusGnielinski(GnielinskiAnnulusData),
+
+    /// fully developed laminar Nusselt number for a concentric 
+    /// annulus with uniform heat flux on one wall and the 
+    /// other wall insulated (Kays and Crawford table)
+    ///
+    /// the first in the tuple is the inner to outer diameter 
+    /// ratio, the second is the heated wall
+    AnnulusLaminarUniformHeatFlux(Ratio, AnnulusHeatedWall),
+
+    /// Lyon-Martinelli correlation for liquid metals 
+    /// (Pr << 1) in pipes with uniform heat flux
+    ///
+    /// Nu = 7.0 + 0.025 Pe^0.8
+    ///
+    /// laminar flow (Re < 2300) uses Nu = 4.354, the transition 
+    /// region is interpolated up to Re = 4000
+    ///
+    /// only the bulk prandtl number is used, there is no 
+    /// wall correction for liquid metals
+    PipeLyonMartinelli(LiquidMetalPipeData),
+
+    /// Seban-Shimazaki correlation for liquid metals 
+    /// (Pr << 1) in pipes with uniform wall temperature
+    ///
+    /// Nu = 5.0 + 0.025 Pe^0.8
+    ///
+    /// laminar flow (Re < 2300) uses Nu = 3.66, the transition 
+    /// region is interpolated up to Re = 4000
+    PipeSebanShimazaki(LiquidMetalPipeData),
+
+    /// Skupinski correlation for liquid metals (NaK) 
+    /// in pipes with uniform heat flux
+    ///
+    /// Nu = 4.82 + 0.0185 Pe^0.827
+    ///
+    /// laminar flow (Re < 2300) uses Nu = 4.354, the transition 
+    /// region is interpolated up to Re = 4000
+    PipeSkupinski(LiquidMetalPipeData),
+
+    /// Cheng-Tak correlation for heavy liquid metals 
+    /// (lead, lead-bismuth eutectic) in pipes
+    ///
+    /// Nu = A + 0.018 Pe^0.8, A depends on Pe
+    ///
+    /// laminar flow (Re < 2300) uses Nu = 4.354, the transition 
+    /// region is interpolated up to Re = 4000
+    PipeChengTak(LiquidMetalPipeData),
+
+    /// Ushakov correlation for liquid metals flowing 
+    /// parallel to triangular rod bundles
+    ///
+    /// Nu = 7.55 x - 20 x^(-13) + 0.041/x^2 Pe^(0.56 + 0.19 x)
+    ///
+    /// where x is the pitch to diameter ratio
+    RodBundleUshakov(LiquidMetalRodBundleData),
+
+    /// Mikityuk correlation for liquid metals flowing 
+    /// parallel to triangular rod bundles
+    ///
+    /// Nu = 0.047 (1 - exp(-3.8 (x - 1))) (Pe^0.77 + 250)
+    ///
+    /// where x is the pitch to diameter ratio
+    RodBundleMikityuk(LiquidMetalRodBundleData),
+
+    /// Bell-Delaware method for the shell side of segmentally 
+    /// baffled heat exchangers
+    ///
+    /// reynolds and nusselt numbers are based on the reference 
+    /// hydraulic diameter and flow area of the shell side, 
+    /// these are converted to and from the crossflow reynolds 
+    /// number and tube outer diameter internally
+    ShellSideBellDelaware(BaffledShellSideNusseltData),
+
+    /// Kern method for the shell side of segmentally 
+    /// baffled heat exchangers
+    ///
+    /// Nu_e = 0.36 Re_e^0.55 Pr^(1/3) (Pr_bulk/Pr_wall)^0.14
+    ///
+    /// reynolds and nusselt numbers are based on the reference 
+    /// hydraulic diameter and flow area of the shell side
+    ShellSideKern(BaffledShellSideNusseltData),
+
     /// returns a nusselt number of 4.36 for fully developed 
     /// constant heat flux flow
     #[default]
@@ -245,6 +349,38 @@ impl NusseltCorrelation {
             NusseltCorrelation::ReynoldsPrandtl(reynolds_prandtl_data) => {
                 return reynolds_prandtl_data.custom_reynolds_prandtl();
             },
+            NusseltCorrelation::AnnulusGnielinski(data) => {
+                return data.get_nusselt_for_developing_flow();
+            },
+            NusseltCorrelation::AnnulusLaminarUniformHeatFlux(
+                radius_ratio, heated_wall) => {
+                Ratio::new::<ratio>(annulus_laminar_nusselt_uniform_heat_flux(
+                    radius_ratio.get::<ratio>(), *heated_wall)?)
+            },
+            NusseltCorrelation::PipeLyonMartinelli(data) => {
+                return data.get_nusselt_lyon_martinelli();
+            },
+            NusseltCorrelation::PipeSebanShimazaki(data) => {
+                return data.get_nusselt_seban_shimazaki();
+            },
+            NusseltCorrelation::PipeSkupinski(data) => {
+                return data.get_nusselt_skupinski();
+            },
+            NusseltCorrelation::PipeChengTak(data) => {
+                return data.get_nusselt_cheng_tak();
+            },
+            NusseltCorrelation::RodBundleUshakov(data) => {
+                return data.get_nusselt_ushakov();
+            },
+            NusseltCorrelation::RodBundleMikityuk(data) => {
+                return data.get_nusselt_mikityuk();
+            },
+            NusseltCorrelation::ShellSideBellDelaware(data) => {
+                return data.get_nusselt_bell_delaware();
+            },
+            NusseltCorrelation::ShellSideKern(data) => {
+                return data.get_nusselt_kern();
+            },
             NusseltCorrelation::PipeConstantHeatFluxFullyDeveloped => {
                 Ratio::new::<ratio>(4.354)
             },
@@ -385,6 +521,68 @@ impl NusseltCorrelation {
                 modified_data.reynolds = reynolds_number_input;
                 return modified_data.get();
             },
+            NusseltCorrelation::AnnulusGnielinski(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_wall = bulk_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_for_developing_flow();
+            },
+            NusseltCorrelation::AnnulusLaminarUniformHeatFlux(
+                radius_ratio, heated_wall) => {
+                Ratio::new::<ratio>(annulus_laminar_nusselt_uniform_heat_flux(
+                    radius_ratio.get::<ratio>(), *heated_wall)?)
+            },
+            NusseltCorrelation::PipeLyonMartinelli(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_lyon_martinelli();
+            },
+            NusseltCorrelation::PipeSebanShimazaki(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_seban_shimazaki();
+            },
+            NusseltCorrelation::PipeSkupinski(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_skupinski();
+            },
+            NusseltCorrelation::PipeChengTak(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_cheng_tak();
+            },
+            NusseltCorrelation::RodBundleUshakov(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_ushakov();
+            },
+            NusseltCorrelation::RodBundleMikityuk(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_mikityuk();
+            },
+            NusseltCorrelation::ShellSideBellDelaware(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_wall = bulk_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_bell_delaware();
+            },
+            NusseltCorrelation::ShellSideKern(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_wall = bulk_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_kern();
+            },
             NusseltCorrelation::ReynoldsPrandtl(reynolds_prandtl_data) => {
                 let mut modified_data = reynolds_prandtl_data.clone();
                 modified_data.prandtl_wall = bulk_prandtl_number_input;
@@ -489,6 +687,68 @@ impl NusseltCorrelation {
                 modified_data.reynolds = reynolds_number_input;
                 return modified_data.get();
             },
+            NusseltCorrelation::AnnulusGnielinski(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_wall = wall_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_for_developing_flow();
+            },
+            NusseltCorrelation::AnnulusLaminarUniformHeatFlux(
+                radius_ratio, heated_wall) => {
+                Ratio::new::<ratio>(annulus_laminar_nusselt_uniform_heat_flux(
+                    radius_ratio.get::<ratio>(), *heated_wall)?)
+            },
+            NusseltCorrelation::PipeLyonMartinelli(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_lyon_martinelli();
+            },
+            NusseltCorrelation::PipeSebanShimazaki(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_seban_shimazaki();
+            },
+            NusseltCorrelation::PipeSkupinski(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_skupinski();
+            },
+            NusseltCorrelation::PipeChengTak(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_cheng_tak();
+            },
+            NusseltCorrelation::RodBundleUshakov(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_ushakov();
+            },
+            NusseltCorrelation::RodBundleMikityuk(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_mikityuk();
+            },
+            NusseltCorrelation::ShellSideBellDelaware(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_wall = wall_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_bell_delaware();
+            },
+            NusseltCorrelation::ShellSideKern(data) => {
+                let mut modified_data = *data;
+                modified_data.prandtl_wall = wall_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt_kern();
+            },
             NusseltCorrelation::ReynoldsPrandtl(reynolds_prandtl_data) => {
                 let mut modified_data = reynolds_prandtl_data.clone();
                 modified_data.prandtl_wall = wall_prandtl_number_input;
@@ -580,6 +840,211 @@ impl NusseltCorrelation {
     }
 
 
+    /// gets the validity envelope of the nusselt correlation, 
+    /// if one is declared
+    ///
+    /// custom, calibrated to data and fixed nusselt numbers have 
+    /// no declared envelope. The shell side correlations have 
+    /// envelopes in terms of the crossflow reynolds number, which 
+    /// differs from the reynolds number given to this enum, 
+    /// so these are not declared either
+    ///
+    /// calibrated Gnielinski correlations use the same envelope as 
+    /// the uncalibrated one
+    pub fn get_validity_envelope(&self) -> Option<CorrelationValidityEnvelope> {
+        let envelope = match self {
+            Self::PipeGnielinskiGeneric(_) |
+            Self::PipeGnielinskiCalibrated(_, _) |
+            Self::PipeGnielinskiGenericPrandtlFilm(_) => GNIELINSKI_VALIDITY_ENVELOPE,
+            Self::PipeGnielinskiTurbulentPrandtlBulk(_) => 
+                GNIELINSKI_TURBULENT_VALIDITY_ENVELOPE,
+            Self::Wakao(_) => WAKAO_VALIDITY_ENVELOPE,
+            Self::AnnulusGnielinski(_) => GNIELINSKI_ANNULUS_VALIDITY_ENVELOPE,
+            Self::AnnulusLaminarUniformHeatFlux(_, _) |
+            Self::PipeConstantHeatFluxFullyDeveloped |
+            Self::PipeConstantTemperatureFullyDeveloped => 
+                LAMINAR_FULLY_DEVELOPED_VALIDITY_ENVELOPE,
+            Self::PipeLyonMartinelli(_) => LYON_MARTINELLI_VALIDITY_ENVELOPE,
+            Self::PipeSebanShimazaki(_) => SEBAN_SHIMAZAKI_VALIDITY_ENVELOPE,
+            Self::PipeSkupinski(_) => SKUPINSKI_VALIDITY_ENVELOPE,
+            Self::PipeChengTak(_) => CHENG_TAK_VALIDITY_ENVELOPE,
+            Self::RodBundleUshakov(_) => USHAKOV_VALIDITY_ENVELOPE,
+            Self::RodBundleMikityuk(_) => MIKITYUK_VALIDITY_ENVELOPE,
+            Self::CustomGnielinskiGenericPrandtlFilm(..) |
+            Self::CustomGnielinskiGenericPrandtlBulk(..) |
+            Self::ReynoldsPrandtl(_) |
+            Self::ShellSideBellDelaware(_) |
+            Self::ShellSideKern(_) |
+            Self::CIETHeaterVersion2(_) |
+            Self::IdealNusseltOneBillion |
+            Self::FixedNusselt(_) => return None,
+        };
+
+        Some(envelope)
+    }
+
+    /// Returns `true` if the nusselt correlation already accounts 
+    /// for the duct shape (eg. annulus or baffled shell correlations), 
+    /// so that fluid arrays do not apply the laminar duct shape 
+    /// correction on top of it
+    #[must_use]
+    pub fn accounts_for_duct_shape(&self) -> bool {
+        matches!(self, 
+            Self::AnnulusGnielinski(_) | 
+            Self::AnnulusLaminarUniformHeatFlux(_, _) |
+            Self::ShellSideBellDelaware(_) |
+            Self::ShellSideKern(_))
+    }
+
+    /// Returns `true` for the generic circular pipe correlations, 
+    /// ie. the pipe Gnielinski correlations and the fully developed 
+    /// laminar pipe nusselt numbers
+    ///
+    /// these are the only correlations which fluid arrays correct 
+    /// for duct shape. Other correlations (eg. ReynoldsPrandtl, 
+    /// liquid metal or custom correlations) are fitted to their 
+    /// own geometry and are left alone
+    #[must_use]
+    pub fn is_circular_pipe_generic(&self) -> bool {
+        matches!(self,
+            Self::PipeGnielinskiGeneric(_) |
+            Self::PipeGnielinskiCalibrated(_, _) |
+            Self::PipeGnielinskiGenericPrandtlFilm(_) |
+            Self::PipeGnielinskiTurbulentPrandtlBulk(_) |
+            Self::PipeConstantHeatFluxFullyDeveloped |
+            Self::PipeConstantTemperatureFullyDeveloped)
+    }
+
+    /// Returns `true` if the nusselt correlation has developing
+    /// flow (entrance) effects through a length to diameter ratio,
+    /// ie. the Gnielinski type correlations for pipes and annuli
+    #[must_use]
+    pub fn depends_on_length_to_diameter(&self) -> bool {
+        matches!(self,
+            Self::PipeGnielinskiGeneric(_) |
+            Self::PipeGnielinskiCalibrated(_, _) |
+            Self::PipeGnielinskiGenericPrandtlFilm(_) |
+            Self::CustomGnielinskiGenericPrandtlFilm(..) |
+            Self::CustomGnielinskiGenericPrandtlBulk(..) |
+            Self::PipeGnielinskiTurbulentPrandtlBulk(_) |
+            Self::AnnulusGnielinski(_))
+    }
+
+    /// returns a copy of this nusselt correlation with the length
+    /// to diameter ratio replaced
+    ///
+    /// correlations without developing flow effects are returned
+    /// unchanged
+    #[must_use]
+    pub fn with_length_to_diameter(&self,
+        length_to_diameter: Ratio) -> Self {
+
+        let mut correlation = *self;
+
+        match &mut correlation {
+            Self::PipeGnielinskiGeneric(data) |
+            Self::PipeGnielinskiCalibrated(data, _) |
+            Self::PipeGnielinskiGenericPrandtlFilm(data) |
+            Self::CustomGnielinskiGenericPrandtlFilm(data, _, _) |
+            Self::CustomGnielinskiGenericPrandtlBulk(data, _, _) |
+            Self::PipeGnielinskiTurbulentPrandtlBulk(data) => {
+                data.length_to_diameter = length_to_diameter;
+            },
+            Self::AnnulusGnielinski(data) => {
+                data.length_to_diameter = length_to_diameter;
+            },
+            _ => (),
+        };
+
+        correlation
+    }
+
+    /// gets the local nusselt number of each node along a
+    /// component, divided by the nusselt number of the whole
+    /// component
+    ///
+    /// the nodes are of equal length and ordered from the inlet,
+    /// so the caller must reverse the vector for reverse flow
+    ///
+    /// the developing flow correlations give a mean nusselt number
+    /// Nu_m(x) from the inlet up to a distance x. The mean over
+    /// node i, from x_i to x_(i+1), is then:
+    ///
+    /// Nu_i = (x_(i+1) Nu_m(x_(i+1)) - x_i Nu_m(x_i)) / (x_(i+1) - x_i)
+    ///
+    /// so that entrance effects are concentrated at the inlet
+    /// while the average of the nodal nusselt numbers is still
+    /// Nu_m(L). The ratios hence average to one, and can multiply
+    /// whatever mean nusselt number the component uses (including
+    /// calibrated ones)
+    ///
+    /// correlations without developing flow effects return a
+    /// ratio of one for all nodes
+    pub fn try_get_local_nusselt_ratio_vector(&self,
+        bulk_prandtl_number_input: Ratio,
+        wall_prandtl_number_input: Ratio,
+        darcy_friction_factor: Ratio,
+        reynolds_number_input: Ratio,
+        length_to_diameter: Ratio,
+        number_of_nodes: usize) -> Result<Vec<Ratio>, TuasLibError>{
+
+        if number_of_nodes == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "local nusselt numbers need at least one node".to_string()));
+        }
+
+        if !self.depends_on_length_to_diameter() {
+            return Ok(vec![Ratio::new::<ratio>(1.0); number_of_nodes]);
+        }
+
+        if length_to_diameter.get::<ratio>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "length to diameter ratio must be positive for \
+                local nusselt numbers".to_string()));
+        }
+
+        // x Nu_m(x) at each node boundary, this is zero at the inlet
+        let mut length_weighted_mean_nusselt: Vec<Ratio> =
+            vec![Ratio::new::<ratio>(0.0); number_of_nodes + 1];
+
+        for (boundary_index, weighted_nusselt) in
+            length_weighted_mean_nusselt.iter_mut().enumerate().skip(1) {
+
+            let boundary_length_fraction =
+                boundary_index as f64 / number_of_nodes as f64;
+
+            let mean_nusselt_up_to_boundary = self
+                .with_length_to_diameter(
+                    length_to_diameter * boundary_length_fraction)
+                .estimate_based_on_prandtl_darcy_and_reynolds_wall_correction(
+                    bulk_prandtl_number_input,
+                    wall_prandtl_number_input,
+                    darcy_friction_factor,
+                    reynolds_number_input)?;
+
+            *weighted_nusselt = mean_nusselt_up_to_boundary
+                * boundary_length_fraction;
+        }
+
+        // the last boundary is the component outlet, where
+        // the length fraction is one
+        let component_mean_nusselt = length_weighted_mean_nusselt[number_of_nodes];
+
+        if component_mean_nusselt.get::<ratio>() <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "component nusselt number must be positive for \
+                local nusselt numbers".to_string()));
+        }
+
+        let local_nusselt_ratio_vector: Vec<Ratio> =
+            length_weighted_mean_nusselt.windows(2).map(|boundaries| {
+                (boundaries[1] - boundaries[0]) * number_of_nodes as f64
+                    / component_mean_nusselt
+            }).collect();
+
+        Ok(local_nusselt_ratio_vector)
+    }
+
     /// Returns `true` if the nusselt correlation is [`PipeConstantHeatFlux`].
     ///
     /// [`PipeConstantHeatFlux`]: NusseltCorrelation::PipeConstantHeatFlux
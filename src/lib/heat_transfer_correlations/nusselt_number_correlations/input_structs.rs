@@ -3,6 +3,11 @@ use uom::{si::{f64::*, ratio::ratio}, ConstZero};
 use crate::tuas_lib_error::TuasLibError;
 
 use super::pipe_correlations::*;
+use super::liquid_metal_correlations::*;
+use super::annulus_correlations::*;
+use crate::fluid_mechanics_correlations::non_circular_ducts::AnnulusHeatedWall;
+use crate::heat_transfer_correlations::correlation_validity::{
+    CorrelationValidityEnvelope, CorrelationValidityRange};
 /// contains information Nusselt Prandtl Reynold's
 /// correlation
 /// usually in the form:
@@ -144,6 +149,14 @@ impl Default for NusseltPrandtlReynoldsData {
 }
 
 
+/// validity envelope of the Wakao correlation, 
+/// 3 <= Re <= 10000 based on pebble diameter
+pub const WAKAO_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new(3.0, 1.0e4),
+        ..CorrelationValidityEnvelope::new_unbounded("Wakao")
+    };
+
 /// Wakao, N., & Funazkri, T. (1978). Effect 
 /// of fluid dispersion coefficients on particle-to-fluid mass 
 /// transfer coefficients in packed beds: correlation of 
@@ -381,3 +394,215 @@ impl GnielinskiData {
 
 }
 
+
+
+/// contains data for liquid metal (low prandtl number) 
+/// correlations in pipes
+///
+/// these correlations are based on the peclet number,
+/// Pe = Re Pr, and do not use a wall prandtl number correction
+#[derive(Clone,Copy,Debug, PartialEq)]
+pub struct LiquidMetalPipeData {
+    /// reynolds number based on hydraulic_diameter
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+}
+
+impl Default for LiquidMetalPipeData {
+    fn default() -> Self {
+        Self {
+            reynolds: Ratio::ZERO,
+            prandtl_bulk: Ratio::ZERO,
+        }
+    }
+}
+
+impl LiquidMetalPipeData {
+
+    /// peclet number, Pe = Re Pr
+    #[inline]
+    pub fn get_peclet(&self) -> Ratio {
+        self.reynolds * self.prandtl_bulk
+    }
+
+    /// Lyon-Martinelli correlation (uniform heat flux) for 
+    /// turbulent flow, Nu = 7.0 + 0.025 Pe^0.8
+    ///
+    /// laminar flow uses Nu = 4.354, and the transition region 
+    /// (Re = 2300-4000) is interpolated
+    #[inline]
+    pub fn get_nusselt_lyon_martinelli(&self) 
+    -> Result<Ratio,TuasLibError>{
+        let nusselt_value = liquid_metal_pipe_nusselt_interpolated(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            4.354,
+            lyon_martinelli_nusselt)?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+
+    /// Seban-Shimazaki correlation (uniform wall temperature) for 
+    /// turbulent flow, Nu = 5.0 + 0.025 Pe^0.8
+    ///
+    /// laminar flow uses Nu = 3.66, and the transition region 
+    /// (Re = 2300-4000) is interpolated
+    #[inline]
+    pub fn get_nusselt_seban_shimazaki(&self) 
+    -> Result<Ratio,TuasLibError>{
+        let nusselt_value = liquid_metal_pipe_nusselt_interpolated(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            3.66,
+            seban_shimazaki_nusselt)?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+
+    /// Skupinski correlation (uniform heat flux) for 
+    /// turbulent flow, Nu = 4.82 + 0.0185 Pe^0.827
+    ///
+    /// laminar flow uses Nu = 4.354, and the transition region 
+    /// (Re = 2300-4000) is interpolated
+    #[inline]
+    pub fn get_nusselt_skupinski(&self) 
+    -> Result<Ratio,TuasLibError>{
+        let nusselt_value = liquid_metal_pipe_nusselt_interpolated(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            4.354,
+            skupinski_nusselt)?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+
+    /// Cheng-Tak correlation for heavy liquid metals in 
+    /// turbulent flow, Nu = A + 0.018 Pe^0.8
+    ///
+    /// laminar flow uses Nu = 4.354, and the transition region 
+    /// (Re = 2300-4000) is interpolated
+    #[inline]
+    pub fn get_nusselt_cheng_tak(&self) 
+    -> Result<Ratio,TuasLibError>{
+        let nusselt_value = liquid_metal_pipe_nusselt_interpolated(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            4.354,
+            cheng_tak_nusselt)?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+}
+
+/// contains data for liquid metal (low prandtl number) 
+/// correlations for flow parallel to rod bundles
+#[derive(Clone,Copy,Debug, PartialEq)]
+pub struct LiquidMetalRodBundleData {
+    /// reynolds number based on the bundle hydraulic diameter
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+    /// rod pitch to rod diameter ratio, P/D
+    pub pitch_to_diameter: Ratio,
+}
+
+impl Default for LiquidMetalRodBundleData {
+    fn default() -> Self {
+        Self {
+            reynolds: Ratio::ZERO,
+            prandtl_bulk: Ratio::ZERO,
+            pitch_to_diameter: Ratio::new::<ratio>(1.3),
+        }
+    }
+}
+
+impl LiquidMetalRodBundleData {
+
+    /// peclet number, Pe = Re Pr
+    #[inline]
+    pub fn get_peclet(&self) -> Ratio {
+        self.reynolds * self.prandtl_bulk
+    }
+
+    /// Ushakov correlation for triangular rod bundles
+    #[inline]
+    pub fn get_nusselt_ushakov(&self) 
+    -> Result<Ratio,TuasLibError>{
+        let nusselt_value = ushakov_rod_bundle_nusselt(
+            self.get_peclet().get::<ratio>(),
+            self.pitch_to_diameter.get::<ratio>())?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+
+    /// Mikityuk correlation for triangular rod bundles
+    #[inline]
+    pub fn get_nusselt_mikityuk(&self) 
+    -> Result<Ratio,TuasLibError>{
+        let nusselt_value = mikityuk_rod_bundle_nusselt(
+            self.get_peclet().get::<ratio>(),
+            self.pitch_to_diameter.get::<ratio>())?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+}
+
+/// contains data for the Gnielinski correlation in 
+/// concentric annuli
+///
+/// reynolds and nusselt numbers are based on the hydraulic 
+/// diameter, D_h = D_o - D_i
+#[derive(Clone,Copy,Debug, PartialEq)]
+pub struct GnielinskiAnnulusData {
+    /// reynolds number based on hydraulic_diameter
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+    /// wall prandtl number based on wall temperature
+    pub prandtl_wall: Ratio,
+    /// annulus length to hydraulic diameter ratio 
+    pub length_to_diameter: Ratio,
+    /// inner to outer diameter ratio (D_i/D_o)
+    pub radius_ratio: Ratio,
+    /// which wall of the annulus transfers heat, 
+    /// the other is assumed insulated
+    pub heated_wall: AnnulusHeatedWall,
+}
+
+impl Default for GnielinskiAnnulusData {
+    fn default() -> Self {
+        Self {
+            reynolds: Ratio::ZERO,
+            prandtl_bulk: Ratio::ZERO,
+            prandtl_wall: Ratio::ZERO,
+            length_to_diameter: Ratio::new::<ratio>(1.0),
+            radius_ratio: Ratio::new::<ratio>(0.5),
+            heated_wall: AnnulusHeatedWall::default(),
+        }
+    }
+}
+
+impl GnielinskiAnnulusData {
+
+    /// Gnielinski correlation for annuli with Petukhov-Roizen 
+    /// wall factors, suitable for laminar, turbulent and 
+    /// transition flows
+    ///
+    /// laminar flow uses the fully developed annulus Nusselt 
+    /// numbers of Kays and Crawford for the heated wall
+    #[inline]
+    pub fn get_nusselt_for_developing_flow(&self) 
+    -> Result<Ratio,TuasLibError>{
+
+        let nusselt_value = gnielinski_annulus_nusselt_interpolated(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            self.prandtl_wall.get::<ratio>(),
+            self.length_to_diameter.get::<ratio>(),
+            self.radius_ratio.get::<ratio>(),
+            self.heated_wall)?;
+
+        Ok(Ratio::new::<ratio>(nusselt_value))
+    }
+}
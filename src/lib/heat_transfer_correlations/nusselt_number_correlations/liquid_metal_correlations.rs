@@ -0,0 +1,273 @@
+use crate::tuas_lib_error::TuasLibError;
+use crate::heat_transfer_correlations::correlation_validity::{
+    CorrelationValidityEnvelope, CorrelationValidityRange};
+
+/// checks that the peclet number is not negative,
+/// correlations here are based on the magnitude of the peclet number
+fn peclet_check(peclet_number: f64) -> Result<f64, TuasLibError> {
+
+    if !peclet_number.is_finite() {
+        return Err(TuasLibError::GenericStringError(
+            "peclet number for liquid metal correlation must be finite"
+            .to_string()));
+    }
+
+    Ok(peclet_number.abs())
+}
+
+/// validity envelope of the Lyon-Martinelli correlation
+///
+/// valid for 100 <= Pe <= 10000
+///
+/// the laminar and transition regimes are interpolated, so only 
+/// Pr <= 0.1 (liquid metals) and the upper Pe bound are checked
+pub const LYON_MARTINELLI_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        prandtl: CorrelationValidityRange::new_upper_bound(0.1),
+        peclet: CorrelationValidityRange::new_upper_bound(1.0e4),
+        ..CorrelationValidityEnvelope::new_unbounded("Lyon-Martinelli")
+    };
+
+/// Lyon-Martinelli correlation for turbulent liquid metal
+/// flow in pipes with uniform heat flux
+///
+/// Nu = 7.0 + 0.025 Pe^0.8
+///
+/// Lyon, R. N. (1951). Liquid metal heat transfer coefficients.
+/// Chemical Engineering Progress, 47(2), 75-79.
+///
+/// ```rust
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// nusselt_number_correlations::liquid_metal_correlations::
+/// lyon_martinelli_nusselt;
+///
+/// let nusselt = lyon_martinelli_nusselt(1000.0).unwrap();
+///
+/// approx::assert_relative_eq!(nusselt, 13.2797, max_relative = 1e-4);
+/// ```
+pub fn lyon_martinelli_nusselt(peclet_number: f64)
+    -> Result<f64, TuasLibError> {
+
+    let peclet = peclet_check(peclet_number)?;
+
+    Ok(7.0 + 0.025 * peclet.powf(0.8))
+}
+
+/// validity envelope of the Seban-Shimazaki correlation
+///
+/// valid for 100 <= Pe <= 10000
+///
+/// the laminar and transition regimes are interpolated, so only 
+/// Pr <= 0.1 (liquid metals) and the upper Pe bound are checked
+pub const SEBAN_SHIMAZAKI_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        prandtl: CorrelationValidityRange::new_upper_bound(0.1),
+        peclet: CorrelationValidityRange::new_upper_bound(1.0e4),
+        ..CorrelationValidityEnvelope::new_unbounded("Seban-Shimazaki")
+    };
+
+/// Seban-Shimazaki correlation for turbulent liquid metal
+/// flow in pipes with uniform wall temperature
+///
+/// Nu = 5.0 + 0.025 Pe^0.8
+///
+/// Seban, R. A., & Shimazaki, T. T. (1951). Heat transfer to a
+/// fluid flowing turbulently in a smooth pipe with walls at
+/// constant temperature. Transactions of the American Society
+/// of Mechanical Engineers, 73(6), 803-807.
+pub fn seban_shimazaki_nusselt(peclet_number: f64)
+    -> Result<f64, TuasLibError> {
+
+    let peclet = peclet_check(peclet_number)?;
+
+    Ok(5.0 + 0.025 * peclet.powf(0.8))
+}
+
+/// validity envelope of the Skupinski correlation
+///
+/// valid for 100 <= Pe <= 10000
+///
+/// the laminar and transition regimes are interpolated, so only 
+/// Pr <= 0.1 (liquid metals) and the upper Pe bound are checked
+pub const SKUPINSKI_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        prandtl: CorrelationValidityRange::new_upper_bound(0.1),
+        peclet: CorrelationValidityRange::new_upper_bound(1.0e4),
+        ..CorrelationValidityEnvelope::new_unbounded("Skupinski")
+    };
+
+/// Skupinski correlation for turbulent liquid metal
+/// (NaK) flow in pipes with uniform heat flux
+///
+/// Nu = 4.82 + 0.0185 Pe^0.827
+///
+/// Skupinski, E., Tortel, J., & Vautrey, L. (1965). Determination
+/// des coefficients de convection d'un alliage sodium-potassium
+/// dans un tube circulaire. International Journal of Heat and
+/// Mass Transfer, 8(6), 937-951.
+pub fn skupinski_nusselt(peclet_number: f64)
+    -> Result<f64, TuasLibError> {
+
+    let peclet = peclet_check(peclet_number)?;
+
+    Ok(4.82 + 0.0185 * peclet.powf(0.827))
+}
+
+/// validity envelope of the Cheng-Tak correlation
+///
+/// fitted to heavy liquid metal data up to Pe = 10000
+///
+/// the laminar and transition regimes are interpolated, so only 
+/// Pr <= 0.1 (liquid metals) and the upper Pe bound are checked
+pub const CHENG_TAK_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        prandtl: CorrelationValidityRange::new_upper_bound(0.1),
+        peclet: CorrelationValidityRange::new_upper_bound(1.0e4),
+        ..CorrelationValidityEnvelope::new_unbounded("Cheng-Tak")
+    };
+
+/// Cheng-Tak correlation for turbulent heavy liquid metal
+/// (lead and lead-bismuth eutectic) flow in pipes
+///
+/// Nu = A + 0.018 Pe^0.8
+///
+/// where
+///
+/// A = 4.5 for Pe <= 1000
+/// A = 5.4 - 9e-4 Pe for 1000 < Pe < 2000
+/// A = 3.6 for Pe >= 2000
+///
+/// Cheng, X., & Tak, N. I. (2006). Investigation on turbulent
+/// heat transfer to lead–bismuth eutectic flows in circular tubes
+/// for nuclear applications. Nuclear Engineering and Design,
+/// 236(4), 385-393.
+pub fn cheng_tak_nusselt(peclet_number: f64)
+    -> Result<f64, TuasLibError> {
+
+    let peclet = peclet_check(peclet_number)?;
+
+    let constant_a: f64 = if peclet <= 1000.0 {
+        4.5
+    } else if peclet < 2000.0 {
+        5.4 - 9.0e-4 * peclet
+    } else {
+        3.6
+    };
+
+    Ok(constant_a + 0.018 * peclet.powf(0.8))
+}
+
+/// validity envelope of the Ushakov correlation, 
+/// 1 <= Pe <= 4000
+///
+/// the pitch to diameter ratio is not checked here, it is 
+/// fixed by the geometry
+pub const USHAKOV_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        prandtl: CorrelationValidityRange::new_upper_bound(0.1),
+        peclet: CorrelationValidityRange::new(1.0, 4000.0),
+        ..CorrelationValidityEnvelope::new_unbounded("Ushakov")
+    };
+
+/// Ushakov correlation for liquid metal flow parallel to
+/// triangular rod bundles, x is the pitch to diameter ratio
+///
+/// Nu = 7.55 x - 20 x^(-13) + 0.041/x^2 Pe^(0.56 + 0.19 x)
+///
+/// valid for 1.2 <= x <= 2.0 and 1 <= Pe <= 4000
+///
+/// Ushakov, P. A., Zhukov, A. V., & Matyukhin, N. M. (1977).
+/// Heat transfer to liquid metals in regular arrays of fuel
+/// elements. High Temperature, 15(5), 868-873.
+pub fn ushakov_rod_bundle_nusselt(peclet_number: f64,
+    pitch_to_diameter: f64) -> Result<f64, TuasLibError> {
+
+    let peclet = peclet_check(peclet_number)?;
+
+    if pitch_to_diameter <= 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "pitch to diameter ratio for rod bundle must be more than 1"
+            .to_string()));
+    }
+
+    let x = pitch_to_diameter;
+
+    Ok(7.55 * x - 20.0 * x.powf(-13.0)
+        + 0.041 / (x * x) * peclet.powf(0.56 + 0.19 * x))
+}
+
+/// validity envelope of the Mikityuk correlation, 
+/// 30 <= Pe <= 5000
+///
+/// the pitch to diameter ratio is not checked here, it is 
+/// fixed by the geometry
+pub const MIKITYUK_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        prandtl: CorrelationValidityRange::new_upper_bound(0.1),
+        peclet: CorrelationValidityRange::new(30.0, 5000.0),
+        ..CorrelationValidityEnvelope::new_unbounded("Mikityuk")
+    };
+
+/// Mikityuk correlation for liquid metal flow parallel to
+/// triangular rod bundles, x is the pitch to diameter ratio
+///
+/// Nu = 0.047 (1 - exp(-3.8 (x - 1))) (Pe^0.77 + 250)
+///
+/// valid for 1.1 <= x <= 1.95 and 30 <= Pe <= 5000
+///
+/// Mikityuk, K. (2009). Heat transfer to liquid metal: review of
+/// data and correlations for tube bundles. Nuclear Engineering
+/// and Design, 239(4), 680-687.
+pub fn mikityuk_rod_bundle_nusselt(peclet_number: f64,
+    pitch_to_diameter: f64) -> Result<f64, TuasLibError> {
+
+    let peclet = peclet_check(peclet_number)?;
+
+    if pitch_to_diameter <= 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "pitch to diameter ratio for rod bundle must be more than 1"
+            .to_string()));
+    }
+
+    let x = pitch_to_diameter;
+
+    Ok(0.047 * (1.0 - (-3.8 * (x - 1.0)).exp())
+        * (peclet.powf(0.77) + 250.0))
+}
+
+/// interpolates between a laminar nusselt number and a turbulent
+/// liquid metal correlation in pipes
+///
+/// Re < 2300 is laminar, Re > 4000 is turbulent,
+/// in between, the nusselt number is linearly interpolated
+/// using the turbulent correlation at Re = 4000, same as
+/// the gnielinski interpolated correlations
+pub fn liquid_metal_pipe_nusselt_interpolated(
+    reynolds: f64,
+    prandtl: f64,
+    laminar_nusselt: f64,
+    turbulent_correlation: fn(f64) -> Result<f64, TuasLibError>)
+    -> Result<f64, TuasLibError> {
+
+    if prandtl <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "prandtl number for liquid metal correlation must be positive"
+            .to_string()));
+    }
+
+    let reynolds = reynolds.abs();
+
+    if reynolds > 4000_f64 {
+        return turbulent_correlation(reynolds * prandtl);
+    }
+
+    if reynolds < 2300_f64 {
+        return Ok(laminar_nusselt);
+    }
+
+    let turbulent_nusselt = turbulent_correlation(4000_f64 * prandtl)?;
+
+    let gamma = (reynolds - 2300_f64)/(4000_f64 - 2300_f64);
+
+    Ok((1_f64 - gamma) * laminar_nusselt + gamma * turbulent_nusselt)
+}
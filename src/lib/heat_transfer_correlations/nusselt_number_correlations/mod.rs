@@ -5,6 +5,14 @@
 pub mod pipe_correlations;
 
 
+/// nusselt number correlations for liquid metals and other 
+/// low prandtl number fluids in pipes and rod bundles
+pub mod liquid_metal_correlations;
+
+/// nusselt number correlations for concentric annuli 
+/// with either the inner or outer wall heated
+pub mod annulus_correlations;
+
 /// contains data types used for nusselt number correlation 
 /// enums
 pub mod input_structs;
@@ -1,6 +1,9 @@
 use uom::si::f64::*;
 use uom::si::ratio::ratio;
 
+use crate::heat_transfer_correlations::correlation_validity::{
+    CorrelationValidityEnvelope, CorrelationValidityRange};
+
 
 /// A nusselt correlation for CIET heater v1.0
 ///
@@ -61,6 +64,21 @@ pub fn nusselt_ciet_heater_v1_0(reynolds_number: f64)-> f64 {
 }
 
 
+/// validity envelope of the Dittus Boelter correlation
+///
+/// Re >= 10000, 0.6 <= Pr <= 160, L/D >= 10
+///
+/// Incropera, F. P., DeWitt, D. P., Bergman, T. L., & Lavine, 
+/// A. S. (2007). Fundamentals of heat and mass transfer (6th ed.). 
+/// Wiley. (Eq. 8.60)
+pub const DITTUS_BOELTER_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new_lower_bound(1.0e4),
+        prandtl: CorrelationValidityRange::new(0.6, 160.0),
+        length_to_diameter: CorrelationValidityRange::new_lower_bound(10.0),
+        ..CorrelationValidityEnvelope::new_unbounded("Dittus Boelter")
+    };
+
 /// Dittus Boelter Correlation
 ///
 /// <https://www.e3s-conferences.org/articles/e3sconf/pdf/2017/01/e3sconf_wtiue2017_02008.pdf>
@@ -263,6 +281,36 @@ pub fn sieder_tate_correlation(reynolds_number: f64, prandtl_number: f64,
     return nusselt_number_f;
 }
 
+/// validity envelope of the turbulent Gnielinski correlation
+///
+/// 2300 <= Re <= 5e6, 0.5 <= Pr <= 2000
+///
+/// Gnielinski, V. (1976). New equations for heat and mass transfer 
+/// in turbulent pipe and channel flow. International Chemical 
+/// Engineering, 16(2), 359-368.
+///
+/// the original paper gives Re >= 3000, but the correlation is 
+/// used from Re = 2300 onwards in this library
+pub const GNIELINSKI_TURBULENT_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new(2300.0, 5.0e6),
+        prandtl: CorrelationValidityRange::new(0.5, 2000.0),
+        ..CorrelationValidityEnvelope::new_unbounded("Gnielinski turbulent")
+    };
+
+/// validity envelope of the Gnielinski correlation including the 
+/// laminar and transition regimes
+///
+/// the laminar nusselt number does not depend on Pr, but the 
+/// Pr range of the turbulent correlation is kept as the transition 
+/// regime is interpolated from it
+pub const GNIELINSKI_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new_upper_bound(5.0e6),
+        prandtl: CorrelationValidityRange::new(0.5, 2000.0),
+        ..CorrelationValidityEnvelope::new_unbounded("Gnielinski")
+    };
+
 /// Gnielinski Equation for liquids
 ///
 ///
@@ -361,6 +409,16 @@ pub fn gnielinski_correlation_liquids_fully_developed(reynolds_number: f64,
 
 
 
+/// validity envelope of fully developed laminar nusselt numbers
+/// (Nu = 4.36 and Nu = 3.66)
+///
+/// Re <= 2300
+pub const LAMINAR_FULLY_DEVELOPED_VALIDITY_ENVELOPE: CorrelationValidityEnvelope = 
+    CorrelationValidityEnvelope {
+        reynolds: CorrelationValidityRange::new_upper_bound(2300.0),
+        ..CorrelationValidityEnvelope::new_unbounded("laminar fully developed")
+    };
+
 /// returns a nusselt number of 4.36,
 ///
 /// This is an estimate for constant heat flux nusselt number
@@ -534,3 +534,304 @@ pub fn du_nusselt_enum_correlation_empirical_test(){
     test_fn(3510.033, 42.582 ,0.08);
 
 }
+
+/// hand calculated liquid metal nusselt numbers for 
+/// sodium like flows, Re = 1e5, Pr = 0.005 (Pe = 500)
+///
+/// Lyon-Martinelli: Nu = 7.0 + 0.025 (500)^0.8 = 10.6067
+/// Seban-Shimazaki: Nu = 5.0 + 0.025 (500)^0.8 = 8.6067
+/// Skupinski: Nu = 4.82 + 0.0185 (500)^0.827 = 7.9766
+/// Cheng-Tak: Nu = 4.5 + 0.018 (500)^0.8 = 7.0969
+///
+/// and for triangular rod bundles with P/D = 1.3 at Pe = 1000
+///
+/// Ushakov: Nu = 15.5505
+/// Mikityuk: Nu = 14.5193
+#[test] 
+pub fn liquid_metal_nusselt_enum_hand_calc_test(){
+    use uom::si::ratio::ratio;
+    use uom::si::f64::*;
+
+    use super::input_structs::{LiquidMetalPipeData, LiquidMetalRodBundleData};
+    use super::enums::NusseltCorrelation;
+
+    let pipe_data = LiquidMetalPipeData {
+        reynolds: Ratio::new::<ratio>(1.0e5),
+        prandtl_bulk: Ratio::new::<ratio>(0.005),
+    };
+
+    let nusselt = |correlation: NusseltCorrelation| -> f64 {
+        correlation.try_get_nusselt().unwrap().get::<ratio>()
+    };
+
+    approx::assert_relative_eq!(
+        nusselt(NusseltCorrelation::PipeLyonMartinelli(pipe_data)),
+        10.6067, max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        nusselt(NusseltCorrelation::PipeSebanShimazaki(pipe_data)),
+        8.6067, max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        nusselt(NusseltCorrelation::PipeSkupinski(pipe_data)),
+        7.9766, max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        nusselt(NusseltCorrelation::PipeChengTak(pipe_data)),
+        7.0969, max_relative = 1e-4);
+
+    let rod_bundle_data = LiquidMetalRodBundleData {
+        reynolds: Ratio::new::<ratio>(2.0e5),
+        prandtl_bulk: Ratio::new::<ratio>(0.005),
+        pitch_to_diameter: Ratio::new::<ratio>(1.3),
+    };
+
+    approx::assert_relative_eq!(
+        nusselt(NusseltCorrelation::RodBundleUshakov(rod_bundle_data)),
+        15.5505, max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        nusselt(NusseltCorrelation::RodBundleMikityuk(rod_bundle_data)),
+        14.5193, max_relative = 1e-4);
+
+    // pitch to diameter ratios of one or less are not rod bundles
+    let touching_rods = LiquidMetalRodBundleData {
+        pitch_to_diameter: Ratio::new::<ratio>(1.0),
+        ..rod_bundle_data
+    };
+    assert!(NusseltCorrelation::RodBundleMikityuk(touching_rods)
+        .try_get_nusselt().is_err());
+}
+
+/// liquid metal correlations in pipes should give the laminar 
+/// nusselt number below Re = 2300, interpolate in the transition 
+/// region and be usable through the estimate methods used by 
+/// fluid arrays
+///
+/// at Re = 3000, Pr = 0.005 for Lyon-Martinelli,
+/// gamma = (3000-2300)/(4000-2300) 
+/// Nu = (1-gamma) 4.354 + gamma (7.0 + 0.025 (20)^0.8) = 5.5566
+#[test] 
+pub fn liquid_metal_nusselt_laminar_and_transition_test(){
+    use uom::si::ratio::ratio;
+    use uom::si::f64::*;
+
+    use super::input_structs::LiquidMetalPipeData;
+    use super::enums::NusseltCorrelation;
+
+    let lyon_martinelli = NusseltCorrelation::PipeLyonMartinelli(
+        LiquidMetalPipeData::default());
+    let seban_shimazaki = NusseltCorrelation::PipeSebanShimazaki(
+        LiquidMetalPipeData::default());
+
+    let prandtl = Ratio::new::<ratio>(0.005);
+
+    let laminar_nusselt = lyon_martinelli
+        .estimate_based_on_prandtl_and_reynolds_no_wall_correction(
+            prandtl, Ratio::new::<ratio>(1000.0)).unwrap();
+    approx::assert_relative_eq!(
+        laminar_nusselt.get::<ratio>(), 4.354, max_relative = 1e-9);
+
+    let laminar_nusselt_constant_wall_temp = seban_shimazaki
+        .estimate_based_on_prandtl_and_reynolds_no_wall_correction(
+            prandtl, Ratio::new::<ratio>(1000.0)).unwrap();
+    approx::assert_relative_eq!(
+        laminar_nusselt_constant_wall_temp.get::<ratio>(), 
+        3.66, max_relative = 1e-9);
+
+    // wall prandtl number is ignored for liquid metals, and 
+    // the darcy friction factor fallback should work as well
+    let transition_nusselt = lyon_martinelli
+        .estimate_based_on_prandtl_darcy_and_reynolds_wall_correction(
+            prandtl, 
+            Ratio::new::<ratio>(0.002),
+            Ratio::new::<ratio>(0.04),
+            Ratio::new::<ratio>(3000.0)).unwrap();
+    approx::assert_relative_eq!(
+        transition_nusselt.get::<ratio>(), 5.5566, max_relative = 1e-4);
+}
+
+/// hand calculated Gnielinski annulus nusselt numbers for 
+/// Re = 1e4, Pr = Pr_wall = 5, L/D_h = 100 and D_i/D_o = 0.5
+///
+/// Re* = 6719.15, f = (1.8 log10(Re*) - 1.5)^(-2) = 0.034432
+///
+/// inner wall heated: F_ann = 0.75 (0.5)^(-0.17), Nu = 62.170
+/// outer wall heated: F_ann = 0.9 - 0.15 (0.5)^0.6, Nu = 59.019
+///
+/// in laminar flow, Nu = 6.2475 for the inner wall (interpolated 
+/// from Kays and Crawford between r* = 0.4 and 0.6)
+#[test] 
+pub fn gnielinski_annulus_enum_hand_calc_test(){
+    use uom::si::ratio::ratio;
+    use uom::si::f64::*;
+
+    use crate::fluid_mechanics_correlations::non_circular_ducts::AnnulusHeatedWall;
+    use super::input_structs::GnielinskiAnnulusData;
+    use super::enums::NusseltCorrelation;
+    use super::annulus_correlations::gnielinski_annulus_darcy_friction_factor;
+
+    approx::assert_relative_eq!(
+        gnielinski_annulus_darcy_friction_factor(1.0e4, 0.5).unwrap(),
+        0.034432, max_relative = 1e-4);
+
+    let inner_wall_data = GnielinskiAnnulusData {
+        reynolds: Ratio::new::<ratio>(1.0e4),
+        prandtl_bulk: Ratio::new::<ratio>(5.0),
+        prandtl_wall: Ratio::new::<ratio>(5.0),
+        length_to_diameter: Ratio::new::<ratio>(100.0),
+        radius_ratio: Ratio::new::<ratio>(0.5),
+        heated_wall: AnnulusHeatedWall::InnerWall,
+    };
+    let outer_wall_data = GnielinskiAnnulusData {
+        heated_wall: AnnulusHeatedWall::OuterWall,
+        ..inner_wall_data
+    };
+
+    let inner_wall_correlation = NusseltCorrelation::AnnulusGnielinski(
+        inner_wall_data);
+    let outer_wall_correlation = NusseltCorrelation::AnnulusGnielinski(
+        outer_wall_data);
+
+    approx::assert_relative_eq!(
+        inner_wall_correlation.try_get_nusselt().unwrap().get::<ratio>(),
+        62.170, max_relative = 1e-4);
+    approx::assert_relative_eq!(
+        outer_wall_correlation.try_get_nusselt().unwrap().get::<ratio>(),
+        59.019, max_relative = 1e-4);
+
+    // laminar flow through the estimate methods
+    let laminar_nusselt = inner_wall_correlation
+        .estimate_based_on_prandtl_and_reynolds_no_wall_correction(
+            Ratio::new::<ratio>(5.0), Ratio::new::<ratio>(1000.0))
+        .unwrap();
+    approx::assert_relative_eq!(
+        laminar_nusselt.get::<ratio>(), 6.2475, max_relative = 1e-9);
+
+    let laminar_table_nusselt = NusseltCorrelation::
+        AnnulusLaminarUniformHeatFlux(
+            Ratio::new::<ratio>(0.5), AnnulusHeatedWall::InnerWall)
+        .try_get_nusselt().unwrap();
+    approx::assert_relative_eq!(
+        laminar_table_nusselt.get::<ratio>(), 6.2475, max_relative = 1e-9);
+
+    assert!(inner_wall_correlation.accounts_for_duct_shape());
+    assert!(!NusseltCorrelation::PipeConstantHeatFluxFullyDeveloped
+        .accounts_for_duct_shape());
+
+    // radius ratio must be between 0 and 1
+    let bad_data = GnielinskiAnnulusData {
+        radius_ratio: Ratio::new::<ratio>(1.2),
+        ..inner_wall_data
+    };
+    assert!(NusseltCorrelation::AnnulusGnielinski(bad_data)
+        .try_get_nusselt().is_err());
+}
+
+/// local nusselt numbers for developing flow 
+///
+/// for the turbulent Gnielinski correlation with Pr_bulk = Pr_wall,
+/// the mean nusselt number up to x is:
+///
+/// Nu_m(x) = Nu_fd (1 + (D/x)^(2/3))
+///
+/// so x Nu_m(x) = Nu_fd (x + D^(2/3) x^(1/3)), and over node i 
+/// with length dx (in diameters), the local to component ratio is:
+///
+/// (dx + x_(i+1)^(1/3) - x_i^(1/3)) / dx / (1 + (L/D)^(-2/3))
+///
+/// For L/D = 50 and 10 nodes, the inlet node has a ratio of 1.24990,
+/// and the outlet node has a ratio of 0.955058
+#[test] 
+pub fn gnielinski_local_nusselt_ratio_hand_calc_test(){
+    use uom::si::ratio::ratio;
+    use uom::si::f64::*;
+
+    use super::input_structs::GnielinskiData;
+    use super::enums::NusseltCorrelation;
+
+    let gnielinski_data = GnielinskiData {
+        reynolds: Ratio::new::<ratio>(1.0e4),
+        prandtl_bulk: Ratio::new::<ratio>(5.0),
+        prandtl_wall: Ratio::new::<ratio>(5.0),
+        darcy_friction_factor: Ratio::new::<ratio>(0.0309),
+        length_to_diameter: Ratio::new::<ratio>(1.0),
+    };
+
+    let turbulent_correlation = NusseltCorrelation::
+        PipeGnielinskiTurbulentPrandtlBulk(gnielinski_data);
+
+    let number_of_nodes: usize = 10;
+    let length_to_diameter = Ratio::new::<ratio>(50.0);
+
+    let local_nusselt_ratio_vector = turbulent_correlation
+        .try_get_local_nusselt_ratio_vector(
+            Ratio::new::<ratio>(5.0), 
+            Ratio::new::<ratio>(5.0), 
+            Ratio::new::<ratio>(0.0309), 
+            Ratio::new::<ratio>(1.0e4), 
+            length_to_diameter, 
+            number_of_nodes).unwrap();
+
+    assert_eq!(local_nusselt_ratio_vector.len(), number_of_nodes);
+
+    approx::assert_relative_eq!(
+        local_nusselt_ratio_vector[0].get::<ratio>(),
+        1.24990, max_relative = 1e-5);
+    approx::assert_relative_eq!(
+        local_nusselt_ratio_vector[number_of_nodes-1].get::<ratio>(),
+        0.955058, max_relative = 1e-5);
+
+    // entrance effects decay along the pipe, and the component 
+    // mean is unchanged
+    for node_pair in local_nusselt_ratio_vector.windows(2) {
+        assert!(node_pair[0] > node_pair[1]);
+    }
+
+    let mean_ratio: f64 = local_nusselt_ratio_vector.iter()
+        .map(|local_ratio| local_ratio.get::<ratio>())
+        .sum::<f64>() / number_of_nodes as f64;
+
+    approx::assert_relative_eq!(mean_ratio, 1.0, max_relative = 1e-12);
+
+    // the same holds in laminar flow with the interpolated 
+    // Gnielinski correlation
+    let laminar_local_nusselt_ratio_vector = 
+        NusseltCorrelation::PipeGnielinskiGeneric(gnielinski_data)
+        .try_get_local_nusselt_ratio_vector(
+            Ratio::new::<ratio>(5.0), 
+            Ratio::new::<ratio>(5.0), 
+            Ratio::new::<ratio>(0.0309), 
+            Ratio::new::<ratio>(1000.0), 
+            length_to_diameter, 
+            number_of_nodes).unwrap();
+
+    let laminar_mean_ratio: f64 = laminar_local_nusselt_ratio_vector.iter()
+        .map(|local_ratio| local_ratio.get::<ratio>())
+        .sum::<f64>() / number_of_nodes as f64;
+
+    approx::assert_relative_eq!(laminar_mean_ratio, 1.0, max_relative = 1e-12);
+    assert!(laminar_local_nusselt_ratio_vector[0] > 
+        laminar_local_nusselt_ratio_vector[number_of_nodes-1]);
+
+    // correlations without developing flow effects are uniform
+    let fixed_local_nusselt_ratio_vector = 
+        NusseltCorrelation::FixedNusselt(Ratio::new::<ratio>(4.36))
+        .try_get_local_nusselt_ratio_vector(
+            Ratio::new::<ratio>(5.0), 
+            Ratio::new::<ratio>(5.0), 
+            Ratio::new::<ratio>(0.0309), 
+            Ratio::new::<ratio>(1.0e4), 
+            length_to_diameter, 
+            number_of_nodes).unwrap();
+
+    assert_eq!(fixed_local_nusselt_ratio_vector, 
+        vec![Ratio::new::<ratio>(1.0); number_of_nodes]);
+
+    // replacing the length to diameter ratio only affects 
+    // developing flow correlations
+    match turbulent_correlation.with_length_to_diameter(length_to_diameter) {
+        NusseltCorrelation::PipeGnielinskiTurbulentPrandtlBulk(data) => 
+            assert_eq!(data.length_to_diameter, length_to_diameter),
+        _ => panic!("with_length_to_diameter changed the correlation"),
+    };
+    assert_eq!(NusseltCorrelation::FixedNusselt(Ratio::new::<ratio>(4.36))
+        .with_length_to_diameter(length_to_diameter),
+        NusseltCorrelation::FixedNusselt(Ratio::new::<ratio>(4.36)));
+}
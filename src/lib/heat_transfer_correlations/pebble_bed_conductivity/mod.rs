@@ -0,0 +1,219 @@
+//! effective thermal conductivity of packed pebble beds
+//!
+//! the fluid to pebble convection is handled by the Wakao correlation
+//! (see WakaoData), but heat is also conducted through the bed
+//! itself, through the pebbles, the fluid in between them and by
+//! radiation from pebble to pebble at high temperatures.
+//!
+//! The Zehner-Schlunder model gives the conduction part for a bed
+//! of spheres with porosity epsilon, in terms of the conductivity
+//! ratio kappa = k_s/k_f:
+//!
+//! k_eff/k_f = 1 - sqrt(1 - epsilon)
+//! + 2 sqrt(1 - epsilon)/N [ (kappa - 1) B/(N^2 kappa) ln(kappa/B)
+//! - (B + 1)/2 - (B - 1)/N ]
+//!
+//! N = 1 - B/kappa
+//!
+//! B = 1.25 ((1 - epsilon)/epsilon)^(10/9)
+//!
+//! Zehner, P., & Schlünder, E. U. (1970). Wärmeleitfähigkeit von
+//! Schüttungen bei mäßigen Temperaturen. Chemie Ingenieur Technik,
+//! 42(14), 933-941.
+//!
+//! The Zehner-Bauer-Schlunder (ZBS) model, as used in KTA 3102.4 for
+//! pebble bed reactors, adds the radiation part by Breitbach and
+//! Barthels:
+//!
+//! k_r = 4 sigma T^3 d [ (1 - sqrt(1 - epsilon)) epsilon
+//! + sqrt(1 - epsilon)/(2/e - 1) (B + 1)/B 1/(1 + 1/((2/e - 1) Lambda)) ]
+//!
+//! Lambda = k_s/(4 sigma T^3 d)
+//!
+//! where e is the pebble surface emissivity and d the pebble diameter
+//!
+//! Breitbach, G., & Barthels, H. (1980). The radiant heat transfer
+//! in the high temperature reactor core after failure of the afterheat
+//! removal systems. Nuclear Technology, 49(3), 392-399.
+//!
+//! the contact conductance between pebbles is neglected here, it is
+//! usually small compared to the other two in a pebble bed core
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::ratio::ratio;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::STEFAN_BOLTZMANN_CONSTANT;
+use crate::tuas_lib_error::TuasLibError;
+
+/// deformation parameter B for the Zehner-Schlunder model
+/// for spheres,
+///
+/// B = 1.25 ((1 - epsilon)/epsilon)^(10/9)
+pub fn zehner_schlunder_deformation_parameter(
+    porosity: Ratio) -> Result<f64, TuasLibError> {
+
+    let epsilon: f64 = porosity.get::<ratio>();
+
+    if epsilon <= 0.0 || epsilon >= 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "pebble bed porosity must be between 0 and 1".to_string()));
+    }
+
+    Ok(1.25 * ((1.0 - epsilon)/epsilon).powf(10.0/9.0))
+}
+
+/// effective thermal conductivity of a packed bed of spheres
+/// from the Zehner-Schlunder model, conduction through the solid
+/// and fluid only (no radiation)
+///
+/// the formula has a removable singularity when kappa = B,
+/// and suffers from round off close to it, so near kappa = B,
+/// the conductivity ratio is interpolated linearly between
+/// N = -0.001 and N = 0.001
+pub fn zehner_schlunder_effective_thermal_conductivity(
+    solid_thermal_conductivity: ThermalConductivity,
+    fluid_thermal_conductivity: ThermalConductivity,
+    porosity: Ratio) -> Result<ThermalConductivity, TuasLibError> {
+
+    check_positive_conductivities(solid_thermal_conductivity,
+        fluid_thermal_conductivity)?;
+
+    let b: f64 = zehner_schlunder_deformation_parameter(porosity)?;
+    let epsilon: f64 = porosity.get::<ratio>();
+
+    let kappa: f64 = (solid_thermal_conductivity/
+        fluid_thermal_conductivity).get::<ratio>();
+
+    let n: f64 = 1.0 - b/kappa;
+    let interpolation_half_width: f64 = 1e-3;
+
+    let conductivity_ratio: f64 = if n.abs() < interpolation_half_width {
+
+        // kappa = B/(1 - N)
+        let lower_ratio = zehner_schlunder_conductivity_ratio(
+            b/(1.0 + interpolation_half_width), b, epsilon);
+        let upper_ratio = zehner_schlunder_conductivity_ratio(
+            b/(1.0 - interpolation_half_width), b, epsilon);
+
+        let interpolation_fraction: f64 =
+            0.5 * (n + interpolation_half_width)/interpolation_half_width;
+
+        lower_ratio + interpolation_fraction * (upper_ratio - lower_ratio)
+
+    } else {
+        zehner_schlunder_conductivity_ratio(kappa, b, epsilon)
+    };
+
+    Ok(conductivity_ratio * fluid_thermal_conductivity)
+}
+
+/// k_eff/k_f from the Zehner-Schlunder formula, given
+/// kappa = k_s/k_f, the deformation parameter B and porosity
+fn zehner_schlunder_conductivity_ratio(kappa: f64, b: f64,
+    epsilon: f64) -> f64 {
+
+    let n: f64 = 1.0 - b/kappa;
+    let solid_fraction_sqrt: f64 = (1.0 - epsilon).sqrt();
+
+    let bracket_term: f64 = (kappa - 1.0) * b/(n * n * kappa) * (kappa/b).ln()
+        - 0.5 * (b + 1.0)
+        - (b - 1.0)/n;
+
+    1.0 - solid_fraction_sqrt
+        + 2.0 * solid_fraction_sqrt/n * bracket_term
+}
+
+/// radiative part of the effective thermal conductivity of a
+/// pebble bed (Breitbach-Barthels)
+///
+/// this goes with T^3, so it becomes the most important part
+/// in a pebble bed core at high temperatures
+pub fn breitbach_barthels_radiative_thermal_conductivity(
+    solid_thermal_conductivity: ThermalConductivity,
+    porosity: Ratio,
+    pebble_emissivity: Ratio,
+    pebble_diameter: Length,
+    bed_temperature: ThermodynamicTemperature) -> Result<ThermalConductivity, TuasLibError> {
+
+    let b: f64 = zehner_schlunder_deformation_parameter(porosity)?;
+    let epsilon: f64 = porosity.get::<ratio>();
+    let emissivity: f64 = pebble_emissivity.get::<ratio>();
+    let diameter: f64 = pebble_diameter.get::<meter>();
+    let temperature: f64 = bed_temperature.get::<kelvin>();
+    let k_s: f64 = solid_thermal_conductivity.get::<watt_per_meter_kelvin>();
+
+    if emissivity <= 0.0 || emissivity > 1.0 {
+        return Err(TuasLibError::GenericStringError(
+            "pebble emissivity must be more than 0 and at most 1".to_string()));
+    }
+
+    if diameter <= 0.0 || temperature <= 0.0 || k_s <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "pebble diameter, bed temperature and solid \
+            thermal conductivity must be more than zero".to_string()));
+    }
+
+    let radiative_conductivity_scale: f64 =
+        4.0 * STEFAN_BOLTZMANN_CONSTANT * temperature.powi(3) * diameter;
+
+    // Lambda = k_s/(4 sigma T^3 d)
+    let lambda: f64 = k_s/radiative_conductivity_scale;
+
+    let emissivity_term: f64 = 2.0/emissivity - 1.0;
+    let solid_fraction_sqrt: f64 = (1.0 - epsilon).sqrt();
+
+    let radiative_conductivity_ratio: f64 =
+        (1.0 - solid_fraction_sqrt) * epsilon
+        + solid_fraction_sqrt/emissivity_term * (b + 1.0)/b
+        /(1.0 + 1.0/(emissivity_term * lambda));
+
+    Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+            radiative_conductivity_scale * radiative_conductivity_ratio))
+}
+
+/// effective thermal conductivity of a pebble bed from the
+/// Zehner-Bauer-Schlunder model, which is the Zehner-Schlunder
+/// conduction part plus the Breitbach-Barthels radiation part
+pub fn zbs_effective_thermal_conductivity(
+    solid_thermal_conductivity: ThermalConductivity,
+    fluid_thermal_conductivity: ThermalConductivity,
+    porosity: Ratio,
+    pebble_emissivity: Ratio,
+    pebble_diameter: Length,
+    bed_temperature: ThermodynamicTemperature) -> Result<ThermalConductivity, TuasLibError> {
+
+    let conduction_part: ThermalConductivity =
+        zehner_schlunder_effective_thermal_conductivity(
+            solid_thermal_conductivity,
+            fluid_thermal_conductivity,
+            porosity)?;
+
+    let radiation_part: ThermalConductivity =
+        breitbach_barthels_radiative_thermal_conductivity(
+            solid_thermal_conductivity,
+            porosity,
+            pebble_emissivity,
+            pebble_diameter,
+            bed_temperature)?;
+
+    Ok(conduction_part + radiation_part)
+}
+
+fn check_positive_conductivities(
+    solid_thermal_conductivity: ThermalConductivity,
+    fluid_thermal_conductivity: ThermalConductivity) -> Result<(), TuasLibError> {
+
+    if solid_thermal_conductivity.value <= 0.0 ||
+        fluid_thermal_conductivity.value <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "solid and fluid thermal conductivities must be more than zero"
+            .to_string()));
+    }
+
+    Ok(())
+}
+
+/// unit tests for the pebble bed effective conductivity
+pub mod unit_test_pebble_bed_conductivity;
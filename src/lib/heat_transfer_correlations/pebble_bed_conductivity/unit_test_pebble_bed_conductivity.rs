@@ -0,0 +1,127 @@
+/// for a random packing of spheres, porosity 0.39
+///
+/// B = 1.25 (0.61/0.39)^(10/9) = 2.05476
+///
+/// for graphite pebbles (k_s = 26 W/(m K)) in helium
+/// (k_f = 0.3 W/(m K)), the Zehner-Schlunder model gives
+/// k_eff = 2.6413 W/(m K)
+///
+/// this must lie between the series and parallel bounds
+/// (the Wiener bounds)
+///
+/// also, if the solid and fluid have the same conductivity,
+/// the bed conductivity is just that conductivity
+#[test]
+pub fn zehner_schlunder_hand_calc_and_bounds() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use super::{zehner_schlunder_deformation_parameter,
+        zehner_schlunder_effective_thermal_conductivity};
+
+    let conductivity = |value: f64|
+        ThermalConductivity::new::<watt_per_meter_kelvin>(value);
+    let porosity = Ratio::new::<ratio>(0.39);
+
+    approx::assert_relative_eq!(
+        zehner_schlunder_deformation_parameter(porosity)?,
+        2.05476, max_relative = 1e-5);
+
+    let graphite_in_helium = zehner_schlunder_effective_thermal_conductivity(
+        conductivity(26.0), conductivity(0.3), porosity)?
+        .get::<watt_per_meter_kelvin>();
+
+    approx::assert_relative_eq!(graphite_in_helium, 2.6413,
+        max_relative = 1e-4);
+
+    let series_bound: f64 = 1.0/(0.39/0.3 + 0.61/26.0);
+    let parallel_bound: f64 = 0.39 * 0.3 + 0.61 * 26.0;
+
+    assert!(graphite_in_helium > series_bound);
+    assert!(graphite_in_helium < parallel_bound);
+
+    approx::assert_relative_eq!(
+        zehner_schlunder_effective_thermal_conductivity(
+            conductivity(0.5), conductivity(0.5), porosity)?
+        .get::<watt_per_meter_kelvin>(),
+        0.5, max_relative = 1e-9);
+
+    // kappa = B is a removable singularity, so the bed conductivity
+    // there should be in between that of its neighbours
+    let b: f64 = zehner_schlunder_deformation_parameter(porosity)?;
+    let bed_conductivity_at = |kappa: f64| {
+        zehner_schlunder_effective_thermal_conductivity(
+            conductivity(kappa), conductivity(1.0), porosity)
+            .unwrap().get::<watt_per_meter_kelvin>()
+    };
+
+    let at_singularity = bed_conductivity_at(b);
+    assert!(at_singularity.is_finite());
+    assert!(at_singularity > bed_conductivity_at(0.999 * b));
+    assert!(at_singularity < bed_conductivity_at(1.001 * b));
+
+    assert!(zehner_schlunder_effective_thermal_conductivity(
+            conductivity(26.0), conductivity(0.3),
+            Ratio::new::<ratio>(1.0)).is_err());
+    assert!(zehner_schlunder_effective_thermal_conductivity(
+            conductivity(26.0), conductivity(0.0), porosity).is_err());
+
+    Ok(())
+}
+
+/// for the same graphite pebbles, 6 cm diameter with emissivity 0.8
+/// at 1200 K, the Breitbach-Barthels radiative conductivity is
+/// 13.364 W/(m K), so the ZBS bed conductivity is
+/// 2.6413 + 13.364 = 16.006 W/(m K)
+///
+/// radiation dominates at these temperatures, and becomes
+/// less important at lower temperatures
+#[test]
+pub fn zbs_radiation_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::length::centimeter;
+    use uom::si::ratio::ratio;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use super::{breitbach_barthels_radiative_thermal_conductivity,
+        zbs_effective_thermal_conductivity};
+
+    let solid_conductivity = ThermalConductivity::new::<watt_per_meter_kelvin>(26.0);
+    let fluid_conductivity = ThermalConductivity::new::<watt_per_meter_kelvin>(0.3);
+    let porosity = Ratio::new::<ratio>(0.39);
+    let emissivity = Ratio::new::<ratio>(0.8);
+    let pebble_diameter = Length::new::<centimeter>(6.0);
+    let bed_temperature = ThermodynamicTemperature::new::<kelvin>(1200.0);
+
+    let radiative_conductivity = breitbach_barthels_radiative_thermal_conductivity(
+        solid_conductivity, porosity, emissivity,
+        pebble_diameter, bed_temperature)?;
+
+    approx::assert_relative_eq!(
+        radiative_conductivity.get::<watt_per_meter_kelvin>(),
+        13.364, max_relative = 1e-4);
+
+    let bed_conductivity = zbs_effective_thermal_conductivity(
+        solid_conductivity, fluid_conductivity, porosity, emissivity,
+        pebble_diameter, bed_temperature)?;
+
+    approx::assert_relative_eq!(
+        bed_conductivity.get::<watt_per_meter_kelvin>(),
+        16.006, max_relative = 1e-4);
+
+    let cold_radiative_conductivity = breitbach_barthels_radiative_thermal_conductivity(
+        solid_conductivity, porosity, emissivity, pebble_diameter,
+        ThermodynamicTemperature::new::<kelvin>(400.0))?;
+
+    assert!(cold_radiative_conductivity < 0.1 * radiative_conductivity);
+
+    assert!(breitbach_barthels_radiative_thermal_conductivity(
+            solid_conductivity, porosity, Ratio::new::<ratio>(0.0),
+            pebble_diameter, bed_temperature).is_err());
+
+    Ok(())
+}
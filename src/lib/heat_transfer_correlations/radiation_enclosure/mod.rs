@@ -0,0 +1,379 @@
+//! grey diffuse radiation exchange between N surfaces in an
+//! enclosure
+//!
+//! each surface i has an area A_i, an emissivity epsilon_i and
+//! view factors F_(i-j) to the other surfaces. The radiosity
+//! J_i (radiation leaving surface i) follows:
+//!
+//! J_i - (1 - epsilon_i) sum_j F_(i-j) J_j = epsilon_i E_b,i
+//!
+//! where E_b,i = sigma T_i^4 is the blackbody emissive power.
+//! The net radiation leaving surface i is then:
+//!
+//! Q_i = A_i ( J_i - sum_j F_(i-j) J_j )
+//!
+//! Since this system is linear in E_b, we can solve it once
+//! for each surface with unit emissive power, and get the exchange
+//! areas (A_i script F_(i-j)) between every pair of surfaces:
+//!
+//! Q_i = sum_j A_i script F_(i-j) sigma (T_i^4 - T_j^4)
+//!
+//! These only depend on geometry and emissivity, so they are
+//! calculated once when the enclosure is constructed.
+//!
+//! For the implicit solvers, the exchange between each pair of
+//! surfaces is linearised into a thermal conductance using the
+//! temperatures of the current timestep (as with SimpleRadiation)
+//!
+//! H_(i-j) = sigma A_i script F_(i-j) (T_i^2 + T_j^2)(T_i + T_j)
+//!
+//! and each pair of HeatTransferEntity nodes is linked with this
+//! conductance. This is done every timestep, before advancing
+//! the timestep.
+//!
+//! ```rust
+//! use uom::si::f64::*;
+//! use uom::si::area::square_meter;
+//! use uom::si::ratio::ratio;
+//! use uom::si::power::watt;
+//! use uom::si::thermodynamic_temperature::kelvin;
+//! use tuas_boussinesq_solver::heat_transfer_correlations::
+//! radiation_enclosure::GreyDiffuseRadiationEnclosure;
+//!
+//! // two large parallel plates, each seeing only the other
+//! let area = Area::new::<square_meter>(1.0);
+//! let one = Ratio::new::<ratio>(1.0);
+//! let zero = Ratio::new::<ratio>(0.0);
+//!
+//! let enclosure = GreyDiffuseRadiationEnclosure::new(
+//!     vec![area, area],
+//!     vec![Ratio::new::<ratio>(0.5), Ratio::new::<ratio>(0.5)],
+//!     vec![vec![zero, one], vec![one, zero]]).unwrap();
+//!
+//! let net_heat_rates = enclosure.get_net_radiative_heat_rates(&[
+//!     ThermodynamicTemperature::new::<kelvin>(1000.0),
+//!     ThermodynamicTemperature::new::<kelvin>(500.0)]).unwrap();
+//!
+//! // Q = A sigma (T_1^4 - T_2^4)/(1/epsilon_1 + 1/epsilon_2 - 1)
+//! approx::assert_relative_eq!(net_heat_rates[0].get::<watt>(),
+//!     5.670374419e-8 * (1.0e12 - 6.25e10)/3.0, max_relative = 1e-9);
+//! ```
+use ndarray::*;
+use ndarray_linalg::Solve;
+use uom::si::area::square_meter;
+use uom::si::f64::*;
+use uom::si::power::watt;
+use uom::si::ratio::ratio;
+use uom::si::thermal_conductance::watt_per_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::STEFAN_BOLTZMANN_CONSTANT;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::heat_transfer_correlations::view_factors::view_factor_algebra::check_view_factor_matrix;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::pre_built_components::heat_transfer_entities::preprocessing::link_heat_transfer_entity;
+use crate::tuas_lib_error::TuasLibError;
+
+/// relative tolerance for the summation rule and reciprocity
+/// of the view factors supplied to the enclosure
+pub const VIEW_FACTOR_TOLERANCE: f64 = 1e-3;
+
+/// enclosure of N grey diffuse surfaces exchanging radiation
+/// with each other
+///
+/// the surfaces should form a closed enclosure, ie. the view
+/// factors from each surface add up to one. For openings,
+/// add a surface representing the opening (emissivity of one)
+/// and link it to a constant temperature boundary condition
+#[derive(Debug,Clone,PartialEq)]
+pub struct GreyDiffuseRadiationEnclosure {
+    /// surface areas A_i
+    surface_areas: Vec<Area>,
+    /// emissivities epsilon_i
+    emissivities: Vec<Ratio>,
+    /// view factors, view_factors[i][j] is F_(i-j)
+    view_factors: Vec<Vec<Ratio>>,
+    /// exchange areas, exchange_areas[i][j] is A_i script F_(i-j)
+    exchange_areas: Vec<Vec<Area>>,
+}
+
+impl GreyDiffuseRadiationEnclosure {
+
+    /// creates a new enclosure from the areas, emissivities and view
+    /// factors of its surfaces
+    ///
+    /// view_factors[i][j] is the view factor from surface i to surface j
+    ///
+    /// the view factors must obey the summation rule and reciprocity
+    /// to within VIEW_FACTOR_TOLERANCE, and emissivities must be more
+    /// than zero and at most one
+    pub fn new(surface_areas: Vec<Area>,
+        emissivities: Vec<Ratio>,
+        view_factors: Vec<Vec<Ratio>>) -> Result<Self, TuasLibError> {
+
+        let number_of_surfaces = surface_areas.len();
+
+        if number_of_surfaces < 2 {
+            return Err(TuasLibError::GenericStringError(
+                "radiation enclosures need at least two surfaces".to_string()));
+        }
+
+        if emissivities.len() != number_of_surfaces {
+            return Err(TuasLibError::GenericStringError(
+                "each surface in the enclosure needs one emissivity".to_string()));
+        }
+
+        if emissivities.iter().any(|emissivity| {
+            let emissivity_value = emissivity.get::<ratio>();
+            emissivity_value <= 0.0 || emissivity_value > 1.0
+        }) {
+            return Err(TuasLibError::GenericStringError(
+                "emissivity must be more than 0 and at most 1".to_string()));
+        }
+
+        check_view_factor_matrix(&surface_areas, &view_factors,
+            VIEW_FACTOR_TOLERANCE)?;
+
+        let exchange_areas = calculate_exchange_areas(
+            &surface_areas, &emissivities, &view_factors)?;
+
+        Ok(Self {
+            surface_areas,
+            emissivities,
+            view_factors,
+            exchange_areas,
+        })
+    }
+
+    /// number of surfaces in the enclosure
+    pub fn number_of_surfaces(&self) -> usize {
+        self.surface_areas.len()
+    }
+
+    /// surface areas of the enclosure
+    pub fn get_surface_areas(&self) -> Vec<Area> {
+        self.surface_areas.clone()
+    }
+
+    /// emissivities of the enclosure surfaces
+    pub fn get_emissivities(&self) -> Vec<Ratio> {
+        self.emissivities.clone()
+    }
+
+    /// view factors of the enclosure, view_factors[i][j] is F_(i-j)
+    pub fn get_view_factors(&self) -> Vec<Vec<Ratio>> {
+        self.view_factors.clone()
+    }
+
+    /// exchange areas A_i script F_(i-j) between each pair of
+    /// surfaces
+    ///
+    /// these are symmetric, and for black surfaces, they reduce to
+    /// A_i F_(i-j)
+    pub fn get_exchange_areas(&self) -> Vec<Vec<Area>> {
+        self.exchange_areas.clone()
+    }
+
+    /// net radiative heat rate leaving each surface, given the
+    /// surface temperatures (not linearised)
+    ///
+    /// Q_i = sum_j A_i script F_(i-j) sigma (T_i^4 - T_j^4)
+    ///
+    /// the heat rates of all surfaces add up to zero
+    pub fn get_net_radiative_heat_rates(&self,
+        surface_temperatures: &[ThermodynamicTemperature])
+        -> Result<Vec<Power>, TuasLibError> {
+
+        self.check_number_of_temperatures(surface_temperatures)?;
+
+        let emissive_powers: Vec<f64> = surface_temperatures.iter()
+            .map(|temperature| STEFAN_BOLTZMANN_CONSTANT
+                * temperature.get::<kelvin>().powi(4))
+            .collect();
+
+        let net_heat_rates: Vec<Power> = self.exchange_areas.iter()
+            .enumerate()
+            .map(|(i, exchange_area_row)| {
+                let net_heat_rate_value: f64 = exchange_area_row.iter()
+                    .zip(emissive_powers.iter())
+                    .map(|(exchange_area, emissive_power_j)| {
+                        exchange_area.get::<square_meter>()
+                            * (emissive_powers[i] - emissive_power_j)
+                    })
+                    .sum();
+                Power::new::<watt>(net_heat_rate_value)
+            })
+            .collect();
+
+        Ok(net_heat_rates)
+    }
+
+    /// linearised radiation conductance between surfaces i and j
+    ///
+    /// H_(i-j) = sigma A_i script F_(i-j) (T_i^2 + T_j^2)(T_i + T_j)
+    ///
+    /// so that H_(i-j) (T_i - T_j) is the net radiation from
+    /// surface i to surface j
+    pub fn get_linearised_exchange_conductance(&self,
+        surface_i: usize,
+        surface_j: usize,
+        temperature_i: ThermodynamicTemperature,
+        temperature_j: ThermodynamicTemperature)
+        -> Result<ThermalConductance, TuasLibError> {
+
+        let number_of_surfaces = self.number_of_surfaces();
+
+        if surface_i >= number_of_surfaces || surface_j >= number_of_surfaces {
+            return Err(TuasLibError::GenericStringError(
+                "surface index is outside the enclosure".to_string()));
+        }
+
+        let t_i = temperature_i.get::<kelvin>();
+        let t_j = temperature_j.get::<kelvin>();
+
+        let conductance_value: f64 = STEFAN_BOLTZMANN_CONSTANT
+            * self.exchange_areas[surface_i][surface_j].get::<square_meter>()
+            * (t_i * t_i + t_j * t_j) * (t_i + t_j);
+
+        Ok(ThermalConductance::new::<watt_per_kelvin>(conductance_value))
+    }
+
+    /// links the HeatTransferEntity of each surface to every other
+    /// surface with the linearised radiation conductance, based on
+    /// the current temperatures of each entity
+    ///
+    /// entities[i] represents surface i of the enclosure. Do this
+    /// every timestep before advancing the timestep, as with other
+    /// links between heat transfer entities
+    ///
+    /// pairs of boundary conditions are not linked, since they
+    /// cannot exchange heat anyway
+    pub fn link_heat_transfer_entities(&self,
+        entities: &mut [&mut HeatTransferEntity]) -> Result<(), TuasLibError> {
+
+        let number_of_surfaces = self.number_of_surfaces();
+
+        if entities.len() != number_of_surfaces {
+            return Err(TuasLibError::GenericStringError(
+                "each surface in the enclosure needs one heat transfer entity"
+                .to_string()));
+        }
+
+        let mut surface_temperatures: Vec<ThermodynamicTemperature> =
+            Vec::with_capacity(number_of_surfaces);
+
+        for entity in entities.iter_mut() {
+            surface_temperatures.push(entity.try_get_bulk_temperature()?);
+        }
+
+        for i in 0..number_of_surfaces {
+            // split so that we can borrow entity i and entity j
+            // mutably at the same time
+            let (entities_up_to_i, entities_after_i) = entities.split_at_mut(i+1);
+            let entity_i: &mut HeatTransferEntity = entities_up_to_i[i];
+
+            for (offset, entity_j) in entities_after_i.iter_mut().enumerate() {
+
+                let j = i + 1 + offset;
+
+                let both_boundary_conditions: bool =
+                    matches!(entity_i, HeatTransferEntity::BoundaryConditions(_))
+                    && matches!(entity_j, HeatTransferEntity::BoundaryConditions(_));
+
+                if both_boundary_conditions ||
+                    self.exchange_areas[i][j].value <= 0.0 {
+                    continue;
+                }
+
+                let radiation_conductance = self.get_linearised_exchange_conductance(
+                    i, j, surface_temperatures[i], surface_temperatures[j])?;
+
+                link_heat_transfer_entity(
+                    entity_i,
+                    entity_j,
+                    HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                        radiation_conductance))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_number_of_temperatures(&self,
+        surface_temperatures: &[ThermodynamicTemperature]) -> Result<(), TuasLibError> {
+
+        if surface_temperatures.len() != self.number_of_surfaces() {
+            return Err(TuasLibError::GenericStringError(
+                "each surface in the enclosure needs one temperature".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// solves the radiosity equations once for each surface with unit
+/// emissive power (and zero for the others) to get the exchange
+/// areas A_i script F_(i-k)
+///
+/// the coefficient matrix is
+///
+/// M_(i,j) = delta_(i,j) - (1 - epsilon_i) F_(i-j)
+///
+/// for unit emissive power at surface k, M J = epsilon_k e_k,
+/// and the net heat rate leaving surface i (i not k) is
+///
+/// Q_i = A_i ( J_i - sum_j F_(i-j) J_j ) = - A_i script F_(i-k)
+fn calculate_exchange_areas(surface_areas: &[Area],
+    emissivities: &[Ratio],
+    view_factors: &[Vec<Ratio>]) -> Result<Vec<Vec<Area>>, TuasLibError> {
+
+    let number_of_surfaces = surface_areas.len();
+
+    let view_factor_matrix: Array2<f64> = Array2::from_shape_fn(
+        (number_of_surfaces, number_of_surfaces),
+        |(i, j)| view_factors[i][j].get::<ratio>());
+
+    let coefficient_matrix: Array2<f64> = Array2::from_shape_fn(
+        (number_of_surfaces, number_of_surfaces),
+        |(i, j)| {
+            let kronecker_delta = if i == j { 1.0 } else { 0.0 };
+            kronecker_delta
+                - (1.0 - emissivities[i].get::<ratio>()) * view_factor_matrix[[i, j]]
+        });
+
+    let mut exchange_area_values: Array2<f64> =
+        Array2::zeros((number_of_surfaces, number_of_surfaces));
+
+    for k in 0..number_of_surfaces {
+
+        let mut emission_vector: Array1<f64> = Array1::zeros(number_of_surfaces);
+        emission_vector[k] = emissivities[k].get::<ratio>();
+
+        let radiosity: Array1<f64> = coefficient_matrix.solve(&emission_vector)?;
+        let irradiation: Array1<f64> = Array1::from_shape_fn(number_of_surfaces,
+            |i| (0..number_of_surfaces)
+                .map(|j| view_factor_matrix[[i, j]] * radiosity[j])
+                .sum());
+
+        for i in 0..number_of_surfaces {
+            if i != k {
+                exchange_area_values[[i, k]] = -surface_areas[i].get::<square_meter>()
+                    * (radiosity[i] - irradiation[i]);
+            }
+        }
+    }
+
+    // exchange areas are symmetric in theory, average them so that
+    // round off does not create or destroy energy
+    let exchange_areas: Vec<Vec<Area>> = (0..number_of_surfaces)
+        .map(|i| (0..number_of_surfaces)
+            .map(|j| Area::new::<square_meter>(
+                0.5 * (exchange_area_values[[i, j]] + exchange_area_values[[j, i]])))
+            .collect())
+        .collect();
+
+    Ok(exchange_areas)
+}
+
+/// unit tests for radiation enclosures
+pub mod unit_test_radiation_enclosure;
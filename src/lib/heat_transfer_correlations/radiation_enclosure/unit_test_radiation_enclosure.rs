@@ -0,0 +1,302 @@
+/// for two long concentric cylinders, the net radiation from the
+/// inner cylinder (1) to the outer cylinder (2) is
+///
+/// Q = A_1 sigma (T_1^4 - T_2^4) / (1/epsilon_1 + (1 - epsilon_2)/epsilon_2 (r_1/r_2))
+///
+/// (Bergman, Table 13.3)
+///
+/// with F_(1-2) = 1, F_(2-1) = r_1/r_2, F_(2-2) = 1 - r_1/r_2
+#[test]
+pub fn long_concentric_cylinders_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::area::square_meter;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::temperature_interval::kelvin as interval_kelvin;
+    use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::STEFAN_BOLTZMANN_CONSTANT;
+    use super::GreyDiffuseRadiationEnclosure;
+
+    let inner_radius = Length::new::<meter>(0.02);
+    let outer_radius = Length::new::<meter>(0.05);
+    let cylinder_length = Length::new::<meter>(1.0);
+    let inner_area: Area = 2.0 * PI * inner_radius * cylinder_length;
+    let outer_area: Area = 2.0 * PI * outer_radius * cylinder_length;
+
+    let inner_emissivity = Ratio::new::<ratio>(0.6);
+    let outer_emissivity = Ratio::new::<ratio>(0.3);
+    let radius_ratio: Ratio = inner_radius/outer_radius;
+
+    let zero = Ratio::new::<ratio>(0.0);
+    let one = Ratio::new::<ratio>(1.0);
+
+    let enclosure = GreyDiffuseRadiationEnclosure::new(
+        vec![inner_area, outer_area],
+        vec![inner_emissivity, outer_emissivity],
+        vec![vec![zero, one], vec![radius_ratio, one - radius_ratio]])?;
+
+    let inner_temperature = ThermodynamicTemperature::new::<kelvin>(800.0);
+    let outer_temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+
+    let net_heat_rates = enclosure.get_net_radiative_heat_rates(
+        &[inner_temperature, outer_temperature])?;
+
+    let expected_heat_rate: f64 = inner_area.get::<square_meter>()
+        * STEFAN_BOLTZMANN_CONSTANT * (800.0_f64.powi(4) - 400.0_f64.powi(4))
+        / (1.0/0.6 + (1.0 - 0.3)/0.3 * 0.4);
+
+    approx::assert_relative_eq!(
+        net_heat_rates[0].get::<watt>(),
+        expected_heat_rate,
+        max_relative = 1e-9);
+
+    approx::assert_relative_eq!(
+        net_heat_rates[1].get::<watt>(),
+        -expected_heat_rate,
+        max_relative = 1e-9);
+
+    // the linearised conductance gives the same heat rate at
+    // the same temperatures
+    let radiation_conductance = enclosure.get_linearised_exchange_conductance(
+        0, 1, inner_temperature, outer_temperature)?;
+
+    approx::assert_relative_eq!(
+        (radiation_conductance * TemperatureInterval::new::<interval_kelvin>(400.0))
+            .get::<watt>(),
+        expected_heat_rate,
+        max_relative = 1e-9);
+
+    Ok(())
+}
+
+/// for black surfaces, the exchange areas are just A_i F_(i-j)
+///
+/// for grey surfaces, net heat rates in a closed enclosure
+/// should add up to zero, and a surface at the same temperature as
+/// all the others should have no net heat rate
+///
+/// the enclosure is a cylinder with both ends closed by disks
+#[test]
+pub fn closed_cylinder_energy_conservation() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::area::square_meter;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::heat_transfer_correlations::view_factors::coaxial_disks::coaxial_parallel_disks_view_factor;
+    use crate::heat_transfer_correlations::view_factors::view_factor_algebra::*;
+    use super::GreyDiffuseRadiationEnclosure;
+
+    let radius = Length::new::<meter>(0.3);
+    let height = Length::new::<meter>(0.8);
+
+    let disk_area: Area = PI * radius * radius;
+    let cylinder_area: Area = 2.0 * PI * radius * height;
+
+    let disk_to_disk = coaxial_parallel_disks_view_factor(radius, radius, height)?;
+    let disk_to_cylinder = summation_rule_remaining_view_factor(&[disk_to_disk])?;
+    let cylinder_to_disk = reciprocal_view_factor(
+        disk_area, cylinder_area, disk_to_cylinder)?;
+    let cylinder_to_cylinder = summation_rule_remaining_view_factor(
+        &[cylinder_to_disk, cylinder_to_disk])?;
+
+    let zero = Ratio::new::<ratio>(0.0);
+    let surface_areas = vec![disk_area, disk_area, cylinder_area];
+    let view_factors = vec![
+        vec![zero, disk_to_disk, disk_to_cylinder],
+        vec![disk_to_disk, zero, disk_to_cylinder],
+        vec![cylinder_to_disk, cylinder_to_disk, cylinder_to_cylinder],
+    ];
+
+    let black_enclosure = GreyDiffuseRadiationEnclosure::new(
+        surface_areas.clone(),
+        vec![Ratio::new::<ratio>(1.0); 3],
+        view_factors.clone())?;
+
+    let black_exchange_areas = black_enclosure.get_exchange_areas();
+
+    for i in 0..3 {
+        for j in 0..3 {
+            if i != j {
+                approx::assert_relative_eq!(
+                    black_exchange_areas[i][j].get::<square_meter>(),
+                    (surface_areas[i] * view_factors[i][j]).get::<square_meter>(),
+                    max_relative = 1e-9);
+            }
+        }
+    }
+
+    let grey_enclosure = GreyDiffuseRadiationEnclosure::new(
+        surface_areas,
+        vec![Ratio::new::<ratio>(0.9),
+            Ratio::new::<ratio>(0.2),
+            Ratio::new::<ratio>(0.5)],
+        view_factors)?;
+
+    let net_heat_rates = grey_enclosure.get_net_radiative_heat_rates(&[
+        ThermodynamicTemperature::new::<kelvin>(900.0),
+        ThermodynamicTemperature::new::<kelvin>(300.0),
+        ThermodynamicTemperature::new::<kelvin>(500.0)])?;
+
+    let sum_of_net_heat_rates: f64 = net_heat_rates.iter()
+        .map(|heat_rate| heat_rate.get::<watt>())
+        .sum();
+
+    assert!(net_heat_rates[0].get::<watt>() > 0.0);
+    assert!(net_heat_rates[1].get::<watt>() < 0.0);
+    approx::assert_abs_diff_eq!(sum_of_net_heat_rates, 0.0, epsilon = 1e-6);
+
+    let isothermal_heat_rates = grey_enclosure.get_net_radiative_heat_rates(
+        &[ThermodynamicTemperature::new::<kelvin>(600.0); 3])?;
+
+    for heat_rate in isothermal_heat_rates.iter() {
+        approx::assert_abs_diff_eq!(heat_rate.get::<watt>(), 0.0, epsilon = 1e-9);
+    }
+
+    Ok(())
+}
+
+/// bad inputs should be rejected when constructing the enclosure
+#[test]
+pub fn enclosure_input_checks(){
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::area::square_meter;
+    use super::GreyDiffuseRadiationEnclosure;
+
+    let area = Area::new::<square_meter>(1.0);
+    let zero = Ratio::new::<ratio>(0.0);
+    let one = Ratio::new::<ratio>(1.0);
+    let half = Ratio::new::<ratio>(0.5);
+
+    // zero emissivity
+    assert!(GreyDiffuseRadiationEnclosure::new(
+            vec![area, area], vec![zero, half],
+            vec![vec![zero, one], vec![one, zero]]).is_err());
+
+    // view factors not adding up to one
+    assert!(GreyDiffuseRadiationEnclosure::new(
+            vec![area, area], vec![half, half],
+            vec![vec![zero, half], vec![one, zero]]).is_err());
+
+    // wrong number of emissivities
+    assert!(GreyDiffuseRadiationEnclosure::new(
+            vec![area, area], vec![half],
+            vec![vec![zero, one], vec![one, zero]]).is_err());
+
+    // only one surface
+    assert!(GreyDiffuseRadiationEnclosure::new(
+            vec![area], vec![half], vec![vec![one]]).is_err());
+}
+
+/// two steel balls and the surroundings (at constant temperature)
+/// form an enclosure, the balls are linked to each other and
+/// to the surroundings every timestep
+///
+/// right after linking, the power into each ball should be the
+/// negative of its net radiative heat rate, as the linearised
+/// conductances are exact at the current temperatures
+///
+/// over time, the hot ball cools down and the cold ball heats up
+#[test]
+pub fn steel_balls_in_enclosure_transient() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::time::second;
+    use uom::si::pressure::atmosphere;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boundary_conditions::BCType;
+    use crate::boussinesq_thermophysical_properties::SolidMaterial;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+    use crate::single_control_vol::SingleCVNode;
+    use super::GreyDiffuseRadiationEnclosure;
+
+    let ball_diameter = Length::new::<meter>(0.05);
+    let ball_area: Area = PI * ball_diameter * ball_diameter;
+    let surroundings_area: Area = 100.0 * ball_area;
+    let pressure = Pressure::new::<atmosphere>(1.0);
+
+    let hot_initial_temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+    let cold_initial_temperature = ThermodynamicTemperature::new::<kelvin>(350.0);
+    let surroundings_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+
+    // the balls see a little of each other, and the rest is
+    // the surroundings
+    let ball_to_ball = Ratio::new::<ratio>(0.05);
+    let ball_to_surroundings = Ratio::new::<ratio>(0.95);
+    let surroundings_to_ball: Ratio = ball_to_surroundings * ball_area/surroundings_area;
+    let surroundings_to_surroundings = Ratio::new::<ratio>(1.0)
+        - 2.0 * surroundings_to_ball;
+    let zero = Ratio::new::<ratio>(0.0);
+
+    let enclosure = GreyDiffuseRadiationEnclosure::new(
+        vec![ball_area, ball_area, surroundings_area],
+        vec![Ratio::new::<ratio>(0.8),
+            Ratio::new::<ratio>(0.8),
+            Ratio::new::<ratio>(1.0)],
+        vec![vec![zero, ball_to_ball, ball_to_surroundings],
+            vec![ball_to_ball, zero, ball_to_surroundings],
+            vec![surroundings_to_ball, surroundings_to_ball,
+                surroundings_to_surroundings]])?;
+
+    let mut hot_ball: HeatTransferEntity = SingleCVNode::new_sphere(
+        ball_diameter, SolidMaterial::SteelSS304L.into(),
+        hot_initial_temperature, pressure)?.into();
+    let mut cold_ball: HeatTransferEntity = SingleCVNode::new_sphere(
+        ball_diameter, SolidMaterial::SteelSS304L.into(),
+        cold_initial_temperature, pressure)?.into();
+    let mut surroundings: HeatTransferEntity = BCType::new_const_temperature(
+        surroundings_temperature).into();
+
+    let timestep = Time::new::<second>(0.5);
+
+    for timestep_number in 0..20 {
+
+        enclosure.link_heat_transfer_entities(
+            &mut [&mut hot_ball, &mut cold_ball, &mut surroundings])?;
+
+        if timestep_number == 0 {
+            let net_heat_rates = enclosure.get_net_radiative_heat_rates(&[
+                hot_initial_temperature,
+                cold_initial_temperature,
+                surroundings_temperature])?;
+
+            let hot_ball_node: SingleCVNode = hot_ball.clone().try_into()?;
+            let cold_ball_node: SingleCVNode = cold_ball.clone().try_into()?;
+
+            let hot_ball_power: Power = hot_ball_node
+                .rate_enthalpy_change_vector.iter().copied().sum();
+            let cold_ball_power: Power = cold_ball_node
+                .rate_enthalpy_change_vector.iter().copied().sum();
+
+            approx::assert_relative_eq!(hot_ball_power.get::<watt>(),
+                -net_heat_rates[0].get::<watt>(), max_relative = 1e-9);
+            approx::assert_relative_eq!(cold_ball_power.get::<watt>(),
+                -net_heat_rates[1].get::<watt>(), max_relative = 1e-9);
+        }
+
+        hot_ball.advance_timestep_mut_self(timestep)?;
+        cold_ball.advance_timestep_mut_self(timestep)?;
+    }
+
+    let hot_ball_temperature = hot_ball.try_get_bulk_temperature()?;
+    let cold_ball_temperature = cold_ball.try_get_bulk_temperature()?;
+
+    assert!(hot_ball_temperature < hot_initial_temperature);
+    assert!(cold_ball_temperature > cold_initial_temperature);
+
+    Ok(())
+}
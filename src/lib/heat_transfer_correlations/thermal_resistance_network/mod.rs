@@ -0,0 +1,453 @@
+//! builder for thermal resistance networks with any number of
+//! layers
+//!
+//! the functions in thermal_resistance.rs handle specific
+//! combinations of convection and conduction resistances. Here,
+//! resistances are stacked in series one by one, and parallel
+//! branches (each a series network of its own) can be added as a
+//! single element
+//!
+//! for example, heat loss from hot fluid in an insulated pipe with
+//! a gap between pipe and insulation, and radiation in parallel with
+//! natural convection on the outside:
+//!
+//! ```rust
+//! use uom::si::f64::*;
+//! use uom::si::length::meter;
+//! use uom::si::area::square_meter;
+//! use uom::si::ratio::ratio;
+//! use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+//! use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+//! use uom::si::thermodynamic_temperature::degree_celsius;
+//! use tuas_boussinesq_solver::boussinesq_thermophysical_properties::SolidMaterial;
+//! use tuas_boussinesq_solver::heat_transfer_correlations::
+//! thermal_resistance_network::ThermalResistanceNetwork;
+//!
+//! let length = Length::new::<meter>(1.0);
+//! let outer_area = Area::new::<square_meter>(0.3);
+//!
+//! let outside_surface = vec![
+//!     ThermalResistanceNetwork::new()
+//!         .add_convection(HeatTransfer::new::<watt_per_square_meter_kelvin>(5.0),
+//!         outer_area),
+//!     ThermalResistanceNetwork::new()
+//!         .add_radiation(Ratio::new::<ratio>(0.9), outer_area),
+//! ];
+//!
+//! let pipe_heat_loss = ThermalResistanceNetwork::new()
+//!     .add_convection(HeatTransfer::new::<watt_per_square_meter_kelvin>(1000.0),
+//!         Area::new::<square_meter>(0.1))
+//!     .add_cylindrical_conduction(Length::new::<meter>(0.03),
+//!         Length::new::<meter>(0.04), length, SolidMaterial::SteelSS304L.into())
+//!     .add_contact(HeatTransfer::new::<watt_per_square_meter_kelvin>(500.0),
+//!         Area::new::<square_meter>(0.126))
+//!     .add_cylindrical_conduction(Length::new::<meter>(0.04),
+//!         Length::new::<meter>(0.1), length,
+//!         ThermalConductivity::new::<watt_per_meter_kelvin>(0.05).into())
+//!     .add_parallel(outside_surface)
+//!     .try_solve(ThermodynamicTemperature::new::<degree_celsius>(200.0),
+//!         ThermodynamicTemperature::new::<degree_celsius>(25.0))
+//!     .unwrap();
+//!
+//! // one interface temperature at each end of each element
+//! assert_eq!(pipe_heat_loss.interface_temperatures.len(), 6);
+//! ```
+//!
+//! temperature dependent properties (thermal conductivity and
+//! radiation heat transfer coefficients) are evaluated at the
+//! interface temperatures, and the network is solved iteratively
+//! until the interface temperatures stop changing
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::thermal_conductance::watt_per_kelvin;
+
+use crate::boussinesq_thermophysical_properties::{Material, SolidMaterial};
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::STEFAN_BOLTZMANN_CONSTANT;
+use crate::heat_transfer_correlations::thermal_resistance::subtract_two_thermodynamic_temperatures;
+use crate::tuas_lib_error::TuasLibError;
+
+/// maximum number of iterations for temperature dependent properties
+const MAX_ITERATIONS: usize = 200;
+
+/// convergence tolerance for interface temperatures in kelvin
+const TEMPERATURE_TOLERANCE_KELVIN: f64 = 1e-8;
+
+/// thermal conductivity of a conduction layer
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum LayerThermalConductivity {
+    /// thermal conductivity does not change with temperature
+    Constant(ThermalConductivity),
+    /// thermal conductivity is evaluated at the mean temperature
+    /// of the layer
+    TemperatureDependent(Material),
+}
+
+impl From<ThermalConductivity> for LayerThermalConductivity {
+    fn from(thermal_conductivity: ThermalConductivity) -> Self {
+        LayerThermalConductivity::Constant(thermal_conductivity)
+    }
+}
+
+impl From<SolidMaterial> for LayerThermalConductivity {
+    fn from(solid_material: SolidMaterial) -> Self {
+        LayerThermalConductivity::TemperatureDependent(solid_material.into())
+    }
+}
+
+impl LayerThermalConductivity {
+    fn try_get_thermal_conductivity(&self,
+        mean_temperature: ThermodynamicTemperature)
+        -> Result<ThermalConductivity, TuasLibError> {
+        match self {
+            LayerThermalConductivity::Constant(thermal_conductivity) =>
+                Ok(*thermal_conductivity),
+            LayerThermalConductivity::TemperatureDependent(material) =>
+                material.try_get_thermal_conductivity(mean_temperature),
+        }
+    }
+}
+
+/// a single element in a series thermal resistance network
+#[derive(Debug,Clone,PartialEq)]
+pub enum ThermalResistanceElement {
+    /// convection, R = 1/(hA)
+    Convection(HeatTransfer, Area),
+
+    /// conduction through a flat wall of given thickness and area,
+    /// R = dx/(kA)
+    PlanarConduction(Length, Area, LayerThermalConductivity),
+
+    /// conduction through a cylindrical shell of given inner
+    /// diameter, outer diameter and length,
+    /// R = ln(d_o/d_i)/(2 pi k L)
+    CylindricalConduction(Length, Length, Length, LayerThermalConductivity),
+
+    /// conduction through a spherical shell of given inner and
+    /// outer diameter, R = (1/r_i - 1/r_o)/(4 pi k)
+    SphericalConduction(Length, Length, LayerThermalConductivity),
+
+    /// contact (or gap) conductance over an area, R = 1/(h_c A)
+    Contact(HeatTransfer, Area),
+
+    /// radiation between two grey surfaces with a given effective
+    /// emissivity (including view factors) over an area, linearised
+    /// using the temperatures on either side
+    ///
+    /// h_rad = epsilon sigma (T_1 + T_2)(T_1^2 + T_2^2)
+    Radiation(Ratio, Area),
+
+    /// fixed thermal resistance
+    FixedResistance(ThermalResistance),
+
+    /// parallel branches, each branch is a series network
+    /// between the same two temperatures
+    Parallel(Vec<ThermalResistanceNetwork>),
+}
+
+impl ThermalResistanceElement {
+
+    /// thermal resistance of the element given the temperatures
+    /// on either side of it
+    pub fn try_get_thermal_resistance(&self,
+        temperature_start: ThermodynamicTemperature,
+        temperature_end: ThermodynamicTemperature)
+        -> Result<ThermalResistance, TuasLibError> {
+
+        let mean_temperature = ThermodynamicTemperature::new::<kelvin>(
+            0.5 * (temperature_start.get::<kelvin>()
+                + temperature_end.get::<kelvin>()));
+
+        let resistance: ThermalResistance = match self {
+            ThermalResistanceElement::Convection(heat_transfer_coeff, area) => {
+                check_positive(heat_transfer_coeff.value, "heat transfer coefficient")?;
+                check_positive(area.value, "area")?;
+                1.0/(*heat_transfer_coeff * *area)
+            },
+            ThermalResistanceElement::PlanarConduction(thickness, area, conductivity) => {
+                check_positive(thickness.value, "wall thickness")?;
+                check_positive(area.value, "area")?;
+                let k = conductivity.try_get_thermal_conductivity(mean_temperature)?;
+                *thickness/(k * *area)
+            },
+            ThermalResistanceElement::CylindricalConduction(
+                inner_diameter, outer_diameter, length, conductivity) => {
+                check_positive(inner_diameter.value, "inner diameter")?;
+                check_positive(length.value, "cylinder length")?;
+                check_shell_diameters(*inner_diameter, *outer_diameter)?;
+                let k = conductivity.try_get_thermal_conductivity(mean_temperature)?;
+                let log_diameter_ratio: f64 =
+                    (*outer_diameter / *inner_diameter).get::<ratio>().ln();
+                log_diameter_ratio/(2.0 * PI * k * *length)
+            },
+            ThermalResistanceElement::SphericalConduction(
+                inner_diameter, outer_diameter, conductivity) => {
+                check_positive(inner_diameter.value, "inner diameter")?;
+                check_shell_diameters(*inner_diameter, *outer_diameter)?;
+                let k = conductivity.try_get_thermal_conductivity(mean_temperature)?;
+                (2.0 / *inner_diameter - 2.0 / *outer_diameter)/(4.0 * PI * k)
+            },
+            ThermalResistanceElement::Contact(contact_conductance, area) => {
+                check_positive(contact_conductance.value, "contact conductance")?;
+                check_positive(area.value, "area")?;
+                1.0/(*contact_conductance * *area)
+            },
+            ThermalResistanceElement::Radiation(effective_emissivity, area) => {
+                let emissivity = effective_emissivity.get::<ratio>();
+                if emissivity <= 0.0 || emissivity > 1.0 {
+                    return Err(TuasLibError::GenericStringError(
+                        "effective emissivity must be more than 0 \
+                        and at most 1".to_string()));
+                }
+                check_positive(area.value, "area")?;
+                let t_1 = temperature_start.get::<kelvin>();
+                let t_2 = temperature_end.get::<kelvin>();
+                let h_rad = HeatTransfer::new::<watt_per_square_meter_kelvin>(
+                    emissivity * STEFAN_BOLTZMANN_CONSTANT
+                    * (t_1 + t_2) * (t_1 * t_1 + t_2 * t_2));
+                1.0/(h_rad * *area)
+            },
+            ThermalResistanceElement::FixedResistance(resistance) => {
+                check_positive(resistance.value, "thermal resistance")?;
+                *resistance
+            },
+            ThermalResistanceElement::Parallel(branches) => {
+                if branches.is_empty() {
+                    return Err(TuasLibError::GenericStringError(
+                        "parallel element needs at least one branch".to_string()));
+                }
+                let mut total_conductance = ThermalConductance::new::<watt_per_kelvin>(0.0);
+                for branch in branches {
+                    total_conductance += 1.0/branch.try_solve(
+                        temperature_start, temperature_end)?.total_resistance;
+                }
+                1.0/total_conductance
+            },
+        };
+
+        Ok(resistance)
+    }
+}
+
+/// a series of thermal resistances between two temperatures
+///
+/// elements are added from the start temperature to the end
+/// temperature, use the add_* methods to build the network
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct ThermalResistanceNetwork {
+    /// elements in series, from start to end
+    pub elements: Vec<ThermalResistanceElement>,
+}
+
+/// heat rate and interface temperatures of a solved network
+#[derive(Debug,Clone,PartialEq)]
+pub struct ThermalResistanceNetworkSolution {
+    /// heat rate from the start temperature to the end temperature
+    pub heat_rate: Power,
+
+    /// total thermal resistance of the network
+    pub total_resistance: ThermalResistance,
+
+    /// thermal resistance of each element, evaluated at the
+    /// converged interface temperatures
+    pub element_resistances: Vec<ThermalResistance>,
+
+    /// temperatures at either side of each element, starting with
+    /// the start temperature and ending with the end temperature,
+    /// so there is one more interface temperature than elements
+    pub interface_temperatures: Vec<ThermodynamicTemperature>,
+}
+
+impl ThermalResistanceNetwork {
+
+    /// empty network
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds any element in series
+    pub fn add_element(mut self, element: ThermalResistanceElement) -> Self {
+        self.elements.push(element);
+        self
+    }
+
+    /// adds convection, R = 1/(hA)
+    pub fn add_convection(self,
+        heat_transfer_coeff: HeatTransfer, area: Area) -> Self {
+        self.add_element(ThermalResistanceElement::Convection(
+                heat_transfer_coeff, area))
+    }
+
+    /// adds conduction through a flat wall, R = dx/(kA)
+    pub fn add_planar_conduction(self,
+        thickness: Length, area: Area,
+        thermal_conductivity: LayerThermalConductivity) -> Self {
+        self.add_element(ThermalResistanceElement::PlanarConduction(
+                thickness, area, thermal_conductivity))
+    }
+
+    /// adds conduction through a cylindrical shell,
+    /// R = ln(d_o/d_i)/(2 pi k L)
+    pub fn add_cylindrical_conduction(self,
+        inner_diameter: Length, outer_diameter: Length, length: Length,
+        thermal_conductivity: LayerThermalConductivity) -> Self {
+        self.add_element(ThermalResistanceElement::CylindricalConduction(
+                inner_diameter, outer_diameter, length, thermal_conductivity))
+    }
+
+    /// adds conduction through a spherical shell,
+    /// R = (1/r_i - 1/r_o)/(4 pi k)
+    pub fn add_spherical_conduction(self,
+        inner_diameter: Length, outer_diameter: Length,
+        thermal_conductivity: LayerThermalConductivity) -> Self {
+        self.add_element(ThermalResistanceElement::SphericalConduction(
+                inner_diameter, outer_diameter, thermal_conductivity))
+    }
+
+    /// adds a contact (or gap) conductance, R = 1/(h_c A)
+    pub fn add_contact(self,
+        contact_conductance: HeatTransfer, area: Area) -> Self {
+        self.add_element(ThermalResistanceElement::Contact(
+                contact_conductance, area))
+    }
+
+    /// adds linearised radiation between two grey surfaces
+    /// with an effective emissivity
+    pub fn add_radiation(self,
+        effective_emissivity: Ratio, area: Area) -> Self {
+        self.add_element(ThermalResistanceElement::Radiation(
+                effective_emissivity, area))
+    }
+
+    /// adds a fixed thermal resistance
+    pub fn add_resistance(self, resistance: ThermalResistance) -> Self {
+        self.add_element(ThermalResistanceElement::FixedResistance(resistance))
+    }
+
+    /// adds parallel branches between the same two temperatures
+    pub fn add_parallel(self, branches: Vec<ThermalResistanceNetwork>) -> Self {
+        self.add_element(ThermalResistanceElement::Parallel(branches))
+    }
+
+    /// solves for the heat rate and interface temperatures given
+    /// the temperatures at the start and end of the network
+    ///
+    /// the interface temperatures are first guessed by splitting the
+    /// temperature difference equally over every element, then
+    /// resistances are evaluated at the interface temperatures and
+    /// the interface temperatures updated with
+    ///
+    /// T_(i+1) = T_i - Q R_i
+    ///
+    /// until they converge
+    pub fn try_solve(&self,
+        temperature_start: ThermodynamicTemperature,
+        temperature_end: ThermodynamicTemperature)
+        -> Result<ThermalResistanceNetworkSolution, TuasLibError> {
+
+        if self.elements.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "thermal resistance network has no elements".to_string()));
+        }
+
+        let number_of_elements = self.elements.len();
+        let t_start = temperature_start.get::<kelvin>();
+        let t_end = temperature_end.get::<kelvin>();
+
+        let mut interface_temperatures_kelvin: Vec<f64> = (0..=number_of_elements)
+            .map(|index| t_start + (t_end - t_start)
+                * index as f64 / number_of_elements as f64)
+            .collect();
+
+        for _ in 0..MAX_ITERATIONS {
+
+            let element_resistances = self.try_get_element_resistances(
+                &interface_temperatures_kelvin)?;
+
+            let total_resistance: ThermalResistance =
+                element_resistances.iter().copied().sum();
+
+            let heat_rate: Power = subtract_two_thermodynamic_temperatures(
+                temperature_start, temperature_end)/total_resistance;
+
+            let mut new_interface_temperatures_kelvin = vec![t_start];
+            let mut temperature_kelvin = t_start;
+            for resistance in element_resistances.iter() {
+                let temperature_drop: TemperatureInterval = heat_rate * *resistance;
+                temperature_kelvin -= temperature_drop
+                    .get::<uom::si::temperature_interval::kelvin>();
+                new_interface_temperatures_kelvin.push(temperature_kelvin);
+            }
+            // the last interface is the end temperature,
+            // this only removes round off
+            new_interface_temperatures_kelvin[number_of_elements] = t_end;
+
+            let max_change: f64 = interface_temperatures_kelvin.iter()
+                .zip(new_interface_temperatures_kelvin.iter())
+                .map(|(old, new)| (old - new).abs())
+                .fold(0.0, f64::max);
+
+            interface_temperatures_kelvin = new_interface_temperatures_kelvin;
+
+            if max_change < TEMPERATURE_TOLERANCE_KELVIN {
+
+                // resistances at the converged temperatures
+                let element_resistances = self.try_get_element_resistances(
+                    &interface_temperatures_kelvin)?;
+                let total_resistance: ThermalResistance =
+                    element_resistances.iter().copied().sum();
+                let heat_rate: Power = subtract_two_thermodynamic_temperatures(
+                    temperature_start, temperature_end)/total_resistance;
+
+                return Ok(ThermalResistanceNetworkSolution {
+                    heat_rate,
+                    total_resistance,
+                    element_resistances,
+                    interface_temperatures: interface_temperatures_kelvin.iter()
+                        .map(|temperature| ThermodynamicTemperature::new::<kelvin>(
+                                *temperature))
+                        .collect(),
+                });
+            }
+        }
+
+        Err(TuasLibError::GenericStringError(
+            "thermal resistance network did not converge".to_string()))
+    }
+
+    fn try_get_element_resistances(&self,
+        interface_temperatures_kelvin: &[f64])
+        -> Result<Vec<ThermalResistance>, TuasLibError> {
+
+        self.elements.iter().enumerate().map(|(index, element)| {
+            element.try_get_thermal_resistance(
+                ThermodynamicTemperature::new::<kelvin>(
+                    interface_temperatures_kelvin[index]),
+                ThermodynamicTemperature::new::<kelvin>(
+                    interface_temperatures_kelvin[index + 1]))
+        }).collect()
+    }
+}
+
+fn check_positive(value: f64, name: &str) -> Result<(), TuasLibError> {
+    if value <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            format!("{} must be positive", name)));
+    }
+    Ok(())
+}
+
+fn check_shell_diameters(inner_diameter: Length,
+    outer_diameter: Length) -> Result<(), TuasLibError> {
+    if outer_diameter <= inner_diameter {
+        return Err(TuasLibError::GenericStringError(
+            "outer diameter must be larger than inner diameter".to_string()));
+    }
+    Ok(())
+}
+
+/// unit tests for thermal resistance networks
+pub mod unit_test_thermal_resistance_network;
@@ -0,0 +1,211 @@
+/// a series network of constant resistances should agree with
+/// obtain_power_two_convection_two_conduction_thermal_resistance
+///
+/// interface temperatures follow T_(i+1) = T_i - Q R_i
+#[test]
+pub fn series_network_matches_two_convection_two_conduction() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::length::meter;
+    use uom::si::power::watt;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::heat_transfer_correlations::thermal_resistance::obtain_power_two_convection_two_conduction_thermal_resistance;
+    use super::ThermalResistanceNetwork;
+
+    let hot_temperature = ThermodynamicTemperature::new::<kelvin>(500.0);
+    let cold_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let area = Area::new::<square_meter>(2.0);
+    let h_1 = HeatTransfer::new::<watt_per_square_meter_kelvin>(100.0);
+    let h_2 = HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0);
+    let k_1 = ThermalConductivity::new::<watt_per_meter_kelvin>(15.0);
+    let k_2 = ThermalConductivity::new::<watt_per_meter_kelvin>(0.1);
+    let dx_1 = Length::new::<meter>(0.01);
+    let dx_2 = Length::new::<meter>(0.05);
+
+    let expected_heat_rate = obtain_power_two_convection_two_conduction_thermal_resistance(
+        cold_temperature, hot_temperature,
+        area, h_1, area, h_2,
+        k_1, area, dx_1,
+        k_2, area, dx_2);
+
+    let solution = ThermalResistanceNetwork::new()
+        .add_convection(h_1, area)
+        .add_planar_conduction(dx_1, area, k_1.into())
+        .add_planar_conduction(dx_2, area, k_2.into())
+        .add_convection(h_2, area)
+        .try_solve(hot_temperature, cold_temperature)?;
+
+    approx::assert_relative_eq!(
+        solution.heat_rate.get::<watt>(),
+        expected_heat_rate.get::<watt>(),
+        max_relative = 1e-10);
+
+    // R = 0.005 + 0.000333 + 0.25 + 0.025 K/W
+    // Q = 200/0.280333 = 713.44 W
+    // T after first convection = 500 - 713.44 * 0.005 = 496.433 K
+    assert_eq!(solution.interface_temperatures.len(), 5);
+    approx::assert_relative_eq!(
+        solution.interface_temperatures[1].get::<kelvin>(),
+        500.0 - expected_heat_rate.get::<watt>() * 0.005,
+        max_relative = 1e-10);
+    approx::assert_relative_eq!(
+        solution.interface_temperatures[4].get::<kelvin>(), 300.0);
+
+    Ok(())
+}
+
+/// cylindrical, spherical and contact resistances against hand
+/// calculations, with parallel branches
+///
+/// cylinder: d_i = 0.02 m, d_o = 0.04 m, L = 2 m, k = 10 W/(m K)
+/// R = ln 2/(2 pi 10 2) = 0.0055160 K/W
+///
+/// sphere: d_i = 0.02 m, d_o = 0.06 m, k = 1 W/(m K)
+/// R = (100 - 33.333)/(4 pi) = 5.30516 K/W
+///
+/// contact: h_c = 1000 W/(m^2 K), A = 0.1 m^2, R = 0.01 K/W
+///
+/// two contact branches in parallel give R = 0.005 K/W
+#[test]
+pub fn curved_conduction_contact_and_parallel_hand_calc() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::length::meter;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::thermal_resistance::kelvin_per_watt;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use super::{ThermalResistanceElement, ThermalResistanceNetwork};
+
+    let hot_temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+    let cold_temperature = ThermodynamicTemperature::new::<kelvin>(350.0);
+
+    let cylinder = ThermalResistanceElement::CylindricalConduction(
+        Length::new::<meter>(0.02), Length::new::<meter>(0.04),
+        Length::new::<meter>(2.0),
+        ThermalConductivity::new::<watt_per_meter_kelvin>(10.0).into());
+    approx::assert_relative_eq!(
+        cylinder.try_get_thermal_resistance(hot_temperature, cold_temperature)?
+        .get::<kelvin_per_watt>(),
+        2.0_f64.ln()/(2.0 * std::f64::consts::PI * 10.0 * 2.0),
+        max_relative = 1e-12);
+
+    let sphere = ThermalResistanceElement::SphericalConduction(
+        Length::new::<meter>(0.02), Length::new::<meter>(0.06),
+        ThermalConductivity::new::<watt_per_meter_kelvin>(1.0).into());
+    approx::assert_relative_eq!(
+        sphere.try_get_thermal_resistance(hot_temperature, cold_temperature)?
+        .get::<kelvin_per_watt>(),
+        (1.0/0.01 - 1.0/0.03)/(4.0 * std::f64::consts::PI),
+        max_relative = 1e-12);
+
+    let contact_branch = ThermalResistanceNetwork::new()
+        .add_contact(HeatTransfer::new::<watt_per_square_meter_kelvin>(1000.0),
+            Area::new::<square_meter>(0.1));
+
+    let solution = ThermalResistanceNetwork::new()
+        .add_parallel(vec![contact_branch.clone(), contact_branch])
+        .try_solve(hot_temperature, cold_temperature)?;
+
+    approx::assert_relative_eq!(
+        solution.total_resistance.get::<kelvin_per_watt>(), 0.005,
+        max_relative = 1e-12);
+
+    // bad geometry and empty networks are errors
+    let inverted_cylinder = ThermalResistanceElement::CylindricalConduction(
+        Length::new::<meter>(0.04), Length::new::<meter>(0.02),
+        Length::new::<meter>(2.0),
+        ThermalConductivity::new::<watt_per_meter_kelvin>(10.0).into());
+    assert!(inverted_cylinder.try_get_thermal_resistance(
+            hot_temperature, cold_temperature).is_err());
+    assert!(ThermalResistanceNetwork::new()
+        .try_solve(hot_temperature, cold_temperature).is_err());
+
+    Ok(())
+}
+
+/// radiation between two plates, with a contact resistance in
+/// series, should give the same heat rate as solving the
+/// nonlinear radiation equation directly
+///
+/// q = h_c (T_1 - T_m) = epsilon sigma (T_m^4 - T_2^4)
+///
+/// temperature dependent conductivity should be evaluated at
+/// the mean temperature of the converged layer
+#[test]
+pub fn radiation_and_temperature_dependent_conductivity_converge() -> Result<(),
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::length::meter;
+    use uom::si::ratio::ratio;
+    use uom::si::power::watt;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::thermal_resistance::kelvin_per_watt;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use crate::boussinesq_thermophysical_properties::SolidMaterial;
+    use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::STEFAN_BOLTZMANN_CONSTANT;
+    use super::ThermalResistanceNetwork;
+
+    let hot_temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+    let cold_temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+    let area = Area::new::<square_meter>(1.0);
+    let emissivity = 0.8;
+    let contact_conductance = 200.0;
+
+    let solution = ThermalResistanceNetwork::new()
+        .add_contact(
+            HeatTransfer::new::<watt_per_square_meter_kelvin>(contact_conductance),
+            area)
+        .add_radiation(Ratio::new::<ratio>(emissivity), area)
+        .try_solve(hot_temperature, cold_temperature)?;
+
+    let heat_rate = solution.heat_rate.get::<watt>();
+    let t_m = solution.interface_temperatures[1].get::<kelvin>();
+
+    approx::assert_relative_eq!(
+        heat_rate,
+        contact_conductance * (900.0 - t_m),
+        max_relative = 1e-8);
+    approx::assert_relative_eq!(
+        heat_rate,
+        emissivity * STEFAN_BOLTZMANN_CONSTANT * (t_m.powi(4) - 400.0_f64.powi(4)),
+        max_relative = 1e-8);
+
+    // steel wall with a temperature dependent conductivity
+    // in series with a fixed resistance
+    let steel = SolidMaterial::SteelSS304L;
+    let thickness = Length::new::<meter>(0.1);
+
+    let solution = ThermalResistanceNetwork::new()
+        .add_planar_conduction(thickness, area, steel.into())
+        .add_resistance(ThermalResistance::new::<kelvin_per_watt>(0.01))
+        .try_solve(
+            ThermodynamicTemperature::new::<kelvin>(700.0),
+            ThermodynamicTemperature::new::<kelvin>(350.0))?;
+
+    let wall_mean_temperature = ThermodynamicTemperature::new::<kelvin>(
+        0.5 * (solution.interface_temperatures[0].get::<kelvin>()
+            + solution.interface_temperatures[1].get::<kelvin>()));
+    let steel_conductivity = steel.try_get_thermal_conductivity(
+        wall_mean_temperature)?.get::<watt_per_meter_kelvin>();
+
+    approx::assert_relative_eq!(
+        solution.element_resistances[0].get::<kelvin_per_watt>(),
+        0.1/steel_conductivity,
+        max_relative = 1e-10);
+    approx::assert_relative_eq!(
+        solution.heat_rate.get::<watt>(),
+        350.0/(0.1/steel_conductivity + 0.01),
+        max_relative = 1e-8);
+
+    Ok(())
+}
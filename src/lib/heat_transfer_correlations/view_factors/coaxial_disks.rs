@@ -0,0 +1,82 @@
+use uom::si::{f64::*, ratio::ratio};
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::parallel_plates::check_positive_lengths;
+
+/// view factor from disk i to disk j, where both disks are
+/// parallel and share the same axis
+///
+/// R_i = r_i/L, R_j = r_j/L
+///
+/// S = 1 + (1 + R_j^2)/R_i^2
+///
+/// F_(i-j) = 1/2 * ( S - sqrt( S^2 - 4 (r_j/r_i)^2 ) )
+///
+/// where L is the distance between the disks
+///
+/// Bergman, T. L. (2011). Fundamentals of heat and mass transfer.
+/// John Wiley & Sons. (Table 13.2)
+pub fn coaxial_parallel_disks_view_factor(
+    disk_i_radius: Length,
+    disk_j_radius: Length,
+    separation_distance: Length) -> Result<Ratio, TuasLibError> {
+
+    check_positive_lengths(&[disk_i_radius, disk_j_radius, separation_distance])?;
+
+    let r_i: f64 = (disk_i_radius/separation_distance).get::<ratio>();
+    let r_j: f64 = (disk_j_radius/separation_distance).get::<ratio>();
+    let radius_ratio: f64 = r_j/r_i;
+
+    // S = 1 + (1 + R_j^2)/R_i^2
+    let s = 1.0 + (1.0 + r_j.powf(2.0))/r_i.powf(2.0);
+
+    // F_(i-j) = 1/2 * ( S - sqrt( S^2 - 4 (r_j/r_i)^2 ) )
+    let view_factor_value: f64 =
+        0.5 * (s - (s.powf(2.0) - 4.0 * radius_ratio.powf(2.0)).sqrt());
+
+    Ok(Ratio::new::<ratio>(view_factor_value))
+}
+
+/// for two disks of radius equal to the separation distance,
+///
+/// S = 3, F = (3 - sqrt(5))/2 = 0.382
+///
+/// also, reciprocity should hold for disks of unequal sizes
+///
+/// r_i^2 F_(i-j) = r_j^2 F_(j-i)
+#[cfg(test)]
+#[test]
+pub fn coaxial_parallel_disks_view_factor_test(){
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+
+    let one_meter = Length::new::<meter>(1.0);
+
+    let equal_disks_view_factor = coaxial_parallel_disks_view_factor(
+        one_meter, one_meter, one_meter).unwrap();
+
+    approx::assert_relative_eq!(
+        equal_disks_view_factor.get::<ratio>(),
+        0.5 * (3.0 - 5.0_f64.sqrt()),
+        max_relative = 1e-9
+        );
+
+    let small_radius = Length::new::<meter>(0.2);
+    let large_radius = Length::new::<meter>(0.9);
+    let separation = Length::new::<meter>(0.5);
+
+    let small_to_large = coaxial_parallel_disks_view_factor(
+        small_radius, large_radius, separation).unwrap();
+    let large_to_small = coaxial_parallel_disks_view_factor(
+        large_radius, small_radius, separation).unwrap();
+
+    approx::assert_relative_eq!(
+        (small_to_large * small_radius * small_radius).get::<square_meter>(),
+        (large_to_small * large_radius * large_radius).get::<square_meter>(),
+        max_relative = 1e-9
+        );
+
+    assert!(coaxial_parallel_disks_view_factor(
+            -one_meter, one_meter, one_meter).is_err());
+}
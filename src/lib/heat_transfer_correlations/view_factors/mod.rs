@@ -5,3 +5,18 @@
 /// However, the view factors themselves have been tested to check 
 /// if they add up to one
 pub mod cocentric_cylinders;
+
+/// view factors for aligned parallel rectangles and
+/// parallel strips
+pub mod parallel_plates;
+
+/// view factors for coaxial parallel disks
+pub mod coaxial_disks;
+
+/// view factors for infinitely long parallel cylinders
+/// side by side
+pub mod parallel_cylinders;
+
+/// reciprocity and summation rules, to get the remaining view
+/// factors of an enclosure from the known ones
+pub mod view_factor_algebra;
@@ -0,0 +1,125 @@
+use std::f64::consts::PI;
+
+use uom::si::{f64::*, ratio::ratio};
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::parallel_plates::check_positive_lengths;
+
+/// view factor from cylinder 1 to cylinder 2, where both are
+/// infinitely long and parallel to each other (side by side, not
+/// one inside the other)
+///
+/// R = r_2/r_1
+/// S = s/r_1
+/// C = 1 + R + S
+///
+/// where s is the gap between the cylinder surfaces
+///
+/// F_(1-2) = 1/(2 PI) * (PI + A - B + D - E)
+///
+/// A = sqrt( C^2 - (R + 1)^2 )
+///
+/// B = sqrt( C^2 - (R - 1)^2 )
+///
+/// D = (R - 1) acos( R/C - 1/C )
+///
+/// E = (R + 1) acos( R/C + 1/C )
+///
+/// for cylinders nested inside each other, see the
+/// cocentric_cylinders module
+///
+/// Howell, J. R., Mengüç, M. P., Daun, K., & Siegel, R. (2020).
+/// Thermal radiation heat transfer. CRC press. (Appendix C)
+pub fn parallel_cylinders_view_factor(
+    cylinder_1_radius: Length,
+    cylinder_2_radius: Length,
+    surface_to_surface_gap: Length) -> Result<Ratio, TuasLibError> {
+
+    check_positive_lengths(&[cylinder_1_radius, cylinder_2_radius])?;
+
+    if surface_to_surface_gap.value < 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "parallel cylinders cannot overlap".to_string()));
+    }
+
+    let r_value: f64 = (cylinder_2_radius/cylinder_1_radius).get::<ratio>();
+    let s_value: f64 = (surface_to_surface_gap/cylinder_1_radius).get::<ratio>();
+    let c_value: f64 = 1.0 + r_value + s_value;
+    let c_sq = c_value.powf(2.0);
+
+    // A = sqrt( C^2 - (R + 1)^2 )
+    let a = (c_sq - (r_value + 1.0).powf(2.0)).sqrt();
+
+    // B = sqrt( C^2 - (R - 1)^2 )
+    let b = (c_sq - (r_value - 1.0).powf(2.0)).sqrt();
+
+    // D = (R - 1) acos( R/C - 1/C )
+    let d = (r_value - 1.0) * ((r_value - 1.0)/c_value).acos();
+
+    // E = (R + 1) acos( R/C + 1/C )
+    let e = (r_value + 1.0) * ((r_value + 1.0)/c_value).acos();
+
+    let view_factor_value: f64 = 0.5 * PI.recip() * (PI + a - b + d - e);
+
+    Ok(Ratio::new::<ratio>(view_factor_value))
+}
+
+/// for equal cylinders, the view factor reduces to
+///
+/// F = 1/PI * ( sqrt(X^2 - 1) + asin(1/X) - X )
+///
+/// X = 1 + s/(2r)
+///
+/// (Bergman, Table 13.1)
+///
+/// for unequal cylinders, reciprocity should hold
+///
+/// r_1 F_(1-2) = r_2 F_(2-1)
+#[cfg(test)]
+#[test]
+pub fn parallel_cylinders_view_factor_test(){
+    use uom::si::length::meter;
+
+    let radius = Length::new::<meter>(0.1);
+    let gap = Length::new::<meter>(0.15);
+
+    let equal_cylinders_view_factor = parallel_cylinders_view_factor(
+        radius, radius, gap).unwrap();
+
+    let x_value: f64 = 1.0 + 0.15/0.2;
+
+    approx::assert_relative_eq!(
+        equal_cylinders_view_factor.get::<ratio>(),
+        PI.recip() * ((x_value.powf(2.0) - 1.0).sqrt()
+            + x_value.recip().asin() - x_value),
+        max_relative = 1e-9
+        );
+
+    // touching cylinders see 1/PI * (PI/2 - 1) of each other
+    let touching_view_factor = parallel_cylinders_view_factor(
+        radius, radius, Length::new::<meter>(0.0)).unwrap();
+
+    approx::assert_relative_eq!(
+        touching_view_factor.get::<ratio>(),
+        0.5 - PI.recip(),
+        max_relative = 1e-9
+        );
+
+    let small_radius = Length::new::<meter>(0.05);
+    let large_radius = Length::new::<meter>(0.4);
+
+    let small_to_large = parallel_cylinders_view_factor(
+        small_radius, large_radius, gap).unwrap();
+    let large_to_small = parallel_cylinders_view_factor(
+        large_radius, small_radius, gap).unwrap();
+
+    approx::assert_relative_eq!(
+        (small_to_large * small_radius).get::<meter>(),
+        (large_to_small * large_radius).get::<meter>(),
+        max_relative = 1e-9
+        );
+
+    assert!(parallel_cylinders_view_factor(
+            radius, radius, Length::new::<meter>(-0.01)).is_err());
+}
@@ -0,0 +1,185 @@
+use std::f64::consts::PI;
+
+use uom::si::{f64::*, ratio::ratio};
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// view factor between two aligned parallel rectangles of the
+/// same size, directly opposite each other
+///
+/// X = x/L, Y = y/L
+///
+/// where x and y are the sides of the rectangle and L is the
+/// distance between the rectangles
+///
+/// F_(1-2) = 2/(PI X Y) * (A + B + C - D - E)
+///
+/// A = ln ( sqrt( (1 + X^2)(1 + Y^2)/(1 + X^2 + Y^2) ) )
+///
+/// B = X sqrt(1 + Y^2) atan (X/sqrt(1 + Y^2))
+///
+/// C = Y sqrt(1 + X^2) atan (Y/sqrt(1 + X^2))
+///
+/// D = X atan(X)
+///
+/// E = Y atan(Y)
+///
+/// Bergman, T. L. (2011). Fundamentals of heat and mass transfer.
+/// John Wiley & Sons. (Table 13.2)
+pub fn aligned_parallel_rectangles_view_factor(
+    side_x: Length,
+    side_y: Length,
+    separation_distance: Length) -> Result<Ratio, TuasLibError> {
+
+    check_positive_lengths(&[side_x, side_y, separation_distance])?;
+
+    let x_value: f64 = (side_x/separation_distance).get::<ratio>();
+    let y_value: f64 = (side_y/separation_distance).get::<ratio>();
+
+    let x_sq = x_value.powf(2.0);
+    let y_sq = y_value.powf(2.0);
+
+    // A = ln ( sqrt( (1 + X^2)(1 + Y^2)/(1 + X^2 + Y^2) ) )
+    let a = ((1.0 + x_sq) * (1.0 + y_sq) / (1.0 + x_sq + y_sq)).sqrt().ln();
+
+    // B = X sqrt(1 + Y^2) atan (X/sqrt(1 + Y^2))
+    let b = x_value * (1.0 + y_sq).sqrt() * (x_value/(1.0 + y_sq).sqrt()).atan();
+
+    // C = Y sqrt(1 + X^2) atan (Y/sqrt(1 + X^2))
+    let c = y_value * (1.0 + x_sq).sqrt() * (y_value/(1.0 + x_sq).sqrt()).atan();
+
+    // D = X atan(X)
+    let d = x_value * x_value.atan();
+
+    // E = Y atan(Y)
+    let e = y_value * y_value.atan();
+
+    let view_factor_value: f64 =
+        2.0/(PI * x_value * y_value) * (a + b + c - d - e);
+
+    Ok(Ratio::new::<ratio>(view_factor_value))
+
+}
+
+/// view factor between two parallel strips of infinite length
+/// (two dimensional geometry) with their midlines directly
+/// opposite each other
+///
+/// W_i = w_i/L, W_j = w_j/L
+///
+/// F_(i-j) = ( sqrt( (W_i + W_j)^2 + 4 ) - sqrt( (W_j - W_i)^2 + 4 ) )/(2 W_i)
+///
+/// Bergman, T. L. (2011). Fundamentals of heat and mass transfer.
+/// John Wiley & Sons. (Table 13.1)
+pub fn parallel_strips_view_factor(
+    strip_i_width: Length,
+    strip_j_width: Length,
+    separation_distance: Length) -> Result<Ratio, TuasLibError> {
+
+    check_positive_lengths(&[strip_i_width, strip_j_width, separation_distance])?;
+
+    let w_i: f64 = (strip_i_width/separation_distance).get::<ratio>();
+    let w_j: f64 = (strip_j_width/separation_distance).get::<ratio>();
+
+    let view_factor_value: f64 =
+        (((w_i + w_j).powf(2.0) + 4.0).sqrt()
+         - ((w_j - w_i).powf(2.0) + 4.0).sqrt()) * 0.5 / w_i;
+
+    Ok(Ratio::new::<ratio>(view_factor_value))
+}
+
+/// view factors only make sense for positive dimensions
+pub(crate) fn check_positive_lengths(lengths: &[Length]) -> Result<(), TuasLibError> {
+
+    for length in lengths.iter() {
+        if length.value <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "lengths for view factors must be more than zero".to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// for two unit squares one unit apart, the view factor is 0.1998
+/// (Bergman, Figure 13.4)
+///
+/// as the squares get closer together, they see only each other,
+/// and as they move further apart, they barely see each other at all
+#[cfg(test)]
+#[test]
+pub fn aligned_parallel_squares_view_factor_test(){
+    use uom::si::length::meter;
+
+    let one_meter = Length::new::<meter>(1.0);
+
+    let view_factor = aligned_parallel_rectangles_view_factor(
+        one_meter, one_meter, one_meter).unwrap();
+
+    approx::assert_relative_eq!(
+        view_factor.get::<ratio>(),
+        0.1998,
+        max_relative = 1e-3
+        );
+
+    let close_view_factor = aligned_parallel_rectangles_view_factor(
+        one_meter, one_meter, Length::new::<meter>(1e-4)).unwrap();
+
+    approx::assert_relative_eq!(
+        close_view_factor.get::<ratio>(),
+        1.0,
+        max_relative = 1e-3
+        );
+
+    // far apart, the squares behave like differential areas
+    // F = A_j/(PI L^2)
+    let far_view_factor = aligned_parallel_rectangles_view_factor(
+        one_meter, one_meter, Length::new::<meter>(100.0)).unwrap();
+
+    approx::assert_relative_eq!(
+        far_view_factor.get::<ratio>(),
+        1.0/(PI * 1.0e4),
+        max_relative = 1e-3
+        );
+
+    assert!(aligned_parallel_rectangles_view_factor(
+            one_meter, one_meter, Length::new::<meter>(0.0)).is_err());
+}
+
+/// for strips, reciprocity should hold
+///
+/// w_i F_(i-j) = w_j F_(j-i)
+///
+/// for equal strips of width w one w apart,
+/// F = sqrt(2) - 1
+#[cfg(test)]
+#[test]
+pub fn parallel_strips_reciprocity_test(){
+    use uom::si::length::meter;
+
+    let one_meter = Length::new::<meter>(1.0);
+
+    let equal_strips_view_factor = parallel_strips_view_factor(
+        one_meter, one_meter, one_meter).unwrap();
+
+    approx::assert_relative_eq!(
+        equal_strips_view_factor.get::<ratio>(),
+        2.0_f64.sqrt() - 1.0,
+        max_relative = 1e-9
+        );
+
+    let narrow_strip = Length::new::<meter>(0.3);
+    let wide_strip = Length::new::<meter>(2.0);
+    let separation = Length::new::<meter>(0.7);
+
+    let narrow_to_wide = parallel_strips_view_factor(
+        narrow_strip, wide_strip, separation).unwrap();
+    let wide_to_narrow = parallel_strips_view_factor(
+        wide_strip, narrow_strip, separation).unwrap();
+
+    approx::assert_relative_eq!(
+        (narrow_to_wide * narrow_strip).get::<meter>(),
+        (wide_to_narrow * wide_strip).get::<meter>(),
+        max_relative = 1e-9
+        );
+}
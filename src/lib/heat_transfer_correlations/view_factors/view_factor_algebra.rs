@@ -0,0 +1,168 @@
+use uom::si::{f64::*, ratio::ratio};
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// reciprocity rule for view factors
+///
+/// A_i F_(i-j) = A_j F_(j-i)
+///
+/// so F_(j-i) = A_i/A_j F_(i-j)
+pub fn reciprocal_view_factor(
+    area_i: Area,
+    area_j: Area,
+    view_factor_i_to_j: Ratio) -> Result<Ratio, TuasLibError> {
+
+    if area_i.value <= 0.0 || area_j.value <= 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "areas for view factors must be more than zero".to_string()));
+    }
+
+    Ok(area_i/area_j * view_factor_i_to_j)
+}
+
+/// summation rule for view factors
+///
+/// the view factors from surface i to all surfaces in an
+/// enclosure (including itself) add up to one, so the last
+/// unknown view factor is:
+///
+/// F_(i-N) = 1 - sum of known F_(i-j)
+///
+/// returns an error if the known view factors already add up
+/// to more than one
+pub fn summation_rule_remaining_view_factor(
+    known_view_factors: &[Ratio]) -> Result<Ratio, TuasLibError> {
+
+    let sum_of_known_view_factors: f64 = known_view_factors.iter()
+        .map(|view_factor| view_factor.get::<ratio>())
+        .sum();
+
+    // allow for some round off
+    if sum_of_known_view_factors > 1.0 + 1e-9 {
+        return Err(TuasLibError::GenericStringError(
+            "known view factors add up to more than one".to_string()));
+    }
+
+    Ok(Ratio::new::<ratio>((1.0 - sum_of_known_view_factors).max(0.0)))
+}
+
+/// checks that a matrix of view factors for an enclosure of
+/// N surfaces obeys the summation rule (each row adds up to one)
+/// and reciprocity (A_i F_(i-j) = A_j F_(j-i))
+///
+/// view_factors[i][j] is the view factor from surface i to surface j
+///
+/// the tolerance is relative, eg. 1e-3 means rows may add up to
+/// anywhere between 0.999 and 1.001
+pub fn check_view_factor_matrix(
+    surface_areas: &[Area],
+    view_factors: &[Vec<Ratio>],
+    tolerance: f64) -> Result<(), TuasLibError> {
+
+    let number_of_surfaces = surface_areas.len();
+
+    if view_factors.len() != number_of_surfaces ||
+        view_factors.iter().any(|row| row.len() != number_of_surfaces) {
+        return Err(TuasLibError::GenericStringError(
+            "view factor matrix must be N by N for N surfaces".to_string()));
+    }
+
+    if surface_areas.iter().any(|area| area.value <= 0.0) {
+        return Err(TuasLibError::GenericStringError(
+            "areas for view factors must be more than zero".to_string()));
+    }
+
+    for (i, row) in view_factors.iter().enumerate() {
+
+        if row.iter().any(|view_factor| {
+            let view_factor_value = view_factor.get::<ratio>();
+            !(0.0..=1.0 + tolerance).contains(&view_factor_value)
+        }) {
+            return Err(TuasLibError::GenericStringError(
+                format!("view factors from surface {} must be between 0 and 1", i)));
+        }
+
+        let row_sum: f64 = row.iter()
+            .map(|view_factor| view_factor.get::<ratio>())
+            .sum();
+
+        if (row_sum - 1.0).abs() > tolerance {
+            return Err(TuasLibError::GenericStringError(
+                format!("view factors from surface {} add up to {}, not one",
+                    i, row_sum)));
+        }
+
+        for j in (i+1)..number_of_surfaces {
+
+            let exchange_area_i_to_j = surface_areas[i] * view_factors[i][j];
+            let exchange_area_j_to_i = surface_areas[j] * view_factors[j][i];
+            let largest_exchange_area = exchange_area_i_to_j.max(exchange_area_j_to_i);
+
+            if (exchange_area_i_to_j - exchange_area_j_to_i).abs()
+                > tolerance * largest_exchange_area {
+                return Err(TuasLibError::GenericStringError(
+                    format!("view factors between surfaces {} and {} do not obey reciprocity",
+                        i, j)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// for an enclosure made of a cylinder and its two end disks,
+/// the view factors can be found from the coaxial disk view factor
+/// with view factor algebra:
+///
+/// F_(disk-disk) from the coaxial disk formula
+/// F_(disk-cylinder) = 1 - F_(disk-disk) (summation)
+/// F_(cylinder-disk) = A_disk/A_cylinder F_(disk-cylinder) (reciprocity)
+/// F_(cylinder-cylinder) = 1 - 2 F_(cylinder-disk) (summation)
+///
+/// the resulting matrix should pass the check
+#[cfg(test)]
+#[test]
+pub fn cylinder_with_end_disks_view_factor_algebra(){
+    use std::f64::consts::PI;
+    use uom::si::length::meter;
+    use super::coaxial_disks::coaxial_parallel_disks_view_factor;
+
+    let radius = Length::new::<meter>(0.5);
+    let height = Length::new::<meter>(2.0);
+
+    let disk_area: Area = PI * radius * radius;
+    let cylinder_area: Area = 2.0 * PI * radius * height;
+
+    let disk_to_disk = coaxial_parallel_disks_view_factor(
+        radius, radius, height).unwrap();
+    let disk_to_cylinder = summation_rule_remaining_view_factor(
+        &[disk_to_disk]).unwrap();
+    let cylinder_to_disk = reciprocal_view_factor(
+        disk_area, cylinder_area, disk_to_cylinder).unwrap();
+    let cylinder_to_cylinder = summation_rule_remaining_view_factor(
+        &[cylinder_to_disk, cylinder_to_disk]).unwrap();
+
+    let zero = Ratio::new::<ratio>(0.0);
+
+    // surfaces are: bottom disk, top disk, cylinder
+    let surface_areas = vec![disk_area, disk_area, cylinder_area];
+    let view_factors = vec![
+        vec![zero, disk_to_disk, disk_to_cylinder],
+        vec![disk_to_disk, zero, disk_to_cylinder],
+        vec![cylinder_to_disk, cylinder_to_disk, cylinder_to_cylinder],
+    ];
+
+    check_view_factor_matrix(&surface_areas, &view_factors, 1e-9).unwrap();
+
+    // a matrix breaking reciprocity should fail
+    let mut wrong_view_factors = view_factors.clone();
+    wrong_view_factors[2] = vec![disk_to_cylinder, zero,
+        Ratio::new::<ratio>(1.0) - disk_to_cylinder];
+
+    assert!(check_view_factor_matrix(
+            &surface_areas, &wrong_view_factors, 1e-6).is_err());
+
+    // and so should view factors adding up to more than one
+    assert!(summation_rule_remaining_view_factor(
+            &[Ratio::new::<ratio>(0.7), Ratio::new::<ratio>(0.4)]).is_err());
+}
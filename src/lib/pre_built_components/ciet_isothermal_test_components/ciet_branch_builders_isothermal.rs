@@ -4,6 +4,9 @@ fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 use crate::array_control_vol_and_fluid_component_collections::
 fluid_component_collection::fluid_component_collection::FluidComponentCollection;
 
+use crate::array_control_vol_and_fluid_component_collections::
+fluid_component_collection::centrifugal_pump::CentrifugalPump;
+
 use super::*;
 
 /// builds a dhx branch to simulate isothermal testing of ciet
@@ -125,3 +128,21 @@ pub fn ctah_branch_builder_isothermal_test(
     ctah_branch
 }
 
+
+/// builds the ctah branch to simulate isothermal testing of ciet,
+/// with the ctah pump driven by a centrifugal pump model
+///
+/// the pump pressure depends on the ctah branch mass flowrate, 
+/// so this is meant to be rebuilt within a root finder or 
+/// every timestep, see new_ctah_centrifugal_pump
+pub fn ctah_branch_builder_with_centrifugal_pump(
+    pump: &CentrifugalPump,
+    ctah_branch_mass_flowrate: MassRate,
+    initial_temperature: ThermodynamicTemperature) -> FluidComponentCollection {
+
+    let mut ctah_pump = new_ctah_pump(initial_temperature);
+    let pump_pressure = pump.set_internal_pressure_source(
+        &mut ctah_pump, ctah_branch_mass_flowrate);
+
+    ctah_branch_builder_isothermal_test(pump_pressure, initial_temperature)
+}
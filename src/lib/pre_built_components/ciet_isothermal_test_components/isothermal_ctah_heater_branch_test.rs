@@ -18,8 +18,6 @@ pub fn heater_branch_with_heater_v2_test(){
     use crate::pre_built_components::ciet_isothermal_test_components::*;
     use crate::array_control_vol_and_fluid_component_collections::
         fluid_component_collection::fluid_component_collection::FluidComponentCollection;
-    use crate::array_control_vol_and_fluid_component_collections::
-        fluid_component_collection::fluid_component_collection::FluidComponentCollectionOreintation;
     use crate::array_control_vol_and_fluid_component_collections::
         fluid_component_collection::fluid_component::FluidComponent;
     use uom::si::mass_rate::kilogram_per_second;
@@ -94,21 +92,19 @@ pub fn heater_branch_with_heater_v2_test(){
             );
 
 
-    let heater_branch = 
-        FluidComponentCollection{
-            components: vec![
-                branch_5_component,
-                pipe_4_component,
-                pipe_3_component,
-                static_mixer_2_component,
-                static_mixer_pipe_2a_component,
-                heater_top_head_1a_component,
-                heater_1_component,
-                heater_bottom_head_1b_component,
-                pipe_18_component
-            ],
-            orientation: FluidComponentCollectionOreintation::Series,
-        };
+    let mut heater_branch = 
+        FluidComponentCollection::new_series_component_collection();
+    heater_branch.set_fluid_component_vector(vec![
+            branch_5_component,
+            pipe_4_component,
+            pipe_3_component,
+            static_mixer_2_component,
+            static_mixer_pipe_2a_component,
+            heater_top_head_1a_component,
+            heater_1_component,
+            heater_bottom_head_1b_component,
+            pipe_18_component
+        ]);
 
 
     // let's check the hydrostatic pressure, 0.0 kg/s fluid flow 
@@ -594,6 +590,107 @@ pub fn isothermal_ctah_and_heater_branch_code_to_code_verification_test(){
 
 }
 
+/// the ctah pump driven by the centrifugal pump model in the 
+/// ctah and heater loop, the pump trips and coasts down
+///
+/// the ctah and heater branches are in parallel, so the ctah branch 
+/// flowrate m is found where the pressure change across the ctah 
+/// branch (with the pump pressure at m) equals that across the 
+/// heater branch at -m
+///
+/// after the trip, both the shaft speed and the flowrate should fall 
+/// steadily. Since the hydraulic torque depends on flow, the pump 
+/// in the loop should also slow down faster than one with the 
+/// flow held at zero
+#[test]
+pub fn ctah_pump_coastdown_in_ctah_and_heater_loop(){
+
+    use uom::si::time::second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::centrifugal_pump::CentrifugalPump;
+    use super::new_ctah_centrifugal_pump;
+    use super::new_ctah_pump;
+    use super::ciet_branch_builders_isothermal::heater_branch_builder_isothermal_test;
+    use super::ciet_branch_builders_isothermal::ctah_branch_builder_with_centrifugal_pump;
+    use crate::array_control_vol_and_fluid_component_collections::
+        fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+
+    let test_temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    let heater_branch = heater_branch_builder_isothermal_test(test_temperature);
+    let fluid_density = new_ctah_pump(test_temperature)
+        .get_fluid_density_immutable_at_ref_temperature();
+
+    let get_ctah_branch_mass_flowrate = |pump: &CentrifugalPump| -> MassRate {
+
+        let pressure_change_difference = |mass_flowrate_kg_per_s: f64| -> f64 {
+            let mass_flowrate = 
+                MassRate::new::<kilogram_per_second>(mass_flowrate_kg_per_s);
+            let ctah_branch = ctah_branch_builder_with_centrifugal_pump(
+                pump, mass_flowrate, test_temperature);
+
+            (ctah_branch.get_pressure_change(mass_flowrate) 
+             - heater_branch.get_pressure_change(-mass_flowrate))
+                .get::<pascal>()
+        };
+
+        let mut convergency = SimpleConvergency { eps:1e-12_f64, max_iter:50 };
+
+        let mass_flowrate_kg_per_s = find_root_brent(
+            -0.5, 0.5, 
+            &pressure_change_difference, 
+            &mut convergency).unwrap();
+
+        MassRate::new::<kilogram_per_second>(mass_flowrate_kg_per_s)
+    };
+
+    let mut pump = new_ctah_centrifugal_pump(test_temperature).unwrap();
+    let timestep = Time::new::<second>(1.0);
+
+    // run with the motor on for a while
+    let mut mass_flowrate = MassRate::ZERO;
+    for _ in 0..5 {
+        mass_flowrate = get_ctah_branch_mass_flowrate(&pump);
+        pump.advance_timestep(timestep, mass_flowrate/fluid_density).unwrap();
+    }
+
+    // the pump runs near its rated point
+    approx::assert_relative_eq!(
+        mass_flowrate.get::<kilogram_per_second>(),
+        0.15,
+        max_relative = 0.1);
+    approx::assert_relative_eq!(
+        pump.get_speed_ratio().get::<uom::si::ratio::ratio>(),
+        1.0,
+        max_relative = 0.1);
+
+    pump.trip();
+    let mut pump_without_flow = pump;
+    let mass_flowrate_before_trip = mass_flowrate;
+
+    for _ in 0..20 {
+        let previous_speed = pump.get_shaft_speed();
+        let previous_mass_flowrate = mass_flowrate;
+
+        mass_flowrate = get_ctah_branch_mass_flowrate(&pump);
+        pump.advance_timestep(timestep, mass_flowrate/fluid_density).unwrap();
+        pump_without_flow.advance_timestep(timestep, 
+            VolumeRate::ZERO).unwrap();
+
+        assert!(pump.get_shaft_speed() < previous_speed);
+        assert!(mass_flowrate < previous_mass_flowrate);
+        assert!(mass_flowrate > MassRate::ZERO);
+    }
+
+    assert!(pump_without_flow.get_shaft_speed() > pump.get_shaft_speed());
+
+    // after 20 s, the pump is at about 60% of rated speed, and the 
+    // flow falls at least as quickly as the speed
+    let speed_ratio = pump.get_speed_ratio().get::<uom::si::ratio::ratio>();
+    assert!(speed_ratio < 0.7);
+    assert!(mass_flowrate < mass_flowrate_before_trip * speed_ratio);
+}
+
 /// specific functions and traits meant to solve mass flowrate 
 /// and pressure drop for CIET only
 pub trait IsothermalCIETSolvers{
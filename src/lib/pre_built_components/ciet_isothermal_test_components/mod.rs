@@ -69,6 +69,10 @@ use uom::si::pressure::atmosphere;
 
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::centrifugal_pump::CentrifugalPump;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::absolute_pressure::GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED;
+use crate::tuas_lib_error::TuasLibError;
 
 use super::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
 use super::non_insulated_fluid_components::NonInsulatedFluidComponent;
@@ -821,6 +825,47 @@ pub fn new_ctah_pump(initial_temperature: ThermodynamicTemperature) -> NonInsula
 
 }
 
+/// creates a centrifugal pump model to drive the ctah pump
+/// (new_ctah_pump), so that pump trips and coastdowns can be 
+/// simulated rather than setting the pump pressure directly
+///
+/// the rated point is placed near the upper end of the isothermal 
+/// ctah and heater branch data, 0.15 kg/s at 12500 Pa, 
+/// with a shutoff pressure of 15625 Pa. The speed, efficiency and 
+/// inertia are representative of a small centrifugal pump, 
+/// not measured values
+///
+/// the pump uses the therminol density in the ctah pump at the 
+/// given temperature
+pub fn new_ctah_centrifugal_pump(
+    initial_temperature: ThermodynamicTemperature) 
+    -> Result<CentrifugalPump, TuasLibError> {
+
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::acceleration::meter_per_second_squared;
+    use uom::si::angular_velocity::revolution_per_minute;
+    use uom::si::moment_of_inertia::kilogram_square_meter;
+
+    let fluid_density = new_ctah_pump(initial_temperature)
+        .get_fluid_density_immutable_at_ref_temperature();
+    let g = Acceleration::new::<meter_per_second_squared>(
+        GRAVITATIONAL_ACCELERATION_METER_PER_SECOND_SQUARED);
+
+    let rated_mass_flowrate = MassRate::new::<kilogram_per_second>(0.15);
+    let rated_pressure = Pressure::new::<pascal>(12500.0);
+    let shutoff_pressure = Pressure::new::<pascal>(15625.0);
+
+    CentrifugalPump::new(
+        AngularVelocity::new::<revolution_per_minute>(1750.0),
+        rated_mass_flowrate / fluid_density,
+        rated_pressure / (fluid_density * g),
+        shutoff_pressure / (fluid_density * g),
+        Ratio::new::<ratio>(0.3),
+        fluid_density,
+        MomentOfInertia::new::<kilogram_square_meter>(0.005))
+}
+
 /// creates a new component for CIET using the RELAP5-3D and SAM parameters 
 ///
 /// pipe number 13 in CIET's CTAH branch
@@ -1275,6 +1275,14 @@ pub fn three_branch_ciet_ver3(
             let tube_side_total_mass_flowrate = -counter_clockwise_dracs_flowrate;
             let shell_side_total_mass_flowrate = dhx_flow;
 
+            // the dhx is counter current, unless flow through one 
+            // side reverses
+            dhx_sthe.try_set_flow_arrangement(
+                crate::pre_built_components::shell_and_tube_heat_exchanger::
+                flow_arrangement::ShellAndTubeFlowArrangement::
+                single_pass_from_mass_flowrate_directions(
+                    tube_side_total_mass_flowrate, 
+                    shell_side_total_mass_flowrate)).unwrap();
             dhx_sthe.lateral_and_miscellaneous_connections(
                 prandtl_wall_correction_setting, 
                 tube_side_total_mass_flowrate, 
@@ -28,6 +28,15 @@ use crate::
 array_control_vol_and_fluid_component_collections::
 fluid_component_collection::
 fluid_component_super_collection::FluidComponentSuperCollection;
+use crate::
+array_control_vol_and_fluid_component_collections::
+fluid_component_collection::
+fluid_component_super_collection::ParallelBranchSolution;
+use crate::
+array_control_vol_and_fluid_component_collections::
+fluid_component_collection::
+root_finding::RootFindingSettings;
+use crate::tuas_lib_error::TuasLibError;
 
 use crate::pre_built_components::
 insulated_pipes_and_fluid_components::InsulatedFluidComponent;
@@ -784,6 +793,26 @@ pub fn get_mass_flowrate_vector_for_dhx_heater_and_ctah_branches
 
 }
 
+/// fluid mechanics bit for parallel branches in the primary loop,
+/// warm started from the solution of the previous timestep
+///
+/// basically the net flowrate through the branches as a whole 
+/// is zero. The branches are usually rebuilt every timestep, so 
+/// the previous solution (if any) is kept by the caller, and is 
+/// replaced by the new solution for the next timestep
+///
+/// note, the mass flowrate order depends on how u add the branches 
+pub fn try_get_branch_mass_flowrates_with_previous_solution(
+    parallel_branches: &FluidComponentSuperCollection,
+    previous_parallel_solution: &mut Option<ParallelBranchSolution>) -> 
+Result<ParallelBranchSolution, TuasLibError> {
+
+    parallel_branches.solve_parallel_branch_flows(
+        MassRate::ZERO, 
+        previous_parallel_solution,
+        &RootFindingSettings::default())
+}
+
 /// fluid mechanics calcs, specific to the primary loop
 /// note that this only works if the components are correct
 /// obtains mass flowrate across the primary loop 
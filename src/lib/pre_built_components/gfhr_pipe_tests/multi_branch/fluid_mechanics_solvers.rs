@@ -1,6 +1,9 @@
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollection;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::FluidComponentCollectionMethods;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_super_collection::FluidComponentSuperCollection;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_super_collection::ParallelBranchSolution;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::root_finding::RootFindingSettings;
+use crate::tuas_lib_error::TuasLibError;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::super_collection_series_and_parallel_functions::FluidComponentSuperCollectionParallelAssociatedFunctions;
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
@@ -65,10 +68,13 @@ pub(crate) fn four_branch_pri_and_intermediate_loop_single_time_step(
     // steam generator settings 
     steam_generator_tube_side_temperature: ThermodynamicTemperature,
     steam_generator_overall_ua: ThermalConductance,
+    // parallel branch solutions from the previous timestep
+    previous_parallel_solutions: &mut FHRParallelBranchSolutions,
 
     ) -> FHRThermalHydraulicsState {
 
-        // fluid mechnaics portion for both loops
+        // fluid mechnaics portion for both loops, warm started 
+        // from the previous timestep
 
 
         let (reactor_branch_flow, downcomer_branch_1_flow,
@@ -93,7 +99,8 @@ pub(crate) fn four_branch_pri_and_intermediate_loop_single_time_step(
                 fhr_intrmd_loop_pump_16, 
                 fhr_pipe_15, 
                 fhr_steam_generator_shell_side_14, 
-                fhr_pipe_13);
+                fhr_pipe_13,
+                previous_parallel_solutions).unwrap();
 
         // thermal hydraulics part
         //
@@ -859,6 +866,16 @@ pub(crate) fn four_branch_pri_and_intermediate_loop_single_time_step(
         return fhr_state;
 }
 
+/// parallel branch solutions of the gFHR primary and intermediate 
+/// loops, kept between timesteps to warm start the flow solvers
+#[derive(Debug,Clone,Default)]
+pub struct FHRParallelBranchSolutions {
+    /// reactor, downcomer and ihx branches
+    pub pri_loop_branches: Option<ParallelBranchSolution>,
+    /// ihx and steam generator branches
+    pub intrmd_loop_branches: Option<ParallelBranchSolution>,
+}
+
 #[derive(Debug,Clone)]
 pub(crate) struct FHRThermalHydraulicsState {
     /// reactor branch flow (upwards through the core)
@@ -970,8 +987,12 @@ pub fn four_branch_pri_and_intermediate_loop_fluid_mechanics_only(
     fhr_pipe_15: &InsulatedFluidComponent,
     fhr_steam_generator_shell_side_14: &NonInsulatedFluidComponent,
     fhr_pipe_13: &InsulatedFluidComponent,
+    // parallel branch solutions from the previous timestep, 
+    // updated with the new solutions
+    previous_parallel_solutions: &mut FHRParallelBranchSolutions,
 
-    ) -> (MassRate, MassRate, MassRate, MassRate, MassRate, MassRate){
+    ) -> Result<(MassRate, MassRate, MassRate, MassRate, MassRate, MassRate),
+    TuasLibError>{
 
         // pri loop
 
@@ -1071,13 +1092,13 @@ pub fn four_branch_pri_and_intermediate_loop_fluid_mechanics_only(
 
         pri_loop_branches.fluid_component_super_vector.push(pri_loop_intermediate_heat_exchanger_branch);
 
-        let pressure_change_across_each_branch_pri_loop = 
-            pri_loop_branches.get_pressure_change(MassRate::ZERO);
-
+        let pri_loop_solution = pri_loop_branches.solve_parallel_branch_flows(
+            MassRate::ZERO, 
+            &mut previous_parallel_solutions.pri_loop_branches,
+            &RootFindingSettings::default())?;
 
         let pri_loop_mass_rate_vector 
-            = pri_loop_branches.get_mass_flowrate_across_each_parallel_branch(
-                pressure_change_across_each_branch_pri_loop);
+            = pri_loop_solution.branch_mass_flowrates;
 
         let (reactor_branch_flow, downcomer_branch_1_flow,
             downcomer_branch_2_flow, intermediate_heat_exchanger_branch_flow)
@@ -1096,20 +1117,21 @@ pub fn four_branch_pri_and_intermediate_loop_fluid_mechanics_only(
         intrmd_loop_branches.fluid_component_super_vector.push(
             intrmd_loop_steam_generator_branch);
 
-        let pressure_change_across_each_branch_intrmd_loop = 
-            intrmd_loop_branches.get_pressure_change(MassRate::ZERO);
+        let intrmd_loop_solution = intrmd_loop_branches.solve_parallel_branch_flows(
+            MassRate::ZERO, 
+            &mut previous_parallel_solutions.intrmd_loop_branches,
+            &RootFindingSettings::default())?;
 
         let intrmd_loop_mass_rate_vector 
-            = intrmd_loop_branches.get_mass_flowrate_across_each_parallel_branch(
-                pressure_change_across_each_branch_intrmd_loop);
+            = intrmd_loop_solution.branch_mass_flowrates;
         let (intrmd_loop_ihx_br_flow, intrmd_loop_steam_gen_br_flow) = 
             (intrmd_loop_mass_rate_vector[0],
              intrmd_loop_mass_rate_vector[1]);
 
 
-        return (reactor_branch_flow, downcomer_branch_1_flow,
+        Ok((reactor_branch_flow, downcomer_branch_1_flow,
             downcomer_branch_2_flow, intermediate_heat_exchanger_branch_flow,
-            intrmd_loop_ihx_br_flow, intrmd_loop_steam_gen_br_flow);
+            intrmd_loop_ihx_br_flow, intrmd_loop_steam_gen_br_flow))
 }
 /// for the gFHR primary loop,
 /// there are four branches that need to be solved for flowrate 
@@ -15,6 +15,7 @@ use crate::pre_built_components::gfhr_pipe_tests::components::new_fhr_pipe_10;
 use crate::pre_built_components::gfhr_pipe_tests::components::new_fhr_intermediate_loop_steam_generator_shell_side_14;
 use crate::pre_built_components::gfhr_pipe_tests::components::new_fhr_intermediate_loop_pump_16;
 use crate::pre_built_components::gfhr_pipe_tests::multi_branch::fluid_mechanics_solvers::four_branch_pri_and_intermediate_loop_fluid_mechanics_only;
+use crate::pre_built_components::gfhr_pipe_tests::multi_branch::fluid_mechanics_solvers::FHRParallelBranchSolutions;
 use uom::si::f64::*;
 use uom::si::thermodynamic_temperature::degree_celsius;
 use uom::si::pressure::megapascal;
@@ -79,6 +80,9 @@ pub fn test_fhr_four_branch_solver_pri_and_intrmd_loop_isothermal_check(){
     let pri_loop_pump_pressure = Pressure::new::<megapascal>(-0.2);
     let intrmd_loop_pump_pressure = Pressure::new::<kilopascal>(-150.0);
 
+    let mut previous_parallel_solutions = 
+        FHRParallelBranchSolutions::default();
+
     let (reactor_flow, downcomer_branch_1_flow, 
         downcomer_branch_2_flow, intermediate_heat_exchanger_branch_flow,
         intrmd_loop_ihx_br_flow,
@@ -102,7 +106,8 @@ pub fn test_fhr_four_branch_solver_pri_and_intrmd_loop_isothermal_check(){
             &fhr_int_loop_pump_16, 
             &fhr_pipe_15, 
             &fhr_steam_generator_shell_side_14, 
-            &fhr_pipe_13);
+            &fhr_pipe_13,
+            &mut previous_parallel_solutions).unwrap();
 
     dbg!(&(reactor_flow, downcomer_branch_1_flow, 
             downcomer_branch_2_flow, intermediate_heat_exchanger_branch_flow,
@@ -203,6 +208,9 @@ pub fn test_fhr_four_branch_solver_pri_and_intrmd_loop_zero_flow(){
     let pri_loop_pump_pressure = Pressure::new::<megapascal>(0.0);
     let intrmd_loop_pump_pressure = Pressure::new::<kilopascal>(0.0);
 
+    let mut previous_parallel_solutions = 
+        FHRParallelBranchSolutions::default();
+
     let (reactor_flow, downcomer_branch_1_flow, 
         downcomer_branch_2_flow, intermediate_heat_exchanger_branch_flow,
         intrmd_loop_ihx_br_flow,
@@ -227,7 +235,8 @@ pub fn test_fhr_four_branch_solver_pri_and_intrmd_loop_zero_flow(){
             &fhr_int_loop_pump_16, 
             &fhr_pipe_15, 
             &fhr_steam_generator_shell_side_14, 
-            &fhr_pipe_13);
+            &fhr_pipe_13,
+            &mut previous_parallel_solutions).unwrap();
 
     dbg!(&(reactor_flow, downcomer_branch_1_flow, 
             downcomer_branch_2_flow, intermediate_heat_exchanger_branch_flow,
@@ -9,7 +9,7 @@ pub mod multi_branch_solvers;
 /// contains code iterative solution for single branches 
 pub mod single_branch_solvers;
 
-use fluid_mechanics_solvers::{four_branch_pri_and_intermediate_loop_single_time_step, FHRThermalHydraulicsState};
+use fluid_mechanics_solvers::{four_branch_pri_and_intermediate_loop_single_time_step, FHRThermalHydraulicsState, FHRParallelBranchSolutions};
 use uom::si::power::megawatt;
 use uom::si::pressure::megapascal;
 use uom::si::thermal_conductance::watt_per_kelvin;
@@ -146,6 +146,9 @@ pub(crate) fn test_fhr_four_branch_full_th_long_regression(){
         = ThermalConductance::new::<watt_per_kelvin>(1.2e5);
 
     // start with some initial flow rates
+    let mut previous_parallel_solutions = 
+        FHRParallelBranchSolutions::default();
+
     let (mut reactor_branch_flow, mut downcomer_branch_1_flow, 
         mut downcomer_branch_2_flow, mut intermediate_heat_exchanger_branch_flow,
         mut intrmd_loop_ihx_br_flow,
@@ -170,7 +173,7 @@ pub(crate) fn test_fhr_four_branch_full_th_long_regression(){
             &fhr_pipe_15, 
             &fhr_steam_generator_shell_side_14, 
             &fhr_pipe_13,
-            );
+            &mut previous_parallel_solutions).unwrap();
 
     let mut fhr_state = FHRThermalHydraulicsState {
         downcomer_branch_1_flow,
@@ -236,6 +239,7 @@ pub(crate) fn test_fhr_four_branch_full_th_long_regression(){
             &mut top_mixing_node_intrmd_loop,
             steam_generator_tube_side_temperature,
             steam_generator_overall_ua,
+            &mut previous_parallel_solutions,
             );
 
 
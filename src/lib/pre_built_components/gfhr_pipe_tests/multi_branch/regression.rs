@@ -29,6 +29,7 @@ use crate::pre_built_components::gfhr_pipe_tests::components::new_fhr_intermedia
 use crate::pre_built_components::gfhr_pipe_tests::components::new_fhr_intermediate_loop_pump_16;
 use crate::pre_built_components::gfhr_pipe_tests::multi_branch::fluid_mechanics_solvers::four_branch_pri_loop_flowrates_parallel_debug_library;
 use crate::pre_built_components::gfhr_pipe_tests::multi_branch::fluid_mechanics_solvers::four_branch_pri_and_intermediate_loop_fluid_mechanics_only;
+use crate::pre_built_components::gfhr_pipe_tests::multi_branch::fluid_mechanics_solvers::FHRParallelBranchSolutions;
 use crate::pre_built_components::gfhr_pipe_tests::multi_branch::FHRThermalHydraulicsState;
 use crate::pre_built_components::gfhr_pipe_tests::multi_branch::four_branch_pri_and_intermediate_loop_single_time_step;
 use uom::si::f64::*;
@@ -122,6 +123,9 @@ pub(crate) fn test_fhr_four_branch_solver_pri_and_intrmd_loop_full_th_short_regr
         = ThermalConductance::new::<watt_per_kelvin>(1.2e5);
 
     // start with some initial flow rates
+    let mut previous_parallel_solutions = 
+        FHRParallelBranchSolutions::default();
+
     let (mut reactor_branch_flow, mut downcomer_branch_1_flow, 
         mut downcomer_branch_2_flow, mut intermediate_heat_exchanger_branch_flow,
         mut intrmd_loop_ihx_br_flow,
@@ -146,7 +150,7 @@ pub(crate) fn test_fhr_four_branch_solver_pri_and_intrmd_loop_full_th_short_regr
             &fhr_pipe_15, 
             &fhr_steam_generator_shell_side_14, 
             &fhr_pipe_13,
-            );
+            &mut previous_parallel_solutions).unwrap();
 
     let mut fhr_state = FHRThermalHydraulicsState {
         downcomer_branch_1_flow,
@@ -212,6 +216,7 @@ pub(crate) fn test_fhr_four_branch_solver_pri_and_intrmd_loop_full_th_short_regr
             &mut top_mixing_node_intrmd_loop,
             steam_generator_tube_side_temperature,
             steam_generator_overall_ua,
+            &mut previous_parallel_solutions,
             );
 
 
@@ -169,6 +169,30 @@ pub fn link_heat_transfer_entity(entity_1: &mut HeatTransferEntity,
    
 }
 
+/// links a single cv (eg. the inner or outer surface node of 
+/// a cylindrical or spherical conduction array) to a 
+/// HeatTransferEntity
+///
+/// the single cv is cloned into a HeatTransferEntity, 
+/// linked using link_heat_transfer_entity, and then 
+/// put back into the array
+pub(crate) fn link_single_cv_to_heat_transfer_entity(
+    single_cv: &mut SingleCVNode,
+    other_entity: &mut HeatTransferEntity,
+    interaction: HeatTransferInteractionType) -> Result<(), TuasLibError> {
+
+    let mut surface_entity: HeatTransferEntity = 
+        single_cv.clone().into();
+
+    link_heat_transfer_entity(&mut surface_entity, 
+        other_entity, 
+        interaction)?;
+
+    *single_cv = surface_entity.try_into()?;
+
+    Ok(())
+}
+
 
 // the job of this function is to take in a control volume 
 // and then mutate it by calculating its interaction
@@ -15,6 +15,11 @@ impl InsulatedFluidComponent {
         self.pipe_fluid_array.advance_timestep_mut_self(timestep)?;
         self.pipe_shell.advance_timestep_mut_self(timestep)?;
         self.insulation.advance_timestep_mut_self(timestep)?;
+
+        // fouling grows with operating time
+        if let Some(pipe_fouling) = self.options.pipe_fouling.as_mut() {
+            pipe_fouling.advance_operating_time(timestep);
+        }
         Ok(())
         
     }
@@ -3,6 +3,10 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::heat_transfer_correlations::ambient_heat_transfer::AmbientHeatTransferModel;
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::RadiationToSurroundings;
+use crate::heat_transfer_correlations::fouling_and_contact_resistance::FoulingResistance;
+use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityMonitor;
 
 use super::heat_transfer_entities::cv_types::CVType;
 use super::heat_transfer_entities::HeatTransferEntity;
@@ -49,6 +53,11 @@ pub struct InsulatedFluidComponent {
     /// pipe heat transfer coefficient to ambient
     pub heat_transfer_to_ambient: HeatTransfer,
 
+    /// optional physics (ambient heat transfer models, fouling, 
+    /// contact conductance, correlation validity monitor and 
+    /// developing flow), set using set_options
+    options: InsulatedFluidComponentOptions,
+
     /// pipe outer diameter (tube)
     pub tube_od: Length,
 
@@ -69,6 +78,43 @@ pub struct InsulatedFluidComponent {
 
 }
 
+/// optional physics for the insulated fluid component 
+///
+/// these are all off by default, so that the component has a clean 
+/// pipe surface, perfect contact between the pipe shell and 
+/// insulation and a constant heat transfer coefficient to ambient
+#[derive(Clone,Debug,PartialEq,Default)]
+pub struct InsulatedFluidComponentOptions {
+
+    /// optional temperature dependent model for the heat transfer 
+    /// coefficient to ambient (eg. natural convection), 
+    /// if None, heat_transfer_to_ambient is used
+    pub ambient_heat_transfer_model: Option<AmbientHeatTransferModel>,
+
+    /// optional radiation from the outer surface to the surroundings, 
+    /// this is linearised and added in parallel to the heat transfer 
+    /// to ambient every timestep
+    pub radiation_to_surroundings: Option<RadiationToSurroundings>,
+
+    /// optional fouling on the inner (wetted) surface of the pipe
+    pub pipe_fouling: Option<FoulingResistance>,
+
+    /// optional contact (gap) conductance between the pipe shell 
+    /// and insulation, if None, there is perfect contact
+    pub pipe_to_insulation_contact_conductance: Option<HeatTransfer>,
+
+    /// checks the nusselt and friction factor correlations against 
+    /// their validity envelopes and reports extrapolations
+    pub correlation_validity_monitor: CorrelationValidityMonitor,
+
+    /// if true, the fluid to pipe shell conductance varies from 
+    /// node to node using local nusselt numbers measured from the 
+    /// inlet, so that developing flow (entrance) effects are 
+    /// concentrated at the inlet. The component average is unchanged
+    pub local_developing_flow_heat_transfer: bool,
+
+}
+
 impl InsulatedFluidComponent {
 
     /// constructs a new insulated pipe
@@ -179,6 +225,7 @@ impl InsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            options: InsulatedFluidComponentOptions::default(),
             tube_od: shell_od,
             tube_id: shell_id,
             insulation_od: shell_od+2.0*insulation_thickness,
@@ -284,6 +331,7 @@ impl InsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            options: InsulatedFluidComponentOptions::default(),
             tube_od: shell_od,
             tube_id: shell_id,
             insulation_id: shell_od,
@@ -293,6 +341,16 @@ impl InsulatedFluidComponent {
             insulation: CVType::SolidArrayCV(insulation).into(),
         };
     }
+
+    /// returns the optional physics of the component
+    pub fn get_options(&self) -> &InsulatedFluidComponentOptions {
+        &self.options
+    }
+
+    /// sets the optional physics of the component
+    pub fn set_options(&mut self, options: InsulatedFluidComponentOptions) {
+        self.options = options;
+    }
 }
 
 
@@ -5,6 +5,7 @@ use uom::ConstZero;
 use uom::si::pressure::atmosphere;
 use uom::si::f64::*;
 use ndarray::*;
+use std::f64::consts::PI;
 use super::InsulatedFluidComponent;
 use crate::heat_transfer_correlations::thermal_resistance::try_get_thermal_conductance_annular_cylinder;
 use crate::pre_built_components::heat_transfer_entities::preprocessing::try_get_thermal_conductance_based_on_interaction;
@@ -17,6 +18,8 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::RadiationToSurroundings;
+use crate::heat_transfer_correlations::fouling_and_contact_resistance::{try_add_contact_resistance_in_series, try_add_fouling_resistance_in_series};
 use crate::tuas_lib_error::TuasLibError;
 
 impl InsulatedFluidComponent {
@@ -105,7 +108,7 @@ impl InsulatedFluidComponent {
         // |                        |               |               |
         //
         // 1. we'll need the ambient to insulation midpoint (nodal) thermal conductance
-        let heat_transfer_to_ambient: HeatTransfer = self.heat_transfer_to_ambient;
+        let heat_transfer_to_ambient: HeatTransfer = self.try_get_heat_transfer_to_ambient()?;
 
 
         let insulation_to_air_nodal_conductance: ThermalConductance 
@@ -117,8 +120,16 @@ impl InsulatedFluidComponent {
 
         self.set_mass_flowrate(mass_flowrate);
 
-        let pipe_shell_node_to_fluid_array_conductance: ThermalConductance 
-        = self.get_fluid_array_node_to_pipe_shell_conductance(correct_prandtl_for_wall_temperatures)?;
+        // only the lateral connections record into the correlation 
+        // validity monitor, so each timestep is counted once
+        let local_developing_flow = self.options.local_developing_flow_heat_transfer;
+        let record_correlation_validity = true;
+
+        let pipe_shell_node_to_fluid_array_conductance_vector: Vec<ThermalConductance> 
+        = self.try_get_fluid_array_node_to_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures,
+            local_developing_flow,
+            record_correlation_validity)?;
 
 
         // 3. we'll need the shell midpoint to insulation midpoint thermal conductance
@@ -196,6 +207,21 @@ impl InsulatedFluidComponent {
                 ambient_temperature_vector
             )?;
 
+            // insulation to surroundings radiation, linearised about 
+            // the current insulation temperatures
+
+            if let Some(radiation_to_surroundings) = self.options.radiation_to_surroundings {
+                let insulation_to_surroundings_conductance_vector = 
+                    self.get_radiation_to_surroundings_nodal_conductance_vector(
+                        radiation_to_surroundings)?;
+
+                insulation_array_clone.lateral_link_new_temperature_vector_conductance_vector(
+                    insulation_to_surroundings_conductance_vector,
+                    vec![radiation_to_surroundings.surroundings_temperature;
+                        number_of_temperature_nodes]
+                )?;
+            }
+
             // insulation to shell interaction 
 
             pipe_shell_clone.lateral_link_new_temperature_vector_avg_conductance(
@@ -210,13 +236,13 @@ impl InsulatedFluidComponent {
 
             // pipe_shell shell to fluid_array interaction
 
-            pipe_shell_clone.lateral_link_new_temperature_vector_avg_conductance(
-                pipe_shell_node_to_fluid_array_conductance,
+            pipe_shell_clone.lateral_link_new_temperature_vector_conductance_vector(
+                pipe_shell_node_to_fluid_array_conductance_vector.clone(),
                 fluid_temp_vector.clone()
             )?;
 
-            fluid_array_clone.lateral_link_new_temperature_vector_avg_conductance(
-                pipe_shell_node_to_fluid_array_conductance,
+            fluid_array_clone.lateral_link_new_temperature_vector_conductance_vector(
+                pipe_shell_node_to_fluid_array_conductance_vector,
                 pipe_temp_vector
             )?;
 
@@ -303,6 +329,33 @@ impl InsulatedFluidComponent {
 
 
 
+    /// obtains the heat transfer coefficient from the insulation 
+    /// to ambient
+    ///
+    /// this is heat_transfer_to_ambient unless an ambient heat 
+    /// transfer model is set, in which case it is calculated from 
+    /// the insulation bulk temperature and outer diameter
+    #[inline]
+    pub fn try_get_heat_transfer_to_ambient(&mut self) 
+        -> Result<HeatTransfer,TuasLibError> {
+
+        let ambient_heat_transfer_model = match self.options.ambient_heat_transfer_model {
+            Some(model) => model,
+            None => return Ok(self.heat_transfer_to_ambient),
+        };
+
+        let mut insulation_clone: SolidColumn = 
+        self.insulation.clone().try_into()?;
+
+        let insulation_shell_temperature: ThermodynamicTemperature 
+        = insulation_clone.try_get_bulk_temperature()?;
+
+        ambient_heat_transfer_model.try_get_heat_transfer_coefficient(
+            insulation_shell_temperature, 
+            self.ambient_temperature, 
+            self.insulation_od)
+    }
+
     /// obtains air to insulation shell conductance
     ///
     /// it goes roughly to the middle of the insulation
@@ -355,13 +408,117 @@ impl InsulatedFluidComponent {
     }
 
 
+    /// obtains the linearised radiation conductance from each 
+    /// insulation node to the surroundings
+    ///
+    /// the radiation heat transfer coefficient is calculated from 
+    /// each node temperature, and is in series with conduction 
+    /// through the outer half of the insulation
+    #[inline]
+    pub fn get_radiation_to_surroundings_nodal_conductance_vector(&mut self,
+        radiation_to_surroundings: RadiationToSurroundings)
+        -> Result<Vec<ThermalConductance>,TuasLibError> {
+
+        let insulation_clone: SolidColumn = 
+        self.insulation.clone().try_into()?;
+
+        let number_of_temperature_nodes = self.inner_nodes + 2;
+        let heated_length = self.get_component_length();
+        let insulation_id = self.insulation_id;
+        let insulation_od = self.insulation_od;
+
+        let cylinder_mid_diameter: Length = 0.5*(insulation_id+insulation_od);
+
+        let node_length = heated_length / 
+            number_of_temperature_nodes as f64;
+
+        let insulation_temp_vector: Vec<ThermodynamicTemperature> 
+        = insulation_clone.get_temperature_vector()?;
+
+        insulation_temp_vector.iter().map(|&node_temperature| {
+
+            let h_rad: HeatTransfer = radiation_to_surroundings
+                .get_linearised_heat_transfer_coefficient(node_temperature);
+
+            let insulation_surroundings_conductance_interaction: HeatTransferInteractionType
+            = HeatTransferInteractionType::
+                CylindricalConductionConvectionLiquidOutside(
+                    (insulation_clone.material_control_volume, 
+                        (insulation_od-cylinder_mid_diameter).into(),
+                        node_temperature,
+                        insulation_clone.pressure_control_volume),
+                    (h_rad,
+                        insulation_od.into(),
+                        node_length.into())
+                );
+
+            try_get_thermal_conductance_based_on_interaction(
+                radiation_to_surroundings.surroundings_temperature,
+                node_temperature,
+                insulation_clone.pressure_control_volume,
+                insulation_clone.pressure_control_volume,
+                insulation_surroundings_conductance_interaction,
+            )
+        }).collect()
+    }
+
     /// obtains fluid_array node to pipe_shell shell conductance
+    ///
+    /// this is based on the nusselt number of the whole component, 
+    /// so it is the same for every node
     #[inline]
     pub fn get_fluid_array_node_to_pipe_shell_conductance(
         &mut self,
         correct_prandtl_for_wall_temperatures: bool) 
         -> Result<ThermalConductance,TuasLibError> {
 
+        let local_developing_flow = false;
+        let record_correlation_validity = false;
+
+        let uniform_conductance_vector = 
+            self.try_get_fluid_array_node_to_pipe_shell_conductance_vector(
+                correct_prandtl_for_wall_temperatures,
+                local_developing_flow,
+                record_correlation_validity)?;
+
+        Ok(uniform_conductance_vector[0])
+    }
+
+    /// obtains fluid_array node to pipe_shell shell conductance 
+    /// for each node
+    ///
+    /// if local_developing_flow_heat_transfer is set, local 
+    /// nusselt numbers are used for each node, otherwise all 
+    /// nodes have the same conductance
+    #[inline]
+    pub fn get_fluid_array_node_to_pipe_shell_conductance_vector(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool) 
+        -> Result<Vec<ThermalConductance>,TuasLibError> {
+
+        let local_developing_flow = self.options.local_developing_flow_heat_transfer;
+        let record_correlation_validity = false;
+
+        self.try_get_fluid_array_node_to_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures,
+            local_developing_flow,
+            record_correlation_validity)
+    }
+
+    /// obtains fluid_array node to pipe_shell shell conductance 
+    /// for each node, optionally with local nusselt numbers
+    ///
+    /// the correlation validity monitor is only updated if 
+    /// record_correlation_validity is set, the public getters 
+    /// leave it alone so that they don't double count evaluations
+    #[inline]
+    fn try_get_fluid_array_node_to_pipe_shell_conductance_vector(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool,
+        local_developing_flow: bool,
+        record_correlation_validity: bool) 
+        -> Result<Vec<ThermalConductance>,TuasLibError> {
+
         // the thermal conductance here should be based on the 
         // nusselt number correlation
 
@@ -456,34 +613,41 @@ impl InsulatedFluidComponent {
             = self.pipe_fluid_array.clone().try_into()?;
 
         let nusselt_estimate: Ratio;
+        let wall_prandtl_number_for_nusselt: Ratio;
 
-        if !correct_prandtl_for_wall_temperatures {
-            // nusselt estimate is gotten straight from fluid array
-            nusselt_estimate = fluid_array.get_nusselt(
-                reynolds_number, 
-                bulk_prandtl_number, 
-                bulk_prandtl_number)?;
-        } else {
+        // the wall temperature is needed for wall prandtl number 
+        // corrections and for mixed convection corrections
+        // Firstly, get the shell temperature 
 
-            // in this case, we do wall correction, but must have a 
-            // bounded Prandtl number approach for the wall
-            // Firstly, get the shell temperature 
+        let mut wall_temperature_estimate = pipe_shell_clone
+            .try_get_bulk_temperature()?;
 
-            let mut wall_temperature_estimate = pipe_shell_clone
-                .try_get_bulk_temperature()?;
+        // then ensure that the wall temperature is within 
+        // the temperature bounds of the fluid properties
+        if wall_temperature_estimate > fluid_material.max_temperature() {
 
-            // then ensure that the wall temperature is within 
-            // the temperature bounds of the fluid properties
-            if wall_temperature_estimate > fluid_material.max_temperature() {
+            wall_temperature_estimate = fluid_material.max_temperature();
 
-                wall_temperature_estimate = fluid_material.max_temperature();
+        } else if wall_temperature_estimate < fluid_material.min_temperature() {
 
-            } else if wall_temperature_estimate < fluid_material.min_temperature() {
+            wall_temperature_estimate = fluid_material.min_temperature();
 
-                wall_temperature_estimate = fluid_material.min_temperature();
+        }
 
-            }
-            // then get the wall prandtl number
+        if !correct_prandtl_for_wall_temperatures {
+            // nusselt estimate is gotten straight from fluid array
+            nusselt_estimate = fluid_array.get_nusselt_with_wall_temperature(
+                reynolds_number, 
+                bulk_prandtl_number, 
+                bulk_prandtl_number,
+                wall_temperature_estimate)?;
+            wall_prandtl_number_for_nusselt = bulk_prandtl_number;
+        } else {
+
+            // in this case, we do wall correction, but must have a 
+            // bounded Prandtl number approach for the wall, 
+            // the wall temperature estimate is already bounded, 
+            // so get the wall prandtl number
 
             let wall_prandtl_number: Ratio 
                 = fluid_material.try_get_prandtl_liquid(
@@ -492,14 +656,25 @@ impl InsulatedFluidComponent {
                 )?;
 
 
-            nusselt_estimate = fluid_array.get_nusselt(
+            nusselt_estimate = fluid_array.get_nusselt_with_wall_temperature(
                 reynolds_number, 
                 bulk_prandtl_number, 
-                wall_prandtl_number)?;
+                wall_prandtl_number,
+                wall_temperature_estimate)?;
+            wall_prandtl_number_for_nusselt = wall_prandtl_number;
 
         }
 
 
+        // record whether the correlations are used within 
+        // their validity envelopes
+        if record_correlation_validity {
+            fluid_array.try_check_correlation_validity(
+                &mut self.options.correlation_validity_monitor,
+                reynolds_number,
+                bulk_prandtl_number)?;
+        }
+
         // now we can get the heat transfer coeff, 
 
         let h_to_fluid_array: HeatTransfer;
@@ -527,38 +702,68 @@ impl InsulatedFluidComponent {
 
         let cylinder_mid_diameter: Length = 0.5*(id+od);
 
+        let pipe_fouling = self.options.pipe_fouling;
+
+        let get_nodal_conductance = |h_to_fluid_array: HeatTransfer| 
+            -> Result<ThermalConductance,TuasLibError> {
+
+            let fluid_array_pipe_shell_conductance_interaction: HeatTransferInteractionType
+            = HeatTransferInteractionType::
+                CylindricalConductionConvectionLiquidInside(
+                    (solid_material.into(), 
+                        (cylinder_mid_diameter - id).into(),
+                        pipe_shell_surf_temperature,
+                        atmospheric_pressure),
+                    (h_to_fluid_array,
+                        id.into(),
+                        node_length.into())
+                );
+
+            // now based on conductance interaction, 
+            // we can obtain thermal conductance, the temperatures 
+            // and pressures don't really matter
+            //
+            // this is because all the thermal conductance data 
+            // has already been loaded into the thermal conductance 
+            // interaction object
+
+            let fluid_array_pipe_shell_nodal_thermal_conductance: ThermalConductance = 
+                try_get_thermal_conductance_based_on_interaction(
+                    fluid_temperature,
+                    pipe_shell_surf_temperature,
+                    atmospheric_pressure,
+                    atmospheric_pressure,
+                    fluid_array_pipe_shell_conductance_interaction)?;
 
 
-        let fluid_array_pipe_shell_conductance_interaction: HeatTransferInteractionType
-        = HeatTransferInteractionType::
-            CylindricalConductionConvectionLiquidInside(
-                (solid_material.into(), 
-                    (cylinder_mid_diameter - id).into(),
-                    pipe_shell_surf_temperature,
-                    atmospheric_pressure),
-                (h_to_fluid_array,
-                    id.into(),
-                    node_length.into())
-            );
+            // fouling on the inner surface of the pipe sits in series 
+            // with the convection and conduction resistances
+            try_add_fouling_resistance_in_series(
+                fluid_array_pipe_shell_nodal_thermal_conductance,
+                pipe_fouling,
+                PI * id * node_length)
+        };
 
-        // now based on conductance interaction, 
-        // we can obtain thermal conductance, the temperatures 
-        // and pressures don't really matter
-        //
-        // this is because all the thermal conductance data 
-        // has already been loaded into the thermal conductance 
-        // interaction object
+        if !local_developing_flow {
+            let fluid_array_pipe_shell_nodal_thermal_conductance = 
+                get_nodal_conductance(h_to_fluid_array)?;
 
-        let fluid_array_pipe_shell_nodal_thermal_conductance: ThermalConductance = 
-            try_get_thermal_conductance_based_on_interaction(
-                fluid_temperature,
-                pipe_shell_surf_temperature,
-                atmospheric_pressure,
-                atmospheric_pressure,
-                fluid_array_pipe_shell_conductance_interaction)?;
+            return Ok(vec![fluid_array_pipe_shell_nodal_thermal_conductance;
+                number_of_temperature_nodes]);
+        }
 
+        // for developing flow, the heat transfer coefficient 
+        // is scaled by the local nusselt number of each node, 
+        // measured from the inlet
+        let local_nusselt_ratio_vector: Vec<Ratio> = 
+            fluid_array.get_local_nusselt_ratio_vector(
+                reynolds_number, 
+                bulk_prandtl_number, 
+                wall_prandtl_number_for_nusselt)?;
 
-        return Ok(fluid_array_pipe_shell_nodal_thermal_conductance);
+        local_nusselt_ratio_vector.iter().map(|&local_nusselt_ratio| {
+            get_nodal_conductance(h_to_fluid_array * local_nusselt_ratio)
+        }).collect()
     }
 
     /// gets the reynolds number based on mass flworate and 
@@ -681,8 +886,15 @@ impl InsulatedFluidComponent {
 
         let total_resistance = insulation_material_resistance + solid_pipe_material_resistance;
 
+        // contact resistance between the pipe shell and insulation
+        // sits at the pipe outer diameter
+        let pipe_shell_to_insulation_nodal_conductance = 
+            try_add_contact_resistance_in_series(
+                1.0/total_resistance,
+                self.options.pipe_to_insulation_contact_conductance,
+                PI * tube_od * node_length)?;
 
-        return Ok(1.0/total_resistance);
+        return Ok(pipe_shell_to_insulation_nodal_conductance);
     }
 
     /// spawns a thread and moves the clone of the entire heater object into the 
@@ -0,0 +1,237 @@
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::boundary_conditions::BCType;
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::tuas_lib_error::TuasLibError;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+use uom::si::angle::degree;
+use uom::si::area::square_meter;
+use uom::si::f64::*;
+use uom::si::length::{meter, millimeter};
+use uom::si::power::watt;
+use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
+use uom::si::thermal_conductance::watt_per_kelvin;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::temperature_interval::kelvin as interval_kelvin;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::time::second;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::ConstZero;
+
+/// creates a 1 m long insulated therminol pipe with 10 nodes,
+/// based on pipe 6a of CIET
+fn new_one_meter_therminol_pipe() -> InsulatedFluidComponent {
+
+    let initial_temperature = ThermodynamicTemperature::new::<degree_celsius>(100.0);
+    let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    let fluid_pressure = Pressure::new::<atmosphere>(1.0);
+    let solid_pressure = Pressure::new::<atmosphere>(1.0);
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let pipe_length = Length::new::<meter>(1.0);
+    let flow_area = Area::new::<square_meter>(6.11e-4);
+    let incline_angle = Angle::new::<degree>(0.0);
+    let form_loss = Ratio::new::<ratio>(0.0);
+    let surface_roughness = Length::new::<millimeter>(0.015);
+    let shell_id = hydraulic_diameter;
+    let pipe_thickness = Length::new::<meter>(0.0027686);
+    let shell_od = shell_id + 2.0 * pipe_thickness;
+    let insulation_thickness = Length::new::<meter>(0.0508);
+    let htc_to_ambient = HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0);
+    let user_specified_inner_nodes = 8;
+
+    InsulatedFluidComponent::new_insulated_pipe(
+        initial_temperature,
+        ambient_temperature,
+        fluid_pressure,
+        solid_pressure,
+        flow_area,
+        incline_angle,
+        form_loss,
+        shell_id,
+        shell_od,
+        insulation_thickness,
+        pipe_length,
+        hydraulic_diameter,
+        SolidMaterial::SteelSS304L,
+        SolidMaterial::Fiberglass,
+        LiquidMaterial::TherminolVP1,
+        htc_to_ambient,
+        user_specified_inner_nodes,
+        surface_roughness)
+}
+
+/// with local developing flow heat transfer, the fluid to pipe shell
+/// conductance is highest at the inlet, which is the back for
+/// positive flow and the front for reverse flow
+///
+/// without it, all nodes have the same conductance as before
+#[test]
+pub fn local_developing_flow_conductance_follows_flow_direction(){
+
+    let mut pipe = new_one_meter_therminol_pipe();
+    let number_of_nodes = 10;
+    let correct_prandtl_for_wall_temperatures = false;
+
+    // Re is about 8000 at 100 degC, so this is turbulent flow
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.18);
+    pipe.set_mass_flowrate(mass_flowrate);
+
+    let average_conductance: ThermalConductance = pipe
+        .get_fluid_array_node_to_pipe_shell_conductance(
+            correct_prandtl_for_wall_temperatures).unwrap();
+
+    let uniform_conductance_vector = pipe
+        .get_fluid_array_node_to_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures).unwrap();
+
+    assert_eq!(uniform_conductance_vector,
+        vec![average_conductance; number_of_nodes]);
+
+    let mut options = pipe.get_options().clone();
+    options.local_developing_flow_heat_transfer = true;
+    pipe.set_options(options);
+
+    // the scalar conductance is still the component average
+    assert_eq!(pipe.get_fluid_array_node_to_pipe_shell_conductance(
+            correct_prandtl_for_wall_temperatures).unwrap(),
+        average_conductance);
+
+    let forward_conductance_vector = pipe
+        .get_fluid_array_node_to_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures).unwrap();
+
+    assert_eq!(forward_conductance_vector.len(), number_of_nodes);
+    assert!(forward_conductance_vector[0] > average_conductance);
+    assert!(forward_conductance_vector[number_of_nodes-1] < average_conductance);
+    for node_pair in forward_conductance_vector.windows(2) {
+        assert!(node_pair[0] > node_pair[1]);
+    }
+
+    // for reverse flow, the profile is mirrored
+    pipe.set_mass_flowrate(-mass_flowrate);
+
+    let reverse_conductance_vector = pipe
+        .get_fluid_array_node_to_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures).unwrap();
+
+    for (forward_conductance, reverse_conductance) in
+        forward_conductance_vector.iter()
+        .zip(reverse_conductance_vector.iter().rev()) {
+        approx::assert_relative_eq!(
+            forward_conductance.get::<watt_per_kelvin>(),
+            reverse_conductance.get::<watt_per_kelvin>(),
+            max_relative = 1e-12);
+    }
+
+}
+
+/// runs a heated pipe to (nearly) steady state and returns the
+/// outlet temperature, as well as the pipe shell to fluid
+/// temperature difference at the inlet and outlet nodes
+fn simulate_heated_pipe(local_developing_flow_heat_transfer: bool)
+    -> Result<(ThermodynamicTemperature, TemperatureInterval, TemperatureInterval),
+    TuasLibError>{
+
+    let mut pipe = new_one_meter_therminol_pipe();
+    let mut options = pipe.get_options().clone();
+    options.local_developing_flow_heat_transfer = local_developing_flow_heat_transfer;
+    pipe.set_options(options);
+
+    let inlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(100.0);
+    let mut inlet_bc: HeatTransferEntity = BCType::new_const_temperature(
+        inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.18);
+    let heater_power = Power::new::<watt>(300.0);
+    let timestep = Time::new::<second>(0.5);
+    let max_time = Time::new::<second>(300.0);
+    let mut simulation_time = Time::ZERO;
+    let correct_prandtl_for_wall_temperatures = false;
+
+    while max_time > simulation_time {
+
+        let fluid_array_clone: FluidArray =
+            pipe.pipe_fluid_array.clone().try_into()?;
+        let fluid_temperature_vector =
+            fluid_array_clone.get_temperature_vector()?;
+
+        let inlet_density = LiquidMaterial::TherminolVP1
+            .try_get_density(inlet_temperature)?;
+        let back_cv_density = LiquidMaterial::TherminolVP1
+            .try_get_density(fluid_temperature_vector[0])?;
+        let front_cv_density = LiquidMaterial::TherminolVP1
+            .try_get_density(*fluid_temperature_vector.last().unwrap())?;
+
+        pipe.pipe_fluid_array.link_to_back(
+            &mut inlet_bc,
+            HeatTransferInteractionType::new_advection_interaction(
+                mass_flowrate, inlet_density, back_cv_density))?;
+
+        pipe.pipe_fluid_array.link_to_front(
+            &mut outlet_bc,
+            HeatTransferInteractionType::new_advection_interaction(
+                mass_flowrate, front_cv_density, front_cv_density))?;
+
+        pipe.lateral_and_miscellaneous_connections(
+            mass_flowrate,
+            heater_power,
+            correct_prandtl_for_wall_temperatures)?;
+
+        pipe.advance_timestep(timestep)?;
+
+        simulation_time += timestep;
+    }
+
+    let fluid_array: FluidArray = pipe.pipe_fluid_array.clone().try_into()?;
+    let pipe_shell: SolidColumn = pipe.pipe_shell.clone().try_into()?;
+
+    let fluid_temperature_vector = fluid_array.get_temperature_vector()?;
+    let shell_temperature_vector = pipe_shell.get_temperature_vector()?;
+    let outlet_index = fluid_temperature_vector.len() - 1;
+
+    let inlet_wall_to_fluid_temperature_difference = TemperatureInterval::new::<interval_kelvin>(
+        shell_temperature_vector[0].get::<kelvin>()
+        - fluid_temperature_vector[0].get::<kelvin>());
+    let outlet_wall_to_fluid_temperature_difference = TemperatureInterval::new::<interval_kelvin>(
+        shell_temperature_vector[outlet_index].get::<kelvin>()
+        - fluid_temperature_vector[outlet_index].get::<kelvin>());
+
+    Ok((fluid_temperature_vector[outlet_index],
+        inlet_wall_to_fluid_temperature_difference,
+        outlet_wall_to_fluid_temperature_difference))
+}
+
+/// for a uniformly heated pipe, local developing flow heat transfer
+/// lowers the wall to fluid temperature difference at the inlet
+/// and raises it at the outlet,
+/// while the outlet temperature hardly changes as the heater
+/// power still goes into the fluid
+#[test]
+pub fn local_developing_flow_heated_pipe_wall_temperatures(){
+
+    let (uniform_outlet_temperature,
+        uniform_inlet_wall_to_fluid_temperature_difference,
+        uniform_outlet_wall_to_fluid_temperature_difference) =
+        simulate_heated_pipe(false).unwrap();
+
+    let (local_outlet_temperature,
+        local_inlet_wall_to_fluid_temperature_difference,
+        local_outlet_wall_to_fluid_temperature_difference) =
+        simulate_heated_pipe(true).unwrap();
+
+    assert!(uniform_inlet_wall_to_fluid_temperature_difference.get::<interval_kelvin>() > 0.0);
+    assert!(local_inlet_wall_to_fluid_temperature_difference
+        < uniform_inlet_wall_to_fluid_temperature_difference);
+    assert!(local_outlet_wall_to_fluid_temperature_difference
+        > uniform_outlet_wall_to_fluid_temperature_difference);
+
+    approx::assert_abs_diff_eq!(
+        local_outlet_temperature.get::<degree_celsius>(),
+        uniform_outlet_temperature.get::<degree_celsius>(),
+        epsilon = 0.05);
+}
@@ -61,3 +61,8 @@ pub mod axial_conduction_verification;
 
 /// for setting internal pressure source 
 pub mod fluid_mechanics;
+
+/// local (developing flow) nusselt numbers along the pipe, 
+/// these should concentrate entrance effects at the inlet 
+/// for either flow direction
+pub mod developing_flow;
@@ -2365,3 +2365,7 @@ pub mod type_conversion;
 
 /// calibration 
 pub mod calibration;
+
+/// pebble bed effective conductivity and representative 
+/// fuel pebbles for pebble bed cores
+pub mod pebble_bed;
@@ -0,0 +1,136 @@
+use uom::si::f64::*;
+
+use super::InsulatedPorousMediaFluidComponent;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::SphericalConduction1DArray;
+use crate::boundary_conditions::BCType;
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::boussinesq_thermophysical_properties::SolidMaterial;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::heat_transfer_correlations::pebble_bed_conductivity::zbs_effective_thermal_conductivity;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::tuas_lib_error::TuasLibError;
+
+/// for a pebble bed core, the interior_solid_array_for_porous_media
+/// represents all the pebbles at each axial node, lumped together.
+/// The nusselt correlation to the interior should then be the
+/// Wakao correlation (with the pebble diameter as hydraulic diameter),
+/// and the darcy loss correlation the Ergun or KTA correlations
+///
+/// the lumped pebbles do not give the fuel centreline temperature
+/// however. For that, each axial node can have a representative fuel
+/// pebble (a SphericalConduction1DArray), which is heated by its
+/// share of the porous media power and cooled by the fluid at that
+/// axial node
+impl InsulatedPorousMediaFluidComponent {
+
+    /// effective thermal conductivity of the pebble bed within this
+    /// component, using the ZBS model (conduction and radiation)
+    ///
+    /// the solid conductivity and bed temperature are taken from
+    /// the bulk temperature of the porous media interior,
+    /// and the fluid conductivity from the bulk fluid temperature
+    pub fn get_pebble_bed_effective_thermal_conductivity(&self,
+        porosity: Ratio,
+        pebble_emissivity: Ratio,
+        pebble_diameter: Length) -> Result<ThermalConductivity, TuasLibError> {
+
+        let mut pipe_fluid_arr_clone: FluidArray =
+            self.pipe_fluid_array.clone().try_into()?;
+        let mut interior_solid_array_clone: SolidColumn =
+            self.interior_solid_array_for_porous_media.clone().try_into()?;
+
+        let fluid_temperature: ThermodynamicTemperature
+            = pipe_fluid_arr_clone.try_get_bulk_temperature()?;
+        let bed_temperature: ThermodynamicTemperature
+            = interior_solid_array_clone.try_get_bulk_temperature()?;
+
+        let fluid_material: LiquidMaterial
+            = pipe_fluid_arr_clone.material_control_volume.try_into()?;
+        let solid_material: SolidMaterial
+            = interior_solid_array_clone.material_control_volume.try_into()?;
+
+        zbs_effective_thermal_conductivity(
+            solid_material.try_get_thermal_conductivity(bed_temperature)?,
+            fluid_material.try_get_thermal_conductivity(fluid_temperature)?,
+            porosity,
+            pebble_emissivity,
+            pebble_diameter,
+            bed_temperature)
+    }
+
+    /// advances one representative fuel pebble per axial node
+    ///
+    /// each pebble gets an even share of the porous media power
+    /// (the same even power distribution as in the lateral
+    /// connections), divided by the number of pebbles per node
+    ///
+    /// the outer surface of each pebble is linked to the fluid
+    /// temperature at its node, with the fluid side conductance of
+    /// the fluid to interior connection, again divided by the number
+    /// of pebbles per node. Only the convective part is used, as the
+    /// conduction within the pebble is calculated by the pebble itself.
+    /// The link helper adds the conduction through the outer half of the
+    /// surface shell in series with it
+    ///
+    /// this is one way coupled, the pebbles take their boundary
+    /// conditions from the component but do not change it. Use this
+    /// after the lateral connections are made, with the same
+    /// timestep as the component
+    pub fn advance_representative_fuel_pebbles(&self,
+        fuel_pebbles: &mut [SphericalConduction1DArray],
+        pebbles_per_node: f64,
+        porous_media_side_steady_state_power: Power,
+        prandtl_wall_correction_setting: bool,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        let number_of_temperature_nodes = self.inner_nodes + 2;
+
+        if fuel_pebbles.len() != number_of_temperature_nodes {
+            return Err(TuasLibError::GenericStringError(
+                format!("need one representative fuel pebble for each of the {} nodes",
+                    number_of_temperature_nodes)));
+        }
+
+        if pebbles_per_node <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "number of pebbles per node must be more than zero".to_string()));
+        }
+
+        let (nodalised_fluid_side_conductance, _nodalised_solid_side_conductance) =
+            self.get_interior_to_fluid_nodal_fluid_and_solid_side_conductances(
+                prandtl_wall_correction_setting)?;
+
+        let pebble_to_fluid_conductance: ThermalConductance =
+            nodalised_fluid_side_conductance / pebbles_per_node;
+
+        let power_per_pebble: Power = porous_media_side_steady_state_power
+            / number_of_temperature_nodes as f64
+            / pebbles_per_node;
+
+        let pipe_fluid_arr_clone: FluidArray =
+            self.pipe_fluid_array.clone().try_into()?;
+
+        let fluid_temperature_vector: Vec<ThermodynamicTemperature> =
+            pipe_fluid_arr_clone.get_temperature_vector()?;
+
+        for (fuel_pebble, fluid_temperature) in
+            fuel_pebbles.iter_mut().zip(fluid_temperature_vector) {
+
+                let mut fluid_at_node: HeatTransferEntity =
+                    BCType::new_const_temperature(fluid_temperature).into();
+
+                fuel_pebble.set_heat_generation(power_per_pebble);
+
+                fuel_pebble.link_outer_surface_to_heat_transfer_entity(
+                    &mut fluid_at_node,
+                    HeatTransferInteractionType::UserSpecifiedThermalConductance(
+                        pebble_to_fluid_conductance))?;
+
+                fuel_pebble.advance_timestep(timestep)?;
+            }
+
+        Ok(())
+    }
+}
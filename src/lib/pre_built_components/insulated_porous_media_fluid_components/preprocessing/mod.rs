@@ -579,6 +579,30 @@ impl InsulatedPorousMediaFluidComponent {
     #[inline]
     pub fn get_interior_to_fluid_nodal_conductance(
         &self, prandtl_wall_correction_setting: bool) -> Result<ThermalConductance, TuasLibError>
+    {
+        let (nodalised_fluid_side_conductance, nodalised_solid_side_conductance) = 
+            self.get_interior_to_fluid_nodal_fluid_and_solid_side_conductances(
+                prandtl_wall_correction_setting)?;
+
+        let nodalised_pipe_fluid_to_shell_thermal_resistance: ThermalResistance 
+            = nodalised_solid_side_conductance.recip() 
+            + nodalised_fluid_side_conductance.recip();
+
+        // return the conductance 
+
+        Ok(nodalised_pipe_fluid_to_shell_thermal_resistance.recip())
+    }
+
+    /// gets the fluid side (convection) and solid side (conduction)
+    /// nodalised conductances from porous media to the fluid
+    /// separately, these are in series 
+    ///
+    /// the fluid side conductance is also used for the 
+    /// representative fuel pebbles in pebble bed cores, where 
+    /// the conduction within the pebbles is calculated separately
+    pub(crate) fn get_interior_to_fluid_nodal_fluid_and_solid_side_conductances(
+        &self, prandtl_wall_correction_setting: bool) 
+        -> Result<(ThermalConductance, ThermalConductance), TuasLibError>
     {
         // the thermal conductance here should be based on the 
         // nusselt number correlation
@@ -773,13 +797,8 @@ impl InsulatedPorousMediaFluidComponent {
             = (self.thermal_conductance_lengthscale_fluid_to_porous_media_internal * 
                 solid_thermal_conductivity) / number_of_temperature_nodes;
 
-        let nodalised_pipe_fluid_to_shell_thermal_resistance: ThermalResistance 
-            = nodalised_solid_side_conductance.recip() 
-            + nodalised_fluid_side_conductance.recip();
-
-        // return the conductance 
-
-        return Ok(nodalised_pipe_fluid_to_shell_thermal_resistance.recip());
+        return Ok((nodalised_fluid_side_conductance, 
+                nodalised_solid_side_conductance));
     }
 
     /// spawns a thread and moves the clone of the entire heater object into the 
@@ -897,3 +897,8 @@ pub fn check_if_number_of_nodes_is_correct(){
 
 
 }
+
+/// representative fuel pebbles within the porous media should 
+/// reach the analytical steady state, given the fluid temperature 
+/// and convective conductance at each node
+pub mod pebble_bed;
@@ -0,0 +1,139 @@
+/// uses the heater v1 with its fluid held at 80 degC
+/// (the component itself is not advanced), with 10 representative
+/// 6 cm graphite pebbles per node sharing 500 W
+///
+/// at steady state, each pebble's surface sits at
+///
+/// T_s = T_f + Q_pebble/G_pebble
+///
+/// where G_pebble is the fluid side nodal conductance divided by
+/// the number of pebbles per node, and the centre of the pebble
+/// is hotter than the surface by Q_pebble/(8 PI k R)
+#[test]
+pub fn representative_fuel_pebbles_steady_state(){
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::ratio::ratio;
+    use uom::si::temperature_interval::kelvin as interval_kelvin;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::thermodynamic_temperature::kelvin;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::SphericalConduction1DArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_dimension_spherical_conducting_medium::tests::fuel_pebble_conduction::constant_property_graphite;
+    use crate::pre_built_components::insulated_porous_media_fluid_components::InsulatedPorousMediaFluidComponent;
+
+    let fluid_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+    let porous_media_power = Power::new::<watt>(500.0);
+    let pebbles_per_node: f64 = 10.0;
+
+    let mut heater_v1 = InsulatedPorousMediaFluidComponent::
+        new_ciet_heater_v1_with_annular_pipe(
+            fluid_temperature, ambient_temperature, 3);
+
+    heater_v1.lateral_and_miscellaneous_connections(
+        false,
+        MassRate::new::<kilogram_per_second>(0.18),
+        Power::new::<watt>(0.0),
+        porous_media_power).unwrap();
+
+    let number_of_nodes = heater_v1.number_of_nodes();
+
+    let pebble_radius = Length::new::<meter>(0.03);
+    let fuel_pebble = SphericalConduction1DArray::new(
+        constant_property_graphite().into(),
+        fluid_temperature,
+        Pressure::new::<atmosphere>(1.0),
+        8,
+        Length::new::<meter>(0.0),
+        pebble_radius).unwrap();
+
+    let mut fuel_pebbles = vec![fuel_pebble; number_of_nodes];
+
+    let (nodalised_fluid_side_conductance, _) = heater_v1
+        .get_interior_to_fluid_nodal_fluid_and_solid_side_conductances(false)
+        .unwrap();
+
+    let pebble_to_fluid_conductance = nodalised_fluid_side_conductance/pebbles_per_node;
+    let power_per_pebble = porous_media_power/number_of_nodes as f64/pebbles_per_node;
+
+    // run for about 20 time constants of the pebble
+    let pebble_heat_capacity: HeatCapacity =
+        fuel_pebbles[0].get_total_volume()
+        * MassDensity::new::<uom::si::mass_density::kilogram_per_cubic_meter>(1800.0)
+        * SpecificHeatCapacity::new::<uom::si::specific_heat_capacity::joule_per_kilogram_kelvin>(700.0);
+    let pebble_time_constant: Time = pebble_heat_capacity/pebble_to_fluid_conductance;
+    let timestep: Time = pebble_time_constant/50.0;
+
+    for _ in 0..1000 {
+        heater_v1.advance_representative_fuel_pebbles(
+            &mut fuel_pebbles,
+            pebbles_per_node,
+            porous_media_power,
+            false,
+            timestep).unwrap();
+    }
+
+    let surface_temperature_rise: f64 =
+        (power_per_pebble/pebble_to_fluid_conductance).get::<interval_kelvin>();
+
+    let centre_temperature_rise: f64 = surface_temperature_rise
+        + power_per_pebble.get::<watt>()
+        /(8.0 * PI * 20.0 * pebble_radius.get::<meter>());
+
+    // T(r) - T_f, the parabolic profile within the pebble
+    let analytical_temperature_rise = |radius: Length| -> f64 {
+        surface_temperature_rise
+            + (centre_temperature_rise - surface_temperature_rise)
+            * (1.0 - (radius/pebble_radius).get::<ratio>().powi(2))
+    };
+
+    for fuel_pebble in fuel_pebbles.iter_mut() {
+        let temperatures = fuel_pebble.get_temperature_vector().unwrap();
+        let node_radii = fuel_pebble.get_node_radii();
+
+        approx::assert_relative_eq!(
+            temperatures.last().unwrap().get::<kelvin>()
+            - fluid_temperature.get::<kelvin>(),
+            analytical_temperature_rise(*node_radii.last().unwrap()),
+            max_relative = 0.01);
+
+        approx::assert_relative_eq!(
+            fuel_pebble.get_centre_temperature().get::<kelvin>()
+            - fluid_temperature.get::<kelvin>(),
+            analytical_temperature_rise(node_radii[0]),
+            max_relative = 0.01);
+    }
+
+    // need one pebble per node, and a positive number of pebbles
+    assert!(heater_v1.advance_representative_fuel_pebbles(
+            &mut fuel_pebbles[1..], pebbles_per_node,
+            porous_media_power, false, timestep).is_err());
+    assert!(heater_v1.advance_representative_fuel_pebbles(
+            &mut fuel_pebbles, 0.0,
+            porous_media_power, false, timestep).is_err());
+
+    // the bed conductivity for the heater v1 (steel interior with
+    // therminol) should just be the ZBS model at the bulk temperatures
+    let bed_conductivity = heater_v1.get_pebble_bed_effective_thermal_conductivity(
+        Ratio::new::<ratio>(0.39),
+        Ratio::new::<ratio>(0.8),
+        Length::new::<meter>(0.06)).unwrap();
+
+    let steel_conductivity = crate::boussinesq_thermophysical_properties::SolidMaterial::SteelSS304L
+        .try_get_thermal_conductivity(fluid_temperature).unwrap();
+    let therminol_conductivity = crate::boussinesq_thermophysical_properties::LiquidMaterial::TherminolVP1
+        .try_get_thermal_conductivity(fluid_temperature).unwrap();
+
+    approx::assert_relative_eq!(
+        bed_conductivity.value,
+        crate::heat_transfer_correlations::pebble_bed_conductivity::zbs_effective_thermal_conductivity(
+            steel_conductivity, therminol_conductivity,
+            Ratio::new::<ratio>(0.39), Ratio::new::<ratio>(0.8),
+            Length::new::<meter>(0.06), fluid_temperature).unwrap().value,
+        max_relative = 1e-9);
+}
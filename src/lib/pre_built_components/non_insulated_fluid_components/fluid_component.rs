@@ -1,5 +1,6 @@
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 
 use super::NonInsulatedFluidComponent;
 use uom::si::f64::*;
@@ -34,8 +35,15 @@ impl FluidComponentTrait for NonInsulatedFluidComponent {
         let pipe_fluid_array: FluidArray = 
         self.pipe_fluid_array.clone().try_into().unwrap();
 
-        pipe_fluid_array.get_mass_flowrate_from_pressure_loss_immutable(
-            pressure_loss)
+        // the pipe shell temperature is used as the wall temperature 
+        // for mixed convection corrections, if switched on
+        let mut pipe_shell: SolidColumn = 
+        self.pipe_shell.clone().try_into().unwrap();
+        let wall_temperature = pipe_shell.try_get_bulk_temperature().unwrap();
+
+        pipe_fluid_array
+            .get_mass_flowrate_from_pressure_loss_with_wall_temperature_immutable(
+                pressure_loss, wall_temperature)
     }
 
     fn get_pressure_loss(&mut self) -> Pressure {
@@ -57,7 +65,14 @@ impl FluidComponentTrait for NonInsulatedFluidComponent {
         let pipe_fluid_array: FluidArray = 
         self.pipe_fluid_array.clone().try_into().unwrap();
 
-        pipe_fluid_array.get_pressure_loss_immutable(mass_flowrate)
+        // the pipe shell temperature is used as the wall temperature 
+        // for mixed convection corrections, if switched on
+        let mut pipe_shell: SolidColumn = 
+        self.pipe_shell.clone().try_into().unwrap();
+        let wall_temperature = pipe_shell.try_get_bulk_temperature().unwrap();
+
+        pipe_fluid_array.get_pressure_loss_with_wall_temperature_immutable(
+            mass_flowrate, wall_temperature)
     }
 
     fn get_cross_sectional_area(&mut self) -> Area {
@@ -4,7 +4,10 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::heat_transfer_correlations::ambient_heat_transfer::AmbientHeatTransferModel;
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::RadiationToSurroundings;
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::NusseltPrandtlReynoldsData;
+use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityMonitor;
 
 use super::heat_transfer_entities::cv_types::CVType;
 use super::heat_transfer_entities::HeatTransferEntity;
@@ -51,6 +54,10 @@ pub struct NonInsulatedFluidComponent {
     /// pipe heat transfer coefficient to ambient
     pub heat_transfer_to_ambient: HeatTransfer,
 
+    /// optional physics (ambient heat transfer models, correlation 
+    /// validity monitor and developing flow), set using set_options
+    options: NonInsulatedFluidComponentOptions,
+
     /// pipe  outer diameter 
     pub od: Length,
 
@@ -65,6 +72,35 @@ pub struct NonInsulatedFluidComponent {
 
 }
 
+/// optional physics for the non insulated fluid component 
+///
+/// these are all off by default, so that the component has a 
+/// constant heat transfer coefficient to ambient
+#[derive(Clone,Debug,PartialEq,Default)]
+pub struct NonInsulatedFluidComponentOptions {
+
+    /// optional temperature dependent model for the heat transfer 
+    /// coefficient to ambient (eg. natural convection), 
+    /// if None, heat_transfer_to_ambient is used
+    pub ambient_heat_transfer_model: Option<AmbientHeatTransferModel>,
+
+    /// optional radiation from the outer surface to the surroundings, 
+    /// this is linearised and added in parallel to the heat transfer 
+    /// to ambient every timestep
+    pub radiation_to_surroundings: Option<RadiationToSurroundings>,
+
+    /// checks the nusselt and friction factor correlations against 
+    /// their validity envelopes and reports extrapolations
+    pub correlation_validity_monitor: CorrelationValidityMonitor,
+
+    /// if true, the fluid to pipe shell conductance varies from 
+    /// node to node using local nusselt numbers measured from the 
+    /// inlet, so that developing flow (entrance) effects are 
+    /// concentrated at the inlet. The component average is unchanged
+    pub local_developing_flow_heat_transfer: bool,
+
+}
+
 impl NonInsulatedFluidComponent {
 
     /// constructs a new pipe
@@ -152,6 +188,7 @@ impl NonInsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            options: NonInsulatedFluidComponentOptions::default(),
             od,
             id,
             flow_area,
@@ -247,6 +284,7 @@ impl NonInsulatedFluidComponent {
             pipe_fluid_array: therminol_array.into(), 
             ambient_temperature, 
             heat_transfer_to_ambient: h_to_air, 
+            options: NonInsulatedFluidComponentOptions::default(),
             od: steel_shell_od, 
             id: steel_shell_id, 
             flow_area, 
@@ -329,12 +367,23 @@ impl NonInsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            options: NonInsulatedFluidComponentOptions::default(),
             od: shell_od,
             id: shell_id,
             flow_area,
             custom_component_loss_correlation,
         };
     }
+
+    /// returns the optional physics of the component
+    pub fn get_options(&self) -> &NonInsulatedFluidComponentOptions {
+        &self.options
+    }
+
+    /// sets the optional physics of the component
+    pub fn set_options(&mut self, options: NonInsulatedFluidComponentOptions) {
+        self.options = options;
+    }
 }
 
 
@@ -16,6 +16,7 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::RadiationToSurroundings;
 use crate::tuas_lib_error::TuasLibError;
 
 impl NonInsulatedFluidComponent {
@@ -110,7 +111,7 @@ impl NonInsulatedFluidComponent {
 
 
         // first let's get all the conductances 
-        let heat_transfer_to_ambient = self.heat_transfer_to_ambient;
+        let heat_transfer_to_ambient = self.try_get_heat_transfer_to_ambient()?;
 
         let pipe_shell_to_air_nodal_conductance: ThermalConductance 
         = self.get_air_shell_nodal_shell_conductance(
@@ -120,9 +121,16 @@ impl NonInsulatedFluidComponent {
         self.set_mass_flowrate(mass_flowrate);
 
 
-        let pipe_shell_surf_to_fluid_conductance: ThermalConductance 
-        = self.get_fluid_array_node_pipe_shell_conductance(
-            correct_prandtl_for_wall_temperatures)?;
+        // only the lateral connections record into the correlation 
+        // validity monitor, so each timestep is counted once
+        let local_developing_flow = self.options.local_developing_flow_heat_transfer;
+        let record_correlation_validity = true;
+
+        let pipe_shell_surf_to_fluid_conductance_vector: Vec<ThermalConductance> 
+        = self.try_get_fluid_array_node_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures,
+            local_developing_flow,
+            record_correlation_validity)?;
 
 
         // other stuff 
@@ -182,15 +190,30 @@ impl NonInsulatedFluidComponent {
                 ambient_temperature_vector
             )?;
 
+            // pipe to surroundings radiation, linearised about 
+            // the current pipe shell temperatures
+
+            if let Some(radiation_to_surroundings) = self.options.radiation_to_surroundings {
+                let pipe_shell_to_surroundings_conductance_vector = 
+                    self.get_radiation_to_surroundings_nodal_conductance_vector(
+                        radiation_to_surroundings)?;
+
+                pipe_shell_clone.lateral_link_new_temperature_vector_conductance_vector(
+                    pipe_shell_to_surroundings_conductance_vector,
+                    vec![radiation_to_surroundings.surroundings_temperature;
+                        number_of_temperature_nodes]
+                )?;
+            }
+
             // pipe shell to fluid interaction
 
-            pipe_shell_clone.lateral_link_new_temperature_vector_avg_conductance(
-                pipe_shell_surf_to_fluid_conductance,
+            pipe_shell_clone.lateral_link_new_temperature_vector_conductance_vector(
+                pipe_shell_surf_to_fluid_conductance_vector.clone(),
                 fluid_temp_vector.clone()
             )?;
 
-            fluid_array_clone.lateral_link_new_temperature_vector_avg_conductance(
-                pipe_shell_surf_to_fluid_conductance,
+            fluid_array_clone.lateral_link_new_temperature_vector_conductance_vector(
+                pipe_shell_surf_to_fluid_conductance_vector,
                 pipe_temp_vector
             )?;
 
@@ -317,13 +340,143 @@ impl NonInsulatedFluidComponent {
     }
 
 
+    /// obtains the heat transfer coefficient from the pipe shell 
+    /// to ambient
+    ///
+    /// this is heat_transfer_to_ambient unless an ambient heat 
+    /// transfer model is set, in which case it is calculated from 
+    /// the pipe shell bulk temperature and outer diameter
+    #[inline]
+    pub fn try_get_heat_transfer_to_ambient(&mut self) 
+        -> Result<HeatTransfer,TuasLibError> {
+
+        let ambient_heat_transfer_model = match self.options.ambient_heat_transfer_model {
+            Some(model) => model,
+            None => return Ok(self.heat_transfer_to_ambient),
+        };
+
+        let mut pipe_shell_clone: SolidColumn = 
+        self.pipe_shell.clone().try_into()?;
+
+        let pipe_surf_temperature: ThermodynamicTemperature 
+        = pipe_shell_clone.try_get_bulk_temperature()?;
+
+        ambient_heat_transfer_model.try_get_heat_transfer_coefficient(
+            pipe_surf_temperature, 
+            self.ambient_temperature, 
+            self.od)
+    }
+
+    /// obtains the linearised radiation conductance from each 
+    /// pipe shell node to the surroundings
+    ///
+    /// the radiation heat transfer coefficient is calculated from 
+    /// each node temperature, and is in series with conduction 
+    /// through the outer half of the pipe shell
+    #[inline]
+    pub fn get_radiation_to_surroundings_nodal_conductance_vector(&mut self,
+        radiation_to_surroundings: RadiationToSurroundings)
+        -> Result<Vec<ThermalConductance>,TuasLibError> {
+
+        let pipe_shell_clone: SolidColumn = 
+        self.pipe_shell.clone().try_into()?;
+
+        let number_of_temperature_nodes = self.inner_nodes + 2;
+        let heated_length = self.get_component_length();
+        let id = self.id;
+        let od = self.od;
+
+        let cylinder_mid_diameter: Length = 0.5*(id+od);
+
+        let node_length = heated_length / 
+            number_of_temperature_nodes as f64;
+
+        let pipe_temp_vector: Vec<ThermodynamicTemperature> 
+        = pipe_shell_clone.get_temperature_vector()?;
+
+        pipe_temp_vector.iter().map(|&node_temperature| {
+
+            let h_rad: HeatTransfer = radiation_to_surroundings
+                .get_linearised_heat_transfer_coefficient(node_temperature);
+
+            let pipe_surroundings_conductance_interaction: HeatTransferInteractionType
+            = HeatTransferInteractionType::
+                CylindricalConductionConvectionLiquidOutside(
+                    (pipe_shell_clone.material_control_volume, 
+                        (od-cylinder_mid_diameter).into(),
+                        node_temperature,
+                        pipe_shell_clone.pressure_control_volume),
+                    (h_rad,
+                        od.into(),
+                        node_length.into())
+                );
+
+            try_get_thermal_conductance_based_on_interaction(
+                radiation_to_surroundings.surroundings_temperature,
+                node_temperature,
+                pipe_shell_clone.pressure_control_volume,
+                pipe_shell_clone.pressure_control_volume,
+                pipe_surroundings_conductance_interaction,
+            )
+        }).collect()
+    }
+
     /// obtains fluid to pipe  shell conductance
+    ///
+    /// this is based on the nusselt number of the whole component, 
+    /// so it is the same for every node
     #[inline]
     pub fn get_fluid_array_node_pipe_shell_conductance(
         &mut self, 
         correct_prandtl_for_wall_temperatures: bool) 
         -> Result<ThermalConductance,TuasLibError> {
 
+        let local_developing_flow = false;
+        let record_correlation_validity = false;
+
+        let uniform_conductance_vector = 
+            self.try_get_fluid_array_node_pipe_shell_conductance_vector(
+                correct_prandtl_for_wall_temperatures,
+                local_developing_flow,
+                record_correlation_validity)?;
+
+        Ok(uniform_conductance_vector[0])
+    }
+
+    /// obtains fluid to pipe shell conductance for each node
+    ///
+    /// if local_developing_flow_heat_transfer is set, local 
+    /// nusselt numbers are used for each node, otherwise all 
+    /// nodes have the same conductance
+    #[inline]
+    pub fn get_fluid_array_node_pipe_shell_conductance_vector(
+        &mut self, 
+        correct_prandtl_for_wall_temperatures: bool) 
+        -> Result<Vec<ThermalConductance>,TuasLibError> {
+
+        let local_developing_flow = self.options.local_developing_flow_heat_transfer;
+        let record_correlation_validity = false;
+
+        self.try_get_fluid_array_node_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures,
+            local_developing_flow,
+            record_correlation_validity)
+    }
+
+    /// obtains fluid to pipe shell conductance for each node, 
+    /// optionally with local nusselt numbers
+    ///
+    /// the correlation validity monitor is only updated if 
+    /// record_correlation_validity is set, the public getters 
+    /// leave it alone so that they don't double count evaluations
+    #[inline]
+    fn try_get_fluid_array_node_pipe_shell_conductance_vector(
+        &mut self, 
+        correct_prandtl_for_wall_temperatures: bool,
+        local_developing_flow: bool,
+        record_correlation_validity: bool) 
+        -> Result<Vec<ThermalConductance>,TuasLibError> {
+
         // the thermal conductance here should be based on the 
         // nusselt number correlation
 
@@ -397,33 +550,40 @@ impl NonInsulatedFluidComponent {
         // there is wall correction
 
         let nusselt_estimate: Ratio;
+        let wall_prandtl_number_for_nusselt: Ratio;
 
-        if !correct_prandtl_for_wall_temperatures {
-            nusselt_estimate = fluid_array.get_nusselt(
-                reynolds_number, 
-                bulk_prandtl_number, 
-                bulk_prandtl_number)?;
-        } else {
+        // the wall temperature is needed for wall prandtl number 
+        // corrections and for mixed convection corrections
+        // Firstly, get the shell temperature 
 
-            // in this case, we do wall correction, but must have a 
-            // bounded Prandtl number approach for the wall
-            // Firstly, get the shell temperature 
+        let mut wall_temperature_estimate = pipe_shell_clone
+            .try_get_bulk_temperature()?;
 
-            let mut wall_temperature_estimate = pipe_shell_clone
-                .try_get_bulk_temperature()?;
+        // then ensure that the wall temperature is within 
+        // the temperature bounds of the fluid properties
+        if wall_temperature_estimate > fluid_material.max_temperature() {
 
-            // then ensure that the wall temperature is within 
-            // the temperature bounds of the fluid properties
-            if wall_temperature_estimate > fluid_material.max_temperature() {
+            wall_temperature_estimate = fluid_material.max_temperature();
 
-                wall_temperature_estimate = fluid_material.max_temperature();
+        } else if wall_temperature_estimate < fluid_material.min_temperature() {
 
-            } else if wall_temperature_estimate < fluid_material.min_temperature() {
+            wall_temperature_estimate = fluid_material.min_temperature();
 
-                wall_temperature_estimate = fluid_material.min_temperature();
+        }
 
-            }
-            // then get the wall prandtl number
+        if !correct_prandtl_for_wall_temperatures {
+            nusselt_estimate = fluid_array.get_nusselt_with_wall_temperature(
+                reynolds_number, 
+                bulk_prandtl_number, 
+                bulk_prandtl_number,
+                wall_temperature_estimate)?;
+            wall_prandtl_number_for_nusselt = bulk_prandtl_number;
+        } else {
+
+            // in this case, we do wall correction, but must have a 
+            // bounded Prandtl number approach for the wall, 
+            // the wall temperature estimate is already bounded, 
+            // so get the wall prandtl number
 
             let wall_prandtl_number: Ratio 
                 = fluid_material.try_get_prandtl_liquid(
@@ -432,15 +592,26 @@ impl NonInsulatedFluidComponent {
                 )?;
 
 
-            nusselt_estimate = fluid_array.get_nusselt(
+            nusselt_estimate = fluid_array.get_nusselt_with_wall_temperature(
                 reynolds_number, 
                 bulk_prandtl_number, 
-                wall_prandtl_number)?;
+                wall_prandtl_number,
+                wall_temperature_estimate)?;
+            wall_prandtl_number_for_nusselt = wall_prandtl_number;
 
         }
 
 
 
+        // record whether the correlations are used within 
+        // their validity envelopes
+        if record_correlation_validity {
+            fluid_array.try_check_correlation_validity(
+                &mut self.options.correlation_validity_monitor,
+                reynolds_number,
+                bulk_prandtl_number)?;
+        }
+
         // now we can get the heat transfer coeff, 
 
         let h_to_fluid: HeatTransfer;
@@ -469,37 +640,57 @@ impl NonInsulatedFluidComponent {
         let cylinder_mid_diameter: Length = 0.5*(id+od);
 
 
+        let get_nodal_conductance = |h_to_fluid: HeatTransfer| 
+            -> Result<ThermalConductance,TuasLibError> {
+
+            let fluid_pipe_shell_conductance_interaction: HeatTransferInteractionType
+            = HeatTransferInteractionType::
+                CylindricalConductionConvectionLiquidInside(
+                    (SolidMaterial::SteelSS304L.into(), 
+                        (cylinder_mid_diameter - id).into(),
+                        pipe_shell_surf_temperature,
+                        atmospheric_pressure),
+                    (h_to_fluid,
+                        id.into(),
+                        node_length.into())
+                );
+
+            // now based on conductance interaction, 
+            // we can obtain thermal conductance, the temperatures 
+            // and pressures don't really matter
+            //
+            // this is because all the thermal conductance data 
+            // has already been loaded into the thermal conductance 
+            // interaction object
 
-        let fluid_pipe_shell_conductance_interaction: HeatTransferInteractionType
-        = HeatTransferInteractionType::
-            CylindricalConductionConvectionLiquidInside(
-                (SolidMaterial::SteelSS304L.into(), 
-                    (cylinder_mid_diameter - id).into(),
-                    pipe_shell_surf_temperature,
-                    atmospheric_pressure),
-                (h_to_fluid,
-                    id.into(),
-                    node_length.into())
-            );
-
-        // now based on conductance interaction, 
-        // we can obtain thermal conductance, the temperatures 
-        // and pressures don't really matter
-        //
-        // this is because all the thermal conductance data 
-        // has already been loaded into the thermal conductance 
-        // interaction object
-
-        let fluid_pipe_shell_nodal_thermal_conductance: ThermalConductance = 
             try_get_thermal_conductance_based_on_interaction(
                 fluid_temperature,
                 pipe_shell_surf_temperature,
                 atmospheric_pressure,
                 atmospheric_pressure,
-                fluid_pipe_shell_conductance_interaction)?;
+                fluid_pipe_shell_conductance_interaction)
+        };
 
+        if !local_developing_flow {
+            let fluid_pipe_shell_nodal_thermal_conductance = 
+                get_nodal_conductance(h_to_fluid)?;
+
+            return Ok(vec![fluid_pipe_shell_nodal_thermal_conductance;
+                number_of_temperature_nodes]);
+        }
+
+        // for developing flow, the heat transfer coefficient 
+        // is scaled by the local nusselt number of each node, 
+        // measured from the inlet
+        let local_nusselt_ratio_vector: Vec<Ratio> = 
+            fluid_array.get_local_nusselt_ratio_vector(
+                reynolds_number, 
+                bulk_prandtl_number, 
+                wall_prandtl_number_for_nusselt)?;
 
-        return Ok(fluid_pipe_shell_nodal_thermal_conductance);
+        local_nusselt_ratio_vector.iter().map(|&local_nusselt_ratio| {
+            get_nodal_conductance(h_to_fluid * local_nusselt_ratio)
+        }).collect()
     }
 
     /// gets the reynolds number based on mass flworate and 
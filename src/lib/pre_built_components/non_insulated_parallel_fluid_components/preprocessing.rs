@@ -400,34 +400,37 @@ impl NonInsulatedParallelFluidComponent {
         let mut fluid_array: FluidArray 
             = self.pipe_fluid_array.clone().try_into()?;
 
-        let nusselt_estimate: Ratio;
+        // the wall temperature is needed for wall prandtl number 
+        // corrections and for mixed convection corrections
+        // Firstly, get the shell temperature 
 
-        if !correct_prandtl_for_wall_temperatures {
-            nusselt_estimate = fluid_array.get_nusselt(
-                reynolds_number_single_tube, 
-                bulk_prandtl_number, 
-                bulk_prandtl_number)?;
-        } else {
+        let mut wall_temperature_estimate = pipe_shell_clone
+            .try_get_bulk_temperature()?;
 
-            // in this case, we do wall correction, but must have a 
-            // bounded Prandtl number approach for the wall
-            // Firstly, get the shell temperature 
+        // then ensure that the wall temperature is within 
+        // the temperature bounds of the fluid properties
+        if wall_temperature_estimate > fluid_material.max_temperature() {
 
-            let mut wall_temperature_estimate = pipe_shell_clone
-                .try_get_bulk_temperature()?;
+            wall_temperature_estimate = fluid_material.max_temperature();
 
-            // then ensure that the wall temperature is within 
-            // the temperature bounds of the fluid properties
-            if wall_temperature_estimate > fluid_material.max_temperature() {
+        } else if wall_temperature_estimate < fluid_material.min_temperature() {
 
-                wall_temperature_estimate = fluid_material.max_temperature();
+            wall_temperature_estimate = fluid_material.min_temperature();
 
-            } else if wall_temperature_estimate < fluid_material.min_temperature() {
+        }
 
-                wall_temperature_estimate = fluid_material.min_temperature();
+        let nusselt_estimate: Ratio = if !correct_prandtl_for_wall_temperatures {
+            fluid_array.get_nusselt_with_wall_temperature(
+                reynolds_number_single_tube, 
+                bulk_prandtl_number, 
+                bulk_prandtl_number,
+                wall_temperature_estimate)?
+        } else {
 
-            }
-            // then get the wall prandtl number
+            // in this case, we do wall correction, but must have a 
+            // bounded Prandtl number approach for the wall, 
+            // the wall temperature estimate is already bounded, 
+            // so get the wall prandtl number
 
             let wall_prandtl_number: Ratio 
                 = fluid_material.try_get_prandtl_liquid(
@@ -436,12 +439,13 @@ impl NonInsulatedParallelFluidComponent {
                 )?;
 
 
-            nusselt_estimate = fluid_array.get_nusselt(
+            fluid_array.get_nusselt_with_wall_temperature(
                 reynolds_number_single_tube, 
                 bulk_prandtl_number, 
-                wall_prandtl_number)?;
+                wall_prandtl_number,
+                wall_temperature_estimate)?
 
-        }
+        };
 
         // I need to use Nusselt correlations present in this struct 
         //
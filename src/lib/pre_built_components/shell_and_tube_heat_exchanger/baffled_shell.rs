@@ -0,0 +1,107 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::ConstZero;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+use crate::heat_transfer_correlations::baffled_shell_side::{BaffledShellGeometry, BaffledShellSide, BaffledShellSideNusseltData};
+use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::SimpleShellAndTubeHeatExchanger;
+
+impl SimpleShellAndTubeHeatExchanger {
+
+    /// combines a baffled shell geometry with the shell side
+    /// hydraulic diameter and flow area of this heat exchanger
+    ///
+    /// the geometry must have the same shell inner diameter,
+    /// tube outer diameter and number of tubes as the heat exchanger
+    pub fn try_get_baffled_shell_side(&self,
+        geometry: BaffledShellGeometry) -> Result<BaffledShellSide, TuasLibError> {
+
+        geometry.try_validate()?;
+
+        let same_length = |a: Length, b: Length| -> bool {
+            ((a - b)/b).get::<ratio>().abs() < 1e-9
+        };
+
+        if !same_length(geometry.shell_id, self.shell_side_id)
+            || !same_length(geometry.tube_od, self.tube_side_od)
+            || geometry.number_of_tubes != self.number_of_tubes {
+            return Err(TuasLibError::GenericStringError(
+                "baffled shell geometry must match the shell inner \
+                diameter, tube outer diameter and number of tubes of \
+                the heat exchanger".to_string()));
+        }
+
+        Ok(BaffledShellSide {
+            geometry,
+            reference_flow_area: self.get_shell_side_cross_sectional_area(),
+            reference_hydraulic_diameter: self.get_shell_side_hydraulic_diameter(),
+        })
+    }
+
+    /// uses the Bell-Delaware method for the shell side fluid
+    /// to tube heat transfer and for the shell side pressure drop
+    ///
+    /// this replaces the shell side nusselt correlation to tubes,
+    /// the shell side loss correlation and the loss correlation
+    /// of the shell side fluid array
+    pub fn try_set_bell_delaware_shell_side(&mut self,
+        geometry: BaffledShellGeometry) -> Result<(), TuasLibError> {
+
+        let shell_side = self.try_get_baffled_shell_side(geometry)?;
+
+        self.set_shell_side_correlations(
+            NusseltCorrelation::ShellSideBellDelaware(
+                Self::baffled_shell_side_nusselt_data(shell_side)),
+            DimensionlessDarcyLossCorrelations::BellDelawareShellSide(shell_side))
+    }
+
+    /// uses the Kern method for the shell side fluid
+    /// to tube heat transfer and for the shell side pressure drop
+    ///
+    /// this replaces the shell side nusselt correlation to tubes,
+    /// the shell side loss correlation and the loss correlation
+    /// of the shell side fluid array
+    pub fn try_set_kern_shell_side(&mut self,
+        geometry: BaffledShellGeometry) -> Result<(), TuasLibError> {
+
+        let shell_side = self.try_get_baffled_shell_side(geometry)?;
+
+        self.set_shell_side_correlations(
+            NusseltCorrelation::ShellSideKern(
+                Self::baffled_shell_side_nusselt_data(shell_side)),
+            DimensionlessDarcyLossCorrelations::KernShellSide(shell_side))
+    }
+
+    /// reynolds and prandtl numbers are placeholders,
+    /// these are supplied when the nusselt number is estimated
+    fn baffled_shell_side_nusselt_data(shell_side: BaffledShellSide)
+        -> BaffledShellSideNusseltData {
+        BaffledShellSideNusseltData {
+            shell_side,
+            reynolds: Ratio::ZERO,
+            prandtl_bulk: Ratio::new::<ratio>(1.0),
+            prandtl_wall: Ratio::new::<ratio>(1.0),
+        }
+    }
+
+    fn set_shell_side_correlations(&mut self,
+        nusselt_correlation: NusseltCorrelation,
+        loss_correlation: DimensionlessDarcyLossCorrelations)
+        -> Result<(), TuasLibError> {
+
+        let mut shell_side_fluid_array: FluidArray =
+            self.shell_side_fluid_array.clone().try_into()?;
+
+        shell_side_fluid_array.fluid_component_loss_properties = loss_correlation;
+
+        self.shell_side_fluid_array.set(shell_side_fluid_array.into())?;
+        self.shell_side_nusselt_correlation_to_tubes = nusselt_correlation;
+        self.shell_side_custom_component_loss_correlation = loss_correlation;
+
+        Ok(())
+    }
+}
@@ -42,6 +42,13 @@ impl SimpleShellAndTubeHeatExchanger {
         if self.heat_exchanger_has_insulation {
             self.insulation_array.advance_timestep_mut_self(timestep)?;
         }
+
+        // fouling grows with operating time
+        for fouling in [&mut self.options.tube_side_fouling,
+            &mut self.options.shell_side_fouling_on_tubes,
+            &mut self.options.shell_side_fouling_on_outer_shell].into_iter().flatten() {
+            fouling.advance_operating_time(timestep);
+        }
         // done, pending test
 
         Ok(())
@@ -6,8 +6,10 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_
 use crate::tuas_lib_error::TuasLibError;
 use crate::boussinesq_thermophysical_properties::*;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::heat_transfer_correlations::effectiveness_ntu::try_get_effectiveness_and_capacity_rate_ratio_from_temperatures;
 
 use super::SimpleShellAndTubeHeatExchanger;
+use super::flow_arrangement::ShellAndTubeFlowArrangement;
 
 impl SimpleShellAndTubeHeatExchanger {
 
@@ -528,8 +530,9 @@ impl SimpleShellAndTubeHeatExchanger {
     /// Q is based on shell or tube side, whichever is smaller 
     /// because we don't want to include parasitic heat losses 
     ///
-    /// also allows you to specify if the sthe is counter current 
-    /// manually
+    /// the LMTD is based on the flow arrangement of the sthe, 
+    /// returns an error if the LMTD correction factor cannot be 
+    /// found for the given temperatures
     #[inline]
     pub fn get_ua_based_on_mass_flowrates_and_temperature_differences(&self,
         tube_inlet_temperature: ThermodynamicTemperature,
@@ -537,8 +540,7 @@ impl SimpleShellAndTubeHeatExchanger {
         tube_mass_flowrate: MassRate,
         shell_inlet_temperature: ThermodynamicTemperature,
         shell_outlet_temeprature: ThermodynamicTemperature,
-        shell_mass_flowrate: MassRate,
-        is_counter_current: bool) -> ThermalConductance {
+        shell_mass_flowrate: MassRate) -> Result<ThermalConductance, TuasLibError> {
 
         let tube_side_heat_rate: Power = 
             self.get_tube_side_heat_rate_based_on_mass_flowrate(
@@ -568,14 +570,74 @@ impl SimpleShellAndTubeHeatExchanger {
         }
 
         // then get ua 
-        Self::get_ua_based_on_heat_transfer_and_temperature_differences(
+        self.get_ua_based_on_heat_transfer_and_flow_arrangement(
             heat_transfer_rate_through_sthe_no_parasitic_losses, 
             tube_inlet_temperature, 
             tube_outlet_temeprature, 
             shell_inlet_temperature, 
-            shell_outlet_temeprature, 
-            is_counter_current)
+            shell_outlet_temeprature)
+
+
+    }
+
+    /// gets the overall thermal resistance for heat 
+    /// exchanger based on Q = F UA (LMTD) 
+    ///
+    /// co-current and counter current flow arrangements use 
+    /// their own LMTD with F = 1,
+    /// U-bend tube passes use the counter current LMTD with 
+    /// the LMTD correction factor F for one shell pass
+    ///
+    /// for U-bend tube passes, an error is returned if the 
+    /// temperatures give an effectiveness beyond what one shell 
+    /// pass can reach, as F is undefined there
+    #[inline]
+    pub fn get_ua_based_on_heat_transfer_and_flow_arrangement(&self,
+        heat_transfer_rate: Power,
+        tube_inlet_temperature: ThermodynamicTemperature,
+        tube_outlet_temeprature: ThermodynamicTemperature,
+        shell_inlet_temperature: ThermodynamicTemperature,
+        shell_outlet_temeprature: ThermodynamicTemperature) 
+        -> Result<ThermalConductance, TuasLibError> {
+
+        let is_counter_current = self.options.flow_arrangement 
+            != ShellAndTubeFlowArrangement::CoCurrent;
 
+        let ua_without_correction = 
+            Self::get_ua_based_on_heat_transfer_and_temperature_differences(
+                heat_transfer_rate, 
+                tube_inlet_temperature, 
+                tube_outlet_temeprature, 
+                shell_inlet_temperature, 
+                shell_outlet_temeprature, 
+                is_counter_current);
+
+        if let ShellAndTubeFlowArrangement::UBendTubePasses(_) = self.options.flow_arrangement {
+
+            // the hotter inlet is the hot stream
+            let (effectiveness, capacity_rate_ratio) = 
+                if shell_inlet_temperature > tube_inlet_temperature {
+                    try_get_effectiveness_and_capacity_rate_ratio_from_temperatures(
+                        shell_inlet_temperature, 
+                        shell_outlet_temeprature, 
+                        tube_inlet_temperature, 
+                        tube_outlet_temeprature)?
+                } else {
+                    try_get_effectiveness_and_capacity_rate_ratio_from_temperatures(
+                        tube_inlet_temperature, 
+                        tube_outlet_temeprature, 
+                        shell_inlet_temperature, 
+                        shell_outlet_temeprature)?
+                };
+
+            let lmtd_correction_factor: Ratio = self.options.flow_arrangement
+                .get_effectiveness_ntu_flow_arrangement()
+                .try_get_lmtd_correction_factor(effectiveness, capacity_rate_ratio)?;
+
+            return Ok(ua_without_correction/lmtd_correction_factor);
+        }
+
+        Ok(ua_without_correction)
 
     }
 
@@ -643,8 +705,7 @@ impl SimpleShellAndTubeHeatExchanger {
         tube_mass_flowrate: MassRate,
         shell_inlet_temperature: ThermodynamicTemperature,
         shell_outlet_temeprature: ThermodynamicTemperature,
-        shell_mass_flowrate: MassRate,
-        is_counter_current: bool) -> Ratio {
+        shell_mass_flowrate: MassRate) -> Result<Ratio, TuasLibError> {
 
         let overall_ua: ThermalConductance = 
             self.get_ua_based_on_mass_flowrates_and_temperature_differences(
@@ -653,8 +714,7 @@ impl SimpleShellAndTubeHeatExchanger {
                 tube_mass_flowrate, 
                 shell_inlet_temperature, 
                 shell_outlet_temeprature, 
-                shell_mass_flowrate, 
-                is_counter_current);
+                shell_mass_flowrate)?;
 
         let overall_thermal_resistance = overall_ua.recip();
 
@@ -683,7 +743,7 @@ impl SimpleShellAndTubeHeatExchanger {
             h_s * shell_side_fluid_hydraulic_diameter/
             self.get_shell_side_fluid_thermal_conductivity();
 
-        expt_nusselt_number_shell_side
+        Ok(expt_nusselt_number_shell_side)
 
 
     }
@@ -696,17 +756,15 @@ impl SimpleShellAndTubeHeatExchanger {
         tube_inlet_temperature: ThermodynamicTemperature,
         tube_outlet_temeprature: ThermodynamicTemperature,
         shell_inlet_temperature: ThermodynamicTemperature,
-        shell_outlet_temeprature: ThermodynamicTemperature,
-        is_counter_current: bool) -> Ratio {
+        shell_outlet_temeprature: ThermodynamicTemperature) -> Result<Ratio, TuasLibError> {
 
         let overall_ua: ThermalConductance = 
-            Self::get_ua_based_on_heat_transfer_and_temperature_differences(
+            self.get_ua_based_on_heat_transfer_and_flow_arrangement(
                 sthe_heat_transfer_rate,
                 tube_inlet_temperature, 
                 tube_outlet_temeprature, 
                 shell_inlet_temperature, 
-                shell_outlet_temeprature, 
-                is_counter_current);
+                shell_outlet_temeprature)?;
 
         let overall_thermal_resistance = overall_ua.recip();
 
@@ -735,7 +793,7 @@ impl SimpleShellAndTubeHeatExchanger {
             h_s * shell_side_fluid_hydraulic_diameter/
             self.get_shell_side_fluid_thermal_conductivity();
 
-        expt_nusselt_number_shell_side
+        Ok(expt_nusselt_number_shell_side)
 
 
     }
@@ -752,8 +810,7 @@ impl SimpleShellAndTubeHeatExchanger {
         tube_mass_flowrate: MassRate,
         shell_inlet_temperature: ThermodynamicTemperature,
         shell_outlet_temeprature: ThermodynamicTemperature,
-        shell_mass_flowrate: MassRate,
-        is_counter_current: bool) -> Ratio {
+        shell_mass_flowrate: MassRate) -> Result<Ratio, TuasLibError> {
 
         let overall_ua: ThermalConductance = 
             self.get_ua_based_on_mass_flowrates_and_temperature_differences(
@@ -762,8 +819,7 @@ impl SimpleShellAndTubeHeatExchanger {
                 tube_mass_flowrate, 
                 shell_inlet_temperature, 
                 shell_outlet_temeprature, 
-                shell_mass_flowrate, 
-                is_counter_current);
+                shell_mass_flowrate)?;
 
         let overall_thermal_resistance = overall_ua.recip();
 
@@ -790,7 +846,7 @@ impl SimpleShellAndTubeHeatExchanger {
             h_t * tube_side_fluid_hydraulic_diameter/
             self.get_shell_side_fluid_thermal_conductivity();
 
-        expt_nusselt_number_tube_side
+        Ok(expt_nusselt_number_tube_side)
 
 
     }
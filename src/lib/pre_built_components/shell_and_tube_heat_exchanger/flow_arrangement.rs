@@ -0,0 +1,339 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::ConstZero;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+use crate::heat_transfer_correlations::effectiveness_ntu::HeatExchangerFlowArrangement;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::SimpleShellAndTubeHeatExchanger;
+
+/// flow arrangement of the shell and tube heat exchanger
+///
+/// for single tube pass arrangements, node i of the tube side
+/// arrays sits next to node i of the shell side array.
+/// The direction each fluid flows in is given by the sign
+/// of its mass flowrate, so for counter current flow, the tube
+/// side and shell side mass flowrates have opposite signs
+/// (eg. tube side flows back to front, shell side flows
+/// front to back)
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum ShellAndTubeFlowArrangement {
+    /// single tube pass, tube and shell side fluids flow
+    /// in the same direction
+    CoCurrent,
+    /// single tube pass, tube and shell side fluids flow
+    /// in opposite directions
+    #[default]
+    CounterCurrent,
+    /// one shell pass and an even number of tube passes
+    /// joined by U-bends (1-2, 1-4 etc.)
+    ///
+    /// the tube side arrays represent a single U-tube unrolled
+    /// along its whole length, so they are tube_passes times
+    /// as long as the shell, and number_of_tubes is the number
+    /// of U-tubes in parallel
+    UBendTubePasses(u32),
+}
+
+impl ShellAndTubeFlowArrangement {
+
+    /// number of times the tube side fluid traverses the shell
+    pub fn get_number_of_tube_passes(&self) -> u32 {
+        match self {
+            ShellAndTubeFlowArrangement::CoCurrent => 1,
+            ShellAndTubeFlowArrangement::CounterCurrent => 1,
+            ShellAndTubeFlowArrangement::UBendTubePasses(tube_passes) => *tube_passes,
+        }
+    }
+
+    /// equivalent flow arrangement for the effectiveness-NTU method
+    pub fn get_effectiveness_ntu_flow_arrangement(&self) -> HeatExchangerFlowArrangement {
+        match self {
+            ShellAndTubeFlowArrangement::CoCurrent =>
+                HeatExchangerFlowArrangement::ParallelFlow,
+            ShellAndTubeFlowArrangement::CounterCurrent =>
+                HeatExchangerFlowArrangement::CounterFlow,
+            ShellAndTubeFlowArrangement::UBendTubePasses(_) =>
+                HeatExchangerFlowArrangement::ShellAndTube(1),
+        }
+    }
+
+    /// single tube pass arrangement matching the directions of 
+    /// the tube side and shell side mass flowrates, 
+    /// counter current unless both flow the same way
+    ///
+    /// useful in loop simulations where flow through one side 
+    /// may reverse
+    pub fn single_pass_from_mass_flowrate_directions(
+        tube_side_mass_flowrate: MassRate,
+        shell_side_mass_flowrate: MassRate) -> Self {
+
+        if tube_side_mass_flowrate.value * shell_side_mass_flowrate.value > 0.0 {
+            return ShellAndTubeFlowArrangement::CoCurrent;
+        }
+
+        ShellAndTubeFlowArrangement::CounterCurrent
+    }
+
+    /// checks that the directions of the tube side and shell side
+    /// mass flowrates match the flow arrangement
+    ///
+    /// single tube pass arrangements pair node i of the tube side 
+    /// with node i of the shell side, so co-current flow needs 
+    /// mass flowrates of the same sign and counter current flow 
+    /// needs opposite signs. Zero flow on either side is accepted. 
+    /// U-bend tube passes accept either direction, as the tubes 
+    /// pass both ways along the shell anyway
+    pub fn check_mass_flowrate_directions(&self,
+        tube_side_mass_flowrate: MassRate,
+        shell_side_mass_flowrate: MassRate) -> Result<(), TuasLibError> {
+
+        let flowrate_product: f64 = tube_side_mass_flowrate.value 
+            * shell_side_mass_flowrate.value;
+
+        match self {
+            ShellAndTubeFlowArrangement::CoCurrent if flowrate_product < 0.0 => {
+                Err(TuasLibError::GenericStringError(
+                    "co-current flow needs tube side and shell side mass flowrates of the same sign"
+                    .to_string()))
+            },
+            ShellAndTubeFlowArrangement::CounterCurrent if flowrate_product > 0.0 => {
+                Err(TuasLibError::GenericStringError(
+                    "counter current flow needs tube side and shell side mass flowrates of opposite signs"
+                    .to_string()))
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+/// scales the length to diameter ratio of pipe type loss
+/// correlations, other correlations are returned as is
+fn scale_loss_correlation_length(
+    loss_correlation: DimensionlessDarcyLossCorrelations,
+    length_scale: Ratio) -> DimensionlessDarcyLossCorrelations {
+
+    match loss_correlation {
+        DimensionlessDarcyLossCorrelations::Pipe(
+            roughness_ratio, length_to_diameter, form_loss) =>
+            DimensionlessDarcyLossCorrelations::Pipe(
+                roughness_ratio, length_to_diameter * length_scale, form_loss),
+        DimensionlessDarcyLossCorrelations::PipeWithFittings(
+            roughness_ratio, length_to_diameter, fittings) =>
+            DimensionlessDarcyLossCorrelations::PipeWithFittings(
+                roughness_ratio, length_to_diameter * length_scale, fittings),
+        DimensionlessDarcyLossCorrelations::NonCircularPipe(
+            roughness_ratio, length_to_diameter, form_loss, duct_shape) =>
+            DimensionlessDarcyLossCorrelations::NonCircularPipe(
+                roughness_ratio, length_to_diameter * length_scale,
+                form_loss, duct_shape),
+        _ => loss_correlation,
+    }
+}
+
+/// averages a set of temperatures in kelvin
+fn average_temperature(temperatures: &[ThermodynamicTemperature])
+    -> ThermodynamicTemperature {
+
+    let sum_kelvin: f64 = temperatures.iter()
+        .map(|temperature| temperature.get::<kelvin>())
+        .sum();
+
+    ThermodynamicTemperature::new::<kelvin>(sum_kelvin/temperatures.len() as f64)
+}
+
+impl SimpleShellAndTubeHeatExchanger {
+
+    /// returns the flow arrangement of the heat exchanger
+    pub fn get_flow_arrangement(&self) -> ShellAndTubeFlowArrangement {
+        self.options.flow_arrangement
+    }
+
+    /// sets the flow arrangement of the heat exchanger
+    ///
+    /// for U-bend tube passes, the tube side fluid array and
+    /// inner tube array are rebuilt to be tube_passes times as long
+    /// as the shell, the same goes the other way round when going
+    /// back to a single tube pass. Rebuilt arrays start at the bulk
+    /// temperature of the old arrays, so this is best done right
+    /// after construction
+    ///
+    /// for U-bend tube passes, the number of temperature nodes
+    /// (inner nodes + 2) must be divisible by the number of tube
+    /// passes
+    pub fn try_set_flow_arrangement(&mut self,
+        flow_arrangement: ShellAndTubeFlowArrangement) -> Result<(), TuasLibError> {
+
+        let tube_passes = flow_arrangement.get_number_of_tube_passes();
+        let number_of_temperature_nodes = self.inner_nodes + 2;
+
+        if let ShellAndTubeFlowArrangement::UBendTubePasses(_) = flow_arrangement {
+            if tube_passes < 2 || !tube_passes.is_multiple_of(2) {
+                return Err(TuasLibError::GenericStringError(
+                    "U-bend tube passes must be an even number, \
+                    at least two".to_string()));
+            }
+
+            if !number_of_temperature_nodes.is_multiple_of(tube_passes as usize) {
+                return Err(TuasLibError::GenericStringError(
+                    "number of temperature nodes (inner nodes + 2) must be \
+                    divisible by the number of tube passes".to_string()));
+            }
+        }
+
+        let tube_length: Length = self.get_effective_length() * tube_passes as f64;
+
+        let mut tube_side_fluid_array: FluidArray =
+            self.tube_side_fluid_array_for_single_tube.clone().try_into()?;
+        let mut inner_pipe_shell: SolidColumn =
+            self.inner_pipe_shell_array_for_single_tube.clone().try_into()?;
+
+        let current_tube_length: Length =
+            tube_side_fluid_array.get_component_length_immutable();
+
+        let length_scale: Ratio = tube_length/current_tube_length;
+
+        // rebuild the tube side arrays only if the length changes
+        if (length_scale.get::<ratio>() - 1.0).abs() > 1e-9 {
+
+            let liquid_material: LiquidMaterial =
+                tube_side_fluid_array.material_control_volume.try_into()?;
+            let solid_material: SolidMaterial =
+                inner_pipe_shell.material_control_volume.try_into()?;
+
+            let fluid_temperature = tube_side_fluid_array.try_get_bulk_temperature()?;
+            let wall_temperature = inner_pipe_shell.try_get_bulk_temperature()?;
+
+            // form losses are carried over in the loss correlation
+            // below, so the form loss here is just a placeholder
+            let mut new_tube_side_fluid_array = FluidArray::new_odd_shaped_pipe(
+                tube_length,
+                tube_side_fluid_array.get_hydraulic_diameter_immutable(),
+                tube_side_fluid_array.get_cross_sectional_area_immutable(),
+                fluid_temperature,
+                tube_side_fluid_array.pressure_control_volume,
+                solid_material,
+                liquid_material,
+                Ratio::ZERO,
+                self.inner_nodes,
+                tube_side_fluid_array.get_incline_angle_immutable());
+
+            new_tube_side_fluid_array.fluid_component_loss_properties =
+                scale_loss_correlation_length(
+                    tube_side_fluid_array.fluid_component_loss_properties,
+                    length_scale);
+            new_tube_side_fluid_array.nusselt_correlation =
+                tube_side_fluid_array.nusselt_correlation;
+            new_tube_side_fluid_array.duct_shape = tube_side_fluid_array.duct_shape;
+            new_tube_side_fluid_array.set_mass_flowrate(
+                tube_side_fluid_array.get_mass_flowrate());
+
+            let new_inner_pipe_shell = SolidColumn::new_cylindrical_shell(
+                tube_length,
+                self.tube_side_id,
+                self.tube_side_od,
+                wall_temperature,
+                inner_pipe_shell.pressure_control_volume,
+                solid_material,
+                self.inner_nodes);
+
+            self.tube_side_custom_component_loss_correlation =
+                scale_loss_correlation_length(
+                    self.tube_side_custom_component_loss_correlation,
+                    length_scale);
+
+            self.tube_side_fluid_array_for_single_tube
+                .set(new_tube_side_fluid_array.into())?;
+            self.inner_pipe_shell_array_for_single_tube
+                .set(new_inner_pipe_shell.into())?;
+        }
+
+        self.options.flow_arrangement = flow_arrangement;
+
+        Ok(())
+    }
+
+    /// for U-bend tube passes, each tube side node spans
+    /// tube_passes shell side nodes, and each shell side node
+    /// has one tube side node from every pass next to it
+    ///
+    /// returns the range of shell side nodes next to a tube side node
+    fn get_shell_side_nodes_next_to_tube_node(&self,
+        tube_node_index: usize) -> std::ops::Range<usize> {
+
+        let tube_passes = self.options.flow_arrangement.get_number_of_tube_passes() as usize;
+        let tube_nodes_per_pass = (self.inner_nodes + 2)/tube_passes;
+
+        let pass_index = tube_node_index / tube_nodes_per_pass;
+        let index_within_pass = tube_node_index % tube_nodes_per_pass;
+
+        // odd passes flow back along the shell after the U-bend
+        let axial_segment = if pass_index.is_multiple_of(2) {
+            index_within_pass
+        } else {
+            tube_nodes_per_pass - 1 - index_within_pass
+        };
+
+        (axial_segment * tube_passes)..((axial_segment + 1) * tube_passes)
+    }
+
+    /// maps the shell side fluid temperatures onto the tube side
+    /// nodes for lateral linking according to the flow arrangement
+    ///
+    /// for single tube passes, this returns the temperatures as is
+    pub(crate) fn map_shell_side_temperatures_to_tube_nodes(&self,
+        shell_side_temperature_vector: Vec<ThermodynamicTemperature>)
+        -> Vec<ThermodynamicTemperature> {
+
+        match self.options.flow_arrangement {
+            ShellAndTubeFlowArrangement::CoCurrent |
+            ShellAndTubeFlowArrangement::CounterCurrent =>
+                shell_side_temperature_vector,
+            ShellAndTubeFlowArrangement::UBendTubePasses(_) => {
+                (0..shell_side_temperature_vector.len()).map(|tube_node_index| {
+                    let shell_side_nodes =
+                        self.get_shell_side_nodes_next_to_tube_node(tube_node_index);
+                    average_temperature(&shell_side_temperature_vector[shell_side_nodes])
+                }).collect()
+            },
+        }
+    }
+
+    /// maps the inner tube temperatures onto the shell side
+    /// nodes for lateral linking according to the flow arrangement
+    ///
+    /// for single tube passes, this returns the temperatures as is
+    pub(crate) fn map_tube_temperatures_to_shell_side_nodes(&self,
+        tube_temperature_vector: Vec<ThermodynamicTemperature>)
+        -> Vec<ThermodynamicTemperature> {
+
+        match self.options.flow_arrangement {
+            ShellAndTubeFlowArrangement::CoCurrent |
+            ShellAndTubeFlowArrangement::CounterCurrent =>
+                tube_temperature_vector,
+            ShellAndTubeFlowArrangement::UBendTubePasses(_) => {
+                let number_of_nodes = tube_temperature_vector.len();
+                let mut tube_temperatures_next_to_shell_nodes:
+                    Vec<Vec<ThermodynamicTemperature>> = vec![vec![]; number_of_nodes];
+
+                for (tube_node_index, tube_temperature) in
+                    tube_temperature_vector.iter().enumerate() {
+                    for shell_side_node_index in
+                        self.get_shell_side_nodes_next_to_tube_node(tube_node_index) {
+                        tube_temperatures_next_to_shell_nodes[shell_side_node_index]
+                            .push(*tube_temperature);
+                    }
+                }
+
+                tube_temperatures_next_to_shell_nodes.iter()
+                    .map(|temperatures| average_temperature(temperatures))
+                    .collect()
+            },
+        }
+    }
+}
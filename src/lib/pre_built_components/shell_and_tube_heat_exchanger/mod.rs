@@ -109,6 +109,11 @@
 
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::heat_transfer_correlations::ambient_heat_transfer::AmbientHeatTransferModel;
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::RadiationToSurroundings;
+use crate::heat_transfer_correlations::fouling_and_contact_resistance::FoulingResistance;
+use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityMonitor;
+use crate::tuas_lib_error::TuasLibError;
 use std::f64::consts::PI;
 
 use uom::si::angle::degree;
@@ -124,6 +129,7 @@ use crate::heat_transfer_correlations::nusselt_number_correlations::input_struct
 use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
 
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use flow_arrangement::ShellAndTubeFlowArrangement;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
 
@@ -233,6 +239,72 @@ pub struct SimpleShellAndTubeHeatExchanger {
     /// the shell side
     pub insulation_thickness: Length,
 
+    /// optional physics (ambient heat transfer models, fouling, 
+    /// contact conductance, correlation validity monitors, 
+    /// developing flow and flow arrangement), 
+    /// set using try_set_options
+    options: ShellAndTubeHeatExchangerOptions,
+
+
+}
+
+/// optional physics for the shell and tube heat exchanger 
+///
+/// these are all off by default, so that the heat exchanger behaves 
+/// as a single pass counter current heat exchanger (the default 
+/// flow arrangement) with clean surfaces and perfect contact 
+/// between the outer shell and insulation
+#[derive(Clone,Debug,PartialEq,Default)]
+pub struct ShellAndTubeHeatExchangerOptions {
+
+    /// optional temperature dependent model for the heat transfer 
+    /// coefficient to ambient (eg. natural convection), 
+    /// if None, heat_transfer_to_ambient is used
+    pub ambient_heat_transfer_model: Option<AmbientHeatTransferModel>,
+
+    /// optional radiation from the outer surface to the surroundings, 
+    /// this is linearised and added in parallel to the heat transfer 
+    /// to ambient every timestep
+    pub radiation_to_surroundings: Option<RadiationToSurroundings>,
+
+    /// optional fouling on the inner surface of the tubes
+    /// (tube side fluid)
+    pub tube_side_fouling: Option<FoulingResistance>,
+
+    /// optional fouling on the outer surface of the tubes
+    /// (shell side fluid)
+    pub shell_side_fouling_on_tubes: Option<FoulingResistance>,
+
+    /// optional fouling on the inner surface of the outer shell
+    /// (shell side fluid)
+    pub shell_side_fouling_on_outer_shell: Option<FoulingResistance>,
+
+    /// optional contact (gap) conductance between the outer shell
+    /// and insulation, if None, there is perfect contact
+    pub outer_shell_to_insulation_contact_conductance: Option<HeatTransfer>,
+
+    /// checks the tube side nusselt and friction factor correlations 
+    /// against their validity envelopes and reports extrapolations
+    pub tube_side_correlation_validity_monitor: CorrelationValidityMonitor,
+
+    /// checks the shell side nusselt and friction factor correlations 
+    /// against their validity envelopes and reports extrapolations
+    pub shell_side_correlation_validity_monitor: CorrelationValidityMonitor,
+
+    /// if true, the tube side fluid to inner tube conductance varies 
+    /// from node to node using local nusselt numbers measured from 
+    /// the tube inlet, so that developing flow (entrance) effects 
+    /// are concentrated at the tube inlet
+    ///
+    /// for U-bend tube passes, the flow is taken to develop once 
+    /// over the whole tube length rather than after every bend
+    pub tube_side_local_developing_flow_heat_transfer: bool,
+
+    /// flow arrangement (co-current, counter current or 
+    /// U-bend tube passes), the tube side arrays are rebuilt 
+    /// if needed when this is set using try_set_options 
+    /// or try_set_flow_arrangement
+    pub flow_arrangement: ShellAndTubeFlowArrangement,
 
 }
 
@@ -263,6 +335,18 @@ pub mod type_conversion;
 /// functions to help calibrate the shell and tube heat exchanger 
 pub mod calibration;
 
+/// Bell-Delaware and Kern shell side correlations for 
+/// baffled shell and tube heat exchangers
+pub mod baffled_shell;
+
+/// steady state rating and sizing using the 
+/// effectiveness-NTU method
+pub mod rating;
+
+/// co-current, counter current and U-bend multi pass 
+/// flow arrangements
+pub mod flow_arrangement;
+
 
 /// verification and validation tests for parallel tubing
 /// as well as constructors
@@ -417,6 +501,7 @@ impl SimpleShellAndTubeHeatExchanger {
                 shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
                 tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
                 insulation_thickness,
+                options: ShellAndTubeHeatExchangerOptions::default(),
             };
 
         sthe
@@ -739,8 +824,30 @@ impl SimpleShellAndTubeHeatExchanger {
                 shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
                 tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
                 insulation_thickness: dummy_insulation_thickness,
+                options: ShellAndTubeHeatExchangerOptions::default(),
             };
 
         du_heat_exchanger
     }
+
+    /// returns the optional physics (ambient heat transfer models, 
+    /// fouling, contact conductance, correlation validity monitors, 
+    /// developing flow and flow arrangement) of the heat exchanger
+    pub fn get_options(&self) -> &ShellAndTubeHeatExchangerOptions {
+        &self.options
+    }
+
+    /// sets the optional physics of the heat exchanger
+    ///
+    /// the flow arrangement is set using try_set_flow_arrangement, 
+    /// so the tube side arrays are rebuilt if needed. If the flow 
+    /// arrangement cannot be set, none of the options are changed
+    pub fn try_set_options(&mut self, 
+        options: ShellAndTubeHeatExchangerOptions) -> Result<(), TuasLibError> {
+
+        self.try_set_flow_arrangement(options.flow_arrangement)?;
+        self.options = options;
+
+        Ok(())
+    }
 }
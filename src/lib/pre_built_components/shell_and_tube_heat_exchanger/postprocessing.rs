@@ -69,9 +69,14 @@ impl SimpleShellAndTubeHeatExchanger {
         self.set_shell_side_total_mass_flowrate(shell_side_total_mass_flowrate);
 
 
+        // the shell side conductance is based on the shell side node 
+        // length, for U-bend tube passes, the tube side nodes are 
+        // longer by a factor of the number of tube passes
+        let tube_passes: f64 = 
+            self.options.flow_arrangement.get_number_of_tube_passes() as f64;
         let single_tube_to_shell_side_fluid_conductance: ThermalConductance
             = self.get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
-                correct_for_prandtl_wall_temperatures).unwrap();
+                correct_for_prandtl_wall_temperatures).unwrap() * tube_passes;
         let single_tube_to_tube_side_fluid_conductance: ThermalConductance
             = self.get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
                 correct_for_prandtl_wall_temperatures).unwrap();
@@ -5,6 +5,7 @@ use uom::ConstZero;
 use uom::si::pressure::atmosphere;
 use uom::si::f64::*;
 use ndarray::*;
+use std::f64::consts::PI;
 use super::SimpleShellAndTubeHeatExchanger;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component::FluidComponent;
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
@@ -19,6 +20,8 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 
+use crate::heat_transfer_correlations::ambient_heat_transfer::radiation_to_surroundings::RadiationToSurroundings;
+use crate::heat_transfer_correlations::fouling_and_contact_resistance::{try_add_contact_resistance_in_series, try_add_fouling_resistance_in_series};
 use crate::tuas_lib_error::TuasLibError;
 
 // preprocessing is where heat transfer entities 
@@ -38,6 +41,11 @@ use crate::tuas_lib_error::TuasLibError;
 // the mass flowrates are going in opposite sides, otherwise,
 // it will be in co-current mode
 //
+// the flow arrangement (see flow_arrangement.rs) must match this, 
+// lateral_and_miscellaneous_connections returns an error otherwise. 
+// It is also used in calibration, and for U-bend tube passes, it 
+// determines how the inner tubes are linked to the shell side fluid
+//
 impl SimpleShellAndTubeHeatExchanger {
 
     /// The shell and tube heat exchanger has two configurations,
@@ -70,12 +78,17 @@ impl SimpleShellAndTubeHeatExchanger {
         shell_side_total_mass_flowrate: MassRate,
     ) -> Result<(), TuasLibError>
     {
+        // the flow directions must match the flow arrangement
+        self.options.flow_arrangement.check_mass_flowrate_directions(
+            tube_side_total_mass_flowrate, 
+            shell_side_total_mass_flowrate)?;
+
         // set the mass flowrates first on shell and tube side
         self.set_tube_side_total_mass_flowrate(tube_side_total_mass_flowrate);
         self.set_shell_side_total_mass_flowrate(shell_side_total_mass_flowrate);
 
         // first let's get all the conductances 
-        let heat_transfer_to_ambient = self.heat_transfer_to_ambient;
+        let heat_transfer_to_ambient = self.try_get_heat_transfer_to_ambient()?;
 
         // note that this outer node layer depends on whether 
         // insulation is toggled on by the user 
@@ -93,9 +106,14 @@ impl SimpleShellAndTubeHeatExchanger {
         let insulation_to_outer_shell_conductance: ThermalConductance;
         
         
+        // only the lateral connections record into the correlation 
+        // validity monitors, so each timestep is counted once
+        let record_correlation_validity = true;
+
         let outer_shell_to_shell_side_fluid_conductance: ThermalConductance = 
-            self.get_shell_side_fluid_to_outer_pipe_shell_nodal_conductance(
-                prandtl_wall_correction_setting)?;
+            self.try_get_shell_side_fluid_to_outer_pipe_shell_nodal_conductance(
+                prandtl_wall_correction_setting,
+                record_correlation_validity)?;
 
 
         // for the parallel tube bundle, we have to be extra careful 
@@ -113,11 +131,14 @@ impl SimpleShellAndTubeHeatExchanger {
         // This avoids ambiguity when dealing with the conductance arrays
         //
         let single_tube_to_shell_side_fluid_conductance: ThermalConductance
-            = self.get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
-                prandtl_wall_correction_setting)?;
-        let single_tube_to_tube_side_fluid_conductance: ThermalConductance
-            = self.get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
-                prandtl_wall_correction_setting)?;
+            = self.try_get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+                prandtl_wall_correction_setting,
+                record_correlation_validity)?;
+        let single_tube_to_tube_side_fluid_conductance_vector: Vec<ThermalConductance>
+            = self.try_get_single_tube_side_fluid_array_node_to_inner_pipe_shell_conductance_vector(
+                prandtl_wall_correction_setting,
+                self.options.tube_side_local_developing_flow_heat_transfer,
+                record_correlation_validity)?;
 
         let tube_bundle_to_shell_side_fluid_conductance: ThermalConductance 
             = single_tube_to_shell_side_fluid_conductance * 
@@ -182,13 +203,13 @@ impl SimpleShellAndTubeHeatExchanger {
             // tube side fluid conductance
 
             single_inner_tube_fluid_arr_clone.
-                lateral_link_new_temperature_vector_avg_conductance(
-                    single_tube_to_tube_side_fluid_conductance, 
+                lateral_link_new_temperature_vector_conductance_vector(
+                    single_tube_to_tube_side_fluid_conductance_vector.clone(), 
                     single_inner_pipe_shell_arr_temp_vec.clone())?;
 
             single_inner_pipe_shell_clone.
-                lateral_link_new_temperature_vector_avg_conductance(
-                    single_tube_to_tube_side_fluid_conductance, 
+                lateral_link_new_temperature_vector_conductance_vector(
+                    single_tube_to_tube_side_fluid_conductance_vector, 
                     single_inner_tube_fluid_arr_temp_vec)?;
 
             // next the single inner tube needs to be connected 
@@ -204,15 +225,28 @@ impl SimpleShellAndTubeHeatExchanger {
             // while the conductance to shell side fluid is based on all 
             // the parallel tubes
 
+            //
+            // for U-bend tube passes, each inner tube node spans 
+            // several shell side nodes, and each shell side node 
+            // is next to one inner tube node from every pass. 
+            // The temperatures are mapped accordingly, and the 
+            // conductances are scaled by the number of tube passes 
+            // because they were based on the shell side node length
+
+            let tube_passes: f64 = 
+                self.options.flow_arrangement.get_number_of_tube_passes() as f64;
+
             single_inner_pipe_shell_clone.
                 lateral_link_new_temperature_vector_avg_conductance(
-                    single_tube_to_shell_side_fluid_conductance, 
-                    shell_side_fluid_arry_temp_vec.clone())?;
+                    single_tube_to_shell_side_fluid_conductance * tube_passes, 
+                    self.map_shell_side_temperatures_to_tube_nodes(
+                        shell_side_fluid_arry_temp_vec.clone()))?;
 
             shell_side_fluid_arr_clone. 
                 lateral_link_new_temperature_vector_avg_conductance(
-                    tube_bundle_to_shell_side_fluid_conductance, 
-                    single_inner_pipe_shell_arr_temp_vec)?;
+                    tube_bundle_to_shell_side_fluid_conductance * tube_passes, 
+                    self.map_tube_temperatures_to_shell_side_nodes(
+                        single_inner_pipe_shell_arr_temp_vec))?;
 
             // next, we need to link the shell side fluid 
             // to the outer shell 
@@ -266,6 +300,20 @@ impl SimpleShellAndTubeHeatExchanger {
                         outer_node_layer_to_air_conductance, 
                         ambient_temperature_vector)?;
 
+                // and radiation to the surroundings, if any
+
+                if let Some(radiation_to_surroundings) = self.options.radiation_to_surroundings {
+                    let insulation_to_surroundings_conductance_vector = 
+                        self.get_radiation_to_surroundings_nodal_conductance_vector(
+                            radiation_to_surroundings)?;
+
+                    insulation_array_clone
+                        .lateral_link_new_temperature_vector_conductance_vector(
+                            insulation_to_surroundings_conductance_vector, 
+                            vec![radiation_to_surroundings.surroundings_temperature;
+                                number_of_temperature_nodes])?;
+                }
+
                 // for the insulation array,
                 // lateral connections are done, 
                 // so now, modify the heat transfer entity 
@@ -282,6 +330,20 @@ impl SimpleShellAndTubeHeatExchanger {
                     .lateral_link_new_temperature_vector_avg_conductance(
                         outer_shell_to_shell_side_fluid_conductance, 
                         ambient_temperature_vector)?;
+
+                // and radiation to the surroundings, if any
+
+                if let Some(radiation_to_surroundings) = self.options.radiation_to_surroundings {
+                    let outer_shell_to_surroundings_conductance_vector = 
+                        self.get_radiation_to_surroundings_nodal_conductance_vector(
+                            radiation_to_surroundings)?;
+
+                    outer_shell_clone
+                        .lateral_link_new_temperature_vector_conductance_vector(
+                            outer_shell_to_surroundings_conductance_vector, 
+                            vec![radiation_to_surroundings.surroundings_temperature;
+                                number_of_temperature_nodes])?;
+                }
             }
 
             // after this, we are done for the internal connections
@@ -395,6 +457,40 @@ impl SimpleShellAndTubeHeatExchanger {
     }
 
 
+    /// obtains the heat transfer coefficient from the outer 
+    /// layer of the shell and tube heat exchanger (sthe) to ambient
+    ///
+    /// this is heat_transfer_to_ambient unless an ambient heat 
+    /// transfer model is set, in which case it is calculated from 
+    /// the bulk temperature and outer diameter of the insulation 
+    /// (if switched on) or the outer shell
+    #[inline]
+    pub fn try_get_heat_transfer_to_ambient(&mut self) 
+        -> Result<HeatTransfer,TuasLibError> {
+
+        let ambient_heat_transfer_model = match self.options.ambient_heat_transfer_model {
+            Some(model) => model,
+            None => return Ok(self.heat_transfer_to_ambient),
+        };
+
+        let (mut outer_solid_array_clone, od): (SolidColumn, Length) = 
+            if self.heat_exchanger_has_insulation {
+                (self.insulation_array.clone().try_into()?,
+                self.shell_side_od + 2.0*self.insulation_thickness)
+            } else {
+                (self.outer_shell.clone().try_into()?,
+                self.shell_side_od)
+            };
+
+        let outer_node_temperature: ThermodynamicTemperature = 
+            outer_solid_array_clone.try_get_bulk_temperature()?;
+
+        ambient_heat_transfer_model.try_get_heat_transfer_coefficient(
+            outer_node_temperature, 
+            self.ambient_temperature, 
+            od)
+    }
+
     /// obtains air to shell and tube heat exchanger (sthe)
     /// outer array conductance 
     ///
@@ -507,13 +603,130 @@ impl SimpleShellAndTubeHeatExchanger {
     }
 
 
+    /// obtains the linearised radiation conductance from each 
+    /// node of the outer sthe layer to the surroundings
+    ///
+    /// The outer array will be insulation if insulation is switched on,
+    /// or the outer shell if insulation is switched off. The 
+    /// radiation heat transfer coefficient is calculated from 
+    /// each node temperature
+    #[inline]
+    pub fn get_radiation_to_surroundings_nodal_conductance_vector(&mut self,
+        radiation_to_surroundings: RadiationToSurroundings)
+        -> Result<Vec<ThermalConductance>,TuasLibError> 
+    {
+
+        let number_of_temperature_nodes = self.inner_nodes + 2;
+
+        let (outer_solid_array_clone, id, od): (SolidColumn, Length, Length) = 
+            if self.heat_exchanger_has_insulation {
+                (self.insulation_array.clone().try_into()?,
+                self.shell_side_od,
+                self.shell_side_od + 2.0*self.insulation_thickness)
+            } else {
+                (self.outer_shell.clone().try_into()?,
+                self.shell_side_id,
+                self.shell_side_od)
+            };
+
+        // heated length is the shell side length 
+        let heated_length: Length = self
+            .get_clone_of_shell_side_fluid_component()
+            .get_component_length_immutable();
+
+        let cylinder_mid_diameter: Length = 0.5*(id+od);
+
+        let node_length = heated_length / 
+            number_of_temperature_nodes as f64;
+
+        let outer_node_temp_vector: Vec<ThermodynamicTemperature> 
+            = outer_solid_array_clone.get_temperature_vector()?;
+
+        outer_node_temp_vector.iter().map(|&node_temperature| {
+
+            let h_rad: HeatTransfer = radiation_to_surroundings
+                .get_linearised_heat_transfer_coefficient(node_temperature);
+
+            let outer_node_surroundings_conductance_interaction: HeatTransferInteractionType
+            = HeatTransferInteractionType::
+                CylindricalConductionConvectionLiquidOutside(
+                    (outer_solid_array_clone.material_control_volume, 
+                        (od-cylinder_mid_diameter).into(),
+                        node_temperature,
+                        outer_solid_array_clone.pressure_control_volume),
+                    (h_rad,
+                        od.into(),
+                        node_length.into())
+                );
+
+            try_get_thermal_conductance_based_on_interaction(
+                radiation_to_surroundings.surroundings_temperature,
+                node_temperature,
+                outer_solid_array_clone.pressure_control_volume,
+                outer_solid_array_clone.pressure_control_volume,
+                outer_node_surroundings_conductance_interaction,
+            )
+        }).collect()
+    }
+
     /// obtains tube side fluid to pipe shell conductance
+    ///
+    /// this is based on the nusselt number of the whole tube, 
+    /// so it is the same for every node
     #[inline]
     pub fn get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
         &mut self,
         correct_prandtl_for_wall_temperatures: bool) 
         -> Result<ThermalConductance,TuasLibError> 
     {
+        let local_developing_flow = false;
+        let record_correlation_validity = false;
+
+        let uniform_conductance_vector = self
+            .try_get_single_tube_side_fluid_array_node_to_inner_pipe_shell_conductance_vector(
+                correct_prandtl_for_wall_temperatures,
+                local_developing_flow,
+                record_correlation_validity)?;
+
+        Ok(uniform_conductance_vector[0])
+    }
+
+    /// obtains tube side fluid to pipe shell conductance for 
+    /// each node
+    ///
+    /// if tube_side_local_developing_flow_heat_transfer is set, 
+    /// local nusselt numbers are used for each node, otherwise 
+    /// all nodes have the same conductance
+    #[inline]
+    pub fn get_single_tube_side_fluid_array_node_to_inner_pipe_shell_conductance_vector(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool) 
+        -> Result<Vec<ThermalConductance>,TuasLibError> 
+    {
+        let local_developing_flow = 
+            self.options.tube_side_local_developing_flow_heat_transfer;
+        let record_correlation_validity = false;
+
+        self.try_get_single_tube_side_fluid_array_node_to_inner_pipe_shell_conductance_vector(
+            correct_prandtl_for_wall_temperatures,
+            local_developing_flow,
+            record_correlation_validity)
+    }
+
+    /// obtains tube side fluid to pipe shell conductance for 
+    /// each node, optionally with local nusselt numbers
+    ///
+    /// the tube side correlation validity monitor is only updated 
+    /// if record_correlation_validity is set, the public getters 
+    /// leave it alone so that they don't double count evaluations
+    #[inline]
+    fn try_get_single_tube_side_fluid_array_node_to_inner_pipe_shell_conductance_vector(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool,
+        local_developing_flow: bool,
+        record_correlation_validity: bool) 
+        -> Result<Vec<ThermalConductance>,TuasLibError> 
+    {
 
         // the thermal conductance here should be based on the 
         // nusselt number correlation
@@ -695,6 +908,22 @@ impl SimpleShellAndTubeHeatExchanger {
         // for debugging
         //dbg!(&nusselt_estimate_tube_side);
 
+        // record whether the tube side correlations are used within 
+        // their validity envelopes
+        if record_correlation_validity {
+            self.options.tube_side_correlation_validity_monitor
+                .try_check_nusselt_correlation(
+                    &self.tube_side_nusselt_correlation,
+                    reynolds_number_abs_for_nusselt,
+                    pipe_prandtl_reynolds_data.prandtl_bulk,
+                    pipe_prandtl_reynolds_data.length_to_diameter)?;
+            self.options.tube_side_correlation_validity_monitor
+                .try_check_friction_correlation(
+                    &self.tube_side_custom_component_loss_correlation,
+                    reynolds_number_abs_for_nusselt,
+                    pipe_prandtl_reynolds_data.length_to_diameter)?;
+        }
+
 
         // now we can get the heat transfer coeff, 
 
@@ -723,38 +952,77 @@ impl SimpleShellAndTubeHeatExchanger {
 
         let cylinder_mid_diameter: Length = 0.5*(id+od);
 
+        let tube_side_fouling = self.options.tube_side_fouling;
+
+        let get_nodal_conductance = |tube_h_to_fluid: HeatTransfer| 
+            -> Result<ThermalConductance,TuasLibError> {
+
+            let fluid_pipe_shell_conductance_interaction: HeatTransferInteractionType
+                = HeatTransferInteractionType::
+                CylindricalConductionConvectionLiquidInside(
+                    (solid_material.into(), 
+                     (cylinder_mid_diameter - id).into(),
+                     pipe_shell_surf_temperature,
+                     atmospheric_pressure),
+                     (tube_h_to_fluid,
+                      id.into(),
+                      node_length.into())
+                );
+
+            // now based on conductance interaction, 
+            // we can obtain thermal conductance, the temperatures 
+            // and pressures don't really matter
+            //
+            // this is because all the thermal conductance data 
+            // has already been loaded into the thermal conductance 
+            // interaction object
+
+            let fluid_pipe_shell_nodal_thermal_conductance: ThermalConductance = 
+                try_get_thermal_conductance_based_on_interaction(
+                    fluid_temperature,
+                    pipe_shell_surf_temperature,
+                    atmospheric_pressure,
+                    atmospheric_pressure,
+                    fluid_pipe_shell_conductance_interaction)?;
+
+
+            // fouling on the inner surface of the tube sits in series 
+            // with the convection and conduction resistances
+            try_add_fouling_resistance_in_series(
+                fluid_pipe_shell_nodal_thermal_conductance,
+                tube_side_fouling,
+                PI * id * node_length)
+        };
+
+        if !local_developing_flow {
+            let fluid_pipe_shell_nodal_thermal_conductance = 
+                get_nodal_conductance(tube_h_to_fluid)?;
+
+            return Ok(vec![fluid_pipe_shell_nodal_thermal_conductance;
+                number_of_temperature_nodes]);
+        }
 
+        // for developing flow, the heat transfer coefficient 
+        // is scaled by the local nusselt number of each node, 
+        // measured from the tube inlet
+        let mut local_nusselt_ratio_vector: Vec<Ratio> = 
+            self.tube_side_nusselt_correlation
+            .try_get_local_nusselt_ratio_vector(
+                pipe_prandtl_reynolds_data.prandtl_bulk, 
+                pipe_prandtl_reynolds_data.prandtl_wall, 
+                modified_darcy_friction_factor,
+                reynolds_number_abs_for_nusselt,
+                pipe_prandtl_reynolds_data.length_to_diameter,
+                number_of_temperature_nodes)?;
 
-        let fluid_pipe_shell_conductance_interaction: HeatTransferInteractionType
-            = HeatTransferInteractionType::
-            CylindricalConductionConvectionLiquidInside(
-                (solid_material.into(), 
-                 (cylinder_mid_diameter - id).into(),
-                 pipe_shell_surf_temperature,
-                 atmospheric_pressure),
-                 (tube_h_to_fluid,
-                  id.into(),
-                  node_length.into())
-            );
-
-        // now based on conductance interaction, 
-        // we can obtain thermal conductance, the temperatures 
-        // and pressures don't really matter
-        //
-        // this is because all the thermal conductance data 
-        // has already been loaded into the thermal conductance 
-        // interaction object
-
-        let fluid_pipe_shell_nodal_thermal_conductance: ThermalConductance = 
-            try_get_thermal_conductance_based_on_interaction(
-                fluid_temperature,
-                pipe_shell_surf_temperature,
-                atmospheric_pressure,
-                atmospheric_pressure,
-                fluid_pipe_shell_conductance_interaction)?;
-
+        // for reverse flow, the tube inlet is at the front
+        if single_tube_mass_flowrate.is_sign_negative() {
+            local_nusselt_ratio_vector.reverse();
+        }
 
-        return Ok(fluid_pipe_shell_nodal_thermal_conductance);
+        local_nusselt_ratio_vector.iter().map(|&local_nusselt_ratio| {
+            get_nodal_conductance(tube_h_to_fluid * local_nusselt_ratio)
+        }).collect()
     }
 
 
@@ -775,6 +1043,23 @@ impl SimpleShellAndTubeHeatExchanger {
         correct_prandtl_for_wall_temperatures: bool) 
         -> Result<ThermalConductance,TuasLibError> 
     {
+        let record_correlation_validity = false;
+
+        self.try_get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+            correct_prandtl_for_wall_temperatures,
+            record_correlation_validity)
+    }
+
+    /// obtains shell side fluid to *single* pipe shell conductance, 
+    /// the shell side correlation validity monitor is only updated 
+    /// if record_correlation_validity is set
+    #[inline]
+    fn try_get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool,
+        record_correlation_validity: bool) 
+        -> Result<ThermalConductance,TuasLibError> 
+    {
 
         // the thermal conductance here should be based on the 
         // nusselt number correlation
@@ -936,6 +1221,22 @@ impl SimpleShellAndTubeHeatExchanger {
         // for debugging
         //dbg!(&nusselt_estimate_shell);
 
+        // record whether the shell side correlations are used within 
+        // their validity envelopes
+        if record_correlation_validity {
+            self.options.shell_side_correlation_validity_monitor
+                .try_check_nusselt_correlation(
+                    &shell_side_fluid_to_inner_tube_surf_nusselt_correlation,
+                    reynolds_number_abs_for_nusselt_estimate,
+                    bulk_prandtl_number,
+                    length_to_diameter)?;
+            self.options.shell_side_correlation_validity_monitor
+                .try_check_friction_correlation(
+                    &self.shell_side_custom_component_loss_correlation,
+                    reynolds_number_abs_for_nusselt_estimate,
+                    length_to_diameter)?;
+        }
+
 
 
         // now we can get the heat transfer coeff, 
@@ -999,6 +1300,13 @@ impl SimpleShellAndTubeHeatExchanger {
                 shell_fluid_to_inner_tube_surf_conductance_interaction)?;
 
 
+        // fouling on the outer surface of the tube
+        let shell_fluid_to_inner_tube_surf_nodal_thermal_conductance = 
+            try_add_fouling_resistance_in_series(
+                shell_fluid_to_inner_tube_surf_nodal_thermal_conductance,
+                self.options.shell_side_fouling_on_tubes,
+                PI * od * node_length)?;
+
         return Ok(shell_fluid_to_inner_tube_surf_nodal_thermal_conductance);
     }
 
@@ -1017,6 +1325,23 @@ impl SimpleShellAndTubeHeatExchanger {
         &mut self,
         correct_prandtl_for_wall_temperatures: bool) 
         -> Result<ThermalConductance,TuasLibError> 
+    {
+        let record_correlation_validity = false;
+
+        self.try_get_shell_side_fluid_to_outer_pipe_shell_nodal_conductance(
+            correct_prandtl_for_wall_temperatures,
+            record_correlation_validity)
+    }
+
+    /// obtains shell side fluid to outer shell conductance per node, 
+    /// the shell side correlation validity monitor is only updated 
+    /// if record_correlation_validity is set
+    #[inline]
+    fn try_get_shell_side_fluid_to_outer_pipe_shell_nodal_conductance(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool,
+        record_correlation_validity: bool) 
+        -> Result<ThermalConductance,TuasLibError> 
     {
         // the thermal conductance here should be based on the 
         // nusselt number correlation
@@ -1173,6 +1498,18 @@ impl SimpleShellAndTubeHeatExchanger {
 
         }
 
+        // the friction factor was already checked for the 
+        // shell side to tubes, so only the nusselt correlation 
+        // to the outer shell is checked here
+        if record_correlation_validity {
+            self.options.shell_side_correlation_validity_monitor
+                .try_check_nusselt_correlation(
+                    &shell_side_fluid_to_outer_tube_surf_nusselt_correlation,
+                    reynolds_number_abs_for_nusselt_estimate,
+                    bulk_prandtl_number,
+                    length_to_diameter)?;
+        }
+
 
         // now we can get the heat transfer coeff, 
 
@@ -1228,6 +1565,13 @@ impl SimpleShellAndTubeHeatExchanger {
                 atmospheric_pressure,
                 shell_fluid_to_outer_tube_conductance_interaction)?;
 
+        // fouling on the inner surface of the outer shell
+        let shell_fluid_to_outer_tube_surf_nodal_thermal_conductance = 
+            try_add_fouling_resistance_in_series(
+                shell_fluid_to_outer_tube_surf_nodal_thermal_conductance,
+                self.options.shell_side_fouling_on_outer_shell,
+                PI * self.shell_side_id * node_length)?;
+
         return Ok(shell_fluid_to_outer_tube_surf_nodal_thermal_conductance);
 
     }
@@ -1315,8 +1659,15 @@ impl SimpleShellAndTubeHeatExchanger {
 
         let total_resistance = insulation_material_resistance + solid_pipe_material_resistance;
 
+        // contact resistance between the outer shell and insulation
+        // sits at the outer shell outer diameter
+        let outer_shell_to_insulation_nodal_conductance = 
+            try_add_contact_resistance_in_series(
+                1.0/total_resistance,
+                self.options.outer_shell_to_insulation_contact_conductance,
+                PI * shell_od * node_length)?;
 
-        return Ok(1.0/total_resistance);
+        return Ok(outer_shell_to_insulation_nodal_conductance);
     }
 
     /// spawns a thread and moves the clone of the entire heater object into the 
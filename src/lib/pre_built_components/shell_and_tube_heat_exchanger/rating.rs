@@ -0,0 +1,199 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::heat_transfer_correlations::effectiveness_ntu::{try_get_required_overall_conductance, try_rate_heat_exchanger, HeatExchangerRating};
+use crate::tuas_lib_error::TuasLibError;
+
+use super::SimpleShellAndTubeHeatExchanger;
+
+/// steady state rating and sizing using the effectiveness-NTU method
+///
+/// the overall conductance UA is based on the current state of the
+/// heat exchanger (fluid temperatures and nusselt correlations), and
+/// parasitic heat loss to the outer shell and ambient is neglected
+impl SimpleShellAndTubeHeatExchanger {
+
+    /// overall conductance UA between the tube side and shell side
+    /// fluids for the whole tube bundle
+    ///
+    /// UA = U A_shell, where U is from overall_htc_based_on_conductance
+    /// and A_shell = N_t pi d_o L
+    pub fn get_tube_bundle_overall_conductance(&mut self,
+        correct_for_prandtl_wall_temperatures: bool,
+        tube_side_total_mass_flowrate: MassRate,
+        shell_side_total_mass_flowrate: MassRate) -> ThermalConductance {
+
+        let overall_heat_transfer_coeff = self.overall_htc_based_on_conductance(
+            correct_for_prandtl_wall_temperatures,
+            tube_side_total_mass_flowrate,
+            shell_side_total_mass_flowrate);
+
+        overall_heat_transfer_coeff
+            * self.circular_tube_bundle_heat_transfer_area_shell_side()
+    }
+
+    /// returns heat capacity rates (m c_p) of the tube side and shell
+    /// side streams, c_p is evaluated at the inlet temperatures
+    fn try_get_capacity_rates(&self,
+        tube_inlet_temperature: ThermodynamicTemperature,
+        tube_side_total_mass_flowrate: MassRate,
+        shell_inlet_temperature: ThermodynamicTemperature,
+        shell_side_total_mass_flowrate: MassRate)
+        -> Result<(ThermalConductance, ThermalConductance), TuasLibError> {
+
+        let tube_side_fluid_array: FluidArray =
+            self.tube_side_fluid_array_for_single_tube.clone().try_into()?;
+        let shell_side_fluid_array: FluidArray =
+            self.shell_side_fluid_array.clone().try_into()?;
+
+        let tube_fluid_material: LiquidMaterial =
+            tube_side_fluid_array.material_control_volume.try_into()?;
+        let shell_fluid_material: LiquidMaterial =
+            shell_side_fluid_array.material_control_volume.try_into()?;
+
+        let tube_capacity_rate: ThermalConductance = tube_side_total_mass_flowrate.abs()
+            * tube_fluid_material.try_get_cp(tube_inlet_temperature)?;
+        let shell_capacity_rate: ThermalConductance = shell_side_total_mass_flowrate.abs()
+            * shell_fluid_material.try_get_cp(shell_inlet_temperature)?;
+
+        Ok((tube_capacity_rate, shell_capacity_rate))
+    }
+
+    /// rates the heat exchanger at steady state using the
+    /// effectiveness-NTU method with the current UA
+    ///
+    /// the effectiveness-NTU flow arrangement follows from the flow 
+    /// arrangement of the heat exchanger
+    ///
+    /// the hot stream is whichever stream has the hotter inlet
+    /// temperature, check the inlet temperatures of the returned
+    /// rating to see which stream is which
+    pub fn try_rate_with_effectiveness_ntu(&mut self,
+        correct_for_prandtl_wall_temperatures: bool,
+        tube_inlet_temperature: ThermodynamicTemperature,
+        tube_side_total_mass_flowrate: MassRate,
+        shell_inlet_temperature: ThermodynamicTemperature,
+        shell_side_total_mass_flowrate: MassRate)
+        -> Result<HeatExchangerRating, TuasLibError> {
+
+        let flow_arrangement = self.options.flow_arrangement
+            .get_effectiveness_ntu_flow_arrangement();
+
+        let overall_conductance = self.get_tube_bundle_overall_conductance(
+            correct_for_prandtl_wall_temperatures,
+            tube_side_total_mass_flowrate,
+            shell_side_total_mass_flowrate);
+
+        let (tube_capacity_rate, shell_capacity_rate) =
+            self.try_get_capacity_rates(
+                tube_inlet_temperature,
+                tube_side_total_mass_flowrate,
+                shell_inlet_temperature,
+                shell_side_total_mass_flowrate)?;
+
+        if shell_inlet_temperature >= tube_inlet_temperature {
+            try_rate_heat_exchanger(flow_arrangement, overall_conductance,
+                shell_inlet_temperature, shell_capacity_rate,
+                tube_inlet_temperature, tube_capacity_rate)
+        } else {
+            try_rate_heat_exchanger(flow_arrangement, overall_conductance,
+                tube_inlet_temperature, tube_capacity_rate,
+                shell_inlet_temperature, shell_capacity_rate)
+        }
+    }
+
+    /// ratio of the UA required for a heat duty to the current UA
+    ///
+    /// for sizing, the nusselt numbers are always corrected for
+    /// wall prandtl number
+    fn try_get_required_to_current_conductance_ratio(&mut self,
+        heat_duty: Power,
+        tube_inlet_temperature: ThermodynamicTemperature,
+        tube_side_total_mass_flowrate: MassRate,
+        shell_inlet_temperature: ThermodynamicTemperature,
+        shell_side_total_mass_flowrate: MassRate) -> Result<Ratio, TuasLibError> {
+
+        let flow_arrangement = self.options.flow_arrangement
+            .get_effectiveness_ntu_flow_arrangement();
+
+        let correct_for_prandtl_wall_temperatures = true;
+        let current_conductance = self.get_tube_bundle_overall_conductance(
+            correct_for_prandtl_wall_temperatures,
+            tube_side_total_mass_flowrate,
+            shell_side_total_mass_flowrate);
+
+        let (tube_capacity_rate, shell_capacity_rate) =
+            self.try_get_capacity_rates(
+                tube_inlet_temperature,
+                tube_side_total_mass_flowrate,
+                shell_inlet_temperature,
+                shell_side_total_mass_flowrate)?;
+
+        let required_conductance = if shell_inlet_temperature >= tube_inlet_temperature {
+            try_get_required_overall_conductance(flow_arrangement, heat_duty.abs(),
+                shell_inlet_temperature, shell_capacity_rate,
+                tube_inlet_temperature, tube_capacity_rate)?
+        } else {
+            try_get_required_overall_conductance(flow_arrangement, heat_duty.abs(),
+                tube_inlet_temperature, tube_capacity_rate,
+                shell_inlet_temperature, shell_capacity_rate)?
+        };
+
+        Ok(required_conductance/current_conductance)
+    }
+
+    /// tube length needed to reach a heat duty, keeping the
+    /// overall heat transfer coefficient U at its current value
+    ///
+    /// L_required = L UA_required/UA_current
+    ///
+    /// this is a first estimate, entrance effects in the nusselt
+    /// correlations will change U slightly for the new length
+    pub fn try_size_tube_length_for_heat_duty(&mut self,
+        heat_duty: Power,
+        tube_inlet_temperature: ThermodynamicTemperature,
+        tube_side_total_mass_flowrate: MassRate,
+        shell_inlet_temperature: ThermodynamicTemperature,
+        shell_side_total_mass_flowrate: MassRate) -> Result<Length, TuasLibError> {
+
+        let conductance_ratio = self.try_get_required_to_current_conductance_ratio(
+            heat_duty,
+            tube_inlet_temperature,
+            tube_side_total_mass_flowrate,
+            shell_inlet_temperature,
+            shell_side_total_mass_flowrate)?;
+
+        Ok(self.get_effective_length() * conductance_ratio)
+    }
+
+    /// number of tubes needed to reach a heat duty, keeping the
+    /// overall heat transfer coefficient U at its current value,
+    /// rounded up to the next whole tube
+    ///
+    /// N_required = N_t UA_required/UA_current
+    ///
+    /// this is a first estimate, changing the number of tubes
+    /// changes the flow velocities and hence U, so it is best
+    /// to rebuild the heat exchanger and check again
+    pub fn try_size_number_of_tubes_for_heat_duty(&mut self,
+        heat_duty: Power,
+        tube_inlet_temperature: ThermodynamicTemperature,
+        tube_side_total_mass_flowrate: MassRate,
+        shell_inlet_temperature: ThermodynamicTemperature,
+        shell_side_total_mass_flowrate: MassRate) -> Result<u32, TuasLibError> {
+
+        let conductance_ratio = self.try_get_required_to_current_conductance_ratio(
+            heat_duty,
+            tube_inlet_temperature,
+            tube_side_total_mass_flowrate,
+            shell_inlet_temperature,
+            shell_side_total_mass_flowrate)?;
+
+        let number_of_tubes = (self.number_of_tubes as f64
+            * conductance_ratio.get::<ratio>() - 1e-9).ceil().max(1.0);
+
+        Ok(number_of_tubes as u32)
+    }
+}
@@ -190,6 +190,7 @@ pub fn overall_htc_postprocessing_basic_test_shell_and_tube_heat_exchanger(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     let correct_for_prandtl_wall_temperatures = true;
@@ -410,6 +411,7 @@ pub fn shell_side_tube_bundle_area_basic_test_shell_and_tube_heat_exchanger(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
 
@@ -170,7 +170,7 @@ pub fn dhx_calibration_regression_unit_test_shell_to_tube_only(
 
     use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
     use crate::pre_built_components::ciet_steady_state_natural_circulation_test_components::coupled_dracs_loop_tests::dhx_constructor::*;
-    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::flow_arrangement::ShellAndTubeFlowArrangement;
     use crate::prelude::beta_testing::{FluidArray, HeatTransferEntity, HeatTransferInteractionType, LiquidMaterial};
     use uom::ConstZero;
 
@@ -503,7 +503,9 @@ pub fn dhx_calibration_regression_unit_test_shell_to_tube_only(
     let shell_inlet_temperature = dhx_shell_side_inlet_temperature;
     let shell_outlet_temeprature = dhx_shell_outlet_actual_temperature;
     let shell_mass_flowrate = experimental_pri_mass_flowrate;
-    let is_counter_current = true;
+    // the dhx is counter current by default
+    assert_eq!(dhx_sthe.get_flow_arrangement(), 
+        ShellAndTubeFlowArrangement::CounterCurrent);
     // assert overall thermal resistance 
     // vs that gained from expt data
     let thermal_resistance_based_on_expt_data: ThermalResistance = 
@@ -513,8 +515,7 @@ pub fn dhx_calibration_regression_unit_test_shell_to_tube_only(
             tube_mass_flowrate, 
             shell_inlet_temperature, 
             shell_outlet_temeprature, 
-            shell_mass_flowrate, 
-            is_counter_current).recip();
+            shell_mass_flowrate).unwrap().recip();
 
     // should agree within 5%
     //
@@ -560,13 +561,12 @@ pub fn dhx_calibration_regression_unit_test_shell_to_tube_only(
             tube_outlet_temeprature, 
             tube_mass_flowrate);
     let thermal_resistance_based_on_entrance_cv_temp: ThermalResistance = 
-        SimpleShellAndTubeHeatExchanger::get_ua_based_on_heat_transfer_and_temperature_differences(
+        dhx_sthe.get_ua_based_on_heat_transfer_and_flow_arrangement(
             heat_transferred_in_sthe, 
             dhx_tube_entrance_cv_actual_temperature, 
             tube_outlet_temeprature, 
             dhx_shell_entrance_cv_actual_temperature, 
-            shell_outlet_temeprature, 
-            is_counter_current).recip();
+            shell_outlet_temeprature).unwrap().recip();
 
 
     dbg!(&(thermal_resistance_overall_expected,
@@ -597,8 +597,7 @@ pub fn dhx_calibration_regression_unit_test_shell_to_tube_only(
             tube_mass_flowrate, 
             shell_inlet_temperature, 
             shell_outlet_temeprature, 
-            shell_mass_flowrate, 
-            is_counter_current);
+            shell_mass_flowrate).unwrap();
 
     // these two should agree to within 8%
     // now due to error propagation, the calculated shell side nusselt 
@@ -627,8 +626,7 @@ pub fn dhx_calibration_regression_unit_test_shell_to_tube_only(
             dhx_tube_entrance_cv_actual_temperature, 
             tube_outlet_temeprature, 
             dhx_shell_entrance_cv_actual_temperature, 
-            shell_outlet_temeprature, 
-            is_counter_current);
+            shell_outlet_temeprature).unwrap();
 
     dbg!(&(
             nusselt_number_shell_side_to_tubes,
@@ -1024,7 +1022,6 @@ pub fn dhx_calibration_regression_unit_test_parasitic_heat_loss_only(
     let shell_inlet_temperature = dhx_shell_side_inlet_temperature;
     let shell_outlet_temeprature = dhx_shell_outlet_actual_temperature;
     let shell_mass_flowrate = experimental_pri_mass_flowrate;
-    let is_counter_current = true;
     // assert overall thermal resistance 
     // vs that gained from expt data
     let thermal_resistance_based_on_expt_data: ThermalResistance = 
@@ -1034,8 +1031,7 @@ pub fn dhx_calibration_regression_unit_test_parasitic_heat_loss_only(
             tube_mass_flowrate, 
             shell_inlet_temperature, 
             shell_outlet_temeprature, 
-            shell_mass_flowrate, 
-            is_counter_current).recip();
+            shell_mass_flowrate).unwrap().recip();
 
     // should agree within 8%
     approx::assert_relative_eq!(
@@ -1052,8 +1048,7 @@ pub fn dhx_calibration_regression_unit_test_parasitic_heat_loss_only(
             tube_mass_flowrate, 
             shell_inlet_temperature, 
             shell_outlet_temeprature, 
-            shell_mass_flowrate, 
-            is_counter_current);
+            shell_mass_flowrate).unwrap();
 
     // these two should agree to within 10%
     approx::assert_relative_eq!(
@@ -234,6 +234,7 @@ pub fn du_heat_exchanger_constructor_test(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     let du_heat_exchanger_test = 
@@ -490,6 +491,7 @@ pub fn insulated_sthe_constructor_test(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     let sthe_length = pipe_length;
@@ -0,0 +1,86 @@
+/// the tube and shell side correlations of Du's heat exchanger
+/// are recorded separately in each monitor
+///
+/// the tube side uses the Gnielinski correlation, while the shell
+/// side uses a custom (fitted) Gnielinski correlation and a fixed
+/// nusselt number for parasitic heat loss, neither of which have
+/// a declared envelope. Both sides use the churchill friction factor
+///
+/// only the lateral connections record into the monitors, the 
+/// conductance getters (eg. for the overall htc) leave them alone
+///
+/// at very low flow, the turbulent Gnielinski correlation is out of
+/// its envelope, and the error policy stops the calculation
+///
+/// Du, B. C., He, Y. L., Qiu, Y., Liang, Q., & Zhou, Y. P.
+/// (2018). Investigation on heat transfer characteristics of
+/// molten salt in a shell-and-tube heat exchanger. International
+/// Communications in Heat and Mass Transfer, 96, 61-68.
+#[test]
+pub fn correlation_validity_in_du_heat_exchanger(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+
+    use crate::heat_transfer_correlations::correlation_validity::CorrelationValidityPolicy;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+
+    let mut du_heat_exchanger =
+        SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+
+    let correct_for_prandtl_wall_temperatures = true;
+
+    du_heat_exchanger.lateral_and_miscellaneous_connections(
+        correct_for_prandtl_wall_temperatures,
+        MassRate::new::<kilogram_per_second>(4.0),
+        MassRate::new::<kilogram_per_second>(-8.0)).unwrap();
+
+    // the getters should not count the evaluations a second time
+    du_heat_exchanger.overall_htc_based_on_conductance(
+        correct_for_prandtl_wall_temperatures,
+        MassRate::new::<kilogram_per_second>(4.0),
+        MassRate::new::<kilogram_per_second>(-8.0));
+
+    let tube_side_monitor = &du_heat_exchanger.get_options()
+        .tube_side_correlation_validity_monitor;
+    let tube_side_gnielinski = tube_side_monitor
+        .get_record("Gnielinski").unwrap();
+    assert_eq!(tube_side_gnielinski.number_of_evaluations, 1);
+    assert_eq!(tube_side_gnielinski.number_of_extrapolations, 0);
+    assert_eq!(tube_side_monitor.get_record("Churchill").unwrap()
+        .number_of_evaluations, 1);
+
+    let shell_side_monitor = &du_heat_exchanger.get_options()
+        .shell_side_correlation_validity_monitor;
+    assert_eq!(shell_side_monitor.extrapolation_report.len(), 1);
+    assert_eq!(shell_side_monitor.get_record("Churchill").unwrap()
+        .number_of_evaluations, 1);
+    assert!(shell_side_monitor.get_record("Gnielinski").is_none());
+
+    // turbulent only gnielinski at very low flow
+    if let NusseltCorrelation::PipeGnielinskiGeneric(data) =
+        du_heat_exchanger.tube_side_nusselt_correlation {
+        du_heat_exchanger.tube_side_nusselt_correlation =
+            NusseltCorrelation::PipeGnielinskiTurbulentPrandtlBulk(data);
+    } else {
+        panic!("Du heat exchanger tube side should use Gnielinski");
+    }
+
+    let mut options = du_heat_exchanger.get_options().clone();
+    options.tube_side_correlation_validity_monitor.policy =
+        CorrelationValidityPolicy::Error;
+    du_heat_exchanger.try_set_options(options).unwrap();
+
+    assert!(du_heat_exchanger
+        .lateral_and_miscellaneous_connections(
+            correct_for_prandtl_wall_temperatures,
+            MassRate::new::<kilogram_per_second>(0.01),
+            MassRate::new::<kilogram_per_second>(-8.0)).is_err());
+
+    let turbulent_gnielinski = du_heat_exchanger.get_options()
+        .tube_side_correlation_validity_monitor
+        .get_record("Gnielinski turbulent").unwrap();
+    assert_eq!(turbulent_gnielinski.number_of_extrapolations, 1);
+    assert!(turbulent_gnielinski.max_reynolds_extrapolation > 0.9);
+}
@@ -0,0 +1,122 @@
+/// rates Du's heat exchanger at the set A inlet conditions 
+/// using the effectiveness-NTU method, checks that the energy 
+/// balance closes and that sizing for the rated heat duty 
+/// gives back the current tube length and number of tubes
+///
+/// Du, B. C., He, Y. L., Qiu, Y., Liang, Q., & Zhou, Y. P. 
+/// (2018). Investigation on heat transfer characteristics of 
+/// molten salt in a shell-and-tube heat exchanger. International 
+/// Communications in Heat and Mass Transfer, 96, 61-68.
+#[test]
+pub fn du_sthe_effectiveness_ntu_rating_and_sizing(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::power::watt;
+    use uom::si::ratio::ratio;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::volume_rate::cubic_meter_per_hour;
+
+    use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+    use crate::heat_transfer_correlations::thermal_resistance::subtract_two_thermodynamic_temperatures;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+
+    let mut du_heat_exchanger = 
+        SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+
+    // set A, HITEC in the shell side, YD325 in the tube side
+    let inlet_temp_salt = 
+        ThermodynamicTemperature::new::<degree_celsius>(214.93);
+    let inlet_temp_oil = 
+        ThermodynamicTemperature::new::<degree_celsius>(74.49);
+
+    let vol_flowrate_salt = 
+        VolumeRate::new::<cubic_meter_per_hour>(12.63);
+    let vol_flowrate_oil = 
+        VolumeRate::new::<cubic_meter_per_hour>(15.635);
+
+    let salt_mass_flowrate: MassRate = vol_flowrate_salt * 
+        LiquidMaterial::HITEC.try_get_density(inlet_temp_salt).unwrap();
+    let oil_mass_flowrate: MassRate = vol_flowrate_oil * 
+        LiquidMaterial::YD325.try_get_density(inlet_temp_oil).unwrap();
+
+    let correct_for_prandtl_wall_temperatures = true;
+
+    let rating = du_heat_exchanger.try_rate_with_effectiveness_ntu(
+        correct_for_prandtl_wall_temperatures, 
+        inlet_temp_oil, 
+        oil_mass_flowrate, 
+        inlet_temp_salt, 
+        salt_mass_flowrate).unwrap();
+
+    // salt is the hot stream
+    assert_eq!(rating.hot_inlet_temperature, inlet_temp_salt);
+
+    let salt_cp = LiquidMaterial::HITEC.try_get_cp(inlet_temp_salt).unwrap();
+    let oil_cp = LiquidMaterial::YD325.try_get_cp(inlet_temp_oil).unwrap();
+
+    let salt_heat_loss: Power = salt_mass_flowrate * salt_cp * 
+        subtract_two_thermodynamic_temperatures(
+            rating.hot_inlet_temperature, rating.hot_outlet_temperature);
+    let oil_heat_gain: Power = oil_mass_flowrate * oil_cp * 
+        subtract_two_thermodynamic_temperatures(
+            rating.cold_outlet_temperature, rating.cold_inlet_temperature);
+
+    approx::assert_relative_eq!(
+        salt_heat_loss.get::<watt>(),
+        rating.heat_transfer_rate.get::<watt>(),
+        max_relative = 1e-9);
+    approx::assert_relative_eq!(
+        oil_heat_gain.get::<watt>(),
+        rating.heat_transfer_rate.get::<watt>(),
+        max_relative = 1e-9);
+
+    let effectiveness = rating.effectiveness.get::<ratio>();
+    assert!(effectiveness > 0.0 && effectiveness < 1.0);
+
+    let overall_conductance = du_heat_exchanger.get_tube_bundle_overall_conductance(
+        correct_for_prandtl_wall_temperatures, 
+        oil_mass_flowrate, 
+        salt_mass_flowrate);
+
+    assert!(overall_conductance.get::<watt_per_kelvin>() > 0.0);
+
+    // sizing for the rated duty should return the current design
+    let sized_tube_length = du_heat_exchanger.try_size_tube_length_for_heat_duty(
+        rating.heat_transfer_rate, 
+        inlet_temp_oil, 
+        oil_mass_flowrate, 
+        inlet_temp_salt, 
+        salt_mass_flowrate).unwrap();
+
+    approx::assert_relative_eq!(
+        sized_tube_length.get::<meter>(),
+        1.95,
+        max_relative = 1e-6);
+
+    let sized_number_of_tubes = du_heat_exchanger.try_size_number_of_tubes_for_heat_duty(
+        rating.heat_transfer_rate, 
+        inlet_temp_oil, 
+        oil_mass_flowrate, 
+        inlet_temp_salt, 
+        salt_mass_flowrate).unwrap();
+
+    assert_eq!(sized_number_of_tubes, 19);
+
+    // a larger duty needs more tubes
+    let larger_number_of_tubes = du_heat_exchanger.try_size_number_of_tubes_for_heat_duty(
+        rating.heat_transfer_rate * 1.2, 
+        inlet_temp_oil, 
+        oil_mass_flowrate, 
+        inlet_temp_salt, 
+        salt_mass_flowrate).unwrap();
+
+    assert!(larger_number_of_tubes > 19);
+
+    // make sure the mass flowrates are sensible, 
+    // a few kg/s on either side
+    assert!(salt_mass_flowrate.get::<kilogram_per_second>() > 1.0);
+    assert!(oil_mass_flowrate.get::<kilogram_per_second>() > 1.0);
+}
@@ -0,0 +1,301 @@
+/// checks that setting U-bend tube passes rebuilds the tube side
+/// arrays to be longer than the shell, and that the inner tube
+/// to shell side temperature mapping conserves energy
+#[test]
+pub fn u_bend_tube_passes_array_lengths_and_mapping(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::thermodynamic_temperature::kelvin;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::flow_arrangement::ShellAndTubeFlowArrangement;
+
+    let mut du_heat_exchanger =
+        SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+
+    assert_eq!(du_heat_exchanger.get_flow_arrangement(),
+        ShellAndTubeFlowArrangement::CounterCurrent);
+
+    let single_pass_area: Area =
+        du_heat_exchanger.circular_tube_bundle_heat_transfer_area_shell_side();
+
+    // 12 inner nodes, 14 nodes in total,
+    // so 4 tube passes and odd tube passes are not allowed
+    assert!(du_heat_exchanger.try_set_flow_arrangement(
+            ShellAndTubeFlowArrangement::UBendTubePasses(4)).is_err());
+    assert!(du_heat_exchanger.try_set_flow_arrangement(
+            ShellAndTubeFlowArrangement::UBendTubePasses(1)).is_err());
+
+    du_heat_exchanger.try_set_flow_arrangement(
+        ShellAndTubeFlowArrangement::UBendTubePasses(2)).unwrap();
+
+    let tube_side_fluid_array: FluidArray =
+        du_heat_exchanger.tube_side_fluid_array_for_single_tube
+        .clone().try_into().unwrap();
+    let inner_pipe_shell: SolidColumn =
+        du_heat_exchanger.inner_pipe_shell_array_for_single_tube
+        .clone().try_into().unwrap();
+
+    approx::assert_relative_eq!(
+        tube_side_fluid_array.get_component_length_immutable().get::<meter>(),
+        3.9,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        inner_pipe_shell.total_length.get::<meter>(),
+        3.9,
+        max_relative = 1e-12);
+    approx::assert_relative_eq!(
+        du_heat_exchanger.get_effective_length().get::<meter>(),
+        1.95,
+        max_relative = 1e-12);
+
+    // each U-tube covers the shell twice
+    approx::assert_relative_eq!(
+        du_heat_exchanger.circular_tube_bundle_heat_transfer_area_shell_side()
+        .get::<uom::si::area::square_meter>(),
+        2.0 * single_pass_area.get::<uom::si::area::square_meter>(),
+        max_relative = 1e-12);
+
+    // tube node j in the first pass sits next to shell nodes
+    // 2j and 2j+1, in the second pass, it runs back along the shell
+    let shell_side_temperatures: Vec<ThermodynamicTemperature> =
+        (0..14).map(|index| ThermodynamicTemperature::new::<kelvin>(
+                400.0 + index as f64)).collect();
+
+    let tube_node_temperatures = du_heat_exchanger
+        .map_shell_side_temperatures_to_tube_nodes(shell_side_temperatures);
+
+    approx::assert_relative_eq!(
+        tube_node_temperatures[0].get::<kelvin>(), 400.5);
+    approx::assert_relative_eq!(
+        tube_node_temperatures[6].get::<kelvin>(), 412.5);
+    approx::assert_relative_eq!(
+        tube_node_temperatures[7].get::<kelvin>(), 412.5);
+    approx::assert_relative_eq!(
+        tube_node_temperatures[13].get::<kelvin>(), 400.5);
+
+    let tube_temperatures: Vec<ThermodynamicTemperature> =
+        (0..14).map(|index| ThermodynamicTemperature::new::<kelvin>(
+                300.0 + index as f64)).collect();
+
+    let shell_node_temperatures = du_heat_exchanger
+        .map_tube_temperatures_to_shell_side_nodes(tube_temperatures.clone());
+
+    // shell node 0 sits next to tube nodes 0 and 13
+    approx::assert_relative_eq!(
+        shell_node_temperatures[0].get::<kelvin>(), 306.5);
+
+    // every tube node is counted tube_passes times over
+    // tube_passes shell nodes, so the sums match
+    let tube_sum: f64 = tube_temperatures.iter()
+        .map(|temperature| temperature.get::<kelvin>()).sum();
+    let shell_sum: f64 = shell_node_temperatures.iter()
+        .map(|temperature| temperature.get::<kelvin>()).sum();
+
+    approx::assert_relative_eq!(tube_sum, shell_sum, max_relative = 1e-12);
+
+    // going back to a single pass restores the tube length
+    du_heat_exchanger.try_set_flow_arrangement(
+        ShellAndTubeFlowArrangement::CoCurrent).unwrap();
+
+    approx::assert_relative_eq!(
+        du_heat_exchanger.circular_tube_bundle_heat_transfer_area_shell_side()
+        .get::<uom::si::area::square_meter>(),
+        single_pass_area.get::<uom::si::area::square_meter>(),
+        max_relative = 1e-12);
+}
+
+/// runs Du's heat exchanger with two U-bend tube passes to
+/// steady state, the outlet temperatures should match the
+/// effectiveness-NTU method for one shell pass, and the
+/// calibration functions should recover the UA without
+/// the caller specifying the flow arrangement
+///
+/// Du, B. C., He, Y. L., Qiu, Y., Liang, Q., & Zhou, Y. P.
+/// (2018). Investigation on heat transfer characteristics of
+/// molten salt in a shell-and-tube heat exchanger. International
+/// Communications in Heat and Mass Transfer, 96, 61-68.
+#[test]
+pub fn u_bend_tube_passes_steady_state_matches_effectiveness_ntu(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use uom::si::time::second;
+    use uom::si::volume_rate::cubic_meter_per_hour;
+    use uom::ConstZero;
+
+    use crate::boundary_conditions::BCType;
+    use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::flow_arrangement::ShellAndTubeFlowArrangement;
+
+    let mut sthe = SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+    let flow_arrangement = ShellAndTubeFlowArrangement::UBendTubePasses(2);
+    sthe.try_set_flow_arrangement(flow_arrangement).unwrap();
+
+    // set A conditions from Du's paper
+    let shell_inlet_temperature =
+        ThermodynamicTemperature::new::<degree_celsius>(214.93);
+    let tube_inlet_temperature =
+        ThermodynamicTemperature::new::<degree_celsius>(74.49);
+
+    let salt_density = LiquidMaterial::HITEC
+        .try_get_density(shell_inlet_temperature).unwrap();
+    let oil_density = LiquidMaterial::YD325
+        .try_get_density(tube_inlet_temperature).unwrap();
+
+    // for U-bend tubes, both streams flow from back to front,
+    // the flow arrangement takes care of how the tubes
+    // sit next to the shell side fluid
+    let tube_mass_flowrate: MassRate = oil_density *
+        VolumeRate::new::<cubic_meter_per_hour>(15.635);
+    let shell_mass_flowrate: MassRate = salt_density *
+        VolumeRate::new::<cubic_meter_per_hour>(12.63);
+
+    let mut tube_inlet_bc: HeatTransferEntity =
+        BCType::new_const_temperature(tube_inlet_temperature).into();
+    let mut shell_inlet_bc: HeatTransferEntity =
+        BCType::new_const_temperature(shell_inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity =
+        BCType::new_adiabatic_bc().into();
+
+    let max_time = Time::new::<second>(300.0);
+    let number_of_nodes = sthe.inner_nodes + 2;
+    let timestep = Time::new::<second>(0.05 * 10.0 / number_of_nodes as f64);
+    let mut simulation_time = Time::ZERO;
+    let prandtl_wall_correction_setting = true;
+
+    while max_time > simulation_time {
+
+        let tube_advection = HeatTransferInteractionType::new_advection_interaction(
+            tube_mass_flowrate, oil_density, oil_density);
+        let shell_advection = HeatTransferInteractionType::new_advection_interaction(
+            shell_mass_flowrate, salt_density, salt_density);
+
+        sthe.tube_side_fluid_array_for_single_tube
+            .link_to_back(&mut tube_inlet_bc, tube_advection).unwrap();
+        sthe.tube_side_fluid_array_for_single_tube
+            .link_to_front(&mut outlet_bc, tube_advection).unwrap();
+        sthe.shell_side_fluid_array
+            .link_to_back(&mut shell_inlet_bc, shell_advection).unwrap();
+        sthe.shell_side_fluid_array
+            .link_to_front(&mut outlet_bc, shell_advection).unwrap();
+
+        sthe.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_mass_flowrate,
+            shell_mass_flowrate).unwrap();
+
+        sthe.advance_timestep(timestep).unwrap();
+
+        simulation_time += timestep;
+    }
+
+    let tube_outlet_temperature: ThermodynamicTemperature =
+        *sthe.tube_side_fluid_array_for_single_tube
+        .get_temperature_vector().unwrap().last().unwrap();
+    let shell_outlet_temperature: ThermodynamicTemperature =
+        *sthe.shell_side_fluid_array
+        .get_temperature_vector().unwrap().last().unwrap();
+
+    // energy balance, the heat exchanger is adiabatic
+    let tube_heat_rate = sthe.get_tube_side_heat_rate_based_on_mass_flowrate(
+        tube_inlet_temperature, tube_outlet_temperature, tube_mass_flowrate);
+    let shell_heat_rate = sthe.get_shell_side_heat_rate_based_on_mass_flowrate(
+        shell_inlet_temperature, shell_outlet_temperature, shell_mass_flowrate);
+
+    approx::assert_relative_eq!(
+        tube_heat_rate.abs().value,
+        shell_heat_rate.abs().value,
+        max_relative = 0.02);
+
+    // effectiveness-NTU rating for one shell pass
+    let rating = sthe.try_rate_with_effectiveness_ntu(
+        prandtl_wall_correction_setting,
+        tube_inlet_temperature,
+        tube_mass_flowrate,
+        shell_inlet_temperature,
+        shell_mass_flowrate).unwrap();
+
+    approx::assert_relative_eq!(
+        tube_outlet_temperature.get::<degree_celsius>(),
+        rating.cold_outlet_temperature.get::<degree_celsius>(),
+        max_relative = 0.01);
+
+    // calibration recovers the UA from the outlet temperatures
+    // using the LMTD correction factor for the flow arrangement
+    let ua_from_temperatures =
+        sthe.get_ua_based_on_mass_flowrates_and_temperature_differences(
+            tube_inlet_temperature,
+            tube_outlet_temperature,
+            tube_mass_flowrate,
+            shell_inlet_temperature,
+            shell_outlet_temperature,
+            shell_mass_flowrate).unwrap();
+
+    let ua_from_conductance = sthe.get_tube_bundle_overall_conductance(
+        prandtl_wall_correction_setting,
+        tube_mass_flowrate,
+        shell_mass_flowrate);
+
+    approx::assert_relative_eq!(
+        ua_from_temperatures.get::<watt_per_kelvin>(),
+        ua_from_conductance.get::<watt_per_kelvin>(),
+        max_relative = 0.05);
+}
+
+/// single tube pass arrangements pair node i of the tube side with 
+/// node i of the shell side, so the signs of the mass flowrates 
+/// decide whether flow is co-current or counter current. 
+/// Lateral connections should return an error if these signs do 
+/// not match the flow arrangement
+#[test]
+pub fn single_pass_flow_directions_must_match_flow_arrangement(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::flow_arrangement::ShellAndTubeFlowArrangement;
+
+    let mut du_heat_exchanger =
+        SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+    let prandtl_wall_correction_setting = true;
+
+    let tube_mass_flowrate = MassRate::new::<kilogram_per_second>(4.0);
+    let shell_mass_flowrate = MassRate::new::<kilogram_per_second>(8.0);
+
+    // counter current by default
+    assert!(du_heat_exchanger.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_mass_flowrate,
+            -shell_mass_flowrate).is_ok());
+    assert!(du_heat_exchanger.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_mass_flowrate,
+            shell_mass_flowrate).is_err());
+
+    du_heat_exchanger.try_set_flow_arrangement(
+        ShellAndTubeFlowArrangement::CoCurrent).unwrap();
+
+    assert!(du_heat_exchanger.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_mass_flowrate,
+            shell_mass_flowrate).is_ok());
+    assert!(du_heat_exchanger.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_mass_flowrate,
+            -shell_mass_flowrate).is_err());
+
+    // no flow on one side fits either arrangement
+    assert!(du_heat_exchanger.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_mass_flowrate,
+            MassRate::new::<kilogram_per_second>(0.0)).is_ok());
+}
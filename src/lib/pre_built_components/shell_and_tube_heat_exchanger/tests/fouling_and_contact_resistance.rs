@@ -0,0 +1,150 @@
+/// fouling on both sides of the tubes should follow the textbook
+/// overall heat transfer coefficient based on the outer tube area
+///
+/// 1/U_fouled = 1/U_clean + R_f,i'' d_o/d_i + R_f,o''
+///
+/// the contact conductance between outer shell and insulation
+/// adds 1/(h_c pi D_o L_node) to the nodal resistance
+///
+/// Du, B. C., He, Y. L., Qiu, Y., Liang, Q., & Zhou, Y. P.
+/// (2018). Investigation on heat transfer characteristics of
+/// molten salt in a shell-and-tube heat exchanger. International
+/// Communications in Heat and Mass Transfer, 96, 61-68.
+#[test]
+pub fn fouling_and_contact_resistances_in_du_heat_exchanger(){
+
+    use std::f64::consts::PI;
+    use uom::si::f64::*;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::length::meter;
+    use uom::si::time::{hour, second};
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    use crate::boundary_conditions::BCType;
+    use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+
+    use crate::heat_transfer_correlations::fouling_and_contact_resistance::FoulingResistance;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+
+    let mut du_heat_exchanger =
+        SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+
+    let tube_mass_flowrate = MassRate::new::<kilogram_per_second>(4.0);
+    let shell_mass_flowrate = MassRate::new::<kilogram_per_second>(-8.0);
+    let correct_for_prandtl_wall_temperatures = true;
+
+    let u_clean: f64 = du_heat_exchanger.overall_htc_based_on_conductance(
+        correct_for_prandtl_wall_temperatures,
+        tube_mass_flowrate,
+        shell_mass_flowrate).get::<watt_per_square_meter_kelvin>();
+
+    let clean_insulation_conductance: f64 = du_heat_exchanger
+        .get_outer_pipe_shell_to_insulation_conductance().unwrap()
+        .get::<watt_per_kelvin>();
+
+    // typical fouling factors for oils and molten salts
+    // 0.0004 and 0.0002 m^2 K/W
+    let tube_side_fouling_factor = 0.0004;
+    let shell_side_fouling_factor = 0.0002;
+
+    let mut options = du_heat_exchanger.get_options().clone();
+    options.tube_side_fouling = Some(FoulingResistance::new_constant(
+            1.0/HeatTransfer::new::<watt_per_square_meter_kelvin>(
+                1.0/tube_side_fouling_factor)));
+    options.shell_side_fouling_on_tubes = Some(FoulingResistance::new_constant(
+            1.0/HeatTransfer::new::<watt_per_square_meter_kelvin>(
+                1.0/shell_side_fouling_factor)));
+    du_heat_exchanger.try_set_options(options).unwrap();
+
+    let u_fouled: f64 = du_heat_exchanger.overall_htc_based_on_conductance(
+        correct_for_prandtl_wall_temperatures,
+        tube_mass_flowrate,
+        shell_mass_flowrate).get::<watt_per_square_meter_kelvin>();
+
+    let d_o: f64 = du_heat_exchanger.tube_side_od.get::<meter>();
+    let d_i: f64 = du_heat_exchanger.tube_side_id.get::<meter>();
+
+    approx::assert_relative_eq!(
+        1.0/u_fouled,
+        1.0/u_clean + tube_side_fouling_factor * d_o/d_i + shell_side_fouling_factor,
+        max_relative = 1e-9);
+
+    // contact conductance of 1000 W/m^2 K between outer shell
+    // and insulation
+    let contact_conductance = 1000.0;
+    let mut options = du_heat_exchanger.get_options().clone();
+    options.outer_shell_to_insulation_contact_conductance =
+        Some(HeatTransfer::new::<watt_per_square_meter_kelvin>(contact_conductance));
+    du_heat_exchanger.try_set_options(options).unwrap();
+
+    let contact_insulation_conductance: f64 = du_heat_exchanger
+        .get_outer_pipe_shell_to_insulation_conductance().unwrap()
+        .get::<watt_per_kelvin>();
+
+    let node_length: f64 = du_heat_exchanger.get_effective_length().get::<meter>()
+        / (du_heat_exchanger.inner_nodes + 2) as f64;
+    let shell_od: f64 = du_heat_exchanger.shell_side_od.get::<meter>();
+
+    approx::assert_relative_eq!(
+        1.0/contact_insulation_conductance,
+        1.0/clean_insulation_conductance
+            + 1.0/(contact_conductance * PI * shell_od * node_length),
+        max_relative = 1e-9);
+
+    // fouling operating time advances with the timestep
+    let mut options = du_heat_exchanger.get_options().clone();
+    options.shell_side_fouling_on_outer_shell = Some(
+        FoulingResistance::new_asymptotic_growth(
+            1.0/HeatTransfer::new::<watt_per_square_meter_kelvin>(1.0e4),
+            1.0/HeatTransfer::new::<watt_per_square_meter_kelvin>(5.0e3),
+            Time::new::<hour>(1000.0)));
+    du_heat_exchanger.try_set_options(options).unwrap();
+
+    let tube_inlet_temperature =
+        ThermodynamicTemperature::new::<degree_celsius>(74.49);
+    let shell_inlet_temperature =
+        ThermodynamicTemperature::new::<degree_celsius>(214.93);
+    let oil_density = LiquidMaterial::YD325
+        .try_get_density(tube_inlet_temperature).unwrap();
+    let salt_density = LiquidMaterial::HITEC
+        .try_get_density(shell_inlet_temperature).unwrap();
+
+    let mut tube_inlet_bc: HeatTransferEntity =
+        BCType::new_const_temperature(tube_inlet_temperature).into();
+    let mut shell_inlet_bc: HeatTransferEntity =
+        BCType::new_const_temperature(shell_inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity =
+        BCType::new_adiabatic_bc().into();
+
+    let tube_advection = HeatTransferInteractionType::new_advection_interaction(
+        tube_mass_flowrate, oil_density, oil_density);
+    let shell_advection = HeatTransferInteractionType::new_advection_interaction(
+        shell_mass_flowrate, salt_density, salt_density);
+
+    du_heat_exchanger.tube_side_fluid_array_for_single_tube
+        .link_to_back(&mut tube_inlet_bc, tube_advection).unwrap();
+    du_heat_exchanger.tube_side_fluid_array_for_single_tube
+        .link_to_front(&mut outlet_bc, tube_advection).unwrap();
+    du_heat_exchanger.shell_side_fluid_array
+        .link_to_front(&mut shell_inlet_bc, shell_advection).unwrap();
+    du_heat_exchanger.shell_side_fluid_array
+        .link_to_back(&mut outlet_bc, shell_advection).unwrap();
+
+    du_heat_exchanger.lateral_and_miscellaneous_connections(
+        correct_for_prandtl_wall_temperatures,
+        tube_mass_flowrate,
+        shell_mass_flowrate).unwrap();
+    du_heat_exchanger.advance_timestep(Time::new::<second>(0.01)).unwrap();
+
+    let outer_shell_fouling = du_heat_exchanger.get_options()
+        .shell_side_fouling_on_outer_shell.unwrap();
+    approx::assert_relative_eq!(
+        outer_shell_fouling.operating_time.get::<second>(), 0.01);
+    approx::assert_relative_eq!(
+        du_heat_exchanger.get_options().tube_side_fouling.unwrap()
+        .operating_time.get::<second>(), 0.01);
+}
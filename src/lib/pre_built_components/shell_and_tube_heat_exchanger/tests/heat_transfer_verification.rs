@@ -202,6 +202,7 @@ pub fn basic_test_shell_and_tube_heat_exchanger(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     //let correct_for_prandtl_wall_temperatures = true;
@@ -801,6 +802,7 @@ pub fn basic_test_shell_and_tube_heat_exchanger_set_two(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     //let correct_for_prandtl_wall_temperatures = true;
@@ -1401,6 +1403,7 @@ pub fn basic_test_shell_and_tube_heat_exchanger_set_three(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     //let correct_for_prandtl_wall_temperatures = true;
@@ -270,6 +270,7 @@ pub fn du_test_shell_and_tube_heat_exchanger_set_a(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
             insulation_thickness: dummy_insulation_thickness,
+            options: Default::default(),
         };
 
     // from data, is set A1 
@@ -22,3 +22,15 @@ pub mod constructor_tests;
 
 /// calibration function tests 
 pub mod calibration_functions;
+
+/// effectiveness-NTU rating and sizing tests
+pub mod effectiveness_ntu_rating;
+
+/// co-current, counter current and U-bend flow arrangement tests
+pub mod flow_arrangement;
+
+/// fouling and contact resistance tests
+pub mod fouling_and_contact_resistance;
+
+/// correlation validity monitoring tests
+pub mod correlation_validity;
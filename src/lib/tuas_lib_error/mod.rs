@@ -49,6 +49,29 @@ pub enum TuasLibError {
     /// wrong heat transfer interaction type
     #[error("Wrong Heat Transfer Interaction Type")]
     WrongHeatTransferInteractionType,
+
+    /// root finder bounds do not bracket a root, ie. the residual
+    /// has the same sign at both bounds
+    #[error("root not bracketed between {lower_bound} and {upper_bound}")]
+    RootNotBracketed {
+        /// lower bound supplied to the root finder
+        lower_bound: f64,
+        /// upper bound supplied to the root finder
+        upper_bound: f64,
+    },
+
+    /// root finder did not converge within the maximum number 
+    /// of iterations
+    #[error("root finding did not converge within {iterations} iterations, \n 
+        last bracket was [{lower_bound}, {upper_bound}]")]
+    RootFindingNonConvergence {
+        /// lower end of the last bracket
+        lower_bound: f64,
+        /// upper end of the last bracket
+        upper_bound: f64,
+        /// number of iterations used
+        iterations: usize,
+    },
     
 }
 
@@ -89,6 +112,12 @@ impl Into<String> for TuasLibError {
             TuasLibError::WrongHeatTransferInteractionType => {
                 self.to_string()
             },
+            TuasLibError::RootNotBracketed { .. } => {
+                self.to_string()
+            },
+            TuasLibError::RootFindingNonConvergence { .. } => {
+                self.to_string()
+            },
 
 
         }